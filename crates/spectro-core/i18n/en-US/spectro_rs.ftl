@@ -14,5 +14,18 @@ menu-title = Please select an action:
 menu-measure = Measure Reflective Spot
 menu-measure-emissive = Measure Emissive (Monitor)
 menu-measure-ambient = Measure Ambient (Light Source)
+menu-measure-projector = Measure Projector (Screen at Distance)
+menu-measure-flash = Measure Flash (Strobe)
+menu-measure-transmissive = Measure Transmissive (Filter/Gel/Film)
+menu-transmission-reference = Capture Transmission Reference (Bare Light Source)
+menu-measure-flicker = Measure Flicker (Temporal Light Modulation)
 menu-calibrate = Restart Calibration
+menu-reference-meter = Match Reference Meter (Lux / Luminance)
 menu-exit = Exit
+flash-armed = Armed: fire the flash within 3 seconds...
+transmission-reference-prompt = Point the instrument at the bare light source (no filter), then press Enter...
+transmission-reference-captured = Transmission reference captured.
+transmission-reference-missing = Capture a transmission reference of the bare light source first.
+reference-meter-prompt = Reading of the reference meter for the same source, 0 to reset
+reference-meter-saved = Reference meter factor saved:
+reference-meter-reset = Reference meter factor reset to factory calibration.
@@ -14,5 +14,18 @@ menu-title = 请选择操作：
 menu-measure = 测量反射样色 (Reflective)
 menu-measure-emissive = 测量发射样色 (屏幕)
 menu-measure-ambient = 测量环境光 (光源)
+menu-measure-projector = 测量投影 (远距离屏幕)
+menu-measure-flash = 测量闪光灯 (频闪)
+menu-measure-transmissive = 测量透射 (滤光片/色纸/胶片)
+menu-transmission-reference = 采集透射参考 (裸光源)
+menu-measure-flicker = 测量频闪 (时域光调制)
 menu-calibrate = 重新校准
+menu-reference-meter = 匹配参考照度计/亮度计
 menu-exit = 退出
+flash-armed = 已就绪：请在 3 秒内触发闪光灯...
+transmission-reference-prompt = 请将仪器对准裸光源 (不加滤光片)，然后按回车键...
+transmission-reference-captured = 透射参考已采集。
+transmission-reference-missing = 请先采集裸光源的透射参考。
+reference-meter-prompt = 参考仪表对同一光源的读数，输入 0 重置
+reference-meter-saved = 已保存参考仪表校正系数：
+reference-meter-reset = 参考仪表校正系数已恢复出厂校准。
@@ -0,0 +1,184 @@
+//! Spectral Bandpass Correction
+//!
+//! Each band of a spectrometer integrates over the instrument's slit
+//! function, so a reading is the true spectrum convolved with a roughly
+//! triangular bandpass. With a bandpass as wide as the sampling interval (the
+//! ColorMunki samples at 10nm with a ~10nm bandpass) narrow features such as
+//! LED peaks and the edges of saturated inks are flattened.
+//!
+//! For a locally smooth spectrum, a triangular bandpass of FWHM w sampled at
+//! interval Δλ acts as M = R + α(Rᵢ₋₁ − 2Rᵢ + Rᵢ₊₁) with α = w²/(12Δλ²).
+//! Two ways of undoing it are offered:
+//!
+//! - **Stearns & Stearns** (1988): the one-step inverse
+//!   R = (1 + 2α)Mᵢ − α(Mᵢ₋₁ + Mᵢ₊₁), with α = 0.083 for a bandpass equal
+//!   to the interval.
+//! - **ASTM E2729**: iterative deconvolution of a triangular bandpass of
+//!   configurable width, refining R until re-convolving it reproduces M.
+//!
+//! Correction must happen on the native instrument grid, before
+//! interpolation or tristimulus integration. Corrected reflectance is
+//! weighted with ASTM E308 Table 6 instead of Table 5 (see [`crate::e308`]).
+
+use serde::{Deserialize, Serialize};
+
+use crate::e308::STEARNS_ALPHA;
+use crate::{Result, SpectroError};
+
+/// Iteration limit for the E2729 deconvolution.
+const MAX_ITERATIONS: usize = 50;
+
+/// The E2729 iteration stops once no band changes by more than this.
+const CONVERGENCE: f32 = 1e-6;
+
+/// Bandpass deconvolution method.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BandpassCorrection {
+    /// Stearns & Stearns, for a bandpass equal to the sampling interval.
+    Stearns,
+    /// ASTM E2729 iterative deconvolution.
+    E2729 {
+        /// FWHM of the triangular instrument bandpass, in nm.
+        bandwidth: f32,
+    },
+}
+
+impl BandpassCorrection {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BandpassCorrection::Stearns => "Stearns",
+            BandpassCorrection::E2729 { .. } => "E2729",
+        }
+    }
+
+    /// Correct `values` sampled at a uniform `interval` (nm). An E2729
+    /// bandwidth of zero leaves the values unchanged.
+    pub fn apply(&self, values: &[f32], interval: f32) -> Result<Vec<f32>> {
+        if values.len() < 3 {
+            return Err(SpectroError::Data(
+                "Bandpass correction needs at least 3 bands".into(),
+            ));
+        }
+        match *self {
+            BandpassCorrection::Stearns => Ok(stearns(values, STEARNS_ALPHA as f32)),
+            BandpassCorrection::E2729 { bandwidth } => {
+                let alpha = bandwidth * bandwidth / (12.0 * interval * interval);
+                // Beyond α = 0.25 the three-band model no longer describes the
+                // bandpass and the iteration diverges
+                if !(bandwidth >= 0.0 && alpha.is_finite() && alpha < 0.25) {
+                    return Err(SpectroError::Data(format!(
+                        "Bandwidth {}nm is out of range for a {}nm interval; \
+                         correct on the native instrument grid",
+                        bandwidth, interval
+                    )));
+                }
+                Ok(e2729(values, alpha))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for BandpassCorrection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BandpassCorrection::Stearns => write!(f, "Stearns"),
+            BandpassCorrection::E2729 { bandwidth } => write!(f, "E2729 {:.1}nm", bandwidth),
+        }
+    }
+}
+
+/// Second difference Rᵢ₋₁ − 2Rᵢ + Rᵢ₊₁, one-sided at the ends.
+fn second_difference(values: &[f32], i: usize) -> f32 {
+    let n = values.len();
+    match i {
+        0 => values[1] - values[0],
+        i if i == n - 1 => values[i - 1] - values[i],
+        i => values[i - 1] - 2.0 * values[i] + values[i + 1],
+    }
+}
+
+fn stearns(values: &[f32], alpha: f32) -> Vec<f32> {
+    (0..values.len())
+        .map(|i| values[i] - alpha * second_difference(values, i))
+        .collect()
+}
+
+/// Van Cittert iteration R ← R + (M − B·R), starting from R = M.
+fn e2729(measured: &[f32], alpha: f32) -> Vec<f32> {
+    let mut r = measured.to_vec();
+    for _ in 0..MAX_ITERATIONS {
+        let residual: Vec<f32> = (0..r.len())
+            .map(|i| measured[i] - (r[i] + alpha * second_difference(&r, i)))
+            .collect();
+        for (v, d) in r.iter_mut().zip(&residual) {
+            *v += d;
+        }
+        if residual.iter().all(|d| d.abs() < CONVERGENCE) {
+            break;
+        }
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_correction_restores_peak() {
+        // Narrow peak, then blurred by a bandpass equal to the interval
+        let truth = [0.0, 0.0, 0.1, 0.6, 1.0, 0.6, 0.1, 0.0, 0.0];
+        let alpha = 1.0 / 12.0;
+        let measured: Vec<f32> = (0..truth.len())
+            .map(|i| truth[i] + alpha * second_difference(&truth, i))
+            .collect();
+        assert!(measured[4] < 0.95);
+
+        let exact = BandpassCorrection::E2729 { bandwidth: 10.0 }
+            .apply(&measured, 10.0)
+            .unwrap();
+        for (e, t) in exact.iter().zip(&truth) {
+            assert!((e - t).abs() < 1e-4, "{:?}", exact);
+        }
+
+        // The one-step correction recovers most of the peak
+        let stearns = BandpassCorrection::Stearns.apply(&measured, 10.0).unwrap();
+        assert!(stearns[4] > measured[4] && (stearns[4] - 1.0).abs() < 0.03);
+
+        // Flat data is unchanged
+        let flat = BandpassCorrection::Stearns.apply(&[0.5; 5], 10.0).unwrap();
+        assert!(flat.iter().all(|v| (v - 0.5).abs() < 1e-6));
+
+        assert!(BandpassCorrection::E2729 { bandwidth: 10.0 }
+            .apply(&measured, 1.0)
+            .is_err());
+        assert!(BandpassCorrection::E2729 { bandwidth: -1.0 }
+            .apply(&measured, 10.0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_edges_and_zero_width() {
+        // Peaks in the first and last bands, blurred with the one-sided
+        // bandpass at the ends
+        let truth = [1.0, 0.4, 0.1, 0.0, 0.2, 0.5, 0.9];
+        let alpha = 1.0 / 12.0;
+        let measured: Vec<f32> = (0..truth.len())
+            .map(|i| truth[i] + alpha * second_difference(&truth, i))
+            .collect();
+        assert!(measured[0] < truth[0] && measured[6] < truth[6]);
+
+        let exact = BandpassCorrection::E2729 { bandwidth: 10.0 }
+            .apply(&measured, 10.0)
+            .unwrap();
+        assert!((exact[0] - 1.0).abs() < 1e-4 && (exact[6] - 0.9).abs() < 1e-4);
+        let stearns = BandpassCorrection::Stearns.apply(&measured, 10.0).unwrap();
+        assert!(stearns[0] > measured[0] && stearns[6] > measured[6]);
+
+        // A zero-width bandpass needs no correction
+        let identity = BandpassCorrection::E2729 { bandwidth: 0.0 }
+            .apply(&measured, 10.0)
+            .unwrap();
+        assert_eq!(identity, measured);
+    }
+}
@@ -1,6 +1,6 @@
 //! CIECAM02 Color Appearance Model and CAM02-UCS Uniform Color Space.
 //!
-//! Used by TM-30-18 for perceptual color difference calculations.
+//! Used by TM-30-20 and CIE 224 for perceptual color difference calculations.
 
 use crate::colorimetry::XYZ;
 
@@ -15,6 +15,8 @@ pub struct ViewingConditions {
     pub wp: XYZ,
     /// Surround parameters (default: Average)
     pub surround: Surround,
+    /// Degree of adaptation D; derived from `la` and the surround when `None`
+    pub d: Option<f32>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -49,8 +51,16 @@ impl ViewingConditions {
             la,
             yb,
             surround,
+            d: None,
         }
     }
+
+    /// Assume complete adaptation to the white point (D = 1), as CIE 224
+    /// and TM-30 do.
+    pub fn with_full_adaptation(mut self) -> Self {
+        self.d = Some(1.0);
+        self
+    }
 }
 
 impl Default for ViewingConditions {
@@ -64,6 +74,7 @@ impl Default for ViewingConditions {
                 z: 1.08883,
             }, // D65
             surround: Surround::AVERAGE,
+            d: None,
         }
     }
 }
@@ -136,6 +147,7 @@ pub struct Cam02State {
     c: f32,
     nc: f32,
     fl: f32,
+    n: f32,
     nbb: f32,
     ncb: f32,
     z: f32,
@@ -151,6 +163,7 @@ impl Cam02State {
             yb,
             wp,
             surround,
+            d,
         } = vc;
         let Surround { f, c, nc } = surround;
 
@@ -171,7 +184,9 @@ impl Cam02State {
             0.0030 * wp.x + 0.0136 * wp.y + 0.9834 * wp.z,
         ];
 
-        let d = (f * (1.0 - (1.0 / 3.6) * ((-la - 42.0) / 92.0).exp())).clamp(0.0, 1.0);
+        let d = d
+            .unwrap_or_else(|| f * (1.0 - (1.0 / 3.6) * ((-la - 42.0) / 92.0).exp()))
+            .clamp(0.0, 1.0);
 
         let mut rgb_cw = [0.0f32; 3];
         for i in 0..3 {
@@ -195,6 +210,7 @@ impl Cam02State {
             c: *c,
             nc: *nc,
             fl,
+            n,
             nbb,
             ncb,
             z,
@@ -246,7 +262,7 @@ impl Cam02State {
 
         let t = (50000.0 / 13.0) * self.nc * self.ncb * et * (a * a + b * b).sqrt()
             / (rgb_a[0] + rgb_a[1] + 1.05 * rgb_a[2]);
-        let c = t.powf(0.9) * (j / 100.0).sqrt() * (1.64 - 0.29f32.powf(self.nbb)).powf(0.73);
+        let c = t.powf(0.9) * (j / 100.0).sqrt() * (1.64 - 0.29f32.powf(self.n)).powf(0.73);
 
         // Step 5: CAM02-UCS scaling (Luo et al. 2006)
         // Using the UCS (Uniform Color Space) coefficients
@@ -284,7 +300,7 @@ impl Cam02State {
 
         let c = m / self.fl.powf(0.25);
         let t =
-            (c / ((j / 100.0).sqrt() * (1.64 - 0.29f32.powf(self.nbb)).powf(0.73))).powf(1.0 / 0.9);
+            (c / ((j / 100.0).sqrt() * (1.64 - 0.29f32.powf(self.n)).powf(0.73))).powf(1.0 / 0.9);
         let et = 0.25 * ((h_rad + 2.0).cos() + 3.8);
 
         let ac = self.aw * (j / 100.0).powf(1.0 / (self.c * self.z));
@@ -0,0 +1,229 @@
+//! Correlated Color Temperature and Duv
+//!
+//! Implements Ohno (2013), "Practical Use and Calculation of CCT and Duv",
+//! LEUKOS 10(1). The chromaticity is located on a table of Planckian
+//! radiators in the CIE 1960 (u, v) diagram, the table is refined around
+//! the nearest entry, and CCT and Duv are solved from the three closest
+//! points:
+//!
+//! - **Triangular** solution close to the locus (|Duv| < 0.002).
+//! - **Parabolic** solution further away, where the triangle underestimates
+//!   the distance.
+//!
+//! Duv is signed: positive above the Planckian locus (greenish), negative
+//! below it (pinkish). The Planckian table uses the CIE 1931 2° observer and
+//! spans [`TABLE_RANGE`]; results outside [`CCT_RANGE`] or beyond
+//! [`MAX_DUV`] are reported but flagged by [`CctDuv::is_valid`].
+
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+use crate::cie_data;
+use crate::colorimetry::XYZ;
+
+/// CCT range in which the calculation is meaningful, in kelvin.
+pub const CCT_RANGE: RangeInclusive<f32> = 1000.0..=20000.0;
+
+/// Largest |Duv| for which a CCT is meaningful (CIE 015).
+pub const MAX_DUV: f32 = 0.05;
+
+/// Temperatures covered by the Planckian table, in kelvin.
+pub const TABLE_RANGE: RangeInclusive<f64> = 1000.0..=100_000.0;
+
+/// Second radiation constant c2 in m·K (CIE 015).
+const C2: f64 = 1.4388e-2;
+
+/// Ratio between neighbouring entries of the initial table (1% steps).
+const TABLE_STEP: f64 = 1.01;
+
+/// Refinement passes around the nearest entry, and entries per pass.
+const CASCADE_PASSES: usize = 6;
+const CASCADE_POINTS: usize = 10;
+
+/// Below this |Duv| the triangular solution is used.
+const TRIANGULAR_LIMIT: f64 = 0.002;
+
+/// Correlated color temperature and distance from the Planckian locus.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CctDuv {
+    /// Correlated color temperature in kelvin.
+    pub cct: f32,
+    /// Signed distance from the Planckian locus in CIE 1960 (u, v).
+    pub duv: f32,
+}
+
+impl CctDuv {
+    /// Whether the CCT lies in [`CCT_RANGE`] and |Duv| ≤ [`MAX_DUV`].
+    pub fn is_valid(&self) -> bool {
+        CCT_RANGE.contains(&self.cct) && self.duv.abs() <= MAX_DUV
+    }
+}
+
+/// CCT and Duv of CIE 1931 2° tristimulus values; `None` for black or when
+/// the chromaticity is nearest to either end of [`TABLE_RANGE`].
+pub fn from_xyz(xyz: &XYZ) -> Option<CctDuv> {
+    let denom = xyz.x as f64 + 15.0 * xyz.y as f64 + 3.0 * xyz.z as f64;
+    if !denom.is_finite() || denom <= 0.0 {
+        return None;
+    }
+    from_uv(4.0 * xyz.x as f64 / denom, 6.0 * xyz.y as f64 / denom)
+}
+
+/// CCT and Duv of CIE 1960 (u, v) chromaticity coordinates.
+pub fn from_uv(u: f64, v: f64) -> Option<CctDuv> {
+    let table = planckian_table();
+    let i = nearest(table, u, v);
+    if i == 0 || i == table.len() - 1 {
+        return None;
+    }
+
+    // Cascade: re-tabulate the interval around the nearest entry
+    let (mut lo, mut hi) = (table[i - 1].t, table[i + 1].t);
+    let mut points = Vec::with_capacity(CASCADE_POINTS);
+    for _ in 0..CASCADE_PASSES {
+        let ratio = (hi / lo).powf(1.0 / (CASCADE_POINTS - 1) as f64);
+        points.clear();
+        points.extend((0..CASCADE_POINTS).map(|k| planckian(lo * ratio.powi(k as i32))));
+        let j = nearest(&points, u, v).clamp(1, CASCADE_POINTS - 2);
+        lo = points[j - 1].t;
+        hi = points[j + 1].t;
+    }
+    let j = nearest(&points, u, v).clamp(1, CASCADE_POINTS - 2);
+    let (p, c, n) = (&points[j - 1], &points[j], &points[j + 1]);
+    let (dp, dc, dn) = (p.distance(u, v), c.distance(u, v), n.distance(u, v));
+
+    // Triangular solution
+    let l = ((n.u - p.u).powi(2) + (n.v - p.v).powi(2)).sqrt();
+    let x = (dp * dp - dn * dn + l * l) / (2.0 * l);
+    let t_tri = p.t + (n.t - p.t) * x / l;
+    let v_tri = p.v + (n.v - p.v) * x / l;
+    let sign = if v >= v_tri { 1.0 } else { -1.0 };
+    let duv_tri = sign * (dp * dp - x * x).max(0.0).sqrt();
+
+    let (t, duv) = if duv_tri.abs() < TRIANGULAR_LIMIT {
+        (t_tri, duv_tri)
+    } else {
+        // Parabolic solution through the three distances
+        let d = (n.t - c.t) * (p.t - n.t) * (c.t - p.t);
+        let a = (p.t * (dn - dc) + c.t * (dp - dn) + n.t * (dc - dp)) / d;
+        let b = -(p.t * p.t * (dn - dc) + c.t * c.t * (dp - dn) + n.t * n.t * (dc - dp)) / d;
+        let k = -(dp * (n.t - c.t) * c.t * n.t
+            + dc * (p.t - n.t) * p.t * n.t
+            + dn * (c.t - p.t) * p.t * c.t)
+            / d;
+        let t = -b / (2.0 * a);
+        (t, sign * (a * t * t + b * t + k))
+    };
+
+    Some(CctDuv {
+        cct: t as f32,
+        duv: duv as f32,
+    })
+}
+
+/// A Planckian radiator and its CIE 1960 chromaticity.
+#[derive(Debug, Clone, Copy)]
+struct LocusPoint {
+    t: f64,
+    u: f64,
+    v: f64,
+}
+
+impl LocusPoint {
+    fn distance(&self, u: f64, v: f64) -> f64 {
+        ((u - self.u).powi(2) + (v - self.v).powi(2)).sqrt()
+    }
+}
+
+/// Chromaticity of a Planckian radiator at `t` kelvin.
+fn planckian(t: f64) -> LocusPoint {
+    let (mut x, mut y, mut z) = (0.0f64, 0.0f64, 0.0f64);
+    for (i, cmf) in cie_data::CIE1931_2.iter().enumerate() {
+        let wavelength = (cie_data::START as f64 + i as f64) * 1e-9;
+        // The constant c1 cancels in the chromaticity
+        let m = wavelength.powi(-5) / ((C2 / (wavelength * t)).exp() - 1.0);
+        x += m * cmf[0] as f64;
+        y += m * cmf[1] as f64;
+        z += m * cmf[2] as f64;
+    }
+    let denom = x + 15.0 * y + 3.0 * z;
+    LocusPoint {
+        t,
+        u: 4.0 * x / denom,
+        v: 6.0 * y / denom,
+    }
+}
+
+/// Initial table over [`TABLE_RANGE`] in 1% steps, built on first use.
+fn planckian_table() -> &'static [LocusPoint] {
+    static TABLE: OnceLock<Vec<LocusPoint>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = Vec::new();
+        let mut t = *TABLE_RANGE.start();
+        while t <= *TABLE_RANGE.end() {
+            table.push(planckian(t));
+            t *= TABLE_STEP;
+        }
+        table
+    })
+}
+
+fn nearest(points: &[LocusPoint], u: f64, v: f64) -> usize {
+    points
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.distance(u, v).total_cmp(&b.1.distance(u, v)))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Illuminant;
+
+    #[test]
+    fn test_ohno_2013() {
+        // Worked example from the colour-science implementation
+        let r = from_uv(0.1978, 0.3122).unwrap();
+        assert!((r.cct - 6507.5).abs() < 3.0, "{:?}", r);
+        assert!((r.duv - 0.00322).abs() < 2e-5, "{:?}", r);
+
+        // Points on the locus, and offset perpendicular to it (up is +Duv)
+        for t in [1500.0, 2856.0, 6500.0, 15000.0] {
+            let p = planckian(t);
+            let on = from_uv(p.u, p.v).unwrap();
+            assert!((on.cct as f64 - t).abs() < 0.5 && on.duv.abs() < 1e-5);
+
+            let q = planckian(t * 1.0001);
+            let (du, dv) = (q.u - p.u, q.v - p.v);
+            let norm = (du * du + dv * dv).sqrt();
+            for offset in [0.001, -0.02, 0.04] {
+                let r = from_uv(p.u + offset * dv / norm, p.v - offset * du / norm).unwrap();
+                assert!(
+                    (r.duv as f64 - offset).abs() < 1e-4,
+                    "{} {}: {:?}",
+                    t,
+                    offset,
+                    r
+                );
+                assert!(
+                    ((r.cct as f64 - t) / t).abs() < 2e-3,
+                    "{} {}: {:?}",
+                    t,
+                    offset,
+                    r
+                );
+            }
+        }
+
+        let a = from_xyz(&Illuminant::A.get_white_point(crate::Observer::CIE1931_2)).unwrap();
+        assert!((a.cct - 2856.0).abs() < 2.0 && a.duv.abs() < 1e-4 && a.is_valid());
+        let far = CctDuv {
+            cct: 5000.0,
+            duv: 0.06,
+        };
+        assert!(!far.is_valid());
+        assert!(from_uv(0.45, 0.2).is_none() || !from_uv(0.45, 0.2).unwrap().is_valid());
+    }
+}
@@ -0,0 +1,371 @@
+//! CIE 2006 Individual Observers
+//!
+//! CIE 170-1 builds the cone fundamentals from physiological components:
+//!
+//! l̄(λ) ∝ λ · (1 − 10^(−D_L·A_L(λ))) · 10^(−D_mac·M(λ) − D_ocul(λ))
+//!
+//! where A is the low-density photopigment absorbance, M the macular pigment
+//! density and D_ocul the density of the lens and ocular media. Field size f
+//! (degrees) sets the peak photopigment densities, D_L = D_M =
+//! 0.38 + 0.54·e^(−f/1.333) and D_S = 0.30 + 0.45·e^(−f/1.333), and the
+//! peak macular density 0.485·e^(−f/6.132). Age a (years) scales the ocular
+//! density by 1 + 0.02(a − 32) up to 60 and by 1.56 + 0.0667(a − 60) beyond.
+//!
+//! The component spectra in [`cie_data`] were recovered from the standard 2°
+//! and 10° fundamentals; the model reproduces the 2° table exactly and the
+//! 10° table to within 0.01 of peak. CIE 170-1 ages only one of the two
+//! components of the ocular density, while here the whole density is scaled.
+//!
+//! [`observer_metamerism`] runs a population of these observers over a pair
+//! of stimuli that match for the standard observer (say the whites of an
+//! OLED and an LCD reference monitor) to show how differently real viewers
+//! see them.
+
+use std::ops::RangeInclusive;
+
+use crate::colorimetry::XYZ;
+use crate::spectrum::SpectralData;
+use crate::{cie_data, Illuminant, Observer, Result, SpectroError};
+
+/// Ages covered by the CIE 170-1 model, in years.
+pub const AGE_RANGE: RangeInclusive<f32> = 20.0..=80.0;
+
+/// Field sizes covered by the CIE 170-1 model, in degrees.
+pub const FIELD_SIZE_RANGE: RangeInclusive<f32> = 1.0..=10.0;
+
+/// Age of the standard observers, in years.
+pub const STANDARD_AGE: f32 = 32.0;
+
+/// An observer of a given age and field size.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct IndividualObserver {
+    /// Age in years.
+    pub age: f32,
+    /// Field size in degrees.
+    pub field_size: f32,
+}
+
+impl IndividualObserver {
+    /// # Errors
+    ///
+    /// Returns an error if `age` or `field_size` is outside [`AGE_RANGE`] or
+    /// [`FIELD_SIZE_RANGE`].
+    pub fn new(age: f32, field_size: f32) -> Result<Self> {
+        if !AGE_RANGE.contains(&age) {
+            return Err(SpectroError::Data(format!(
+                "Observer age {} is outside 20-80 years",
+                age
+            )));
+        }
+        if !FIELD_SIZE_RANGE.contains(&field_size) {
+            return Err(SpectroError::Data(format!(
+                "Field size {}° is outside 1-10°",
+                field_size
+            )));
+        }
+        Ok(Self { age, field_size })
+    }
+
+    /// Energy-based cone fundamentals (l̄, m̄, s̄), 1nm from 380nm to 780nm,
+    /// each normalized to a peak of 1.
+    pub fn lms_1nm(&self) -> Vec<[f32; 3]> {
+        let decay = (-self.field_size / 1.333).exp();
+        let pigment = [
+            0.38 + 0.54 * decay,
+            0.38 + 0.54 * decay,
+            0.30 + 0.45 * decay,
+        ];
+        let macular = 0.485 * (-self.field_size / 6.132).exp();
+        let ocular = if self.age <= 60.0 {
+            1.0 + 0.02 * (self.age - STANDARD_AGE)
+        } else {
+            1.56 + 0.0667 * (self.age - 60.0)
+        };
+
+        let mut lms: Vec<[f32; 3]> = (0..cie_data::LEN)
+            .map(|i| {
+                let wavelength = cie_data::START + i as f32;
+                let filter = 10f32.powf(
+                    -macular * cie_data::CIE2006_MACULAR[i] - ocular * cie_data::CIE2006_OCULAR[i],
+                );
+                let absorbance = cie_data::CIE2006_ABSORBANCE[i];
+                let mut cones = [0.0; 3];
+                for c in 0..3 {
+                    let absorptance = 1.0 - 10f32.powf(-pigment[c] * absorbance[c]);
+                    cones[c] = wavelength * absorptance * filter;
+                }
+                cones
+            })
+            .collect();
+
+        for c in 0..3 {
+            let peak = lms.iter().map(|v| v[c]).fold(0.0, f32::max);
+            if peak > 0.0 {
+                lms.iter_mut().for_each(|v| v[c] /= peak);
+            }
+        }
+        lms
+    }
+
+    /// Color matching functions (x̄, ȳ, z̄), 1nm from 380nm to 780nm: the
+    /// cone fundamentals through the CIE 170-2 matrix of the 2° observer for
+    /// fields under 4°, otherwise that of the 10° observer.
+    pub fn cmfs_1nm(&self) -> Vec<[f32; 3]> {
+        let standard = if self.field_size < 4.0 {
+            Observer::CIE2015_2
+        } else {
+            Observer::CIE2015_10
+        };
+        let m = standard
+            .lms_to_xyz()
+            .expect("CIE 2015 observers are cone-based");
+        self.lms_1nm()
+            .iter()
+            .map(|c| {
+                let mut xyz = [0.0; 3];
+                for (row, v) in xyz.iter_mut().enumerate() {
+                    *v = (0..3).map(|k| m[row][k] * c[k]).sum();
+                }
+                xyz
+            })
+            .collect()
+    }
+
+    /// Observers aged 20 to 80 in 5-year steps at one field size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `field_size` is outside [`FIELD_SIZE_RANGE`].
+    pub fn population(field_size: f32) -> Result<Vec<Self>> {
+        (0..=12)
+            .map(|i| Self::new(20.0 + 5.0 * i as f32, field_size))
+            .collect()
+    }
+}
+
+/// How the color difference between two stimuli varies across observers.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MetamerismSpread {
+    /// CIEDE2000 difference seen by each observer, in population order.
+    pub delta_e: Vec<(IndividualObserver, f32)>,
+    pub mean: f32,
+    pub min: f32,
+    pub max: f32,
+    /// Standard deviation of the differences across the population.
+    pub std_dev: f32,
+}
+
+/// The CIEDE2000 difference between `a` and `b` for each observer in
+/// `population`.
+///
+/// Spectral power is compared with `a` as the adapted white, so the whites of
+/// two displays are judged as a viewer adapted to the reference would see
+/// them. Reflectance and transmittance are seen under `illuminant`.
+///
+/// # Errors
+///
+/// Returns an error if either spectrum is invalid, the two are not the same
+/// kind of quantity, `a` has no luminance, or `population` is empty.
+pub fn observer_metamerism(
+    a: &SpectralData,
+    b: &SpectralData,
+    illuminant: Illuminant,
+    population: &[IndividualObserver],
+) -> Result<MetamerismSpread> {
+    if a.quantity.is_emissive() != b.quantity.is_emissive() {
+        return Err(SpectroError::Data(
+            "Cannot compare spectral power with a reflectance or transmittance factor".into(),
+        ));
+    }
+    if population.is_empty() {
+        return Err(SpectroError::Data("No observers to compare".into()));
+    }
+    let emissive = a.quantity.is_emissive();
+    let (a, b) = (to_1nm(a)?, to_1nm(b)?);
+
+    let mut delta_e = Vec::with_capacity(population.len());
+    for observer in population {
+        let cmfs = observer.cmfs_1nm();
+        let (white, xyz_a, xyz_b) = if emissive {
+            let xyz_a = integrate(&a, &cmfs, None);
+            if xyz_a.y <= 0.0 {
+                return Err(SpectroError::Data(
+                    "Reference stimulus has no luminance".into(),
+                ));
+            }
+            (xyz_a, xyz_a, integrate(&b, &cmfs, None))
+        } else {
+            let spd = illuminant.spd_1nm();
+            let white = (0..cie_data::LEN).fold(
+                XYZ {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+                |w, i| XYZ {
+                    x: w.x + spd[i] * cmfs[i][0],
+                    y: w.y + spd[i] * cmfs[i][1],
+                    z: w.z + spd[i] * cmfs[i][2],
+                },
+            );
+            (
+                white,
+                integrate(&a, &cmfs, Some(spd)),
+                integrate(&b, &cmfs, Some(spd)),
+            )
+        };
+        let lab_a = xyz_a.to_lab(white);
+        let lab_b = xyz_b.to_lab(white);
+        delta_e.push((*observer, lab_a.delta_e_2000(&lab_b)));
+    }
+
+    let n = delta_e.len() as f32;
+    let mean = delta_e.iter().map(|(_, d)| d).sum::<f32>() / n;
+    let variance = delta_e.iter().map(|(_, d)| (d - mean).powi(2)).sum::<f32>() / n;
+    Ok(MetamerismSpread {
+        mean,
+        min: delta_e
+            .iter()
+            .map(|(_, d)| *d)
+            .fold(f32::INFINITY, f32::min),
+        max: delta_e.iter().map(|(_, d)| *d).fold(0.0, f32::max),
+        std_dev: variance.sqrt(),
+        delta_e,
+    })
+}
+
+/// Values at whole nanometres inside the data range, with their index into
+/// the 1nm tables. Data outside 380-780nm is dropped.
+fn to_1nm(data: &SpectralData) -> Result<Vec<(usize, f32)>> {
+    data.validate()?;
+    let start = data.wavelengths[0].max(cie_data::START).ceil();
+    let end = data.wavelengths[data.wavelengths.len() - 1]
+        .min(cie_data::START + (cie_data::LEN - 1) as f32)
+        .floor();
+    if end <= start {
+        return Err(SpectroError::Data(
+            "Spectrum does not overlap 380-780nm".into(),
+        ));
+    }
+    let fine = data.try_resample(start, end, 1.0)?;
+    Ok(fine
+        .wavelengths
+        .iter()
+        .zip(&fine.values)
+        .map(|(w, v)| ((w - cie_data::START).round() as usize, *v))
+        .collect())
+}
+
+/// Σ value · (illuminant) · CMF over the samples.
+fn integrate(samples: &[(usize, f32)], cmfs: &[[f32; 3]], spd: Option<&[f32]>) -> XYZ {
+    samples.iter().fold(
+        XYZ {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+        |acc, &(i, v)| {
+            let v = v * spd.map_or(1.0, |s| s[i]);
+            XYZ {
+                x: acc.x + v * cmfs[i][0],
+                y: acc.y + v * cmfs[i][1],
+                z: acc.z + v * cmfs[i][2],
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Quantity;
+
+    #[test]
+    fn test_standard_observers_reproduced() {
+        let standard = IndividualObserver::new(STANDARD_AGE, 2.0).unwrap();
+        for (v, t) in standard.lms_1nm().iter().zip(&cie_data::CIE2006_LMS_2) {
+            for c in 0..3 {
+                assert!((v[c] - t[c]).abs() < 1e-4);
+            }
+        }
+        let large = IndividualObserver::new(STANDARD_AGE, 10.0).unwrap();
+        for (v, t) in large.lms_1nm().iter().zip(&cie_data::CIE2006_LMS_10) {
+            for c in 0..3 {
+                assert!((v[c] - t[c]).abs() < 0.01);
+            }
+        }
+
+        // The yellowing lens absorbs short wavelengths
+        let old = IndividualObserver::new(75.0, 2.0).unwrap().lms_1nm();
+        let young = IndividualObserver::new(20.0, 2.0).unwrap().lms_1nm();
+        assert!(old[60][2] < 0.85 * young[60][2]);
+
+        assert!(IndividualObserver::new(10.0, 2.0).is_err());
+        assert!(IndividualObserver::new(40.0, 20.0).is_err());
+    }
+
+    #[test]
+    fn test_observer_metamerism() {
+        let population = IndividualObserver::population(2.0).unwrap();
+        assert_eq!(population.len(), 13);
+
+        // A smooth white and a narrow-band RGB white with the same
+        // chromaticity for the 32-year-old differ for the other observers
+        let wavelengths: Vec<f32> = (380..=780).map(|w| w as f32).collect();
+        let broad: Vec<f32> = wavelengths.iter().map(|_| 1.0).collect();
+        let band = |c: f32, w: f32| move |l: f32| (-((l - c) / w).powi(2)).exp();
+        let (r, g, b) = (band(630.0, 8.0), band(532.0, 8.0), band(465.0, 8.0));
+        let cmfs = IndividualObserver::new(STANDARD_AGE, 2.0)
+            .unwrap()
+            .cmfs_1nm();
+        let xyz = |f: &dyn Fn(f32) -> f32| {
+            wavelengths
+                .iter()
+                .enumerate()
+                .fold([0.0f32; 3], |acc, (i, &l)| {
+                    let v = f(l);
+                    [
+                        acc[0] + v * cmfs[i][0],
+                        acc[1] + v * cmfs[i][1],
+                        acc[2] + v * cmfs[i][2],
+                    ]
+                })
+        };
+        // Solve for primary weights matching the broad white
+        let (pr, pg, pb, target) = (xyz(&r), xyz(&g), xyz(&b), xyz(&|_| 1.0));
+        let m = [
+            [pr[0], pg[0], pb[0]],
+            [pr[1], pg[1], pb[1]],
+            [pr[2], pg[2], pb[2]],
+        ];
+        let det = |m: [[f32; 3]; 3]| {
+            m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+        };
+        let weight = |col: usize| {
+            let mut mc = m;
+            for row in 0..3 {
+                mc[row][col] = target[row];
+            }
+            det(mc) / det(m)
+        };
+        let (wr, wg, wb) = (weight(0), weight(1), weight(2));
+        let narrow: Vec<f32> = wavelengths
+            .iter()
+            .map(|&l| wr * r(l) + wg * g(l) + wb * b(l))
+            .collect();
+
+        let a =
+            SpectralData::from_grid(wavelengths.clone(), broad, Quantity::RelativePower).unwrap();
+        let b = SpectralData::from_grid(wavelengths, narrow, Quantity::RelativePower).unwrap();
+        let standard = IndividualObserver::new(STANDARD_AGE, 2.0).unwrap();
+        let matched = observer_metamerism(&a, &b, Illuminant::D65, &[standard]).unwrap();
+        assert!(matched.max < 0.05, "{:?}", matched);
+        let spread = observer_metamerism(&a, &b, Illuminant::D65, &population).unwrap();
+        assert!(spread.max > 1.0 && spread.std_dev > 0.1, "{:?}", spread);
+
+        let reflectance = SpectralData::new(vec![0.5; 41]);
+        assert!(observer_metamerism(&a, &reflectance, Illuminant::D65, &population).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! CIE 224:2017 Colour Fidelity Index
+//!
+//! The general colour fidelity index Rf and the special indices Rf,i for the
+//! 99 colour evaluation samples (CES) of [`tm30_data`](crate::tm30_data),
+//! compared in CAM02-UCS. CIE 224 shares its samples and color space with
+//! IES TM-30 but fixes the details that lighting specifications cite:
+//!
+//! - Integration over 380-780nm at 1nm with the CIE 1964 10° observer; the
+//!   5nm CES are interpolated linearly.
+//! - Between 4000K and 5000K the reference is a blend of a Planckian
+//!   radiator and CIE daylight at the test CCT, each normalized to the same
+//!   luminance before mixing.
+//! - Complete chromatic adaptation (D = 1) with LA = 100cd/m², Yb = 20 and
+//!   an average surround.
+//! - Rf = 10·ln(exp((100 − 6.73·ΔE')/10) + 1).
+//!
+//! The CCT that selects the reference is computed with the CIE 1931 2°
+//! observer (see [`crate::cct`]).
+
+use crate::cam02::{Cam02State, Cam02Ucs, Surround, ViewingConditions};
+use crate::colorimetry::{illuminant, upsample_5nm, XYZ};
+use crate::spectrum::SpectralData;
+use crate::tm30_data::CES99_SPDS;
+use crate::{cie_data, Observer, Result, SpectroError};
+
+/// Scaling factor between ΔE' in CAM02-UCS and Rf.
+pub const CF: f32 = 6.73;
+
+/// Number of colour evaluation samples.
+pub const N_CES: usize = 99;
+
+/// Offset of 380nm in the 360-830nm, 5nm CES tables.
+const CES_OFFSET: usize = 4;
+
+/// CIE 224 colour fidelity indices of a light source.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Cie224Metrics {
+    /// General colour fidelity index Rf.
+    pub rf: f32,
+    /// Special colour fidelity indices Rf,i for CES 1-99.
+    pub rf_i: Vec<f32>,
+    /// CCT of the test source.
+    pub cct: f32,
+    /// Duv of the test source.
+    pub duv: f32,
+    /// CAM02-UCS coordinates of each CES under the test source.
+    pub test_ucs: Vec<Cam02Ucs>,
+    /// CAM02-UCS coordinates of each CES under the reference illuminant.
+    pub ref_ucs: Vec<Cam02Ucs>,
+}
+
+/// Fidelity index for a mean color difference ΔE' in CAM02-UCS.
+pub fn rf_from_delta_e(delta_e: f32) -> f32 {
+    10.0 * (((100.0 - CF * delta_e) / 10.0).exp() + 1.0).ln()
+}
+
+/// Reference illuminant for a CCT, 1nm from 380nm to 780nm, normalized to
+/// Y = 100 with the CIE 1931 2° observer.
+pub fn reference_spd(cct: f32) -> [f32; cie_data::LEN] {
+    let planckian = || normalize(illuminant::planckian_1nm(cct));
+    let daylight = || normalize(illuminant::daylight_1nm(cct));
+    if cct < 4000.0 {
+        planckian()
+    } else if cct > 5000.0 {
+        daylight()
+    } else {
+        let m = (cct - 4000.0) / 1000.0;
+        let (p, d) = (planckian(), daylight());
+        std::array::from_fn(|i| (1.0 - m) * p[i] + m * d[i])
+    }
+}
+
+/// Calculate the CIE 224 colour fidelity indices of a light source.
+pub fn calculate_rf(spd: &SpectralData) -> Result<Cie224Metrics> {
+    let spd = spd.resample(cie_data::START, 780.0, 1.0);
+    if spd.values.len() != cie_data::LEN {
+        return Err(SpectroError::Data(
+            "CIE 224 needs spectral data covering 380-780nm".into(),
+        ));
+    }
+    let cct = spd
+        .to_xyz_emissive_2()
+        .to_cct_duv()
+        .ok_or_else(|| SpectroError::Data("CCT of the source cannot be determined".into()))?;
+
+    let samples: Vec<[f32; cie_data::LEN]> = CES99_SPDS
+        .iter()
+        .map(|ces| {
+            let mut table = [0.0f32; cie_data::LEN_5NM];
+            table.copy_from_slice(&ces[CES_OFFSET..CES_OFFSET + cie_data::LEN_5NM]);
+            upsample_5nm(&table)
+        })
+        .collect();
+
+    let test_ucs = appearance(&spd.values, &samples);
+    let ref_ucs = appearance(&reference_spd(cct.cct), &samples);
+
+    let delta_e: Vec<f32> = test_ucs
+        .iter()
+        .zip(&ref_ucs)
+        .map(|(t, r)| t.distance(r))
+        .collect();
+    let mean = delta_e.iter().sum::<f32>() / N_CES as f32;
+
+    Ok(Cie224Metrics {
+        rf: rf_from_delta_e(mean),
+        rf_i: delta_e.into_iter().map(rf_from_delta_e).collect(),
+        cct: cct.cct,
+        duv: cct.duv,
+        test_ucs,
+        ref_ucs,
+    })
+}
+
+fn normalize(spd: [f32; cie_data::LEN]) -> [f32; cie_data::LEN] {
+    let y: f32 = spd
+        .iter()
+        .zip(Observer::CIE1931_2.cmfs_1nm())
+        .map(|(s, c)| s * c[1])
+        .sum();
+    spd.map(|s| s * 100.0 / y)
+}
+
+/// CAM02-UCS coordinates of each sample under a source, with the source
+/// as adapted white at Y = 100.
+fn appearance(source: &[f32], samples: &[[f32; cie_data::LEN]]) -> Vec<Cam02Ucs> {
+    let cmfs = Observer::CIE1964_10.cmfs_1nm();
+    let integrate = |reflectance: &dyn Fn(usize) -> f64| {
+        let mut xyz = [0.0f64; 3];
+        for (i, (&s, cmf)) in source.iter().zip(cmfs).enumerate() {
+            for (acc, &c) in xyz.iter_mut().zip(cmf) {
+                *acc += s as f64 * reflectance(i) * c as f64;
+            }
+        }
+        xyz
+    };
+    let white = integrate(&|_| 1.0);
+    let k = 100.0 / white[1];
+    let to_xyz = |v: [f64; 3]| XYZ {
+        x: (v[0] * k) as f32,
+        y: (v[1] * k) as f32,
+        z: (v[2] * k) as f32,
+    };
+
+    let vc = ViewingConditions::new(to_xyz(white), 100.0, 20.0, Surround::AVERAGE)
+        .with_full_adaptation();
+    let cam = Cam02State::new(&vc);
+    samples
+        .iter()
+        .map(|r| cam.xyz_to_ucs(to_xyz(integrate(&|i| r[i] as f64))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spectrum::Quantity;
+    use crate::Illuminant;
+
+    fn source(illuminant: Illuminant) -> SpectralData {
+        let wavelengths = (0..cie_data::LEN).map(|i| 380.0 + i as f32).collect();
+        let values = illuminant.spd_1nm().to_vec();
+        SpectralData::from_grid(wavelengths, values, Quantity::RelativePower).unwrap()
+    }
+
+    #[test]
+    fn test_cie224_fidelity() {
+        // A source that is its own reference
+        let a = calculate_rf(&source(Illuminant::A)).unwrap();
+        assert!(a.rf > 99.9 && a.rf_i.iter().all(|r| *r > 99.5), "{}", a.rf);
+        assert_eq!(a.rf_i.len(), N_CES);
+
+        // CIE 224 / TM-30-20 published values
+        for (illuminant, rf) in [
+            (Illuminant::F1, 80.68),
+            (Illuminant::F2, 70.21),
+            (Illuminant::F12, 77.7),
+        ] {
+            let m = calculate_rf(&source(illuminant)).unwrap();
+            assert!((m.rf - rf).abs() < 0.5, "{:?}: {}", illuminant, m.rf);
+        }
+
+        // The blended reference is continuous across 4000K and 5000K
+        for t in [4000.0f32, 5000.0] {
+            let below = reference_spd(t - 0.01);
+            let above = reference_spd(t + 0.01);
+            assert!(below.iter().zip(&above).all(|(b, a)| (b - a).abs() < 0.01));
+        }
+    }
+}
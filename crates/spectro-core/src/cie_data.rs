@@ -0,0 +1,2596 @@
+//! CIE Colorimetric Data Tables
+//!
+//! Color matching functions and illuminant spectral power distributions at
+//! 1nm from 380nm to 780nm ([`LEN`] points), as published by the CIE. The
+//! 5nm tables are every fifth row of the 1nm data; see
+//! [`Observer::cmfs_5nm`](crate::Observer::cmfs_5nm) and
+//! [`Illuminant::spd_5nm`](crate::Illuminant::spd_5nm).
+//!
+//! The CIE 2015 observers and the CIE 2006 cone fundamentals start at 390nm;
+//! their rows below that are zero.
+//! D55 and D75 follow the CIE daylight procedure: S0 + M1·S1 + M2·S2 at 5nm,
+//! with M1 and M2 rounded to three decimals, interpolated linearly to 1nm.
+//! Illuminant C is likewise the 5nm CIE table interpolated linearly.
+//!
+//! The daylight components and the CRI test color samples are the CIE 5nm
+//! tables ([`LEN_5NM`] points), truncated to 380-780nm.
+//! The melanopic action spectrum is a reconstruction; see [`MELANOPIC`].
+
+#![allow(clippy::approx_constant)]
+#![allow(clippy::excessive_precision)]
+#![allow(clippy::large_const_arrays)]
+
+/// First wavelength of every table, in nm.
+pub const START: f32 = 380.0;
+/// Number of 1nm rows (380-780nm).
+pub const LEN: usize = 401;
+/// Number of 5nm rows (380-780nm).
+pub const LEN_5NM: usize = 81;
+
+/// CIE 1931 2° standard observer (x̄, ȳ, z̄).
+#[rustfmt::skip]
+pub const CIE1931_2: [[f32; 3]; LEN] = [
+    [0.001368, 3.9e-5, 0.006450001], [0.00150205, 4.28264e-5, 0.007083216], [0.001642328, 4.69146e-5, 0.007745488],
+    [0.001802382, 5.15896e-5, 0.008501152], [0.001995757, 5.71764e-5, 0.009414544], [0.002236, 6.4e-5, 0.01054999],
+    [0.002535385, 7.234421e-5, 0.0119658], [0.002892603, 8.221224e-5, 0.01365587], [0.003300829, 9.350816e-5, 0.01558805],
+    [0.003753236, 0.0001061361, 0.01773015], [0.004243, 0.00012, 0.02005001], [0.004762389, 0.000134984, 0.02251136],
+    [0.005330048, 0.000151492, 0.02520288], [0.005978712, 0.000170208, 0.02827972], [0.006741117, 0.000191816, 0.03189704],
+    [0.00765, 0.000217, 0.03621], [0.008751373, 0.0002469067, 0.04143771], [0.01002888, 0.00028124, 0.04750372],
+    [0.0114217, 0.00031852, 0.05411988], [0.01286901, 0.0003572667, 0.06099803], [0.01431, 0.000396, 0.06785001],
+    [0.01570443, 0.0004337147, 0.07448632], [0.01714744, 0.000473024, 0.08136156], [0.01878122, 0.000517876, 0.08915364],
+    [0.02074801, 0.0005722187, 0.09854048], [0.02319, 0.00064, 0.1102], [0.02620736, 0.00072456, 0.1246133],
+    [0.02978248, 0.0008255, 0.1417017], [0.03388092, 0.00094116, 0.1613035], [0.03846824, 0.00106988, 0.1832568],
+    [0.04351, 0.00121, 0.2074], [0.0489956, 0.001362091, 0.2336921], [0.0550226, 0.001530752, 0.2626114],
+    [0.0617188, 0.001720368, 0.2947746], [0.069212, 0.001935323, 0.3307985], [0.07763, 0.00218, 0.3713],
+    [0.08695811, 0.0024548, 0.4162091], [0.09717672, 0.002764, 0.4654642], [0.1084063, 0.0031178, 0.5196948],
+    [0.1207672, 0.0035264, 0.5795303], [0.13438, 0.004, 0.6456], [0.1493582, 0.00454624, 0.7184838],
+    [0.1653957, 0.00515932, 0.7967133], [0.1819831, 0.00582928, 0.8778459], [0.198611, 0.00654616, 0.959439],
+    [0.21477, 0.0073, 1.03905], [0.2301868, 0.008086507, 1.115367], [0.2448797, 0.00890872, 1.188497],
+    [0.2587773, 0.00976768, 1.258123], [0.2718079, 0.01066443, 1.32393], [0.2839, 0.0116, 1.3856],
+    [0.2949438, 0.01257317, 1.442635], [0.3048965, 0.01358272, 1.494803], [0.3137873, 0.01462968, 1.54219],
+    [0.3216454, 0.01571509, 1.584881], [0.3285, 0.01684, 1.62296], [0.3343513, 0.01800736, 1.656405],
+    [0.3392101, 0.01921448, 1.685296], [0.3431213, 0.02045392, 1.709874], [0.3461296, 0.02171824, 1.730382],
+    [0.34828, 0.023, 1.74706], [0.3495999, 0.02429461, 1.760045], [0.3501474, 0.02561024, 1.769623],
+    [0.350013, 0.02695857, 1.776264], [0.349287, 0.02835125, 1.780433], [0.34806, 0.0298, 1.7826],
+    [0.3463733, 0.03131083, 1.782968], [0.3442624, 0.03288368, 1.7817], [0.3418088, 0.03452112, 1.779198],
+    [0.3390941, 0.03622571, 1.775867], [0.3362, 0.038, 1.77211], [0.3331977, 0.03984667, 1.768259],
+    [0.3300411, 0.041768, 1.764039], [0.3266357, 0.043766, 1.758944], [0.3228868, 0.04584267, 1.752466],
+    [0.3187, 0.048, 1.7441], [0.3140251, 0.05024368, 1.733559], [0.308884, 0.05257304, 1.720858],
+    [0.3032904, 0.05498056, 1.705937], [0.2972579, 0.05745872, 1.688737], [0.2908, 0.06, 1.6692],
+    [0.2839701, 0.06260197, 1.647529], [0.2767214, 0.06527752, 1.623413], [0.2689178, 0.06804208, 1.596022],
+    [0.2604227, 0.07091109, 1.564528], [0.2511, 0.0739, 1.5281], [0.2408475, 0.077016, 1.486111],
+    [0.2298512, 0.0802664, 1.439521], [0.2184072, 0.0836668, 1.38988], [0.2068115, 0.0872328, 1.338736],
+    [0.19536, 0.09098, 1.28764], [0.1842136, 0.09491755, 1.237422], [0.1733273, 0.09904584, 1.187824],
+    [0.1626881, 0.1033674, 1.138761], [0.1522833, 0.1078846, 1.090148], [0.1421, 0.1126, 1.0419],
+    [0.1321786, 0.117532, 0.9941976], [0.1225696, 0.1226744, 0.9473473], [0.1132752, 0.1279928, 0.9014531],
+    [0.1042979, 0.1334528, 0.8566193], [0.09564, 0.13902, 0.8129501], [0.08729955, 0.1446764, 0.7705173],
+    [0.07930804, 0.1504693, 0.7294448], [0.07171776, 0.1564619, 0.6899136], [0.06458099, 0.1627177, 0.6521049],
+    [0.05795001, 0.1693, 0.6162], [0.05186211, 0.1762431, 0.5823286], [0.04628152, 0.1835581, 0.5504162],
+    [0.04115088, 0.1912735, 0.5203376], [0.03641283, 0.199418, 0.4919673], [0.03201, 0.20802, 0.46518],
+    [0.0279172, 0.2171199, 0.4399246], [0.0241444, 0.2267345, 0.4161836], [0.020687, 0.2368571, 0.3938822],
+    [0.0175404, 0.2474812, 0.3729459], [0.0147, 0.2586, 0.3533], [0.01216179, 0.2701849, 0.3348578],
+    [0.00991996, 0.2822939, 0.3175521], [0.00796724, 0.2950505, 0.3013375], [0.006296346, 0.308578, 0.2861686],
+    [0.0049, 0.323, 0.272], [0.003777173, 0.3384021, 0.2588171], [0.00294532, 0.3546858, 0.2464838],
+    [0.00242488, 0.3716986, 0.2347718], [0.002236293, 0.3892875, 0.2234533], [0.0024, 0.4073, 0.2123],
+    [0.00292552, 0.4256299, 0.2011692], [0.00383656, 0.4443096, 0.1901196], [0.00517484, 0.4633944, 0.1792254],
+    [0.00698208, 0.4829395, 0.1685608], [0.0093, 0.503, 0.1582], [0.01214949, 0.5235693, 0.1481383],
+    [0.01553588, 0.544512, 0.1383758], [0.01947752, 0.56569, 0.1289942], [0.02399277, 0.5869653, 0.1200751],
+    [0.0291, 0.6082, 0.1117], [0.03481485, 0.6293456, 0.1039048], [0.04112016, 0.6503068, 0.09666748],
+    [0.04798504, 0.6708752, 0.08998272], [0.05537861, 0.6908424, 0.08384531], [0.06327, 0.71, 0.07824999],
+    [0.07163501, 0.7281852, 0.07320899], [0.08046224, 0.7454636, 0.06867816], [0.08973996, 0.7619694, 0.06456784],
+    [0.09945645, 0.7778368, 0.06078835], [0.1096, 0.7932, 0.05725001], [0.1201674, 0.8081104, 0.05390435],
+    [0.1311145, 0.8224962, 0.05074664], [0.1423679, 0.8363068, 0.04775276], [0.1538542, 0.8494916, 0.04489859],
+    [0.1655, 0.862, 0.04216], [0.1772571, 0.8738108, 0.03950728], [0.18914, 0.8849624, 0.03693564],
+    [0.2011694, 0.8954936, 0.03445836], [0.2133658, 0.9054432, 0.03208872], [0.2257499, 0.9148501, 0.02984],
+    [0.2383209, 0.9237348, 0.02771181], [0.2510668, 0.9320924, 0.02569444], [0.2639922, 0.9399226, 0.02378716],
+    [0.2771017, 0.9472252, 0.02198925], [0.2904, 0.954, 0.0203], [0.3038912, 0.9602561, 0.01871805],
+    [0.3175726, 0.9660074, 0.01724036], [0.3314384, 0.9712606, 0.01586364], [0.3454828, 0.9760225, 0.01458461],
+    [0.3597, 0.9803, 0.0134], [0.3740839, 0.9840924, 0.01230723], [0.3886396, 0.9874182, 0.01130188],
+    [0.4033784, 0.9903128, 0.01037792], [0.4183115, 0.9928116, 0.009529306], [0.4334499, 0.9949501, 0.008749999],
+    [0.4487953, 0.9967108, 0.0080352], [0.464336, 0.9980983, 0.0073816], [0.480064, 0.999112, 0.0067854],
+    [0.4959713, 0.9997482, 0.0062428], [0.5120501, 1.0, 0.005749999], [0.5282959, 0.9998567, 0.0053036],
+    [0.5446916, 0.9993046, 0.0048998], [0.5612094, 0.9983255, 0.0045342], [0.5778215, 0.9968987, 0.0042024],
+    [0.5945, 0.995, 0.0039], [0.6112209, 0.9926005, 0.0036232], [0.6279758, 0.9897426, 0.0033706],
+    [0.6447602, 0.9864444, 0.0031414], [0.6615697, 0.9827241, 0.0029348], [0.6784, 0.9786, 0.002749999],
+    [0.6952392, 0.9740837, 0.0025852], [0.7120586, 0.9691712, 0.0024386], [0.7288284, 0.9638568, 0.0023094],
+    [0.7455188, 0.9581349, 0.0021968], [0.7621, 0.952, 0.0021], [0.7785432, 0.9454504, 0.002017733],
+    [0.7948256, 0.9384992, 0.0019482], [0.8109264, 0.9311628, 0.0018898], [0.8268248, 0.9234576, 0.001840933],
+    [0.8425, 0.9154, 0.0018], [0.8579325, 0.9070064, 0.001766267], [0.8730816, 0.8982772, 0.0017378],
+    [0.8878944, 0.8892048, 0.0017112], [0.9023181, 0.8797816, 0.001683067], [0.9163, 0.87, 0.001650001],
+    [0.9297995, 0.8598613, 0.001610133], [0.9427984, 0.849392, 0.0015644], [0.9552776, 0.838622, 0.0015136],
+    [0.9672179, 0.8275813, 0.001458533], [0.9786, 0.8163, 0.0014], [0.9893856, 0.8047947, 0.001336667],
+    [0.9995488, 0.793082, 0.00127], [1.009089, 0.781192, 0.001205], [1.018006, 0.7691547, 0.001146667],
+    [1.0263, 0.757, 0.0011], [1.033983, 0.7447541, 0.0010688], [1.040986, 0.7324224, 0.0010494],
+    [1.047188, 0.7200036, 0.0010356], [1.052467, 0.7074965, 0.0010212], [1.0567, 0.6949, 0.001],
+    [1.059794, 0.6822192, 0.00096864], [1.061799, 0.6694716, 0.00092992], [1.062807, 0.6566744, 0.00088688],
+    [1.06291, 0.6438448, 0.00084256], [1.0622, 0.631, 0.0008], [1.060735, 0.6181555, 0.00076096],
+    [1.058444, 0.6053144, 0.00072368], [1.055224, 0.5924756, 0.00068592], [1.050977, 0.5796379, 0.00064544],
+    [1.0456, 0.5668, 0.0006], [1.039037, 0.5539611, 0.0005478667], [1.031361, 0.5411372, 0.0004916],
+    [1.022666, 0.5283528, 0.0004354], [1.013048, 0.5156323, 0.0003834667], [1.0026, 0.503, 0.00034],
+    [0.9913675, 0.4904688, 0.0003072533], [0.9793314, 0.4780304, 0.00028316], [0.9664916, 0.4656776, 0.00026544],
+    [0.9528479, 0.4534032, 0.0002518133], [0.9384, 0.4412, 0.00024], [0.923194, 0.42908, 0.0002295467],
+    [0.907244, 0.417036, 0.00022064], [0.890502, 0.405032, 0.00021196], [0.87292, 0.393032, 0.0002021867],
+    [0.8544499, 0.381, 0.00019], [0.835084, 0.3689184, 0.0001742133], [0.814946, 0.3568272, 0.00015564],
+    [0.794186, 0.3447768, 0.00013596], [0.772954, 0.3328176, 0.0001168533], [0.7514, 0.321, 0.0001],
+    [0.7295836, 0.3093381, 8.613333e-5], [0.7075888, 0.2978504, 7.46e-5], [0.6856022, 0.2865936, 6.5e-5],
+    [0.6638104, 0.2756245, 5.693333e-5], [0.6424, 0.265, 4.999999e-5], [0.6215149, 0.2547632, 4.416e-5],
+    [0.6011138, 0.2448896, 3.948e-5], [0.5811052, 0.2353344, 3.572e-5], [0.5613977, 0.2260528, 3.264e-5],
+    [0.5419, 0.217, 3e-5], [0.5225995, 0.2081616, 2.765333e-5], [0.5035464, 0.1995488, 2.556e-5],
+    [0.4847436, 0.1911552, 2.364e-5], [0.4661939, 0.1829744, 2.181333e-5], [0.4479, 0.175, 2e-5],
+    [0.4298613, 0.1672235, 1.813333e-5], [0.412098, 0.1596464, 1.62e-5], [0.394644, 0.1522776, 1.42e-5],
+    [0.3775333, 0.1451259, 1.213333e-5], [0.3608, 0.1382, 1e-5], [0.3444563, 0.1315003, 7.733333e-6],
+    [0.3285168, 0.1250248, 5.4e-6], [0.3130192, 0.1187792, 3.2e-6], [0.2980011, 0.1127691, 1.333333e-6],
+    [0.2835, 0.107, 0.0], [0.2695448, 0.1014762, 0.0], [0.2561184, 0.09618864, 0.0],
+    [0.2431896, 0.09112296, 0.0], [0.2307272, 0.08626485, 0.0], [0.2187, 0.0816, 0.0],
+    [0.2070971, 0.07712064, 0.0], [0.1959232, 0.07282552, 0.0], [0.1851708, 0.06871008, 0.0],
+    [0.1748323, 0.06476976, 0.0], [0.1649, 0.061, 0.0], [0.1553667, 0.05739621, 0.0],
+    [0.14623, 0.05395504, 0.0], [0.13749, 0.05067376, 0.0], [0.1291467, 0.04754965, 0.0],
+    [0.1212, 0.04458, 0.0], [0.1136397, 0.04175872, 0.0], [0.106465, 0.03908496, 0.0],
+    [0.09969044, 0.03656384, 0.0], [0.09333061, 0.03420048, 0.0], [0.0874, 0.032, 0.0],
+    [0.08190096, 0.02996261, 0.0], [0.07680428, 0.02807664, 0.0], [0.07207712, 0.02632936, 0.0],
+    [0.06768664, 0.02470805, 0.0], [0.0636, 0.0232, 0.0], [0.05980685, 0.02180077, 0.0],
+    [0.05628216, 0.02050112, 0.0], [0.05297104, 0.01928108, 0.0], [0.04981861, 0.01812069, 0.0],
+    [0.04677, 0.017, 0.0], [0.04378405, 0.01590379, 0.0], [0.04087536, 0.01483718, 0.0],
+    [0.03807264, 0.01381068, 0.0], [0.03540461, 0.01283478, 0.0], [0.0329, 0.01192, 0.0],
+    [0.03056419, 0.01106831, 0.0], [0.02838056, 0.01027339, 0.0], [0.02634484, 0.009533311, 0.0],
+    [0.02445275, 0.008846157, 0.0], [0.0227, 0.00821, 0.0], [0.02108429, 0.007623781, 0.0],
+    [0.01959988, 0.007085424, 0.0], [0.01823732, 0.006591476, 0.0], [0.01698717, 0.006138485, 0.0],
+    [0.01584, 0.005723, 0.0], [0.01479064, 0.005343059, 0.0], [0.01383132, 0.004995796, 0.0],
+    [0.01294868, 0.004676404, 0.0], [0.0121292, 0.004380075, 0.0], [0.01135916, 0.004102, 0.0],
+    [0.01062935, 0.003838453, 0.0], [0.009938846, 0.003589099, 0.0], [0.009288422, 0.003354219, 0.0],
+    [0.008678854, 0.003134093, 0.0], [0.008110916, 0.002929, 0.0], [0.007582388, 0.002738139, 0.0],
+    [0.007088746, 0.002559876, 0.0], [0.006627313, 0.002393244, 0.0], [0.006195408, 0.002237275, 0.0],
+    [0.005790346, 0.002091, 0.0], [0.005409826, 0.001953587, 0.0], [0.005052583, 0.00182458, 0.0],
+    [0.004717512, 0.00170358, 0.0], [0.004403507, 0.001590187, 0.0], [0.004109457, 0.001484, 0.0],
+    [0.003833913, 0.001384496, 0.0], [0.003575748, 0.001291268, 0.0], [0.003334342, 0.001204092, 0.0],
+    [0.003109075, 0.001122744, 0.0], [0.002899327, 0.001047, 0.0], [0.002704348, 0.0009765896, 0.0],
+    [0.00252302, 0.0009111088, 0.0], [0.002354168, 0.0008501332, 0.0], [0.002196616, 0.0007932384, 0.0],
+    [0.00204919, 0.00074, 0.0], [0.00191096, 0.0006900827, 0.0], [0.001781438, 0.00064331, 0.0],
+    [0.00166011, 0.000599496, 0.0], [0.001546459, 0.0005584547, 0.0], [0.001439971, 0.00052, 0.0],
+    [0.001340042, 0.0004839136, 0.0], [0.001246275, 0.0004500528, 0.0], [0.001158471, 0.0004183452, 0.0],
+    [0.00107643, 0.0003887184, 0.0], [0.0009999493, 0.0003611, 0.0], [0.0009287358, 0.0003353835, 0.0],
+    [0.0008624332, 0.0003114404, 0.0], [0.0008007503, 0.0002891656, 0.0], [0.000743396, 0.0002684539, 0.0],
+    [0.0006900786, 0.0002492, 0.0], [0.0006405156, 0.0002313019, 0.0], [0.0005945021, 0.0002146856, 0.0],
+    [0.0005518646, 0.0001992884, 0.0], [0.000512429, 0.0001850475, 0.0], [0.0004760213, 0.0001719, 0.0],
+    [0.0004424536, 0.0001597781, 0.0], [0.0004115117, 0.0001486044, 0.0], [0.0003829814, 0.0001383016, 0.0],
+    [0.0003566491, 0.0001287925, 0.0], [0.0003323011, 0.00012, 0.0], [0.0003097586, 0.0001118595, 0.0],
+    [0.0002888871, 0.0001043224, 0.0], [0.0002695394, 9.73356e-5, 0.0], [0.0002515682, 9.084587e-5, 0.0],
+    [0.0002348261, 8.48e-5, 0.0], [0.000219171, 7.914667e-5, 0.0], [0.0002045258, 7.3858e-5, 0.0],
+    [0.0001908405, 6.8916e-5, 0.0], [0.0001780654, 6.430267e-5, 0.0], [0.0001661505, 6e-5, 0.0],
+    [0.0001550236, 5.598187e-5, 0.0], [0.0001446219, 5.22256e-5, 0.0], [0.0001349098, 4.87184e-5, 0.0],
+    [0.000125852, 4.544747e-5, 0.0], [0.000117413, 4.24e-5, 0.0], [0.0001095515, 3.956104e-5, 0.0],
+    [0.0001022245, 3.691512e-5, 0.0], [9.539445e-5, 3.444868e-5, 0.0], [8.90239e-5, 3.214816e-5, 0.0],
+    [8.307527e-5, 3e-5, 0.0], [7.751269e-5, 2.799125e-5, 0.0], [7.231304e-5, 2.611356e-5, 0.0],
+    [6.745778e-5, 2.436024e-5, 0.0], [6.292844e-5, 2.272461e-5, 0.0], [5.870652e-5, 2.12e-5, 0.0],
+    [5.477028e-5, 1.977855e-5, 0.0], [5.109918e-5, 1.845285e-5, 0.0], [4.767654e-5, 1.721687e-5, 0.0],
+    [4.448567e-5, 1.606459e-5, 0.0], [4.150994e-5, 1.499e-5, 0.0],
+];
+
+/// CIE 1964 10° supplementary standard observer (x̄₁₀, ȳ₁₀, z̄₁₀).
+#[rustfmt::skip]
+pub const CIE1964_10: [[f32; 3]; LEN] = [
+    [0.000159952, 1.7364e-5, 0.000704776], [0.00021508, 2.3327e-5, 0.00094823], [0.00028749, 3.115e-5, 0.0012682],
+    [0.00038199, 4.135e-5, 0.0016861], [0.00050455, 5.456e-5, 0.0022285], [0.00066244, 7.156e-5, 0.0029278],
+    [0.0008645, 9.33e-5, 0.0038237], [0.0011215, 0.00012087, 0.0049642], [0.00144616, 0.00015564, 0.0064067],
+    [0.00185359, 0.0001992, 0.0082193], [0.0023616, 0.0002534, 0.0104822], [0.0029906, 0.0003202, 0.013289],
+    [0.0037645, 0.0004024, 0.016747], [0.0047102, 0.0005023, 0.02098], [0.0058581, 0.0006232, 0.026127],
+    [0.0072423, 0.0007685, 0.032344], [0.0088996, 0.0009417, 0.039802], [0.0108709, 0.0011478, 0.048691],
+    [0.0131989, 0.0013903, 0.05921], [0.0159292, 0.001674, 0.071576], [0.0191097, 0.0020044, 0.0860109],
+    [0.022788, 0.002386, 0.10274], [0.027011, 0.002822, 0.122], [0.031829, 0.003319, 0.14402],
+    [0.037278, 0.00388, 0.16899], [0.0434, 0.004509, 0.19712], [0.050223, 0.005209, 0.22857],
+    [0.057764, 0.005985, 0.26347], [0.066038, 0.006833, 0.3019], [0.075033, 0.007757, 0.34387],
+    [0.084736, 0.008756, 0.389366], [0.095041, 0.009816, 0.43797], [0.105836, 0.010918, 0.48922],
+    [0.117066, 0.012058, 0.5429], [0.128682, 0.013237, 0.59881], [0.140638, 0.014456, 0.65676],
+    [0.152893, 0.015717, 0.71658], [0.165416, 0.017025, 0.77812], [0.178191, 0.018399, 0.84131],
+    [0.191214, 0.019848, 0.90611], [0.204492, 0.021391, 0.972542], [0.21765, 0.022992, 1.0389],
+    [0.230267, 0.024598, 1.1031], [0.242311, 0.026213, 1.1651], [0.253793, 0.027841, 1.2249],
+    [0.264737, 0.029497, 1.2825], [0.275195, 0.031195, 1.3382], [0.285301, 0.032927, 1.3926],
+    [0.295143, 0.034738, 1.4461], [0.304869, 0.036654, 1.4994], [0.314679, 0.038676, 1.55348],
+    [0.324355, 0.040792, 1.6072], [0.33357, 0.042946, 1.6589], [0.342243, 0.045114, 1.7082],
+    [0.350312, 0.047333, 1.7548], [0.357719, 0.049602, 1.7985], [0.364482, 0.051934, 1.8392],
+    [0.370493, 0.054337, 1.8766], [0.375727, 0.056822, 1.9105], [0.380158, 0.059399, 1.9408],
+    [0.383734, 0.062077, 1.96728], [0.386327, 0.064737, 1.9891], [0.387858, 0.067285, 2.0057],
+    [0.388396, 0.069764, 2.0174], [0.387978, 0.072218, 2.0244], [0.386726, 0.074704, 2.0273],
+    [0.384696, 0.077272, 2.0264], [0.382006, 0.079979, 2.0223], [0.378709, 0.082874, 2.0153],
+    [0.374915, 0.086, 2.006], [0.370702, 0.089456, 1.9948], [0.366089, 0.092947, 1.9814],
+    [0.361045, 0.096275, 1.9653], [0.355518, 0.099535, 1.9464], [0.349486, 0.102829, 1.9248],
+    [0.342957, 0.106256, 1.9007], [0.335893, 0.109901, 1.8741], [0.328284, 0.113835, 1.8451],
+    [0.32015, 0.118167, 1.8139], [0.311475, 0.122932, 1.7806], [0.302273, 0.128201, 1.74537],
+    [0.292858, 0.133457, 1.7091], [0.283502, 0.138323, 1.6723], [0.274044, 0.143042, 1.6347],
+    [0.264263, 0.147787, 1.5956], [0.254085, 0.152761, 1.5549], [0.243392, 0.158102, 1.5122],
+    [0.232187, 0.163941, 1.4673], [0.220488, 0.170362, 1.4199], [0.208198, 0.177425, 1.37],
+    [0.195618, 0.18519, 1.31756], [0.183034, 0.193025, 1.2624], [0.170222, 0.200313, 1.205],
+    [0.157348, 0.207156, 1.1466], [0.14465, 0.213644, 1.088], [0.132349, 0.21994, 1.0302],
+    [0.120584, 0.22617, 0.97383], [0.109456, 0.232467, 0.91943], [0.099042, 0.239025, 0.86746],
+    [0.089388, 0.245997, 0.81828], [0.080507, 0.253589, 0.772125], [0.072034, 0.261876, 0.72829],
+    [0.06371, 0.270643, 0.68604], [0.055694, 0.279645, 0.64553], [0.048117, 0.288694, 0.60685],
+    [0.041072, 0.297665, 0.57006], [0.034642, 0.306469, 0.53522], [0.028896, 0.315035, 0.50234],
+    [0.023876, 0.323335, 0.4714], [0.019628, 0.331366, 0.44239], [0.016172, 0.339133, 0.415254],
+    [0.0133, 0.34786, 0.390024], [0.010759, 0.358326, 0.366399], [0.008542, 0.370001, 0.344015],
+    [0.006661, 0.382464, 0.322689], [0.005132, 0.395379, 0.302356], [0.003982, 0.408482, 0.283036],
+    [0.003239, 0.421588, 0.264816], [0.002934, 0.434619, 0.247848], [0.003114, 0.447601, 0.232318],
+    [0.003816, 0.460777, 0.218502], [0.005095, 0.47434, 0.205851], [0.006936, 0.4882, 0.193596],
+    [0.009299, 0.50234, 0.181736], [0.012147, 0.51674, 0.170281], [0.015444, 0.53136, 0.159249],
+    [0.019156, 0.54619, 0.148673], [0.02325, 0.56118, 0.138609], [0.02769, 0.57629, 0.129096],
+    [0.032444, 0.5915, 0.120215], [0.037465, 0.606741, 0.112044], [0.042956, 0.62215, 0.10471],
+    [0.049114, 0.63783, 0.098196], [0.05592, 0.65371, 0.092361], [0.063349, 0.66968, 0.087088],
+    [0.071358, 0.68566, 0.082248], [0.079901, 0.70155, 0.077744], [0.088909, 0.71723, 0.073456],
+    [0.098293, 0.73257, 0.069268], [0.107949, 0.74746, 0.06506], [0.117749, 0.761757, 0.060709],
+    [0.127839, 0.77534, 0.056457], [0.13845, 0.78822, 0.052609], [0.149516, 0.80046, 0.049122],
+    [0.161041, 0.81214, 0.045954], [0.172953, 0.82333, 0.04305], [0.185209, 0.83412, 0.040368],
+    [0.197755, 0.8446, 0.037839], [0.210538, 0.85487, 0.035384], [0.22346, 0.86504, 0.032949],
+    [0.236491, 0.875211, 0.030451], [0.249633, 0.88537, 0.028029], [0.262972, 0.89537, 0.025862],
+    [0.276515, 0.90515, 0.02392], [0.290269, 0.91465, 0.022174], [0.304213, 0.92381, 0.020584],
+    [0.318361, 0.93255, 0.019127], [0.332705, 0.94081, 0.01774], [0.347232, 0.94852, 0.016403],
+    [0.361926, 0.9556, 0.015064], [0.376772, 0.961988, 0.013676], [0.391683, 0.96754, 0.012308],
+    [0.406594, 0.97223, 0.011056], [0.421539, 0.97617, 0.009915], [0.436517, 0.97946, 0.008872],
+    [0.451584, 0.9822, 0.007918], [0.466782, 0.98452, 0.00703], [0.482147, 0.98652, 0.006223],
+    [0.497738, 0.98832, 0.005453], [0.513606, 0.99002, 0.004714], [0.529826, 0.991761, 0.003988],
+    [0.54644, 0.99353, 0.003289], [0.563426, 0.99523, 0.002646], [0.580726, 0.99677, 0.002063],
+    [0.59829, 0.99809, 0.001533], [0.616053, 0.99911, 0.001091], [0.633948, 0.99977, 0.000711],
+    [0.651901, 1.0, 0.000407], [0.669824, 0.99971, 0.000184], [0.687632, 0.99885, 4.7e-5],
+    [0.705224, 0.99734, 0.0], [0.722773, 0.99526, 0.0], [0.740483, 0.99274, 0.0],
+    [0.758273, 0.98975, 0.0], [0.776083, 0.9863, 0.0], [0.793832, 0.98238, 0.0],
+    [0.811436, 0.97798, 0.0], [0.828822, 0.97311, 0.0], [0.845879, 0.96774, 0.0],
+    [0.862525, 0.96189, 0.0], [0.878655, 0.955552, 0.0], [0.894208, 0.948601, 0.0],
+    [0.909206, 0.940981, 0.0], [0.923672, 0.932798, 0.0], [0.937638, 0.924158, 0.0],
+    [0.951162, 0.915175, 0.0], [0.964283, 0.905954, 0.0], [0.977068, 0.896608, 0.0],
+    [0.98959, 0.887249, 0.0], [1.00191, 0.877986, 0.0], [1.01416, 0.868934, 0.0],
+    [1.0265, 0.860164, 0.0], [1.0388, 0.851519, 0.0], [1.051, 0.842963, 0.0],
+    [1.0629, 0.834393, 0.0], [1.0743, 0.825623, 0.0], [1.0852, 0.816764, 0.0],
+    [1.0952, 0.807544, 0.0], [1.1042, 0.797947, 0.0], [1.112, 0.787893, 0.0],
+    [1.11852, 0.777405, 0.0], [1.1238, 0.76649, 0.0], [1.128, 0.755309, 0.0],
+    [1.1311, 0.743845, 0.0], [1.1332, 0.73219, 0.0], [1.1343, 0.720353, 0.0],
+    [1.1343, 0.708281, 0.0], [1.1333, 0.696055, 0.0], [1.1312, 0.683621, 0.0],
+    [1.1281, 0.671048, 0.0], [1.12399, 0.658341, 0.0], [1.1189, 0.645545, 0.0],
+    [1.1129, 0.632718, 0.0], [1.1059, 0.619815, 0.0], [1.098, 0.606887, 0.0],
+    [1.0891, 0.593878, 0.0], [1.0792, 0.580781, 0.0], [1.0684, 0.567653, 0.0],
+    [1.0567, 0.55449, 0.0], [1.044, 0.541228, 0.0], [1.03048, 0.527963, 0.0],
+    [1.016, 0.514634, 0.0], [1.0008, 0.501363, 0.0], [0.98479, 0.488124, 0.0],
+    [0.96808, 0.474935, 0.0], [0.95074, 0.461834, 0.0], [0.9328, 0.448823, 0.0],
+    [0.91434, 0.435917, 0.0], [0.89539, 0.423153, 0.0], [0.87603, 0.410526, 0.0],
+    [0.856297, 0.398057, 0.0], [0.83635, 0.385835, 0.0], [0.81629, 0.373951, 0.0],
+    [0.79605, 0.362311, 0.0], [0.77561, 0.350863, 0.0], [0.75493, 0.339554, 0.0],
+    [0.73399, 0.328309, 0.0], [0.71278, 0.317118, 0.0], [0.69129, 0.305936, 0.0],
+    [0.66952, 0.294737, 0.0], [0.647467, 0.283493, 0.0], [0.62511, 0.272222, 0.0],
+    [0.60252, 0.26099, 0.0], [0.57989, 0.249877, 0.0], [0.55737, 0.238946, 0.0],
+    [0.53511, 0.228254, 0.0], [0.51324, 0.217853, 0.0], [0.49186, 0.20778, 0.0],
+    [0.47108, 0.198072, 0.0], [0.45096, 0.188748, 0.0], [0.431567, 0.179828, 0.0],
+    [0.41287, 0.171285, 0.0], [0.39475, 0.163059, 0.0], [0.37721, 0.155151, 0.0],
+    [0.36019, 0.147535, 0.0], [0.34369, 0.140211, 0.0], [0.32769, 0.13317, 0.0],
+    [0.31217, 0.1264, 0.0], [0.29711, 0.119892, 0.0], [0.2825, 0.11364, 0.0],
+    [0.268329, 0.107633, 0.0], [0.25459, 0.10187, 0.0], [0.2413, 0.096347, 0.0],
+    [0.22848, 0.091063, 0.0], [0.21614, 0.08601, 0.0], [0.2043, 0.081187, 0.0],
+    [0.19295, 0.076583, 0.0], [0.18211, 0.072198, 0.0], [0.17177, 0.068024, 0.0],
+    [0.16192, 0.064052, 0.0], [0.152568, 0.060281, 0.0], [0.14367, 0.056697, 0.0],
+    [0.1352, 0.053292, 0.0], [0.12713, 0.050059, 0.0], [0.11948, 0.046998, 0.0],
+    [0.11221, 0.044096, 0.0], [0.10531, 0.041345, 0.0], [0.098786, 0.0387507, 0.0],
+    [0.09261, 0.0362978, 0.0], [0.086773, 0.0339832, 0.0], [0.0812606, 0.0318004, 0.0],
+    [0.076048, 0.0297395, 0.0], [0.071114, 0.0277918, 0.0], [0.066454, 0.0259551, 0.0],
+    [0.062062, 0.0242263, 0.0], [0.05793, 0.0226017, 0.0], [0.05405, 0.0210779, 0.0],
+    [0.050412, 0.0196505, 0.0], [0.047006, 0.0183153, 0.0], [0.043823, 0.0170686, 0.0],
+    [0.0408508, 0.0159051, 0.0], [0.038072, 0.0148183, 0.0], [0.035468, 0.0138008, 0.0],
+    [0.033031, 0.0128495, 0.0], [0.030753, 0.0119607, 0.0], [0.028623, 0.0111303, 0.0],
+    [0.026635, 0.0103555, 0.0], [0.024781, 0.0096332, 0.0], [0.023052, 0.0089599, 0.0],
+    [0.021441, 0.0083324, 0.0], [0.0199413, 0.0077488, 0.0], [0.018544, 0.0072046, 0.0],
+    [0.017241, 0.0066975, 0.0], [0.016027, 0.0062251, 0.0], [0.014896, 0.005785, 0.0],
+    [0.013842, 0.0053751, 0.0], [0.012862, 0.0049941, 0.0], [0.011949, 0.0046392, 0.0],
+    [0.0111, 0.0043093, 0.0], [0.010311, 0.0040028, 0.0], [0.00957688, 0.00371774, 0.0],
+    [0.008894, 0.00345262, 0.0], [0.0082581, 0.00320583, 0.0], [0.0076664, 0.00297623, 0.0],
+    [0.0071163, 0.00276281, 0.0], [0.0066052, 0.00256456, 0.0], [0.0061306, 0.00238048, 0.0],
+    [0.0056903, 0.00220971, 0.0], [0.0052819, 0.00205132, 0.0], [0.0049033, 0.00190449, 0.0],
+    [0.00455263, 0.00176847, 0.0], [0.0042275, 0.00164236, 0.0], [0.0039258, 0.00152535, 0.0],
+    [0.0036457, 0.00141672, 0.0], [0.0033859, 0.00131595, 0.0], [0.0031447, 0.00122239, 0.0],
+    [0.0029208, 0.00113555, 0.0], [0.002713, 0.00105494, 0.0], [0.0025202, 0.00098014, 0.0],
+    [0.0023411, 0.00091066, 0.0], [0.00217496, 0.00084619, 0.0], [0.0020206, 0.00078629, 0.0],
+    [0.0018773, 0.00073068, 0.0], [0.0017441, 0.00067899, 0.0], [0.0016205, 0.00063101, 0.0],
+    [0.0015057, 0.00058644, 0.0], [0.0013992, 0.00054511, 0.0], [0.0013004, 0.00050672, 0.0],
+    [0.0012087, 0.00047111, 0.0], [0.0011236, 0.00043805, 0.0], [0.00104476, 0.00040741, 0.0],
+    [0.00097156, 0.000378962, 0.0], [0.0009036, 0.000352543, 0.0], [0.00084048, 0.000328001, 0.0],
+    [0.00078187, 0.000305208, 0.0], [0.00072745, 0.000284041, 0.0], [0.0006769, 0.000264375, 0.0],
+    [0.00062996, 0.000246109, 0.0], [0.00058637, 0.000229143, 0.0], [0.00054587, 0.000213376, 0.0],
+    [0.000508258, 0.00019873, 0.0], [0.0004733, 0.000185115, 0.0], [0.0004408, 0.000172454, 0.0],
+    [0.00041058, 0.000160678, 0.0], [0.00038249, 0.00014973, 0.0], [0.00035638, 0.00013955, 0.0],
+    [0.00033211, 0.000130086, 0.0], [0.00030955, 0.00012129, 0.0], [0.00028858, 0.000113106, 0.0],
+    [0.00026909, 0.000105501, 0.0], [0.000250969, 9.8428e-5, 0.0], [0.00023413, 9.1853e-5, 0.0],
+    [0.00021847, 8.5738e-5, 0.0], [0.00020391, 8.0048e-5, 0.0], [0.00019035, 7.4751e-5, 0.0],
+    [0.00017773, 6.9819e-5, 0.0], [0.00016597, 6.5222e-5, 0.0], [0.00015502, 6.0939e-5, 0.0],
+    [0.0001448, 5.6942e-5, 0.0], [0.00013528, 5.3217e-5, 0.0], [0.00012639, 4.9737e-5, 0.0],
+    [0.0001181, 4.6491e-5, 0.0], [0.00011037, 4.3464e-5, 0.0], [0.00010315, 4.0635e-5, 0.0],
+    [9.6427e-5, 3.8e-5, 0.0], [9.0151e-5, 3.55405e-5, 0.0], [8.4294e-5, 3.32448e-5, 0.0],
+    [7.883e-5, 3.11006e-5, 0.0], [7.3729e-5, 2.9099e-5, 0.0], [6.8969e-5, 2.72307e-5, 0.0],
+    [6.45258e-5, 2.5486e-5, 0.0], [6.0376e-5, 2.38561e-5, 0.0], [5.65e-5, 2.23332e-5, 0.0],
+    [5.288e-5, 2.09104e-5, 0.0], [4.9498e-5, 1.95808e-5, 0.0], [4.6339e-5, 1.83384e-5, 0.0],
+    [4.3389e-5, 1.71777e-5, 0.0], [4.0634e-5, 1.60934e-5, 0.0], [3.806e-5, 1.508e-5, 0.0],
+    [3.5657e-5, 1.41336e-5, 0.0], [3.34117e-5, 1.3249e-5, 0.0],
+];
+
+/// CIE 2015 2° cone-fundamental-based XYZ color matching functions.
+#[rustfmt::skip]
+pub const CIE2015_2: [[f32; 3]; LEN] = [
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.003769647, 0.0004146161, 0.0184726], [0.004532416, 0.0005028333, 0.02221101],
+    [0.005446553, 0.0006084991, 0.02669819], [0.006538868, 0.0007344436, 0.03206937], [0.007839699, 0.0008837389, 0.03847832],
+    [0.009382967, 0.001059646, 0.04609784], [0.01120608, 0.001265532, 0.05511953], [0.01334965, 0.001504753, 0.06575257],
+    [0.0158569, 0.001780493, 0.07822113], [0.01877286, 0.002095572, 0.09276013], [0.02214302, 0.002452194, 0.109609],
+    [0.02601285, 0.002852216, 0.1290077], [0.03043036, 0.003299115, 0.1512047], [0.03544325, 0.003797466, 0.1764441],
+    [0.0410964, 0.004352768, 0.2049517], [0.04742986, 0.004971717, 0.2369246], [0.05447394, 0.005661014, 0.2725123],
+    [0.06223612, 0.006421615, 0.311782], [0.07070048, 0.007250312, 0.3547064], [0.07982513, 0.008140173, 0.4011473],
+    [0.08953803, 0.00907986, 0.4508369], [0.09974848, 0.01005608, 0.5034164], [0.1104019, 0.01106456, 0.5586361],
+    [0.1214566, 0.01210522, 0.6162734], [0.1328741, 0.01318014, 0.6760982], [0.1446214, 0.01429377, 0.7378822],
+    [0.1566468, 0.01545004, 0.8013019], [0.1687901, 0.01664093, 0.8655573], [0.1808328, 0.01785302, 0.9295791],
+    [0.1925216, 0.01907018, 0.9921293], [0.2035729, 0.02027369, 1.051821], [0.2137531, 0.02144805, 1.107509],
+    [0.2231348, 0.02260041, 1.159527], [0.2319245, 0.02374789, 1.208869], [0.2403892, 0.02491247, 1.256834],
+    [0.2488523, 0.02612106, 1.305008], [0.2575896, 0.02739923, 1.354758], [0.2664991, 0.02874993, 1.405594],
+    [0.2753532, 0.03016909, 1.456414], [0.2838921, 0.03165145, 1.50596], [0.2918246, 0.03319038, 1.552826],
+    [0.29892, 0.03477912, 1.595902], [0.3052993, 0.03641495, 1.635768], [0.3112031, 0.03809569, 1.673573],
+    [0.3169047, 0.03981843, 1.710604], [0.3227087, 0.0415794, 1.74828], [0.3288194, 0.04337098, 1.787504],
+    [0.3349242, 0.0451718, 1.826609], [0.3405452, 0.0469542, 1.863108], [0.3451688, 0.04868718, 1.894332],
+    [0.3482554, 0.05033657, 1.917479], [0.3494153, 0.05187611, 1.930529], [0.3489075, 0.05332218, 1.934819],
+    [0.3471746, 0.05470603, 1.93265], [0.3446705, 0.05606335, 1.926395], [0.3418483, 0.05743393, 1.918437],
+    [0.339024, 0.05885107, 1.91043], [0.3359926, 0.06030809, 1.901224], [0.3324276, 0.06178644, 1.889],
+    [0.3280157, 0.0632657, 1.871996], [0.3224637, 0.06472352, 1.848545], [0.3156225, 0.06614749, 1.817792],
+    [0.3078201, 0.06757256, 1.781627], [0.2994771, 0.06904928, 1.742514], [0.2909776, 0.0706328, 1.702749],
+    [0.2826646, 0.07238339, 1.664439], [0.2747962, 0.0743596, 1.629207], [0.2674312, 0.07659383, 1.59736],
+    [0.2605847, 0.07911436, 1.568896], [0.2542749, 0.08195345, 1.543823], [0.2485254, 0.08514816, 1.522157],
+    [0.2433039, 0.08872657, 1.503611], [0.2383414, 0.09266008, 1.486673], [0.2333253, 0.09689723, 1.469595],
+    [0.2279619, 0.1013746, 1.450709], [0.2219781, 0.1060145, 1.42844], [0.2151735, 0.1107377, 1.401587],
+    [0.2075619, 0.1155111, 1.370094], [0.1992183, 0.1203122, 1.33422], [0.190229, 0.1251161, 1.294275],
+    [0.1806905, 0.1298957, 1.25061], [0.1707154, 0.1346299, 1.203696], [0.1604471, 0.1393309, 1.154316],
+    [0.1500244, 0.1440235, 1.103284], [0.1395705, 0.1487372, 1.051347], [0.129192, 0.1535066, 0.9991789],
+    [0.1189859, 0.1583644, 0.9473958], [0.1090615, 0.1633199, 0.8966222], [0.09951424, 0.1683761, 0.8473981],
+    [0.0904185, 0.1735365, 0.8001576], [0.08182895, 0.1788048, 0.7552379], [0.07376817, 0.1841819, 0.7127879],
+    [0.06619477, 0.1896559, 0.6725198], [0.0590638, 0.1952101, 0.6340976], [0.05234242, 0.2008259, 0.5972433],
+    [0.04600865, 0.2064828, 0.5617313], [0.04006154, 0.2121826, 0.5274921], [0.03454373, 0.2180279, 0.4948809],
+    [0.02949091, 0.2241586, 0.4642586], [0.0249214, 0.2307302, 0.4358841], [0.02083981, 0.237916, 0.4099313],
+    [0.01723591, 0.2458706, 0.3864261], [0.01407924, 0.2546023, 0.3650566], [0.01134516, 0.264076, 0.3454812],
+    [0.009019658, 0.274249, 0.3274095], [0.007097731, 0.285068, 0.3105939], [0.005571145, 0.2964837, 0.2948102],
+    [0.004394566, 0.308501, 0.2798194], [0.003516303, 0.3211393, 0.26541], [0.002887638, 0.3344175, 0.2514084],
+    [0.002461588, 0.3483536, 0.2376753], [0.002206348, 0.3629601, 0.2241211], [0.002149559, 0.3782275, 0.2107484],
+    [0.002337091, 0.3941359, 0.1975839], [0.002818931, 0.4106582, 0.1846574], [0.003649178, 0.4277595, 0.1720018],
+    [0.004891359, 0.4453993, 0.1596918], [0.006629364, 0.4635396, 0.1479415], [0.008942902, 0.4821376, 0.1369428],
+    [0.01190224, 0.501143, 0.1268279], [0.01556989, 0.5204972, 0.1176796], [0.01997668, 0.5401387, 0.109497],
+    [0.02504698, 0.5600208, 0.1020943], [0.0306753, 0.5800972, 0.09527993], [0.03674999, 0.6003172, 0.08890075],
+    [0.04315171, 0.6206256, 0.08283548], [0.04978584, 0.6409398, 0.07700982], [0.05668554, 0.6610772, 0.07144001],
+    [0.06391651, 0.6808134, 0.06615436], [0.07154352, 0.6999044, 0.06117199], [0.07962917, 0.718089, 0.05650407],
+    [0.08821473, 0.7351593, 0.05215121], [0.09726978, 0.7511821, 0.04809566], [0.1067504, 0.7663143, 0.0443172],
+    [0.1166192, 0.7807352, 0.04079734], [0.1268468, 0.7946448, 0.03751912], [0.137406, 0.8082074, 0.03446846],
+    [0.1482471, 0.8213817, 0.03163764], [0.1593076, 0.8340701, 0.02901901], [0.1705181, 0.8461711, 0.02660364],
+    [0.1818026, 0.8575799, 0.02438164], [0.193109, 0.8682408, 0.02234097], [0.2045085, 0.8783061, 0.02046415],
+    [0.2161166, 0.8879907, 0.01873456], [0.228065, 0.8975211, 0.01713788], [0.2405015, 0.9071347, 0.01566174],
+    [0.2535441, 0.9169947, 0.01429644], [0.26713, 0.9269295, 0.01303702], [0.2811351, 0.9366731, 0.01187897],
+    [0.2954164, 0.9459482, 0.01081725], [0.3098117, 0.9544675, 0.00984647], [0.3241678, 0.9619834, 0.008960687],
+    [0.3384319, 0.968439, 0.008152811], [0.3525786, 0.9738289, 0.007416025], [0.3665839, 0.9781519, 0.006744115],
+    [0.3804244, 0.9814106, 0.006131421], [0.3940988, 0.9836669, 0.005572778], [0.4076972, 0.9852081, 0.005063463],
+    [0.4213484, 0.9863813, 0.004599169], [0.4352003, 0.9875357, 0.004175971], [0.4494206, 0.9890228, 0.003790291],
+    [0.4641616, 0.9910811, 0.003438952], [0.4794395, 0.9934913, 0.003119341], [0.495218, 0.9959172, 0.002829038],
+    [0.5114395, 0.9980205, 0.002565722], [0.5280233, 0.9994608, 0.002327186], [0.5448696, 0.999993, 0.00211128],
+    [0.5618898, 0.9997557, 0.001915766], [0.5790137, 0.9989839, 0.001738589], [0.5961882, 0.9979123, 0.00157792],
+    [0.6133784, 0.9967737, 0.001432128], [0.6305897, 0.9957356, 0.001299781], [0.6479223, 0.9947115, 0.001179667],
+    [0.6654866, 0.9935534, 0.001070694], [0.6833782, 0.9921156, 0.0009718623], [0.7016774, 0.9902549, 0.0008822531],
+    [0.720411, 0.9878596, 0.0008010231], [0.7394495, 0.9849324, 0.0007273884], [0.7586285, 0.9815036, 0.0006606347],
+    [0.7777885, 0.9776035, 0.0006001146], [0.796775, 0.9732611, 0.0005452416], [0.815453, 0.9684764, 0.0004954847],
+    [0.8337389, 0.9631369, 0.0004503642], [0.8515493, 0.9571062, 0.0004094455], [0.8687862, 0.950254, 0.0003723345],
+    [0.8853376, 0.9424569, 0.0003386739], [0.9011588, 0.9336897, 0.0003081396], [0.9165278, 0.9242893, 0.000280437],
+    [0.9318245, 0.9146707, 0.0002552996], [0.9474524, 0.9052333, 0.0002324859], [0.9638388, 0.8963613, 0.0002117772],
+    [0.9812596, 0.8883069, 0.0001929758], [0.9992953, 0.8808462, 0.0001759024], [1.017343, 0.8736445, 0.0001603947],
+    [1.03479, 0.8663755, 0.0001463059], [1.051011, 0.8587203, 0.0001335031], [1.065522, 0.8504295, 0.000121866],
+    [1.078421, 0.8415047, 0.0001112857], [1.089944, 0.8320109, 0.0001016634], [1.10032, 0.8220154, 9.291003e-5],
+    [1.109767, 0.8115868, 8.494468e-5], [1.118438, 0.8007874, 7.769425e-5], [1.126266, 0.7896515, 7.109247e-5],
+    [1.133138, 0.7782053, 6.507936e-5], [1.138952, 0.7664733, 5.960061e-5], [1.14362, 0.7544785, 5.460706e-5],
+    [1.147095, 0.7422473, 5.005417e-5], [1.149464, 0.7298229, 4.590157e-5], [1.150838, 0.7172525, 4.211268e-5],
+    [1.151326, 0.7045818, 3.865437e-5], [1.151033, 0.6918553, 3.549661e-5], [1.150002, 0.6791009, 3.26122e-5],
+    [1.148061, 0.6662846, 2.997643e-5], [1.144998, 0.6533595, 2.756693e-5], [1.140622, 0.6402807, 2.536339e-5],
+    [1.134757, 0.6270066, 2.334738e-5], [1.127298, 0.6135148, 2.150221e-5], [1.118342, 0.5998494, 1.981268e-5],
+    [1.108033, 0.5860682, 1.8265e-5], [1.096515, 0.5722261, 1.684667e-5], [1.083928, 0.5583746, 1.554631e-5],
+    [1.070387, 0.5445535, 1.43536e-5], [1.055934, 0.5307673, 1.325915e-5], [1.040592, 0.517013, 1.225443e-5],
+    [1.024385, 0.5032889, 1.133169e-5], [1.007344, 0.489595, 1.048387e-5], [0.9895268, 0.4759442, 0.0],
+    [0.9711213, 0.4623958, 0.0], [0.9523257, 0.4490154, 0.0], [0.9333248, 0.4358622, 0.0],
+    [0.9142877, 0.4229897, 0.0], [0.8952798, 0.4104152, 0.0], [0.8760157, 0.3980356, 0.0],
+    [0.8561607, 0.38573, 0.0], [0.8354235, 0.3733907, 0.0], [0.8135565, 0.3609245, 0.0],
+    [0.7904565, 0.348286, 0.0], [0.7664364, 0.3355702, 0.0], [0.7418777, 0.3228963, 0.0],
+    [0.7171219, 0.3103704, 0.0], [0.6924717, 0.2980865, 0.0], [0.66816, 0.286116, 0.0],
+    [0.6442697, 0.2744822, 0.0], [0.620845, 0.2631953, 0.0], [0.5979243, 0.2522628, 0.0],
+    [0.575541, 0.2416902, 0.0], [0.5537296, 0.2314809, 0.0], [0.5325412, 0.2216378, 0.0],
+    [0.5120218, 0.2121622, 0.0], [0.492207, 0.2030542, 0.0], [0.4731224, 0.1943124, 0.0],
+    [0.4547417, 0.1859227, 0.0], [0.4368719, 0.1778274, 0.0], [0.4193121, 0.1699654, 0.0],
+    [0.401898, 0.1622841, 0.0], [0.3844986, 0.1547397, 0.0], [0.3670592, 0.1473081, 0.0],
+    [0.3497167, 0.1400169, 0.0], [0.3326305, 0.1329013, 0.0], [0.3159341, 0.1259913, 0.0],
+    [0.2997374, 0.119312, 0.0], [0.2841189, 0.112882, 0.0], [0.2691053, 0.1067113, 0.0],
+    [0.2547077, 0.1008052, 0.0], [0.2409319, 0.09516653, 0.0], [0.2277792, 0.08979594, 0.0],
+    [0.2152431, 0.08469044, 0.0], [0.203301, 0.07984009, 0.0], [0.1919276, 0.07523372, 0.0],
+    [0.1810987, 0.07086061, 0.0], [0.1707914, 0.06671045, 0.0], [0.1609842, 0.0627736, 0.0],
+    [0.1516577, 0.05904179, 0.0], [0.1427936, 0.05550703, 0.0], [0.1343737, 0.05216139, 0.0],
+    [0.1263808, 0.04899699, 0.0], [0.1187979, 0.04600578, 0.0], [0.1116088, 0.04317885, 0.0],
+    [0.1047975, 0.04050755, 0.0], [0.09834835, 0.03798376, 0.0], [0.09224597, 0.03559982, 0.0],
+    [0.08647506, 0.03334856, 0.0], [0.08101986, 0.03122332, 0.0], [0.07586514, 0.0292178, 0.0],
+    [0.07099633, 0.02732601, 0.0], [0.0663996, 0.02554223, 0.0], [0.06206225, 0.02386121, 0.0],
+    [0.05797409, 0.02227859, 0.0], [0.05412533, 0.0207902, 0.0], [0.050506, 0.01939185, 0.0],
+    [0.04710606, 0.01807939, 0.0], [0.04391411, 0.01684817, 0.0], [0.04091411, 0.01569188, 0.0],
+    [0.03809067, 0.01460446, 0.0], [0.03543034, 0.01358062, 0.0], [0.03292138, 0.01261573, 0.0],
+    [0.03055672, 0.01170696, 0.0], [0.02834146, 0.01085608, 0.0], [0.02628033, 0.01006476, 0.0],
+    [0.02437465, 0.009333376, 0.0], [0.02262306, 0.008661284, 0.0], [0.02101935, 0.008046048, 0.0],
+    [0.01954647, 0.00748113, 0.0], [0.01818727, 0.006959987, 0.0], [0.01692727, 0.00647707, 0.0],
+    [0.01575417, 0.006027677, 0.0], [0.01465854, 0.005608169, 0.0], [0.01363571, 0.005216691, 0.0],
+    [0.01268205, 0.004851785, 0.0], [0.01179394, 0.004512008, 0.0], [0.01096778, 0.004195941, 0.0],
+    [0.01019964, 0.003902057, 0.0], [0.009484317, 0.003628371, 0.0], [0.008816851, 0.003373005, 0.0],
+    [0.008192921, 0.003134315, 0.0], [0.00760875, 0.002910864, 0.0], [0.007061391, 0.002701528, 0.0],
+    [0.006549509, 0.002505796, 0.0], [0.00607197, 0.002323231, 0.0], [0.005627476, 0.002153333, 0.0],
+    [0.005214608, 0.001995557, 0.0], [0.004831848, 0.001849316, 0.0], [0.004477579, 0.001713976, 0.0],
+    [0.004150166, 0.001588899, 0.0], [0.003847988, 0.001473453, 0.0], [0.003569452, 0.001367022, 0.0],
+    [0.003312857, 0.001268954, 0.0], [0.003076022, 0.001178421, 0.0], [0.002856894, 0.001094644, 0.0],
+    [0.002653681, 0.001016943, 0.0], [0.002464821, 0.0009447269, 0.0], [0.00228906, 0.0008775171, 0.0],
+    [0.002125694, 0.0008150438, 0.0], [0.001974121, 0.0007570755, 0.0], [0.001833723, 0.0007033755, 0.0],
+    [0.001703876, 0.000653705, 0.0], [0.001583904, 0.0006078048, 0.0], [0.001472939, 0.0005653435, 0.0],
+    [0.001370151, 0.0005260046, 0.0], [0.001274803, 0.0004895061, 0.0], [0.001186238, 0.000455597, 0.0],
+    [0.001103871, 0.0004240548, 0.0], [0.001027194, 0.000394686, 0.0], [0.0009557493, 0.0003673178, 0.0],
+    [0.0008891262, 0.0003417941, 0.0], [0.0008269535, 0.0003179738, 0.0], [0.0007689351, 0.0002957441, 0.0],
+    [0.0007149425, 0.0002750558, 0.0], [0.000664859, 0.000255864, 0.0], [0.0006185421, 0.0002381142, 0.0],
+    [0.0005758303, 0.0002217445, 0.0], [0.0005365046, 0.0002066711, 0.0], [0.0005001842, 0.0001927474, 0.0],
+    [0.0004665005, 0.0001798315, 0.0], [0.0004351386, 0.0001678023, 0.0], [0.0004058303, 0.0001565566, 0.0],
+    [0.0003783733, 0.0001460168, 0.0], [0.0003526892, 0.0001361535, 0.0], [0.0003287199, 0.0001269451, 0.0],
+    [0.0003063998, 0.0001183671, 0.0], [0.0002856577, 0.0001103928, 0.0], [0.0002664108, 0.0001029908, 0.0],
+    [0.0002485462, 9.611836e-5, 0.0], [0.0002319529, 8.973323e-5, 0.0], [0.00021653, 8.379694e-5, 0.0],
+    [0.0002021853, 7.827442e-5, 0.0], [0.0001888338, 7.313312e-5, 0.0], [0.0001763935, 6.834142e-5, 0.0],
+    [0.0001647895, 6.387035e-5, 0.0], [0.0001539542, 5.969389e-5, 0.0], [0.000143827, 5.578862e-5, 0.0],
+    [0.0001343572, 5.213509e-5, 0.0], [0.0001255141, 4.872179e-5, 0.0], [0.0001172706, 4.553845e-5, 0.0],
+    [0.0001095983, 4.257443e-5, 0.0], [0.0001024685, 3.981884e-5, 0.0], [9.584715e-5, 3.725877e-5, 0.0],
+    [8.968316e-5, 3.487467e-5, 0.0], [8.392734e-5, 3.264765e-5, 0.0], [7.853708e-5, 3.05614e-5, 0.0],
+    [7.347551e-5, 2.860175e-5, 0.0], [6.871576e-5, 2.675841e-5, 0.0], [6.425257e-5, 2.502943e-5, 0.0],
+    [6.008292e-5, 2.341373e-5, 0.0], [5.620098e-5, 2.190914e-5, 0.0], [5.25987e-5, 2.051259e-5, 0.0],
+    [4.926279e-5, 1.921902e-5, 0.0], [4.616623e-5, 1.801796e-5, 0.0], [4.328212e-5, 1.689899e-5, 0.0],
+    [4.058715e-5, 1.585309e-5, 0.0], [3.806114e-5, 1.487243e-5, 0.0],
+];
+
+/// CIE 2015 10° cone-fundamental-based XYZ color matching functions.
+#[rustfmt::skip]
+pub const CIE2015_10: [[f32; 3]; LEN] = [
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.00295242, 0.0004076779, 0.01318752], [0.003577275, 0.0004977769, 0.01597879],
+    [0.004332146, 0.0006064754, 0.01935758], [0.005241609, 0.000737004, 0.02343758], [0.006333902, 0.0008929388, 0.02835021],
+    [0.007641137, 0.001078166, 0.03424588], [0.009199401, 0.001296816, 0.04129467], [0.01104869, 0.001553159, 0.04968641],
+    [0.01323262, 0.001851463, 0.05962964], [0.01579791, 0.002195795, 0.07134926], [0.01879338, 0.002589775, 0.08508254],
+    [0.02226949, 0.003036799, 0.1010753], [0.02627978, 0.003541926, 0.1195838], [0.03087862, 0.004111422, 0.1408647],
+    [0.0361189, 0.004752618, 0.1651644], [0.04204986, 0.005474207, 0.1927065], [0.04871256, 0.006285034, 0.2236782],
+    [0.05612868, 0.007188068, 0.2582109], [0.06429866, 0.008181786, 0.2963632], [0.07319818, 0.009260417, 0.3381018],
+    [0.08277331, 0.01041303, 0.3832822], [0.09295327, 0.01162642, 0.4316884], [0.1037137, 0.01289884, 0.483244],
+    [0.115052, 0.01423442, 0.5379345], [0.1269771, 0.0156408, 0.595774], [0.1395127, 0.01712968, 0.6568187],
+    [0.1526661, 0.01871265, 0.7210459], [0.1663054, 0.02038394, 0.7878635], [0.1802197, 0.02212935, 0.8563391],
+    [0.1941448, 0.02392985, 0.9253017], [0.2077647, 0.02576133, 0.9933444], [0.2207911, 0.02760156, 1.059178],
+    [0.2332355, 0.02945513, 1.122832], [0.2452462, 0.03133884, 1.184947], [0.2570397, 0.03327575, 1.246476],
+    [0.2688989, 0.03529554, 1.308674], [0.2810677, 0.03742705, 1.372628], [0.2933967, 0.03967137, 1.437661],
+    [0.3055933, 0.04201998, 1.502449], [0.3173165, 0.04446166, 1.565456], [0.3281798, 0.04698226, 1.62494],
+    [0.3378678, 0.04956742, 1.679488], [0.3465097, 0.05221219, 1.729668], [0.3543953, 0.05491387, 1.776755],
+    [0.3618655, 0.05766919, 1.822228], [0.3693084, 0.06047429, 1.867751], [0.3770107, 0.06332195, 1.914504],
+    [0.384685, 0.06619271, 1.961055], [0.3918591, 0.06906185, 2.005136], [0.3980192, 0.0719019, 2.044296],
+    [0.4026189, 0.07468288, 2.075946], [0.4052637, 0.07738452, 2.098231], [0.4062482, 0.08003601, 2.112591],
+    [0.406066, 0.08268524, 2.121427], [0.4052283, 0.08538745, 2.127239], [0.4042529, 0.08820537, 2.132574],
+    [0.4034808, 0.09118925, 2.139093], [0.4025362, 0.09431041, 2.144815], [0.4008675, 0.09751346, 2.146832],
+    [0.3979327, 0.1007349, 2.14225], [0.3932139, 0.103903, 2.128264], [0.3864108, 0.1069639, 2.103205],
+    [0.3779513, 0.1099676, 2.069388], [0.3684176, 0.1129992, 2.03003], [0.3583473, 0.1161541, 1.988178],
+    [0.3482214, 0.1195389, 1.946651], [0.338383, 0.1232503, 1.907521], [0.3288309, 0.1273047, 1.870689],
+    [0.3194977, 0.1316964, 1.835578], [0.3103345, 0.1364178, 1.801657], [0.3013112, 0.1414586, 1.76844],
+    [0.2923754, 0.1468003, 1.735338], [0.2833273, 0.1524002, 1.701254], [0.2739463, 0.1582021, 1.665053],
+    [0.2640352, 0.16414, 1.625712], [0.2534221, 0.1701373, 1.582342], [0.2420135, 0.1761233, 1.534439],
+    [0.2299346, 0.1820896, 1.482544], [0.2173617, 0.1880463, 1.427438], [0.2044672, 0.1940065, 1.369876],
+    [0.1914176, 0.1999859, 1.310576], [0.1783672, 0.2060054, 1.250226], [0.1654407, 0.2120981, 1.189511],
+    [0.1527391, 0.2183041, 1.12905], [0.1403439, 0.2246686, 1.069379], [0.1283167, 0.2312426, 1.010952],
+    [0.1167124, 0.2380741, 0.9541809], [0.1056121, 0.2451798, 0.8995253], [0.09508569, 0.2525682, 0.847372],
+    [0.08518206, 0.2602479, 0.7980093], [0.0759312, 0.2682271, 0.7516389], [0.06733159, 0.2765005, 0.7082645],
+    [0.05932018, 0.2850035, 0.6673867], [0.05184106, 0.2936475, 0.6284798], [0.04486119, 0.3023319, 0.5911174],
+    [0.0383677, 0.3109438, 0.5549619], [0.03237296, 0.3194105, 0.5198843], [0.02692095, 0.3278683, 0.4862772],
+    [0.0220407, 0.3365263, 0.4545497], [0.01773951, 0.3456176, 0.4249955], [0.01400745, 0.3554018, 0.3978114],
+    [0.01082291, 0.3660893, 0.3730218], [0.008168996, 0.3775857, 0.3502618], [0.006044623, 0.389696, 0.3291407],
+    [0.004462638, 0.4021947, 0.3093356], [0.00344681, 0.4148227, 0.2905816], [0.003009513, 0.4273539, 0.2726773],
+    [0.003090744, 0.4398206, 0.2555143], [0.003611221, 0.452336, 0.2390188], [0.004491435, 0.4650298, 0.2231335],
+    [0.005652072, 0.4780482, 0.2078158], [0.007035322, 0.4915173, 0.1930407], [0.008669631, 0.5054224, 0.1788089],
+    [0.01060755, 0.5197057, 0.1651287], [0.01290468, 0.5343012, 0.1520103], [0.01561956, 0.5491344, 0.1394643],
+    [0.0188164, 0.5641302, 0.1275353], [0.02256923, 0.5792416, 0.1163771], [0.02694456, 0.5944264, 0.1061161],
+    [0.0319991, 0.6096388, 0.09682266], [0.03778185, 0.6248296, 0.08852389], [0.04430635, 0.6399656, 0.08118263],
+    [0.05146516, 0.6550943, 0.07463132], [0.05912224, 0.6702903, 0.06870644], [0.0671422, 0.6856375, 0.06327834],
+    [0.07538941, 0.7012292, 0.05824484], [0.08376697, 0.7171103, 0.05353812], [0.09233581, 0.7330917, 0.04914863],
+    [0.101194, 0.7489041, 0.04507511], [0.1104362, 0.764253, 0.04131175], [0.1201511, 0.7788199, 0.03784916],
+    [0.130396, 0.792341, 0.03467234], [0.141131, 0.804851, 0.03175471], [0.1522944, 0.8164747, 0.02907029],
+    [0.1638288, 0.827352, 0.02659651], [0.1756832, 0.8376358, 0.02431375], [0.1878114, 0.8474653, 0.02220677],
+    [0.2001621, 0.8568868, 0.02026852], [0.2126822, 0.8659242, 0.01849246], [0.2253199, 0.8746041, 0.01687084],
+    [0.2380254, 0.8829552, 0.01539505], [0.2507787, 0.8910274, 0.0140545], [0.2636778, 0.8989495, 0.01283354],
+    [0.2768607, 0.9068753, 0.01171754], [0.2904792, 0.9149652, 0.01069415], [0.3046991, 0.9233858, 0.009753],
+    [0.3196485, 0.9322325, 0.008886096], [0.3352447, 0.9412862, 0.008089323], [0.351329, 0.9502378, 0.007359131],
+    [0.3677148, 0.9587647, 0.006691736], [0.3841856, 0.9665325, 0.006083223], [0.4005312, 0.9732504, 0.005529423],
+    [0.4166669, 0.9788415, 0.005025504], [0.432542, 0.9832867, 0.004566879], [0.4481063, 0.986572, 0.004149405],
+    [0.4633109, 0.9886887, 0.003769336], [0.478144, 0.9897056, 0.003423302], [0.4927483, 0.9899849, 0.003108313],
+    [0.5073315, 0.9899624, 0.00282165], [0.5221315, 0.9900731, 0.00256083], [0.537417, 0.99075, 0.002323578],
+    [0.5534217, 0.9922826, 0.002107847], [0.5701242, 0.9943837, 0.001911867], [0.5874093, 0.9966221, 0.001734006],
+    [0.6051269, 0.9985649, 0.001572736], [0.6230892, 0.9997775, 0.001426627], [0.6410999, 0.999944, 0.001294325],
+    [0.6590659, 0.99922, 0.001174475], [0.6769436, 0.9978793, 0.001065842], [0.6947143, 0.9961934, 0.0009673215],
+    [0.7123849, 0.9944304, 0.0008779264], [0.7299978, 0.9927831, 0.0007967847], [0.7476478, 0.9911578, 0.0007231502],
+    [0.765425, 0.9893925, 0.0006563501], [0.7834009, 0.9873288, 0.0005957678], [0.8016277, 0.9848127, 0.0005408385],
+    [0.8201041, 0.9817253, 0.0004910441], [0.8386843, 0.9780714, 0.0004459046], [0.8571936, 0.973886, 0.0004049826],
+    [0.8754652, 0.9692028, 0.0003678818], [0.8933408, 0.9640545, 0.0003342429], [0.9106772, 0.9584409, 0.0003037407],
+    [0.9273554, 0.9522379, 0.0002760809], [0.9432502, 0.9452968, 0.000250997], [0.9582244, 0.9374773, 0.0002282474],
+    [0.9721304, 0.9286495, 0.0002076129], [0.9849237, 0.9187953, 0.0001888948], [0.9970067, 0.9083014, 0.0001719127],
+    [1.008907, 0.8976352, 0.000156503], [1.021163, 0.8872401, 0.0001425177], [1.034327, 0.877536, 0.000129823],
+    [1.048753, 0.868792, 0.0001182974], [1.063937, 0.8607474, 0.000107831], [1.079166, 0.8530233, 9.832455e-5],
+    [1.093723, 0.8452535, 8.968787e-5], [1.106886, 0.8370838, 8.183954e-5], [1.118106, 0.8282409, 7.470582e-5],
+    [1.127493, 0.818732, 6.821991e-5], [1.135317, 0.8086352, 6.232132e-5], [1.141838, 0.7980296, 5.695534e-5],
+    [1.147304, 0.786995, 5.207245e-5], [1.151897, 0.775604, 4.762781e-5], [1.155582, 0.7638996, 4.358082e-5],
+    [1.158284, 0.7519157, 3.989468e-5], [1.159934, 0.7396832, 3.653612e-5], [1.160477, 0.7272309, 3.347499e-5],
+    [1.15989, 0.7145878, 3.0684e-5], [1.158259, 0.7017926, 2.813839e-5], [1.155692, 0.6888866, 2.581574e-5],
+    [1.152293, 0.6759103, 2.369574e-5], [1.148163, 0.6629035, 2.175998e-5], [1.143345, 0.6498911, 1.999179e-5],
+    [1.137685, 0.636841, 1.837603e-5], [1.130993, 0.6237092, 1.689896e-5], [1.123097, 0.6104541, 1.554815e-5],
+    [1.113846, 0.5970375, 1.431231e-5], [1.103152, 0.5834395, 1.318119e-5], [1.091121, 0.5697044, 1.214548e-5],
+    [1.077902, 0.5558892, 1.119673e-5], [1.063644, 0.5420475, 1.032727e-5], [1.048485, 0.5282296, 9.53013e-6],
+    [1.032546, 0.5144746, 8.798979e-6], [1.01587, 0.5007881, 8.128065e-6], [0.9984859, 0.4871687, 7.51216e-6],
+    [0.9804227, 0.473616, 6.946506e-6], [0.9617111, 0.4601308, 6.426776e-6], [0.9424119, 0.446726, 0.0],
+    [0.9227049, 0.4334589, 0.0], [0.9027804, 0.4203919, 0.0], [0.8828123, 0.407581, 0.0],
+    [0.8629581, 0.3950755, 0.0], [0.8432731, 0.3828894, 0.0], [0.8234742, 0.370919, 0.0],
+    [0.8032342, 0.3590447, 0.0], [0.7822715, 0.3471615, 0.0], [0.7603498, 0.3351794, 0.0],
+    [0.7373739, 0.3230562, 0.0], [0.713647, 0.3108859, 0.0], [0.6895336, 0.298784, 0.0],
+    [0.6653567, 0.2868527, 0.0], [0.6413984, 0.2751807, 0.0], [0.6178723, 0.2638343, 0.0],
+    [0.5948484, 0.252833, 0.0], [0.57236, 0.2421835, 0.0], [0.5504353, 0.2318904, 0.0],
+    [0.5290979, 0.2219564, 0.0], [0.5083728, 0.2123826, 0.0], [0.4883006, 0.2031698, 0.0],
+    [0.4689171, 0.1943179, 0.0], [0.4502486, 0.185825, 0.0], [0.4323126, 0.1776882, 0.0],
+    [0.415079, 0.1698926, 0.0], [0.3983657, 0.1623822, 0.0], [0.3819846, 0.1550986, 0.0],
+    [0.3657821, 0.1479918, 0.0], [0.3496358, 0.1410203, 0.0], [0.3334937, 0.1341614, 0.0],
+    [0.3174776, 0.1274401, 0.0], [0.3017298, 0.1208887, 0.0], [0.2863684, 0.1145345, 0.0],
+    [0.27149, 0.1083996, 0.0], [0.2571632, 0.1025007, 0.0], [0.2434101, 0.09684588, 0.0],
+    [0.2302389, 0.09143944, 0.0], [0.2176527, 0.08628318, 0.0], [0.2056507, 0.08137687, 0.0],
+    [0.1942251, 0.07671708, 0.0], [0.183353, 0.07229404, 0.0], [0.1730097, 0.06809696, 0.0],
+    [0.1631716, 0.06411549, 0.0], [0.1538163, 0.06033976, 0.0], [0.144923, 0.05676054, 0.0],
+    [0.1364729, 0.05336992, 0.0], [0.1284483, 0.05016027, 0.0], [0.120832, 0.04712405, 0.0],
+    [0.1136072, 0.04425383, 0.0], [0.1067579, 0.04154205, 0.0], [0.1002685, 0.03898042, 0.0],
+    [0.09412394, 0.03656091, 0.0], [0.08830929, 0.03427597, 0.0], [0.0828101, 0.03211852, 0.0],
+    [0.07761208, 0.03008192, 0.0], [0.07270064, 0.02816001, 0.0], [0.06806167, 0.02634698, 0.0],
+    [0.06368176, 0.02463731, 0.0], [0.05954815, 0.02302574, 0.0], [0.05564917, 0.02150743, 0.0],
+    [0.05197543, 0.02007838, 0.0], [0.04851788, 0.01873474, 0.0], [0.04526737, 0.01747269, 0.0],
+    [0.04221473, 0.01628841, 0.0], [0.03934954, 0.01517767, 0.0], [0.0366573, 0.01413473, 0.0],
+    [0.03412407, 0.01315408, 0.0], [0.03173768, 0.01223092, 0.0], [0.02948752, 0.01136106, 0.0],
+    [0.02736717, 0.0105419, 0.0], [0.02538113, 0.00977505, 0.0], [0.02353356, 0.009061962, 0.0],
+    [0.02182558, 0.008402962, 0.0], [0.0202559, 0.007797457, 0.0], [0.01881892, 0.00724323, 0.0],
+    [0.0174993, 0.006734381, 0.0], [0.01628167, 0.006265001, 0.0], [0.01515301, 0.005830085, 0.0],
+    [0.0141023, 0.005425391, 0.0], [0.01312106, 0.005047634, 0.0], [0.01220509, 0.00469514, 0.0],
+    [0.01135114, 0.004366592, 0.0], [0.01055593, 0.004060685, 0.0], [0.009816228, 0.00377614, 0.0],
+    [0.009128517, 0.003511578, 0.0], [0.008488116, 0.003265211, 0.0], [0.007890589, 0.003035344, 0.0],
+    [0.007332061, 0.002820496, 0.0], [0.006809147, 0.002619372, 0.0], [0.006319204, 0.00243096, 0.0],
+    [0.005861036, 0.002254796, 0.0], [0.005433624, 0.002090489, 0.0], [0.005035802, 0.001937586, 0.0],
+    [0.004666298, 0.001795595, 0.0], [0.00432375, 0.001663989, 0.0], [0.004006709, 0.001542195, 0.0],
+    [0.003713708, 0.001429639, 0.0], [0.003443294, 0.001325752, 0.0], [0.003194041, 0.00122998, 0.0],
+    [0.002964424, 0.001141734, 0.0], [0.002752492, 0.001060269, 0.0], [0.002556406, 0.0009848854, 0.0],
+    [0.002374564, 0.0009149703, 0.0], [0.002205568, 0.0008499903, 0.0], [0.002048294, 0.0007895158, 0.0],
+    [0.001902113, 0.0007333038, 0.0], [0.001766485, 0.0006811458, 0.0], [0.001640857, 0.0006328287, 0.0],
+    [0.001524672, 0.0005881375, 0.0], [0.001417322, 0.0005468389, 0.0], [0.001318031, 0.0005086349, 0.0],
+    [0.001226059, 0.0004732403, 0.0], [0.001140743, 0.0004404016, 0.0], [0.001061495, 0.0004098928, 0.0],
+    [0.0009877949, 0.0003815137, 0.0], [0.0009191847, 0.0003550902, 0.0], [0.0008552568, 0.0003304668, 0.0],
+    [0.0007956433, 0.000307503, 0.0], [0.000740012, 0.0002860718, 0.0], [0.000688098, 0.0002660718, 0.0],
+    [0.0006397864, 0.0002474586, 0.0], [0.0005949726, 0.0002301919, 0.0], [0.0005535291, 0.0002142225, 0.0],
+    [0.0005153113, 0.0001994949, 0.0], [0.0004801234, 0.0001859336, 0.0], [0.0004476245, 0.0001734067, 0.0],
+    [0.0004174846, 0.0001617865, 0.0], [0.0003894221, 0.0001509641, 0.0], [0.0003631969, 0.0001408466, 0.0],
+    [0.0003386279, 0.0001313642, 0.0], [0.0003156452, 0.0001224905, 0.0], [0.0002941966, 0.000114206, 0.0],
+    [0.0002742235, 0.0001064886, 0.0], [0.0002556624, 9.931439e-5, 0.0], [0.000238439, 9.265512e-5, 0.0],
+    [0.0002224525, 8.647225e-5, 0.0], [0.0002076036, 8.07278e-5, 0.0], [0.0001938018, 7.538716e-5, 0.0],
+    [0.0001809649, 7.041878e-5, 0.0], [0.0001690167, 6.579338e-5, 0.0], [0.0001578839, 6.14825e-5, 0.0],
+    [0.0001474993, 5.746008e-5, 0.0], [0.0001378026, 5.370272e-5, 0.0], [0.0001287394, 5.018934e-5, 0.0],
+    [0.0001202644, 4.690245e-5, 0.0], [0.0001123502, 4.383167e-5, 0.0], [0.0001049725, 4.09678e-5, 0.0],
+    [9.810596e-5, 3.830123e-5, 0.0], [9.172477e-5, 3.582218e-5, 0.0], [8.579861e-5, 3.351903e-5, 0.0],
+    [8.028174e-5, 3.137419e-5, 0.0], [7.513013e-5, 2.937068e-5, 0.0], [7.030565e-5, 2.74938e-5, 0.0],
+    [6.577532e-5, 2.573083e-5, 0.0], [6.151508e-5, 2.407249e-5, 0.0], [5.752025e-5, 2.251704e-5, 0.0],
+    [5.378813e-5, 2.10635e-5, 0.0], [5.03135e-5, 1.970991e-5, 0.0], [4.708916e-5, 1.845353e-5, 0.0],
+    [4.410322e-5, 1.728979e-5, 0.0], [4.13315e-5, 1.620928e-5, 0.0], [3.874992e-5, 1.520262e-5, 0.0],
+    [3.633762e-5, 1.426169e-5, 0.0], [3.407653e-5, 1.337946e-5, 0.0],
+];
+
+/// CIE 2006 2° cone fundamentals (l̄, m̄, s̄), energy-based and normalized
+/// to a peak of 1, recovered from [`CIE2015_2`] with the inverse of the
+/// CIE 170-2 transformation.
+#[rustfmt::skip]
+pub const CIE2006_LMS_2: [[f32; 3]; LEN] = [
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0004150036, 0.0003683489, 0.009547286], [0.0005026502, 0.0004480153, 0.01147943],
+    [0.0006073667, 0.0005439657, 0.01379856], [0.0007318501, 0.000658983, 0.01657457], [0.0008790115, 0.0007961212, 0.01988694],
+    [0.001051922, 0.0009586587, 0.02382498], [0.001253733, 0.001150023, 0.0284877], [0.001487561, 0.001373673, 0.03398323],
+    [0.001756332, 0.001632957, 0.04042742], [0.002062613, 0.001930886, 0.04794168], [0.002408363, 0.002269906, 0.05664977],
+    [0.002795219, 0.002652108, 0.0666757], [0.003226399, 0.003081097, 0.07814788], [0.003706171, 0.003561558, 0.09119249],
+    [0.004239723, 0.004099001, 0.1059262], [0.00483339, 0.004700102, 0.1224509], [0.005493349, 0.005371864, 0.1408439],
+    [0.006219325, 0.006117576, 0.1611399], [0.007006307, 0.006937954, 0.1833247], [0.007845033, 0.007831444, 0.207327],
+    [0.008721268, 0.008793686, 0.2330083], [0.00961879, 0.009818649, 0.2601832], [0.01053237, 0.01090443, 0.2887227],
+    [0.01146198, 0.01205083, 0.3185117], [0.01241045, 0.01325824, 0.3494312], [0.0133837, 0.01452772, 0.3813634],
+    [0.01438703, 0.01586002, 0.4141409], [0.01541161, 0.01724961, 0.4473503], [0.01644241, 0.01868776, 0.480439],
+    [0.01746145, 0.02016376, 0.5127672], [0.01844805, 0.02166483, 0.5436179], [0.01938523, 0.02318008, 0.5723994],
+    [0.02028114, 0.02471391, 0.5992842], [0.02115445, 0.02627849, 0.6247858], [0.02202862, 0.02789049, 0.6495758],
+    [0.02293178, 0.02957139, 0.6744738], [0.02388962, 0.03134376, 0.7001864], [0.02490262, 0.03321509, 0.7264602],
+    [0.02596313, 0.03518887, 0.7527258], [0.02706189, 0.03726833, 0.7783329], [0.02818772, 0.03945659, 0.8025548],
+    [0.02933033, 0.04175462, 0.824818], [0.03048984, 0.04415436, 0.8454222], [0.03166944, 0.04664323, 0.8649611],
+    [0.0328731, 0.04920503, 0.8841], [0.03410541, 0.05181984, 0.9035723], [0.03536703, 0.05446448, 0.9238447],
+    [0.03664003, 0.0571131, 0.9440555], [0.03789885, 0.05973692, 0.9629194], [0.0391148, 0.06230378, 0.9790571],
+    [0.04025626, 0.06477821, 0.9910203], [0.0412989, 0.06713298, 0.997765], [0.0422582, 0.06938449, 0.9999822],
+    [0.04316272, 0.07156585, 0.9988612], [0.04404437, 0.07371635, 0.9956284], [0.04493798, 0.07588123, 0.9915154],
+    [0.04587291, 0.07809794, 0.9873771], [0.04684593, 0.08035371, 0.9826191], [0.04784463, 0.08261984, 0.9763013],
+    [0.04885553, 0.08486442, 0.9675131], [0.04986396, 0.08705235, 0.9553928], [0.05086184, 0.08916399, 0.9394986],
+    [0.05187311, 0.09125227, 0.9208072], [0.05293173, 0.09339504, 0.9005922], [0.05407461, 0.09567754, 0.8800403],
+    [0.05534186, 0.09819335, 0.8602404], [0.05677346, 0.1010314, 0.8420312], [0.05839733, 0.1042293, 0.8255716],
+    [0.06024089, 0.1078141, 0.8108604], [0.06233525, 0.1118167, 0.7979018], [0.06471644, 0.1162721, 0.786704],
+    [0.06741305, 0.1212043, 0.7771188], [0.07040401, 0.126573, 0.7683647], [0.07364889, 0.1323106, 0.7595382],
+    [0.07709781, 0.1383336, 0.7497772], [0.08068938, 0.1445407, 0.7382678], [0.08436131, 0.1508277, 0.7243892],
+    [0.08809043, 0.1571457, 0.7081126], [0.09186302, 0.163457, 0.6895716], [0.09566364, 0.1697209, 0.6689266],
+    [0.09947551, 0.1758927, 0.646359], [0.1032856, 0.1819377, 0.6221122], [0.1071035, 0.187872, 0.5965909],
+    [0.1109472, 0.1937309, 0.5702158], [0.1148383, 0.1995565, 0.5433729], [0.1188022, 0.2053981, 0.5164106],
+    [0.1228626, 0.2113021, 0.4896473], [0.1270258, 0.217283, 0.4634057], [0.1312931, 0.223347, 0.437965],
+    [0.1356657, 0.2295013, 0.4135495], [0.1401451, 0.235754, 0.3903334], [0.1447309, 0.2421085, 0.3683937],
+    [0.1494155, 0.2485453, 0.3475818], [0.1541885, 0.2550371, 0.3277238], [0.1590383, 0.2615539, 0.3086762],
+    [0.1639515, 0.268063, 0.2903224], [0.1689323, 0.2745614, 0.2726264], [0.1740633, 0.28118, 0.2557718],
+    [0.1794573, 0.2880971, 0.2399451], [0.1852409, 0.2955084, 0.2252802], [0.1915562, 0.3036296, 0.2118668],
+    [0.1985324, 0.3126492, 0.1997185], [0.2061825, 0.322565, 0.188674], [0.2144848, 0.3333192, 0.1785568],
+    [0.2234114, 0.3448444, 0.1692167], [0.2329255, 0.3570606, 0.1605258], [0.2429922, 0.3698955, 0.1523682],
+    [0.2536155, 0.3833551, 0.1446205], [0.2648096, 0.397467, 0.1371732], [0.2765872, 0.4122601, 0.1299367],
+    [0.2889594, 0.4277645, 0.1228389], [0.3019337, 0.4440008, 0.1158336], [0.3155077, 0.4609468, 0.1089222],
+    [0.3296731, 0.4785618, 0.1021183], [0.3444159, 0.4967955, 0.09543741], [0.3597164, 0.5155868, 0.08889655],
+    [0.3755504, 0.5348675, 0.08253431], [0.3918946, 0.5545746, 0.07646135], [0.4087215, 0.5746395, 0.07077683],
+    [0.4259977, 0.5949843, 0.0655491], [0.4436829, 0.6155202, 0.06082094], [0.4617311, 0.636162, 0.05659188],
+    [0.4800938, 0.6568717, 0.05276591], [0.4987168, 0.6776236, 0.049244], [0.5175393, 0.6983926, 0.04594702],
+    [0.5364938, 0.719154, 0.04281228], [0.5554927, 0.7398441, 0.03980137], [0.5743861, 0.7602355, 0.0369227],
+    [0.592995, 0.7800387, 0.03419089], [0.6111235, 0.798941, 0.03161583], [0.6285611, 0.8166096, 0.02920328],
+    [0.6451386, 0.8327827, 0.02695357], [0.6609074, 0.8475502, 0.02485752], [0.6759933, 0.8611135, 0.02290468],
+    [0.6905424, 0.8736979, 0.02108549], [0.7047203, 0.8855497, 0.01939119], [0.7186623, 0.8968725, 0.01781451],
+    [0.7323229, 0.907638, 0.01635144], [0.7456057, 0.9177566, 0.01499804], [0.7584098, 0.927137, 0.01374969],
+    [0.7706299, 0.935687, 0.01260129], [0.7822078, 0.9433615, 0.0115466], [0.7932896, 0.950309, 0.01057659],
+    [0.804084, 0.9567328, 0.009682677], [0.8148128, 0.9628437, 0.008857456], [0.8257108, 0.9688584, 0.008094536],
+    [0.8369427, 0.974919, 0.007388901], [0.8483488, 0.9808495, 0.006737988], [0.8596756, 0.986388, 0.006139468],
+    [0.8706562, 0.9912674, 0.005590734], [0.8810109, 0.9952165, 0.005089], [0.8904959, 0.9980075, 0.004631197],
+    [0.8990518, 0.9995947, 0.004213658], [0.9066689, 0.9999818, 0.003832861], [0.9133414, 0.999177, 0.003485595],
+    [0.9190667, 0.9971926, 0.003168933], [0.9238977, 0.9941017, 0.002880207], [0.9280993, 0.9902044, 0.002616975],
+    [0.9319955, 0.9858555, 0.002377012], [0.9359163, 0.9814039, 0.002158288], [0.9401977, 0.9771934, 0.001958955],
+    [0.9450758, 0.9734408, 0.001777371], [0.9503666, 0.969881, 0.001612185], [0.9557751, 0.9661333, 0.001462146],
+    [0.9609999, 0.9618231, 0.001326055], [0.9657331, 0.9565833, 0.001202771], [0.9697442, 0.9501666, 0.001091183],
+    [0.9731331, 0.9427732, 0.0009901349], [0.976086, 0.9347086, 0.0008985637], [0.9787926, 0.9262714, 0.0008155243],
+    [0.9814446, 0.9177499, 0.0007401739], [0.9841865, 0.9093389, 0.0006717723], [0.9869654, 0.9008948, 0.0006096932],
+    [0.9896781, 0.8921972, 0.0005533721], [0.9922201, 0.8830346, 0.0005022925], [0.9944859, 0.8732049, 0.0004559793],
+    [0.9963858, 0.8625653, 0.0004139968], [0.9978947, 0.8511729, 0.0003759398], [0.999004, 0.8391319, 0.0003414391],
+    [0.9997061, 0.8265445, 0.0003101602], [0.9999932, 0.8135093, 0.0002818], [0.9998369, 0.8000824, 0.0002560838],
+    [0.9991235, 0.7861662, 0.000232764], [0.9977187, 0.771635, 0.0002116158], [0.9954905, 0.7563761, 0.0001924355],
+    [0.9923099, 0.7402911, 0.0001750385], [0.9881459, 0.7233687, 0.0001592573], [0.9833451, 0.7058898, 0.0001449397],
+    [0.9783423, 0.6881843, 0.0001319478], [0.9735642, 0.6705542, 0.0001201569], [0.9694292, 0.6532735, 0.0001094539],
+    [0.966211, 0.6365241, 9.973665e-5], [0.96363, 0.6202173, 9.091252e-5], [0.9612728, 0.6042106, 8.28976e-5],
+    [0.9587316, 0.5883752, 7.561601e-5], [0.9556019, 0.5725966, 6.899908e-5], [0.9515686, 0.5567831, 6.298462e-5],
+    [0.9466537, 0.5408956, 5.751635e-5], [0.9409624, 0.5249122, 5.25432e-5], [0.9346005, 0.5088166, 4.801916e-5],
+    [0.9276727, 0.4925986, 4.390238e-5], [0.9202643, 0.4762679, 4.015511e-5], [0.9123907, 0.4598926, 3.674308e-5],
+    [0.9040504, 0.4435509, 3.363529e-5], [0.8952429, 0.4273139, 3.080368e-5], [0.8859693, 0.4112456, 2.822284e-5],
+    [0.8762424, 0.3953966, 2.586975e-5], [0.8661173, 0.3797814, 2.372354e-5], [0.8556579, 0.3644094, 2.176531e-5],
+    [0.8449261, 0.3492889, 1.997793e-5], [0.8339821, 0.3344285, 1.834589e-5], [0.8228589, 0.319843, 1.685513e-5],
+    [0.8114911, 0.3055642, 1.549287e-5], [0.799794, 0.2916253, 1.424755e-5], [0.7876889, 0.2780531, 1.310869e-5],
+    [0.7751033, 0.264872, 1.206674e-5], [0.7619959, 0.2520994, 1.11131e-5], [0.7484248, 0.2397469, 1.023989e-5],
+    [0.7344698, 0.2278223, 9.439992e-6], [0.7202081, 0.2163304, 8.706949e-6], [0.7057132, 0.2052732, 8.034877e-6],
+    [0.6910435, 0.1946497, 7.418443e-6], [0.6762115, 0.1844476, 6.852793e-6], [0.6612196, 0.1746539, 6.333519e-6],
+    [0.6460714, 0.1652566, 5.856614e-6], [0.6307734, 0.1562426, 5.418431e-6], [0.6153492, 0.1476023, 0.0],
+    [0.5998882, 0.1393288, 0.0], [0.5844888, 0.1314158, 0.0], [0.5692404, 0.1238559, 0.0],
+    [0.5542244, 0.1166415, 0.0], [0.5394693, 0.1097658, 0.0], [0.524827, 0.1032264, 0.0],
+    [0.5101236, 0.09702052, 0.0], [0.4952055, 0.09114294, 0.0], [0.479941, 0.08558718, 0.0],
+    [0.4642696, 0.08034283, 0.0], [0.4483383, 0.07539123, 0.0], [0.4323294, 0.07071359, 0.0],
+    [0.4164055, 0.0662924, 0.0], [0.4007109, 0.06211205, 0.0], [0.3853555, 0.05815949, 0.0],
+    [0.3703767, 0.05442755, 0.0], [0.3557926, 0.05090981, 0.0], [0.3416177, 0.04759908, 0.0],
+    [0.3278637, 0.04448792, 0.0], [0.3145408, 0.04156592, 0.0], [0.3016623, 0.03881521, 0.0],
+    [0.2892388, 0.03621811, 0.0], [0.2772781, 0.0337599, 0.0], [0.2657842, 0.03142825, 0.0],
+    [0.2547397, 0.02921754, 0.0], [0.2440544, 0.02714033, 0.0], [0.233634, 0.02520848, 0.0],
+    [0.2233988, 0.0234286, 0.0], [0.2132837, 0.02180375, 0.0], [0.2032566, 0.02032838, 0.0],
+    [0.19337, 0.01897788, 0.0], [0.1836875, 0.01772721, 0.0], [0.1742629, 0.01655601, 0.0],
+    [0.1651409, 0.01544799, 0.0], [0.1563537, 0.01439231, 0.0], [0.1479157, 0.0133896, 0.0],
+    [0.1398336, 0.01244148, 0.0], [0.1321111, 0.01154885, 0.0], [0.1247491, 0.01071194, 0.0],
+    [0.1177436, 0.009929949, 0.0], [0.1110813, 0.009200588, 0.0], [0.1047475, 0.008521264, 0.0],
+    [0.09872775, 0.00788945, 0.0], [0.09300849, 0.00730256, 0.0], [0.08757694, 0.006758211, 0.0],
+    [0.08242194, 0.006254759, 0.0], [0.07753281, 0.00579043, 0.0], [0.07289893, 0.00536348, 0.0],
+    [0.06850995, 0.004971804, 0.0], [0.0643554, 0.004613018, 0.0], [0.06042425, 0.004283387, 0.0],
+    [0.05670573, 0.003979401, 0.0], [0.05318961, 0.00369803, 0.0], [0.04986609, 0.003436668, 0.0],
+    [0.04672583, 0.003193258, 0.0], [0.04375981, 0.002966531, 0.0], [0.04095944, 0.002755422, 0.0],
+    [0.03831652, 0.002558957, 0.0], [0.03582325, 0.002376169, 0.0], [0.03347247, 0.002206176, 0.0],
+    [0.03125831, 0.002048094, 0.0], [0.02917514, 0.001901097, 0.0], [0.02721727, 0.001764411, 0.0],
+    [0.02537904, 0.001637338, 0.0], [0.02365408, 0.001519157, 0.0], [0.02203361, 0.001409131, 0.0],
+    [0.02050921, 0.001306544, 0.0], [0.01907353, 0.001210778, 0.0], [0.01772013, 0.001121278, 0.0],
+    [0.0164451, 0.001037663, 0.0], [0.01525103, 0.0009598873, 0.0], [0.01414035, 0.0008879457, 0.0],
+    [0.01311366, 0.0008217298, 0.0], [0.01217011, 0.0007610489, 0.0], [0.01130632, 0.000705625, 0.0],
+    [0.01051311, 0.0006548725, 0.0], [0.009781268, 0.0006082413, 0.0], [0.009103001, 0.0005652381, 0.0],
+    [0.0084717, 0.0005254575, 0.0], [0.007882266, 0.0004885485, 0.0], [0.007332131, 0.0004542749, 0.0],
+    [0.006819283, 0.0004224343, 0.0], [0.006341726, 0.0003928378, 0.0], [0.005897489, 0.0003653162, 0.0],
+    [0.005484437, 0.000339711, 0.0], [0.005099779, 0.0003158555, 0.0], [0.004740864, 0.0002936073, 0.0],
+    [0.004405376, 0.0002728333, 0.0], [0.004091292, 0.0002534171, 0.0], [0.003797028, 0.0002352645, 0.0],
+    [0.003521868, 0.0002183307, 0.0], [0.003265199, 0.0002025742, 0.0], [0.00302632, 0.0001879479, 0.0],
+    [0.002804466, 0.0001744018, 0.0], [0.002598816, 0.0001618781, 0.0], [0.002408487, 0.0001503049, 0.0],
+    [0.002232588, 0.0001396128, 0.0], [0.002070239, 0.000129734, 0.0], [0.001920578, 0.0001206077, 0.0],
+    [0.001782687, 0.0001121758, 0.0], [0.001655401, 0.0001043731, 0.0], [0.00153762, 9.713881e-5, 0.0],
+    [0.001428387, 9.041978e-5, 0.0], [0.001326865, 8.417162e-5, 0.0], [0.001232383, 7.83539e-5, 0.0],
+    [0.001144562, 7.294261e-5, 0.0], [0.001063076, 6.791631e-5, 0.0], [0.0009875925, 6.325407e-5, 0.0],
+    [0.0009177767, 5.893504e-5, 0.0], [0.0008532643, 5.493601e-5, 0.0], [0.0007935892, 5.122885e-5, 0.0],
+    [0.000738306, 4.778714e-5, 0.0], [0.0006870182, 4.458628e-5, 0.0], [0.000639373, 4.160478e-5, 0.0],
+    [0.0005950568, 3.88247e-5, 0.0], [0.0005537973, 3.623012e-5, 0.0], [0.0005153503, 3.38085e-5, 0.0],
+    [0.0004794958, 3.154737e-5, 0.0], [0.0004460351, 2.943542e-5, 0.0], [0.0004148092, 2.746336e-5, 0.0],
+    [0.0003857492, 2.562687e-5, 0.0], [0.0003587919, 2.392175e-5, 0.0], [0.0003338609, 2.234329e-5, 0.0],
+    [0.0003108691, 2.088596e-5, 0.0], [0.0002896989, 1.954245e-5, 0.0], [0.0002701445, 1.8299e-5, 0.0],
+    [0.0002520072, 1.714229e-5, 0.0], [0.000235117, 1.606109e-5, 0.0], [0.0002193292, 1.504576e-5, 0.0],
+    [0.0002045349, 1.408933e-5, 0.0], [0.0001906924, 1.318989e-5, 0.0], [0.0001777709, 1.234622e-5, 0.0],
+    [0.0001657358, 1.155686e-5, 0.0], [0.0001545492, 1.082007e-5, 0.0], [0.0001441669, 1.013341e-5, 0.0],
+    [0.0001345284, 9.493658e-6, 0.0], [0.0001255743, 8.897366e-6, 0.0], [0.0001172505, 8.34133e-6, 0.0],
+    [0.0001095076, 7.822718e-6, 0.0], [0.0001022998, 7.33866e-6, 0.0], [9.558279e-5, 6.886134e-6, 0.0],
+    [8.931607e-5, 6.462271e-6, 0.0], [8.346314e-5, 6.064635e-6, 0.0], [7.799121e-5, 5.690928e-6, 0.0],
+    [7.287297e-5, 5.339399e-6, 0.0], [6.809212e-5, 5.009302e-6, 0.0], [6.363417e-5, 4.699849e-6, 0.0],
+    [5.948405e-5, 4.410359e-6, 0.0], [5.562638e-5, 4.139981e-6, 0.0], [5.204298e-5, 3.887719e-6, 0.0],
+    [4.870635e-5, 3.651865e-6, 0.0], [4.559e-5, 3.430698e-6, 0.0], [4.267099e-5, 3.222787e-6, 0.0],
+    [3.992945e-5, 3.026828e-6, 0.0], [3.735093e-5, 2.841915e-6, 0.0], [3.493264e-5, 2.667945e-6, 0.0],
+    [3.267304e-5, 2.504907e-6, 0.0], [3.056902e-5, 2.352677e-6, 0.0], [2.861628e-5, 2.210999e-6, 0.0],
+    [2.680769e-5, 2.079459e-6, 0.0], [2.512861e-5, 1.957e-6, 0.0], [2.356445e-5, 1.842574e-6, 0.0],
+    [2.210261e-5, 1.735285e-6, 0.0], [2.073214e-5, 1.634331e-6, 0.0],
+];
+
+/// CIE 2006 10° cone fundamentals (l̄, m̄, s̄), energy-based and normalized
+/// to a peak of 1, recovered from [`CIE2015_10`] with the inverse of the
+/// CIE 170-2 transformation.
+#[rustfmt::skip]
+pub const CIE2006_LMS_10: [[f32; 3]; LEN] = [
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0004076193, 0.0003582269, 0.006142646], [0.0004970683, 0.0004386592, 0.007442798],
+    [0.000604713, 0.0005362296, 0.009016612], [0.0007336399, 0.0006540616, 0.01091705], [0.0008872474, 0.0007956485, 0.01320531],
+    [0.001069208, 0.0009648283, 0.01595147], [0.001283403, 0.00116572, 0.01923474], [0.001533823, 0.001402631, 0.02314355],
+    [0.001824426, 0.001679926, 0.02777503], [0.002158962, 0.0020018, 0.03323394], [0.00254073, 0.002372076, 0.0396308],
+    [0.002972821, 0.002794338, 0.0470801], [0.003459931, 0.003273747, 0.05570122], [0.00400793, 0.003816596, 0.0656137],
+    [0.0046237, 0.004430211, 0.07693231], [0.005315463, 0.005123162, 0.08976121], [0.006091377, 0.005904581, 0.1041876],
+    [0.00695291, 0.00678005, 0.1202727], [0.007896335, 0.007752597, 0.1380437], [0.008913001, 0.00882286, 0.1574852],
+    [0.009988356, 0.009988412, 0.1785299], [0.0111054, 0.01124517, 0.2010771], [0.01226072, 0.01259491, 0.2250914],
+    [0.01345782, 0.01404248, 0.2505658], [0.01470442, 0.01559445, 0.277507], [0.01601299, 0.01725957, 0.3059411],
+    [0.01739579, 0.01904668, 0.3358577], [0.01884514, 0.02095451, 0.3669808], [0.02034398, 0.02297627, 0.3988762],
+    [0.02187, 0.0251017, 0.4309984], [0.02339571, 0.02731634, 0.4626922], [0.02489606, 0.02960626, 0.493357],
+    [0.02637609, 0.03197459, 0.5230065], [0.02785416, 0.034433, 0.5519392], [0.02935505, 0.03699833, 0.580599],
+    [0.03091036, 0.03969287, 0.6095703], [0.03254981, 0.04254017, 0.6393596], [0.03427136, 0.04554742, 0.6696515],
+    [0.036062, 0.04871603, 0.6998292], [0.03790518, 0.05204669, 0.7291774], [0.03978099, 0.05553839, 0.7568846],
+    [0.04167048, 0.05918763, 0.7822926], [0.04357282, 0.06298186, 0.8056661], [0.04549318, 0.06690315, 0.8275989],
+    [0.04743845, 0.07092849, 0.8487798], [0.04941722, 0.0750298, 0.8699841], [0.05143433, 0.07917686, 0.8917613],
+    [0.05347347, 0.08334635, 0.9134444], [0.05551017, 0.08751602, 0.933977], [0.05751662, 0.09166243, 0.9522174],
+    [0.05946186, 0.09576122, 0.9669597], [0.06132402, 0.09979769, 0.9773399], [0.0631288, 0.1038045, 0.9840287],
+    [0.06491885, 0.107834, 0.9881444], [0.0667425, 0.1119484, 0.9908516], [0.06865373, 0.1162202, 0.9933366],
+    [0.07069626, 0.1207064, 0.9963731], [0.07285077, 0.1253634, 0.9990384], [0.07507778, 0.1301109, 0.9999779],
+    [0.07733252, 0.1348561, 0.9978436], [0.07956472, 0.1394934, 0.9913291], [0.08173681, 0.1439432, 0.9796568],
+    [0.0838827, 0.1482813, 0.9639051], [0.08605989, 0.1526372, 0.9455724], [0.08833219, 0.1571573, 0.9260781],
+    [0.09077042, 0.1620061, 0.9067351], [0.09343969, 0.1673311, 0.8885087], [0.09635777, 0.173144, 0.8713526],
+    [0.09953036, 0.1794173, 0.8549982], [0.1029636, 0.1861169, 0.839198], [0.1066635, 0.1932017, 0.8237258],
+    [0.1106289, 0.2006209, 0.8083071], [0.1148284, 0.2083147, 0.7924311], [0.1192166, 0.2162123, 0.7755689],
+    [0.12374, 0.2242308, 0.7572442], [0.1283361, 0.2322753, 0.7370428], [0.1329473, 0.2402575, 0.7147299],
+    [0.1375702, 0.24816, 0.6905576], [0.1422177, 0.2559866, 0.6648897], [0.1469052, 0.2637439, 0.6380777],
+    [0.1516506, 0.2714412, 0.6104563], [0.1564751, 0.2790966, 0.5823457], [0.1614036, 0.2867554, 0.5540651],
+    [0.1664644, 0.2944757, 0.5259028], [0.1716899, 0.3023233, 0.4981085], [0.1771162, 0.3103721, 0.4708937],
+    [0.1827774, 0.3186917, 0.4444502], [0.1886851, 0.3273072, 0.418992], [0.1948447, 0.336232, 0.3946994],
+    [0.2012615, 0.3454803, 0.3717066], [0.20794, 0.3550664, 0.3501076], [0.2148754, 0.3649849, 0.3299042],
+    [0.2220217, 0.3751423, 0.3108636], [0.2293156, 0.3854103, 0.2927411], [0.2366844, 0.3956456, 0.275338],
+    [0.2440457, 0.4056884, 0.258497], [0.2513469, 0.4154349, 0.2421581], [0.2586945, 0.4250641, 0.2265042],
+    [0.2662517, 0.4348506, 0.2117258], [0.2742021, 0.445097, 0.1979596], [0.282752, 0.4561372, 0.1852975],
+    [0.2920714, 0.468236, 0.1737507], [0.3020937, 0.4812553, 0.1631493], [0.3126727, 0.4949274, 0.1533112],
+    [0.3236367, 0.5089472, 0.1440862], [0.3347859, 0.5229699, 0.1353507], [0.3459428, 0.5367006, 0.127011],
+    [0.357126, 0.5501945, 0.1190166], [0.3684162, 0.5636158, 0.1113331], [0.3799071, 0.5771498, 0.1039339],
+    [0.3917053, 0.5910029, 0.09679901], [0.403905, 0.6053497, 0.08991688], [0.4164998, 0.6201602, 0.08328782],
+    [0.4294527, 0.6353431, 0.07691568], [0.4427199, 0.6507958, 0.07080523], [0.4562515, 0.6664044, 0.0649614],
+    [0.4699968, 0.6820547, 0.05940497], [0.4839258, 0.6976715, 0.05420756], [0.4980122, 0.7131864, 0.04942807],
+    [0.512227, 0.7285259, 0.04509925], [0.5265385, 0.7436121, 0.04123375], [0.5409234, 0.7583958, 0.03781425],
+    [0.5554056, 0.7729662, 0.0347627], [0.5700249, 0.7874573, 0.03200293], [0.5848276, 0.8020173, 0.02947457],
+    [0.5998669, 0.8168079, 0.02713], [0.6151621, 0.831919, 0.02493765], [0.6305688, 0.847096, 0.02289306],
+    [0.6458833, 0.8619724, 0.02099564], [0.6608803, 0.8761523, 0.0192427], [0.6753132, 0.8892136, 0.01762985],
+    [0.6889747, 0.9008125, 0.01615011], [0.7018863, 0.9110058, 0.0147911], [0.71414, 0.919968, 0.01354072],
+    [0.7258413, 0.9278902, 0.01238845], [0.7371076, 0.9349768, 0.01132516], [0.7480461, 0.941414, 0.01034374],
+    [0.7586845, 0.9472787, 0.009440921], [0.7690317, 0.9526223, 0.008613646], [0.7790988, 0.9574982, 0.007858308],
+    [0.7888995, 0.9619616, 0.007170896], [0.7984717, 0.9660804, 0.006546478], [0.8079448, 0.9699662, 0.005977765],
+    [0.8174781, 0.9737434, 0.00545794], [0.8272389, 0.9775388, 0.004981253], [0.8374031, 0.9814809, 0.004542873],
+    [0.8480785, 0.9856287, 0.004139075], [0.8590642, 0.9897536, 0.003767945], [0.8700676, 0.9935514, 0.003427827],
+    [0.8807787, 0.9967138, 0.003116959], [0.8908714, 0.9989308, 0.002833519], [0.900057, 0.9999424, 0.002575563],
+    [0.9082532, 0.999692, 0.002340841], [0.9154332, 0.998178, 0.002127217], [0.9215746, 0.9954048, 0.001932761],
+    [0.9266597, 0.9913827, 0.001755728], [0.9307436, 0.9861991, 0.001594548], [0.93416, 0.9802287, 0.001447828],
+    [0.9373184, 0.9739064, 0.001314303], [0.940634, 0.9676535, 0.001192815], [0.9445271, 0.9618755, 0.001082305],
+    [0.9492906, 0.9568203, 0.000981819], [0.9546797, 0.9521512, 0.000890533], [0.9603092, 0.9473984, 0.0008076867],
+    [0.9657847, 0.9421052, 0.0007325684], [0.9707025, 0.935829, 0.0006645119], [0.9747559, 0.9282738, 0.0006028867],
+    [0.9780549, 0.9196668, 0.0005470615], [0.9808188, 0.9103564, 0.000496461], [0.9832705, 0.9006772, 0.0004505709],
+    [0.9856357, 0.8909491, 0.0004089314], [0.9880847, 0.8813857, 0.0003711362], [0.9905599, 0.8718335, 0.0003368378],
+    [0.9929453, 0.8620587, 0.0003057228], [0.9951242, 0.8518398, 0.0002775041], [0.9969792, 0.8409688, 0.0002519184],
+    [0.9984107, 0.8293031, 0.0002287246], [0.9993911, 0.8169112, 0.0002076989], [0.9999117, 0.8039103, 0.0001886378],
+    [0.9999645, 0.7904127, 0.0001713565], [0.9995426, 0.7765256, 0.0001556878], [0.9986146, 0.7623105, 0.0001414801],
+    [0.9970511, 0.7476692, 0.0001285964], [0.9947006, 0.7324762, 0.0001169125], [0.9914161, 0.7166219, 0.0001063159],
+    [0.9870571, 0.7000131, 9.67045e-5], [0.9815987, 0.6826473, 8.798575e-5], [0.9754515, 0.6648168, 8.007562e-5],
+    [0.9691205, 0.6468577, 7.28979e-5], [0.9630933, 0.6290721, 6.638365e-5], [0.9578405, 0.6117281, 6.047056e-5],
+    [0.9536639, 0.5949974, 5.510202e-5], [0.9502363, 0.5787829, 5.022685e-5], [0.9470863, 0.562935, 4.579882e-5],
+    [0.9437522, 0.547321, 4.177592e-5], [0.9397814, 0.5318251, 3.812023e-5], [0.9348262, 0.5163543, 3.47974e-5],
+    [0.9289167, 0.5008698, 3.177631e-5], [0.9221766, 0.4853497, 2.902879e-5], [0.9147285, 0.4697774, 2.652936e-5],
+    [0.9066929, 0.4541423, 2.425495e-5], [0.89817, 0.4384535, 2.218467e-5], [0.8891881, 0.4227779, 2.029961e-5],
+    [0.8797593, 0.4071884, 1.858264e-5], [0.8698944, 0.3917521, 1.701824e-5], [0.8596055, 0.3765272, 1.559239e-5],
+    [0.848912, 0.3615584, 1.429237e-5], [0.8378648, 0.3468555, 1.310665e-5], [0.8265218, 0.3324217, 1.202477e-5],
+    [0.8149396, 0.3182609, 1.103729e-5], [0.8031732, 0.3043778, 1.013563e-5], [0.7912528, 0.2907838, 9.312023e-6],
+    [0.7791182, 0.2775065, 8.559414e-6], [0.7666913, 0.2645746, 7.871406e-6], [0.7539002, 0.2520119, 7.242209e-6],
+    [0.7406799, 0.2398374, 6.666564e-6], [0.726995, 0.228065, 6.139697e-6], [0.7129053, 0.2167025, 5.657272e-6],
+    [0.6984908, 0.2057543, 5.215351e-6], [0.6838288, 0.195221, 4.810363e-6], [0.668991, 0.1851038, 4.439062e-6],
+    [0.6540366, 0.1753977, 4.098497e-6], [0.6389795, 0.1660909, 3.78599e-6], [0.6238248, 0.1571694, 3.499107e-6],
+    [0.6085785, 0.1486202, 3.235629e-6], [0.5932479, 0.1404311, 2.993543e-6], [0.5778572, 0.1325909, 0.0],
+    [0.5624928, 0.1250924, 0.0], [0.5472484, 0.1179283, 0.0], [0.5322087, 0.1110913, 0.0],
+    [0.5174488, 0.1045729, 0.0], [0.5029926, 0.09836636, 0.0], [0.488692, 0.09246844, 0.0],
+    [0.474376, 0.08687588, 0.0], [0.4598956, 0.08158336, 0.0], [0.4451246, 0.07658411, 0.0],
+    [0.4300068, 0.07186829, 0.0], [0.4146867, 0.06741867, 0.0], [0.3993398, 0.06321759, 0.0],
+    [0.3841218, 0.05924919, 0.0], [0.3691679, 0.05549898, 0.0], [0.35458, 0.0519549, 0.0],
+    [0.3403894, 0.04861036, 0.0], [0.326609, 0.04545909, 0.0], [0.3132488, 0.04249451, 0.0],
+    [0.3003162, 0.03970972, 0.0], [0.2878175, 0.03709529, 0.0], [0.2757622, 0.03463473, 0.0],
+    [0.2641579, 0.03231253, 0.0], [0.2530089, 0.03011509, 0.0], [0.2423164, 0.02803139, 0.0],
+    [0.2320614, 0.02605645, 0.0], [0.2221578, 0.02420113, 0.0], [0.2125158, 0.02247602, 0.0],
+    [0.2030603, 0.02088696, 0.0], [0.19373, 0.01943662, 0.0], [0.1844948, 0.01812004, 0.0],
+    [0.1754019, 0.01691493, 0.0], [0.1665093, 0.015799, 0.0], [0.1578653, 0.01475433, 0.0],
+    [0.1495094, 0.013766, 0.0], [0.1414704, 0.01282461, 0.0], [0.1337599, 0.01193038, 0.0],
+    [0.1263833, 0.01108497, 0.0], [0.1193427, 0.01028921, 0.0], [0.1126377, 0.009543169, 0.0],
+    [0.1062639, 0.008846071, 0.0], [0.1002081, 0.008196, 0.0], [0.09445584, 0.007590587, 0.0],
+    [0.08899342, 0.007027518, 0.0], [0.08380771, 0.006504544, 0.0], [0.0788865, 0.006019495, 0.0],
+    [0.07421908, 0.005570934, 0.0], [0.06979525, 0.005157283, 0.0], [0.06560496, 0.004776863, 0.0],
+    [0.06163837, 0.004427951, 0.0], [0.05788567, 0.004108327, 0.0], [0.05433656, 0.003814716, 0.0],
+    [0.05098106, 0.003543929, 0.0], [0.04780962, 0.003293294, 0.0], [0.04481319, 0.003060502, 0.0],
+    [0.04198311, 0.002843702, 0.0], [0.03931107, 0.002641754, 0.0], [0.03678915, 0.002453728, 0.0],
+    [0.03440984, 0.002278746, 0.0], [0.03216596, 0.002115961, 0.0], [0.03005093, 0.001964565, 0.0],
+    [0.02805939, 0.00182378, 0.0], [0.02618614, 0.001692862, 0.0], [0.02442601, 0.001571146, 0.0],
+    [0.02277381, 0.001457976, 0.0], [0.02122375, 0.001352741, 0.0], [0.01976789, 0.001254765, 0.0],
+    [0.01839859, 0.001163407, 0.0], [0.0171092, 0.001078124, 0.0], [0.01589392, 0.000998431, 0.0],
+    [0.01474918, 0.00092396, 0.0], [0.01367731, 0.0008547139, 0.0], [0.01268042, 0.000790652, 0.0],
+    [0.01175902, 0.0007316852, 0.0], [0.01091234, 0.0006776544, 0.0], [0.01013731, 0.0006282983, 0.0],
+    [0.00942568, 0.0005831099, 0.0], [0.008769165, 0.0005415842, 0.0], [0.00816076, 0.0005032941, 0.0],
+    [0.007594528, 0.000467871, 0.0], [0.007065885, 0.0004350064, 0.0], [0.006572519, 0.000404491, 0.0],
+    [0.006112624, 0.0003761365, 0.0], [0.005684398, 0.0003497823, 0.0], [0.005286071, 0.0003252783, 0.0],
+    [0.004915728, 0.0003024765, 0.0], [0.004570857, 0.0002812372, 0.0], [0.004249079, 0.0002614271, 0.0],
+    [0.003948317, 0.0002429294, 0.0], [0.003666753, 0.0002256407, 0.0], [0.003402968, 0.0002094789, 0.0],
+    [0.003156315, 0.0001943998, 0.0], [0.002926245, 0.0001803707, 0.0], [0.002712128, 0.0001673477, 0.0],
+    [0.002513275, 0.0001552854, 0.0], [0.002328951, 0.0001441349, 0.0], [0.002158362, 0.0001338295, 0.0],
+    [0.002000711, 0.000124309, 0.0], [0.001855206, 0.000115513, 0.0], [0.001721076, 0.0001073878, 0.0],
+    [0.001597497, 9.987995e-5, 0.0], [0.001483422, 9.293152e-5, 0.0], [0.001377869, 8.649096e-5, 0.0],
+    [0.001279977, 8.050924e-5, 0.0], [0.001188997, 7.494532e-5, 0.0], [0.001104326, 6.97653e-5, 0.0],
+    [0.001025625, 6.49471e-5, 0.0], [0.0009526022, 6.04719e-5, 0.0], [0.0008849594, 5.632091e-5, 0.0],
+    [0.0008223961, 5.247482e-5, 0.0], [0.0007645854, 4.891417e-5, 0.0], [0.0007111098, 4.561387e-5, 0.0],
+    [0.0006615705, 4.254876e-5, 0.0], [0.0006156116, 3.969873e-5, 0.0], [0.0005729167, 3.704452e-5, 0.0],
+    [0.0005332057, 3.456876e-5, 0.0], [0.0004962335, 3.225876e-5, 0.0], [0.0004617819, 3.01026e-5, 0.0],
+    [0.0004296535, 2.808931e-5, 0.0], [0.0003996702, 2.620883e-5, 0.0], [0.0003716896, 2.4453e-5, 0.0],
+    [0.0003456498, 2.281776e-5, 0.0], [0.0003214944, 2.129958e-5, 0.0], [0.0002991546, 1.989404e-5, 0.0],
+    [0.0002785525, 1.859651e-5, 0.0], [0.0002595828, 1.740028e-5, 0.0], [0.000242061, 1.629314e-5, 0.0],
+    [0.0002258089, 1.526324e-5, 0.0], [0.0002106744, 1.430057e-5, 0.0], [0.0001965278, 1.339651e-5, 0.0],
+    [0.0001832713, 1.254492e-5, 0.0], [0.0001708677, 1.174405e-5, 0.0], [0.0001592895, 1.099289e-5, 0.0],
+    [0.0001485055, 1.029002e-5, 0.0], [0.0001384818, 9.633954e-6, 0.0], [0.0001291788, 9.022638e-6, 0.0],
+    [0.0001205423, 8.453014e-6, 0.0], [0.0001125191, 7.922054e-6, 0.0], [0.0001050606, 7.427005e-6, 0.0],
+    [9.812262e-5, 6.965228e-6, 0.0], [9.166413e-5, 6.534239e-6, 0.0], [8.564545e-5, 6.131292e-6, 0.0],
+    [8.003021e-5, 5.753908e-6, 0.0], [7.478578e-5, 5.399844e-6, 0.0], [6.988272e-5, 5.067113e-6, 0.0],
+    [6.529659e-5, 4.754138e-6, 0.0], [6.101277e-5, 4.460202e-6, 0.0], [5.70183e-5, 4.184679e-6, 0.0],
+    [5.329965e-5, 3.926895e-6, 0.0], [4.984304e-5, 3.686173e-6, 0.0], [4.663218e-5, 3.461563e-6, 0.0],
+    [4.364244e-5, 3.251554e-6, 0.0], [4.085009e-5, 3.054636e-6, 0.0], [3.823456e-5, 2.869507e-6, 0.0],
+    [3.577805e-5, 2.695035e-6, 0.0], [3.346761e-5, 2.530393e-6, 0.0], [3.130074e-5, 2.375498e-6, 0.0],
+    [2.927606e-5, 2.230332e-6, 0.0], [2.739079e-5, 2.094779e-6, 0.0], [2.564108e-5, 1.968636e-6, 0.0],
+    [2.402052e-5, 1.851515e-6, 0.0], [2.251601e-5, 1.742483e-6, 0.0], [2.111448e-5, 1.640602e-6, 0.0],
+    [1.980462e-5, 1.545066e-6, 0.0], [1.857663e-5, 1.455181e-6, 0.0],
+];
+
+/// CIE 2006 low-density photopigment absorbance (L, M, S), each normalized
+/// to a peak of 1. Derived with [`CIE2006_MACULAR`] and [`CIE2006_OCULAR`]
+/// from [`CIE2006_LMS_2`], so the model reproduces that table exactly.
+#[rustfmt::skip]
+pub const CIE2006_ABSORBANCE: [[f32; 3]; LEN] = [
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0], [0.2358101, 0.1885908, 0.773783], [0.2404659, 0.193112, 0.7848253],
+    [0.2445674, 0.197377, 0.7957907], [0.2480783, 0.2013426, 0.8066856], [0.2509667, 0.2049643, 0.8175175],
+    [0.2532062, 0.2081978, 0.8282946], [0.2547765, 0.2109993, 0.8390251], [0.2556623, 0.2133257, 0.8497175],
+    [0.2558533, 0.2151376, 0.8603788], [0.2553474, 0.2163963, 0.8710149], [0.2541462, 0.2170681, 0.8816298],
+    [0.2522988, 0.2171595, 0.8922056], [0.2500184, 0.2168256, 0.9026455], [0.2475504, 0.2162632, 0.9128274],
+    [0.2451342, 0.215666, 0.9226253], [0.2429974, 0.215228, 0.9319097], [0.2412875, 0.2151007, 0.9405766],
+    [0.23986, 0.2152762, 0.9486342], [0.238503, 0.2157077, 0.956121], [0.23701, 0.2163483, 0.9630781],
+    [0.2351807, 0.2171512, 0.9695488], [0.2328866, 0.2180801, 0.9755591], [0.2302637, 0.2191412, 0.98106],
+    [0.2275044, 0.2203534, 0.9859839], [0.2247943, 0.2217359, 0.9902633], [0.2223089, 0.2233069, 0.9938327],
+    [0.2201802, 0.2250992, 0.9966315], [0.2184019, 0.2271831, 0.9986181], [0.2169366, 0.2296427, 0.999754],
+    [0.2157474, 0.2325678, 1.0], [0.2147997, 0.2360543, 0.9993157], [0.2140602, 0.2401666, 0.9976726],
+    [0.2135032, 0.2448091, 0.9950946], [0.2131044, 0.2498387, 0.9916233], [0.2128414, 0.2550993, 0.987307],
+    [0.2126931, 0.2604189, 0.9822006], [0.2126505, 0.2656763, 0.9763414], [0.2127613, 0.2709966, 0.9696747],
+    [0.2130843, 0.2765844, 0.9621208], [0.2136785, 0.2826618, 0.9535989], [0.2146021, 0.2894724, 0.9440272],
+    [0.2158792, 0.2971851, 0.9333636], [0.2173962, 0.3055712, 0.9217326], [0.2190014, 0.3142672, 0.9093094],
+    [0.2205436, 0.322862, 0.8962795], [0.2218693, 0.330895, 0.8828361], [0.2228732, 0.3379994, 0.8691229],
+    [0.22365, 0.3443415, 0.8550744], [0.2243428, 0.3502556, 0.8405715], [0.2250949, 0.3561065, 0.8254979],
+    [0.2260499, 0.3622866, 0.8097422], [0.227312, 0.3691122, 0.7932356], [0.2288302, 0.376491, 0.7760567],
+    [0.2305131, 0.3842124, 0.7583205], [0.2322685, 0.3920463, 0.7401417], [0.2340021, 0.3997418, 0.7216339],
+    [0.23566, 0.4071118, 0.7028805], [0.2373497, 0.4143073, 0.6838496], [0.239222, 0.4215793, 0.6644864],
+    [0.2414315, 0.4291966, 0.6447431], [0.2441365, 0.4374464, 0.6245786], [0.24745, 0.4465614, 0.6039831],
+    [0.2512873, 0.4564799, 0.5830499], [0.2555072, 0.4670556, 0.5618951], [0.2599616, 0.4781283, 0.5406313],
+    [0.2644933, 0.4895219, 0.5193682], [0.2689667, 0.5010611, 0.4982037], [0.2733671, 0.5126157, 0.4771984],
+    [0.2777143, 0.5240602, 0.4564028], [0.2820294, 0.5352577, 0.4358666], [0.2863385, 0.5460609, 0.415638],
+    [0.2906719, 0.5563659, 0.3957568], [0.295069, 0.5662728, 0.3762362], [0.2995708, 0.5759446, 0.3570829],
+    [0.3042184, 0.5855531, 0.3383042], [0.3090513, 0.5952801, 0.3199078], [0.3141002, 0.6052696, 0.3019078],
+    [0.3193625, 0.6154957, 0.2843412], [0.3248287, 0.6258827, 0.2672483], [0.3304885, 0.6363537, 0.2506657],
+    [0.3363355, 0.6468293, 0.2346262], [0.3423581, 0.6572347, 0.2191584], [0.3485296, 0.6675154, 0.2042861],
+    [0.3548181, 0.6776266, 0.1900288], [0.3611928, 0.6875287, 0.1764023], [0.3676238, 0.6971889, 0.163418],
+    [0.3740664, 0.706543, 0.1510887], [0.3804173, 0.7153833, 0.1394426], [0.3865682, 0.7234946, 0.1285046],
+    [0.3924252, 0.7307043, 0.1182897], [0.3979119, 0.7368855, 0.1088034], [0.4029983, 0.7420135, 0.1000344],
+    [0.4077843, 0.7463087, 0.09193592], [0.4123974, 0.7500355, 0.08445478], [0.4169592, 0.7534356, 0.07754066],
+    [0.4215826, 0.7567259, 0.07114639], [0.4263541, 0.7600628, 0.06523221], [0.4312819, 0.7634591, 0.05977636],
+    [0.4363582, 0.7669023, 0.05475911], [0.4415791, 0.7703864, 0.05015877], [0.4469419, 0.7739112, 0.04595219],
+    [0.4524534, 0.7774912, 0.0421139], [0.4581471, 0.7811829, 0.03861293], [0.4640586, 0.7850409, 0.03541895],
+    [0.470218, 0.7891039, 0.03250395], [0.4766492, 0.7933964, 0.02984225], [0.4833791, 0.7979608, 0.02740818],
+    [0.4904569, 0.8029429, 0.02517056], [0.4979252, 0.8084873, 0.02310109], [0.5058102, 0.8147081, 0.02117641],
+    [0.5141219, 0.8216916, 0.01937733], [0.5228527, 0.8294686, 0.01768992], [0.5319761, 0.8379427, 0.01610843],
+    [0.5414617, 0.8469839, 0.01462907], [0.5512776, 0.8564595, 0.01324826], [0.5613925, 0.8662349, 0.01196258],
+    [0.5717586, 0.8761459, 0.01077112], [0.5822683, 0.8859323, 0.009680983], [0.5928278, 0.895366, 0.008697067],
+    [0.6033741, 0.9042786, 0.007820117], [0.6138699, 0.9125496, 0.007047704], [0.6243059, 0.920141, 0.006372574],
+    [0.6347081, 0.9272047, 0.005777552], [0.64509, 0.9339067, 0.005246559], [0.6554451, 0.9403844, 0.00476693],
+    [0.6657495, 0.9467534, 0.004328734], [0.6759734, 0.9530758, 0.003925393], [0.6861156, 0.959263, 0.003555801],
+    [0.6961927, 0.9651986, 0.003219452], [0.706231, 0.9707751, 0.002915261], [0.7162666, 0.9758921, 0.002641723],
+    [0.7263331, 0.9804748, 0.00239665], [0.7364274, 0.9845292, 0.002176373], [0.7465327, 0.9880777, 0.001977444],
+    [0.7566272, 0.9911403, 0.001796996], [0.7666855, 0.9937346, 0.00163264], [0.7766779, 0.9958743, 0.001482555],
+    [0.7865716, 0.9975637, 0.001345787], [0.7963331, 0.998808, 0.001221528], [0.8059293, 0.999616, 0.001108945],
+    [0.8153263, 1.0, 0.001007196], [0.8245024, 0.9999652, 0.0009153326], [0.8334843, 0.999479, 0.0008320601],
+    [0.8423118, 0.9984976, 0.0007561994], [0.851025, 0.9969757, 0.0006867802], [0.859666, 0.9948664, 0.0006230066],
+    [0.8682619, 0.9921345, 0.0005643159], [0.8767792, 0.9887905, 0.0005105552], [0.8851689, 0.9848595, 0.0004615929],
+    [0.8933804, 0.9803697, 0.0004172311], [0.9013622, 0.975352, 0.000377226], [0.9090694, 0.9698341, 0.000341274],
+    [0.9164876, 0.963823, 0.0003089604], [0.92361, 0.9573213, 0.000279889], [0.929883, 0.9497668, 0.0002536263],
+    [0.9342943, 0.940189, 0.0002297509], [0.9369395, 0.9288099, 0.0002080507], [0.9384681, 0.9163744, 0.0001883492],
+    [0.9396736, 0.9036997, 0.0001704833], [0.9431204, 0.8931479, 0.0001544593], [0.9478185, 0.8836765, 0.000139938],
+    [0.9536205, 0.8750516, 0.0001267354], [0.9602027, 0.8668382, 0.0001147479], [0.9670299, 0.858446, 0.0001038801],
+    [0.9735427, 0.8493206, 9.404086e-5], [0.9791571, 0.8389474, 8.51438e-5], [0.983426, 0.8270189, 7.71053e-5],
+    [0.9865252, 0.8138953, 6.983918e-5], [0.9888002, 0.8000692, 6.326643e-5], [0.9906075, 0.7859937, 5.731688e-5],
+    [0.9923089, 0.7720775, 5.192807e-5], [0.9941775, 0.7585767, 4.704513e-5], [0.9961134, 0.7452863, 4.262158e-5],
+    [0.9979215, 0.731916, 3.861558e-5], [0.9994039, 0.7182061, 3.498892e-5], [1.0, 0.703719, 3.170046e-5],
+    [0.9995696, 0.6883508, 2.872027e-5], [0.9989471, 0.6727081, 2.603203e-5], [0.9977269, 0.6567172, 2.360137e-5],
+    [0.9957524, 0.6404439, 2.140157e-5], [0.9930182, 0.6240227, 1.941054e-5], [0.989483, 0.6075315, 1.760828e-5],
+    [0.9849522, 0.5908769, 1.597683e-5], [0.9792026, 0.5739437, 1.449986e-5], [0.9720271, 0.5566409, 1.316265e-5],
+    [0.963239, 0.5389008, 1.195186e-5], [0.9528418, 0.5207494, 1.085542e-5], [0.9415013, 0.5024942, 9.862365e-6],
+    [0.9300014, 0.4844626, 8.962799e-6], [0.9190681, 0.4669281, 8.14778e-6], [0.9093732, 0.450115, 7.409216e-6],
+    [0.9013199, 0.4341478, 6.739808e-6], [0.8944019, 0.4189066, 6.132951e-6], [0.887922, 0.4042351, 5.582671e-6],
+    [0.8812187, 0.3899999, 5.083578e-6], [0.8736609, 0.3760887, 4.630799e-6], [0.8647845, 0.3624151, 4.21993e-6],
+    [0.8546711, 0.3489403, 3.846994e-6], [0.8435305, 0.3356415, 3.508386e-6], [0.8315669, 0.3225015, 3.200866e-6],
+    [0.8189745, 0.3095085, 2.921488e-6], [0.8059097, 0.2966666, 2.667604e-6], [0.7924214, 0.2840217, 2.436811e-6],
+    [0.778533, 0.2716244, 2.22694e-6], [0.7642691, 0.2595166, 2.03603e-6], [0.749656, 0.247733, 1.862309e-6],
+    [0.7347345, 0.2362965, 1.704174e-6], [0.7195986, 0.2252047, 1.560174e-6], [0.704347, 0.2144516, 1.428998e-6],
+    [0.6890692, 0.204031, 1.309458e-6], [0.6738461, 0.1939371, 1.200481e-6], [0.6587201, 0.1841686, 1.101097e-6],
+    [0.6436145, 0.174735, 1.010423e-6], [0.6284364, 0.1656464, 9.276638e-7], [0.6131063, 0.1569084, 8.52099e-7],
+    [0.5975586, 0.1485251, 7.83073e-7], [0.5817668, 0.1404964, 7.199963e-7], [0.5658134, 0.1328188, 6.623298e-7],
+    [0.5497978, 0.125487, 6.09587e-7], [0.5338096, 0.1184944, 5.613275e-7], [0.5179274, 0.111833, 5.171506e-7],
+    [0.5022092, 0.1054938, 4.766935e-7], [0.4866641, 0.09946179, 4.396265e-7], [0.4712905, 0.09372231, 4.056507e-7],
+    [0.4560881, 0.08826188, 3.744948e-7], [0.4410591, 0.08306701, 3.459123e-7], [0.4262207, 0.07812672, 0.0],
+    [0.4116462, 0.07343199, 0.0], [0.3974117, 0.0689745, 0.0], [0.3835797, 0.0647457, 0.0],
+    [0.3702018, 0.06073729, 0.0], [0.357283, 0.0569417, 0.0], [0.3446852, 0.05335402, 0.0],
+    [0.3322588, 0.04996936, 0.0], [0.3198791, 0.04678175, 0.0], [0.3074459, 0.0437848, 0.0],
+    [0.2949194, 0.04097028, 0.0], [0.2824167, 0.03832594, 0.0], [0.2700737, 0.03583976, 0.0],
+    [0.2580035, 0.03350067, 0.0], [0.2462995, 0.03129884, 0.0], [0.2350251, 0.02922594, 0.0],
+    [0.2241903, 0.02727683, 0.0], [0.2137917, 0.02544687, 0.0], [0.2038236, 0.02373114, 0.0],
+    [0.194279, 0.02212471, 0.0], [0.1851506, 0.02062124, 0.0], [0.1764336, 0.0192108, 0.0],
+    [0.1681221, 0.01788369, 0.0], [0.1602086, 0.01663183, 0.0], [0.1526842, 0.01544845, 0.0],
+    [0.1455271, 0.01433016, 0.0], [0.1386718, 0.01328264, 0.0], [0.1320531, 0.01231111, 0.0],
+    [0.1256168, 0.01141824, 0.0], [0.1193197, 0.01060491, 0.0], [0.1131396, 0.009867834, 0.0],
+    [0.1071048, 0.009194473, 0.0], [0.1012495, 0.008572221, 0.0], [0.0956009, 0.007990864, 0.0],
+    [0.09018012, 0.007442251, 0.0], [0.0850007, 0.006920937, 0.0], [0.08006574, 0.006427055, 0.0],
+    [0.07537407, 0.00596121, 0.0], [0.07092299, 0.005523663, 0.0], [0.06670847, 0.005114353, 0.0],
+    [0.062724, 0.004732729, 0.0], [0.05895825, 0.004377538, 0.0], [0.05539953, 0.004047392, 0.0],
+    [0.05203665, 0.003740949, 0.0], [0.04885925, 0.003456851, 0.0], [0.0458577, 0.003193846, 0.0],
+    [0.04302349, 0.002951043, 0.0], [0.04034863, 0.002727498, 0.0], [0.03782538, 0.002522286, 0.0],
+    [0.03544632, 0.002334324, 0.0], [0.03320416, 0.002162403, 0.0], [0.03109151, 0.002004693, 0.0],
+    [0.02910126, 0.001859479, 0.0], [0.02722673, 0.001725285, 0.0], [0.02546161, 0.00160084, 0.0],
+    [0.02379995, 0.001485141, 0.0], [0.02223607, 0.001377555, 0.0], [0.02076463, 0.001277549, 0.0],
+    [0.01938056, 0.001184634, 0.0], [0.01807911, 0.001098331, 0.0], [0.01685589, 0.0010182, 0.0],
+    [0.01570728, 0.0009438047, 0.0], [0.01462981, 0.0008747385, 0.0], [0.01362003, 0.0008106205, 0.0],
+    [0.01267458, 0.0007511076, 0.0], [0.01178977, 0.0006958481, 0.0], [0.01096076, 0.0006444853, 0.0],
+    [0.01018295, 0.0005966744, 0.0], [0.009452307, 0.0005521173, 0.0], [0.008765316, 0.0005105464, 0.0],
+    [0.008119745, 0.0004717751, 0.0], [0.007516605, 0.00043577, 0.0], [0.006956826, 0.0004025163, 0.0],
+    [0.006440433, 0.0003719527, 0.0], [0.005966744, 0.0003439809, 0.0], [0.005533845, 0.000318464, 0.0],
+    [0.005137002, 0.0002951272, 0.0], [0.004771502, 0.0002737133, 0.0], [0.004433368, 0.0002539921, 0.0],
+    [0.004119229, 0.0002357744, 0.0], [0.003826474, 0.0002188961, 0.0], [0.003553744, 0.0002032458, 0.0],
+    [0.003299959, 0.0001887277, 0.0], [0.003064055, 0.0001752527, 0.0], [0.002844989, 0.0001627408, 0.0],
+    [0.002641646, 0.0001511173, 0.0], [0.002452605, 0.0001403044, 0.0], [0.00227652, 0.0001302354, 0.0],
+    [0.002112217, 0.0001208481, 0.0], [0.00195867, 0.0001120881, 0.0], [0.001815068, 0.0001039112, 0.0],
+    [0.001681025, 9.629512e-5, 0.0], [0.001556201, 8.921913e-5, 0.0], [0.00144022, 8.266025e-5, 0.0],
+    [0.001332678, 7.659432e-5, 0.0], [0.001233145, 7.09939e-5, 0.0], [0.001141168, 6.582553e-5, 0.0],
+    [0.00105629, 6.105704e-5, 0.0], [0.0009780629, 5.665713e-5, 0.0], [0.0009060505, 5.259772e-5, 0.0],
+    [0.0008397925, 4.885209e-5, 0.0], [0.0007787153, 4.539056e-5, 0.0], [0.0007222792, 4.218555e-5, 0.0],
+    [0.0006700149, 3.921292e-5, 0.0], [0.0006215121, 3.645248e-5, 0.0], [0.0005764405, 3.388587e-5, 0.0],
+    [0.0005346083, 3.15019e-5, 0.0], [0.0004958495, 2.929057e-5, 0.0], [0.0004599961, 2.724216e-5, 0.0],
+    [0.0004268798, 2.534701e-5, 0.0], [0.00039632, 2.359452e-5, 0.0], [0.0003680894, 2.197204e-5, 0.0],
+    [0.0003419719, 2.046772e-5, 0.0], [0.0003177752, 1.907055e-5, 0.0], [0.0002953284, 1.777091e-5, 0.0],
+    [0.0002744793, 1.656074e-5, 0.0], [0.0002550959, 1.543289e-5, 0.0], [0.0002370597, 1.43817e-5, 0.0],
+    [0.0002202641, 1.340156e-5, 0.0], [0.0002046128, 1.248736e-5, 0.0], [0.0001900281, 1.163492e-5, 0.0],
+    [0.0001764743, 1.084215e-5, 0.0], [0.0001639182, 1.010704e-5, 0.0], [0.0001523207, 9.427354e-6, 0.0],
+    [0.0001416384, 8.800548e-6, 0.0], [0.000131814, 8.223328e-6, 0.0], [0.0001227504, 7.689713e-6, 0.0],
+    [0.0001143543, 7.193937e-6, 0.0], [0.0001065461, 6.731139e-6, 0.0], [9.925784e-5, 6.297152e-6, 0.0],
+    [9.243821e-5, 5.888949e-6, 0.0], [8.60665e-5, 5.505626e-6, 0.0], [8.012701e-5, 5.146577e-6, 0.0],
+    [7.460243e-5, 4.811096e-6, 0.0], [6.947406e-5, 4.498365e-6, 0.0], [6.472047e-5, 4.207281e-6, 0.0],
+    [6.031302e-5, 3.936421e-6, 0.0], [5.622373e-5, 3.684276e-6, 0.0], [5.242716e-5, 3.449448e-6, 0.0],
+    [4.890006e-5, 3.230698e-6, 0.0], [4.562094e-5, 3.026778e-6, 0.0], [4.256908e-5, 2.836384e-6, 0.0],
+    [3.972557e-5, 2.658284e-6, 0.0], [3.707337e-5, 2.491428e-6, 0.0], [3.459717e-5, 2.334828e-6, 0.0],
+    [3.228418e-5, 2.187727e-6, 0.0], [3.012654e-5, 2.049781e-6, 0.0], [2.811724e-5, 1.920634e-6, 0.0],
+    [2.624905e-5, 1.799972e-6, 0.0], [2.451463e-5, 1.687416e-6, 0.0], [2.290546e-5, 1.582528e-6, 0.0],
+    [2.140895e-5, 1.484583e-6, 0.0], [2.001305e-5, 1.392857e-6, 0.0], [1.870729e-5, 1.306744e-6, 0.0],
+    [1.748263e-5, 1.225694e-6, 0.0], [1.633244e-5, 1.149322e-6, 0.0], [1.525519e-5, 1.077568e-6, 0.0],
+    [1.424995e-5, 1.010409e-6, 0.0], [1.331508e-5, 9.477774e-7, 0.0], [1.244843e-5, 8.89553e-7, 0.0],
+    [1.164663e-5, 8.355523e-7, 0.0], [1.09031e-5, 7.853347e-7, 0.0], [1.021128e-5, 7.384657e-7, 0.0],
+    [9.565514e-6, 6.945737e-7, 0.0], [8.960899e-6, 6.533266e-7, 0.0],
+];
+
+/// CIE 2006 macular pigment optical density, normalized to 1 at 460nm.
+/// Recovered from the difference between [`CIE2006_LMS_2`] and
+/// [`CIE2006_LMS_10`], whose peak macular densities are set by field size.
+#[rustfmt::skip]
+pub const CIE2006_MACULAR: [f32; LEN] = [
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.112848, 0.1240613, 0.1354965, 0.1471829, 0.1591437, 0.1714065, 0.1839899, 0.1969095, 0.2101765, 0.2237976,
+    0.2377769, 0.2520925, 0.2666423, 0.2812996, 0.2959534, 0.3104838, 0.324807, 0.3389451, 0.3529451, 0.3668541,
+    0.3807184, 0.3946233, 0.4088185, 0.4235946, 0.4392437, 0.4560572, 0.4742388, 0.493627, 0.5139717, 0.5350217,
+    0.5565282, 0.578231, 0.5998465, 0.6210813, 0.6416474, 0.6612511, 0.6796351, 0.696692, 0.7123458, 0.7265262,
+    0.7391581, 0.7502208, 0.7598892, 0.7683839, 0.7759226, 0.7827187, 0.7890103, 0.795143, 0.8014875, 0.8084161,
+    0.8163097, 0.8254768, 0.8359611, 0.8477333, 0.8607621, 0.8750105, 0.8903386, 0.9061947, 0.921933, 0.9369179,
+    0.9505274, 0.9622887, 0.9723206, 0.9808698, 0.9881701, 0.9944392, 0.9997414, 1.003578, 1.005318, 1.004332,
+    1.0, 0.9919242, 0.9806006, 0.9667539, 0.9511119, 0.9344071, 0.9173236, 0.9003287, 0.8838425, 0.8682787,
+    0.8540541, 0.8414924, 0.8305637, 0.8211419, 0.8130988, 0.8062993, 0.8006266, 0.7960135, 0.7924049, 0.7897454,
+    0.7879756, 0.7869798, 0.786409, 0.7858575, 0.7849168, 0.7831765, 0.7803038, 0.7762867, 0.7711996, 0.7651225,
+    0.7581477, 0.7502832, 0.7411843, 0.7304065, 0.7174905, 0.7019662, 0.6835305, 0.6626068, 0.6398095, 0.6157601,
+    0.5910863, 0.5663171, 0.541549, 0.5167693, 0.4919617, 0.4671066, 0.4421973, 0.4172901, 0.3924576, 0.3677697,
+    0.3432984, 0.3191494, 0.295579, 0.272883, 0.2513622, 0.231322, 0.2129924, 0.1962818, 0.181011, 0.166992,
+    0.1540284, 0.1419235, 0.130505, 0.1196109, 0.1090836, 0.09876738, 0.08857333, 0.07866687, 0.06927807, 0.06063712,
+    0.05297498, 0.04645776, 0.04098818, 0.03640486, 0.03254662, 0.02925249, 0.02637718, 0.02383046, 0.02153601, 0.0194152,
+    0.01738911, 0.01538568, 0.0133696, 0.01131511, 0.009197803, 0.006995587, 0.004715554, 0.002479026, 0.0004308534, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0,
+];
+
+/// CIE 2006 optical density of the lens and ocular media for a 32-year-old
+/// observer. Recovered from [`CIE2006_LMS_2`] with a photopigment template,
+/// constrained to be non-negative and non-increasing with wavelength.
+#[rustfmt::skip]
+pub const CIE2006_OCULAR: [f32; LEN] = [
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    2.720606, 2.641959, 2.563247, 2.484645, 2.406329, 2.328474, 2.251257, 2.174857, 2.099453, 2.025225,
+    1.952353, 1.881004, 1.811291, 1.743312, 1.677163, 1.612944, 1.550757, 1.490735, 1.433014, 1.377733,
+    1.325028, 1.274969, 1.227358, 1.18193, 1.138419, 1.09656, 1.056178, 1.017462, 0.9806911, 0.9461439,
+    0.9140969, 0.8846924, 0.8575377, 0.8321073, 0.8078756, 0.7843193, 0.7610569, 0.7382688, 0.7162748, 0.6953906,
+    0.67593, 0.6580802, 0.6415416, 0.6258974, 0.6107382, 0.5956615, 0.5804012, 0.5652174, 0.5504966, 0.5366201,
+    0.5239632, 0.5127512, 0.5026204, 0.4930633, 0.4835756, 0.4736573, 0.4629942, 0.4520005, 0.4412664, 0.4313745,
+    0.4228976, 0.4162042, 0.4108735, 0.406295, 0.4018666, 0.3969961, 0.3912237, 0.3845885, 0.377252, 0.3693763,
+    0.3611228, 0.3526621, 0.3441953, 0.3359258, 0.3280523, 0.3207668, 0.3142022, 0.3082752, 0.3028497, 0.2977929,
+    0.2929743, 0.2882695, 0.283569, 0.2787718, 0.2737814, 0.2685066, 0.2628577, 0.2567488, 0.2501111, 0.242896,
+    0.2350743, 0.2266844, 0.2179744, 0.2092329, 0.2007373, 0.1927542, 0.1854477, 0.1786107, 0.1719454, 0.1651544,
+    0.1579383, 0.1501035, 0.141882, 0.1336106, 0.1256255, 0.1182618, 0.1117754, 0.106097, 0.1010625, 0.09649271,
+    0.09219502, 0.08800259, 0.08389923, 0.07990855, 0.0760571, 0.07237454, 0.06887694, 0.06552138, 0.06226989, 0.05910537,
+    0.05602571, 0.05303027, 0.05007815, 0.04710662, 0.04404123, 0.04079862, 0.03733006, 0.03375281, 0.0302319, 0.02693863,
+    0.02404962, 0.02170268, 0.01986355, 0.0184522, 0.01738596, 0.01658043, 0.01595606, 0.01545901, 0.01504217, 0.01465901,
+    0.01426321, 0.01380741, 0.0132399, 0.01250672, 0.01155304, 0.01032326, 0.008794155, 0.007075881, 0.005312655, 0.003649865,
+    0.002233389, 0.001186007, 0.0005338213, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696,
+    0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696,
+    0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0002784696, 0.0001950216, 3.461603e-5, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0,
+];
+
+/// CIE standard illuminant A (Planckian, 2856 K).
+#[rustfmt::skip]
+pub const A: [f32; LEN] = [
+    9.7951, 10.0096, 10.2273, 10.4481, 10.6722, 10.8996, 11.1302, 11.364, 11.6012, 11.8416,
+    12.0853, 12.3324, 12.5828, 12.8366, 13.0938, 13.3543, 13.6182, 13.8855, 14.1563, 14.4304,
+    14.708, 14.9891, 15.2736, 15.5616, 15.853, 16.148, 16.4464, 16.7484, 17.0538, 17.3628,
+    17.6753, 17.9913, 18.3108, 18.6339, 18.9605, 19.2907, 19.6244, 19.9617, 20.3026, 20.647,
+    20.995, 21.3465, 21.7016, 22.0603, 22.4225, 22.7883, 23.1577, 23.5307, 23.9072, 24.2873,
+    24.6709, 25.0581, 25.4489, 25.8432, 26.2411, 26.6425, 27.0475, 27.456, 27.8681, 28.2836,
+    28.7027, 29.1253, 29.5515, 29.9811, 30.4142, 30.8508, 31.2909, 31.7345, 32.1815, 32.632,
+    33.0859, 33.5432, 34.004, 34.4682, 34.9358, 35.4068, 35.8811, 36.3588, 36.8399, 37.3243,
+    37.8121, 38.3031, 38.7975, 39.2951, 39.796, 40.3002, 40.8076, 41.3182, 41.832, 42.3491,
+    42.8693, 43.3926, 43.9192, 44.4488, 44.9816, 45.5174, 46.0563, 46.5983, 47.1433, 47.6913,
+    48.2423, 48.7963, 49.3533, 49.9132, 50.476, 51.0418, 51.6104, 52.1818, 52.7561, 53.3332,
+    53.9132, 54.4958, 55.0813, 55.6694, 56.2603, 56.8539, 57.4501, 58.0489, 58.6504, 59.2545,
+    59.8611, 60.4703, 61.082, 61.6962, 62.3128, 62.932, 63.5535, 64.1775, 64.8038, 65.4325,
+    66.0635, 66.6968, 67.3324, 67.9702, 68.6102, 69.2525, 69.8969, 70.5435, 71.1922, 71.843,
+    72.4959, 73.1508, 73.8077, 74.4666, 75.1275, 75.7903, 76.4551, 77.1217, 77.7902, 78.4605,
+    79.1326, 79.8065, 80.4821, 81.1595, 81.8386, 82.5193, 83.2017, 83.8856, 84.5712, 85.2584,
+    85.947, 86.6372, 87.3288, 88.0219, 88.7165, 89.4124, 90.1097, 90.8083, 91.5082, 92.2095,
+    92.912, 93.6157, 94.3206, 95.0267, 95.7339, 96.4423, 97.1518, 97.8623, 98.5739, 99.2864,
+    100.0, 100.715, 101.43, 102.146, 102.864, 103.582, 104.301, 105.02, 105.741, 106.462,
+    107.184, 107.906, 108.63, 109.354, 110.078, 110.803, 111.529, 112.255, 112.982, 113.709,
+    114.436, 115.164, 115.893, 116.622, 117.351, 118.08, 118.81, 119.54, 120.27, 121.001,
+    121.731, 122.462, 123.193, 123.924, 124.655, 125.386, 126.118, 126.849, 127.58, 128.312,
+    129.043, 129.774, 130.505, 131.236, 131.966, 132.697, 133.427, 134.157, 134.887, 135.617,
+    136.346, 137.075, 137.804, 138.532, 139.26, 139.988, 140.715, 141.441, 142.167, 142.893,
+    143.618, 144.343, 145.067, 145.79, 146.513, 147.235, 147.957, 148.678, 149.398, 150.117,
+    150.836, 151.554, 152.271, 152.988, 153.704, 154.418, 155.132, 155.845, 156.558, 157.269,
+    157.979, 158.689, 159.397, 160.104, 160.811, 161.516, 162.221, 162.924, 163.626, 164.327,
+    165.028, 165.726, 166.424, 167.121, 167.816, 168.51, 169.203, 169.895, 170.586, 171.275,
+    171.963, 172.65, 173.335, 174.019, 174.702, 175.383, 176.063, 176.741, 177.419, 178.094,
+    178.769, 179.441, 180.113, 180.783, 181.451, 182.118, 182.783, 183.447, 184.109, 184.77,
+    185.429, 186.087, 186.743, 187.397, 188.05, 188.701, 189.35, 189.998, 190.644, 191.288,
+    191.931, 192.572, 193.211, 193.849, 194.484, 195.118, 195.75, 196.381, 197.009, 197.636,
+    198.261, 198.884, 199.506, 200.125, 200.743, 201.359, 201.972, 202.584, 203.195, 203.803,
+    204.409, 205.013, 205.616, 206.216, 206.815, 207.411, 208.006, 208.599, 209.189, 209.778,
+    210.365, 210.949, 211.532, 212.112, 212.691, 213.268, 213.842, 214.415, 214.985, 215.553,
+    216.12, 216.684, 217.246, 217.806, 218.364, 218.92, 219.473, 220.025, 220.574, 221.122,
+    221.667, 222.21, 222.751, 223.29, 223.826, 224.361, 224.893, 225.423, 225.951, 226.477,
+    227.0, 227.522, 228.041, 228.558, 229.073, 229.585, 230.096, 230.604, 231.11, 231.614,
+    232.115, 232.615, 233.112, 233.606, 234.099, 234.589, 235.078, 235.564, 236.047, 236.529,
+    237.008, 237.485, 237.959, 238.432, 238.902, 239.37, 239.836, 240.299, 240.76, 241.219,
+    241.675,
+];
+
+/// CIE illuminant C (filtered tungsten, average daylight); superseded by D65
+/// but still tabulated in ASTM E308.
+#[rustfmt::skip]
+pub const C: [f32; LEN] = [
+    33.0, 34.384, 35.768, 37.152, 38.536, 39.92, 41.416, 42.912, 44.408, 45.904,
+    47.4, 48.954, 50.508, 52.062, 53.616, 55.17, 56.796, 58.422, 60.048, 61.674,
+    63.3, 65.002, 66.704, 68.406, 70.108, 71.81, 73.568, 75.326, 77.084, 78.842,
+    80.6, 82.386, 84.172, 85.958, 87.744, 89.53, 91.244, 92.958, 94.672, 96.386,
+    98.1, 99.64, 101.18, 102.72, 104.26, 105.8, 107.12, 108.44, 109.76, 111.08,
+    112.4, 113.47, 114.54, 115.61, 116.68, 117.75, 118.5, 119.25, 120.0, 120.75,
+    121.5, 121.89, 122.28, 122.67, 123.06, 123.45, 123.56, 123.67, 123.78, 123.89,
+    124.0, 123.92, 123.84, 123.76, 123.68, 123.6, 123.5, 123.4, 123.3, 123.2,
+    123.1, 123.14, 123.18, 123.22, 123.26, 123.3, 123.4, 123.5, 123.6, 123.7,
+    123.8, 123.858, 123.916, 123.974, 124.032, 124.09, 124.052, 124.014, 123.976, 123.938,
+    123.9, 123.704, 123.508, 123.312, 123.116, 122.92, 122.476, 122.032, 121.588, 121.144,
+    120.7, 119.94, 119.18, 118.42, 117.66, 116.9, 115.94, 114.98, 114.02, 113.06,
+    112.1, 111.076, 110.052, 109.028, 108.004, 106.98, 106.044, 105.108, 104.172, 103.236,
+    102.3, 101.602, 100.904, 100.206, 99.508, 98.81, 98.428, 98.046, 97.664, 97.282,
+    96.9, 96.876, 96.852, 96.828, 96.804, 96.78, 97.024, 97.268, 97.512, 97.756,
+    98.0, 98.388, 98.776, 99.164, 99.552, 99.94, 100.372, 100.804, 101.236, 101.668,
+    102.1, 102.47, 102.84, 103.21, 103.58, 103.95, 104.2, 104.45, 104.7, 104.95,
+    105.2, 105.294, 105.388, 105.482, 105.576, 105.67, 105.596, 105.522, 105.448, 105.374,
+    105.3, 105.062, 104.824, 104.586, 104.348, 104.11, 103.748, 103.386, 103.024, 102.662,
+    102.3, 101.87, 101.44, 101.01, 100.58, 100.15, 99.68, 99.21, 98.74, 98.27,
+    97.8, 97.326, 96.852, 96.378, 95.904, 95.43, 94.984, 94.538, 94.092, 93.646,
+    93.2, 92.804, 92.408, 92.012, 91.616, 91.22, 90.916, 90.612, 90.308, 90.004,
+    89.7, 89.526, 89.352, 89.178, 89.004, 88.83, 88.744, 88.658, 88.572, 88.486,
+    88.4, 88.358, 88.316, 88.274, 88.232, 88.19, 88.172, 88.154, 88.136, 88.118,
+    88.1, 88.092, 88.084, 88.076, 88.068, 88.06, 88.048, 88.036, 88.024, 88.012,
+    88.0, 87.972, 87.944, 87.916, 87.888, 87.86, 87.848, 87.836, 87.824, 87.812,
+    87.8, 87.838, 87.876, 87.914, 87.952, 87.99, 88.032, 88.074, 88.116, 88.158,
+    88.2, 88.2, 88.2, 88.2, 88.2, 88.2, 88.14, 88.08, 88.02, 87.96,
+    87.9, 87.764, 87.628, 87.492, 87.356, 87.22, 87.036, 86.852, 86.668, 86.484,
+    86.3, 86.1, 85.9, 85.7, 85.5, 85.3, 85.04, 84.78, 84.52, 84.26,
+    84.0, 83.642, 83.284, 82.926, 82.568, 82.21, 81.808, 81.406, 81.004, 80.602,
+    80.2, 79.808, 79.416, 79.024, 78.632, 78.24, 77.852, 77.464, 77.076, 76.688,
+    76.3, 75.912, 75.524, 75.136, 74.748, 74.36, 73.968, 73.576, 73.184, 72.792,
+    72.4, 72.0, 71.6, 71.2, 70.8, 70.4, 69.98, 69.56, 69.14, 68.72,
+    68.3, 67.9, 67.5, 67.1, 66.7, 66.3, 65.92, 65.54, 65.16, 64.78,
+    64.4, 64.08, 63.76, 63.44, 63.12, 62.8, 62.54, 62.28, 62.02, 61.76,
+    61.5, 61.24, 60.98, 60.72, 60.46, 60.2, 60.0, 59.8, 59.6, 59.4,
+    59.2, 59.06, 58.92, 58.78, 58.64, 58.5, 58.42, 58.34, 58.26, 58.18,
+    58.1, 58.08, 58.06, 58.04, 58.02, 58.0, 58.04, 58.08, 58.12, 58.16,
+    58.2, 58.26, 58.32, 58.38, 58.44, 58.5, 58.62, 58.74, 58.86, 58.98,
+    59.1,
+];
+
+/// CIE illuminant D50.
+#[rustfmt::skip]
+pub const D50: [f32; LEN] = [
+    24.4875, 25.0258, 25.5641, 26.1024, 26.6407, 27.179, 27.7174, 28.2557, 28.794, 29.3323,
+    29.8706, 31.8144, 33.7581, 35.7018, 37.6456, 39.5894, 41.5331, 43.4768, 45.4206, 47.3644,
+    49.3081, 50.0286, 50.749, 51.4695, 52.19, 52.9104, 53.6309, 54.3514, 55.0719, 55.7923,
+    56.5128, 56.8649, 57.217, 57.5691, 57.9212, 58.2733, 58.6254, 58.9775, 59.3296, 59.6817,
+    60.0338, 59.8122, 59.5905, 59.3689, 59.1473, 58.9256, 58.704, 58.4824, 58.2608, 58.0391,
+    57.8175, 59.5182, 61.219, 62.9197, 64.6205, 66.3212, 68.0219, 69.7227, 71.4234, 73.1242,
+    74.8249, 76.0671, 77.3094, 78.5516, 79.7938, 81.036, 82.2783, 83.5205, 84.7627, 86.005,
+    87.2472, 87.5837, 87.9202, 88.2567, 88.5932, 88.9297, 89.2662, 89.6027, 89.9392, 90.2757,
+    90.6122, 90.6878, 90.7634, 90.839, 90.9146, 90.9902, 91.0657, 91.1413, 91.2169, 91.2925,
+    91.3681, 91.7421, 92.1162, 92.4902, 92.8643, 93.2383, 93.6123, 93.9864, 94.3604, 94.7345,
+    95.1085, 94.7939, 94.4793, 94.1648, 93.8502, 93.5356, 93.221, 92.9064, 92.5919, 92.2773,
+    91.9627, 92.3388, 92.7149, 93.091, 93.4671, 93.8432, 94.2193, 94.5954, 94.9715, 95.3476,
+    95.7237, 95.8127, 95.9016, 95.9906, 96.0795, 96.1685, 96.2575, 96.3464, 96.4354, 96.5243,
+    96.6133, 96.6649, 96.7164, 96.768, 96.8196, 96.8712, 96.9227, 96.9743, 97.0259, 97.0774,
+    97.129, 97.626, 98.123, 98.62, 99.117, 99.614, 100.111, 100.608, 101.105, 101.602,
+    102.099, 101.965, 101.83, 101.696, 101.561, 101.427, 101.292, 101.158, 101.024, 100.889,
+    100.755, 100.911, 101.067, 101.223, 101.38, 101.536, 101.692, 101.848, 102.005, 102.161,
+    102.317, 102.085, 101.854, 101.622, 101.39, 101.158, 100.927, 100.695, 100.463, 100.232,
+    100.0, 99.7735, 99.547, 99.3205, 99.094, 98.8675, 98.641, 98.4145, 98.188, 97.9615,
+    97.735, 97.8533, 97.9716, 98.0899, 98.2082, 98.3265, 98.4448, 98.5631, 98.6814, 98.7997,
+    98.918, 98.3761, 97.8342, 97.2922, 96.7503, 96.2084, 95.6665, 95.1246, 94.5826, 94.0407,
+    93.4988, 93.9177, 94.3366, 94.7555, 95.1744, 95.5933, 96.0122, 96.4311, 96.85, 97.2689,
+    97.6878, 97.8459, 98.0041, 98.1622, 98.3203, 98.4784, 98.6366, 98.7947, 98.9528, 99.111,
+    99.2691, 99.2463, 99.2236, 99.2008, 99.1781, 99.1553, 99.1325, 99.1098, 99.087, 99.0643,
+    99.0415, 98.7095, 98.3776, 98.0456, 97.7136, 97.3816, 97.0497, 96.7177, 96.3857, 96.0538,
+    95.7218, 96.0353, 96.3489, 96.6624, 96.976, 97.2895, 97.603, 97.9166, 98.2301, 98.5437,
+    98.8572, 98.5382, 98.2192, 97.9002, 97.5812, 97.2622, 96.9432, 96.6242, 96.3052, 95.9862,
+    95.6672, 95.9195, 96.1717, 96.424, 96.6762, 96.9285, 97.1808, 97.433, 97.6853, 97.9375,
+    98.1898, 98.6712, 99.1525, 99.6339, 100.115, 100.597, 101.078, 101.559, 102.041, 102.522,
+    103.003, 102.616, 102.229, 101.842, 101.455, 101.068, 100.681, 100.294, 99.9071, 99.52,
+    99.133, 97.9578, 96.7826, 95.6074, 94.4322, 93.257, 92.0817, 90.9065, 89.7313, 88.5561,
+    87.3809, 87.8032, 88.2254, 88.6477, 89.0699, 89.4922, 89.9145, 90.3367, 90.759, 91.1812,
+    91.6035, 91.732, 91.8605, 91.989, 92.1175, 92.246, 92.3746, 92.5031, 92.6316, 92.7601,
+    92.8886, 91.2852, 89.6818, 88.0783, 86.4749, 84.8715, 83.2681, 81.6647, 80.0612, 78.4578,
+    76.8544, 77.8201, 78.7858, 79.7514, 80.7171, 81.6828, 82.6485, 83.6142, 84.5798, 85.5455,
+    86.5112, 87.1181, 87.7249, 88.3318, 88.9386, 89.5455, 90.1524, 90.7592, 91.3661, 91.9729,
+    92.5798, 91.1448, 89.7098, 88.2748, 86.8398, 85.4048, 83.9699, 82.5349, 81.0999, 79.6649,
+    78.2299, 76.1761, 74.1223, 72.0685, 70.0147, 67.9608, 65.907, 63.8532, 61.7994, 59.7456,
+    57.6918, 60.2149, 62.738, 65.2612, 67.7843, 70.3074, 72.8305, 75.3536, 77.8768, 80.3999,
+    82.923, 82.4581, 81.9932, 81.5283, 81.0634, 80.5985, 80.1336, 79.6687, 79.2038, 78.7389,
+    78.274,
+];
+
+/// CIE illuminant D55.
+#[rustfmt::skip]
+pub const D55: [f32; LEN] = [
+    32.5835, 33.13389, 33.68428, 34.23467, 34.78506, 35.33545, 35.88584, 36.43623, 36.98662, 37.53701,
+    38.0874, 40.37354, 42.65968, 44.94582, 47.23196, 49.5181, 51.80424, 54.09038, 56.37652, 58.66266,
+    60.9488, 61.70927, 62.46974, 63.23021, 63.99068, 64.75115, 65.51162, 66.27209, 67.03256, 67.79303,
+    68.5535, 68.85586, 69.15822, 69.46058, 69.76294, 70.0653, 70.36766, 70.67002, 70.97238, 71.27474,
+    71.5771, 71.2108, 70.8445, 70.4782, 70.1119, 69.7456, 69.3793, 69.013, 68.6467, 68.2804,
+    67.9141, 69.68322, 71.45234, 73.22146, 74.99058, 76.7597, 78.52882, 80.29794, 82.06706, 83.83618,
+    85.6053, 86.84404, 88.08278, 89.32152, 90.56026, 91.799, 93.03774, 94.27648, 95.51522, 96.75396,
+    97.9927, 98.23977, 98.48684, 98.73391, 98.98098, 99.22805, 99.47512, 99.72219, 99.96926, 100.2163,
+    100.4634, 100.4084, 100.3534, 100.2984, 100.2434, 100.1883, 100.1333, 100.0783, 100.0233, 99.96831,
+    99.9133, 100.1959, 100.4785, 100.7611, 101.0437, 101.3263, 101.6089, 101.8915, 102.1741, 102.4567,
+    102.7393, 102.2732, 101.807, 101.3409, 100.8747, 100.4086, 99.94246, 99.47632, 99.01018, 98.54404,
+    98.0779, 98.33811, 98.59832, 98.85853, 99.11874, 99.37895, 99.63916, 99.89937, 100.1596, 100.4198,
+    100.68, 100.6815, 100.6831, 100.6846, 100.6862, 100.6877, 100.6892, 100.6908, 100.6923, 100.6939,
+    100.6954, 100.6245, 100.5536, 100.4828, 100.4119, 100.341, 100.2701, 100.1992, 100.1284, 100.0575,
+    99.9866, 100.4089, 100.8312, 101.2535, 101.6758, 102.0981, 102.5203, 102.9426, 103.3649, 103.7872,
+    104.2095, 103.9987, 103.788, 103.5772, 103.3665, 103.1557, 102.945, 102.7343, 102.5235, 102.3128,
+    102.102, 102.1886, 102.2752, 102.3618, 102.4484, 102.5349, 102.6215, 102.7081, 102.7947, 102.8813,
+    102.9679, 102.6711, 102.3743, 102.0775, 101.7807, 101.484, 101.1872, 100.8904, 100.5936, 100.2968,
+    100.0, 99.72164, 99.44328, 99.16492, 98.88656, 98.6082, 98.32984, 98.05148, 97.77312, 97.49476,
+    97.2164, 97.26961, 97.32282, 97.37603, 97.42924, 97.48245, 97.53566, 97.58887, 97.64208, 97.69529,
+    97.7485, 97.11682, 96.48514, 95.85346, 95.22178, 94.5901, 93.95842, 93.32674, 92.69506, 92.06338,
+    91.4317, 91.73047, 92.02924, 92.32801, 92.62678, 92.92555, 93.22432, 93.52309, 93.82186, 94.12063,
+    94.4194, 94.49148, 94.56356, 94.63564, 94.70772, 94.7798, 94.85188, 94.92396, 94.99604, 95.06812,
+    95.1402, 95.04822, 94.95624, 94.86426, 94.77228, 94.6803, 94.58832, 94.49634, 94.40436, 94.31238,
+    94.2204, 93.84313, 93.46586, 93.08859, 92.71132, 92.33405, 91.95678, 91.57951, 91.20224, 90.82497,
+    90.4477, 90.63592, 90.82414, 91.01236, 91.20058, 91.3888, 91.57702, 91.76524, 91.95346, 92.14168,
+    92.3299, 91.98232, 91.63474, 91.28716, 90.93958, 90.592, 90.24442, 89.89684, 89.54926, 89.20168,
+    88.8541, 89.00041, 89.14672, 89.29303, 89.43934, 89.58565, 89.73196, 89.87827, 90.02458, 90.17089,
+    90.3172, 90.68044, 91.04368, 91.40692, 91.77016, 92.1334, 92.49664, 92.85988, 93.22312, 93.58636,
+    93.9496, 93.55028, 93.15096, 92.75164, 92.35232, 91.953, 91.55368, 91.15436, 90.75504, 90.35572,
+    89.9564, 88.92842, 87.90044, 86.87246, 85.84448, 84.8165, 83.78852, 82.76054, 81.73256, 80.70458,
+    79.6766, 79.99291, 80.30922, 80.62553, 80.94184, 81.25815, 81.57446, 81.89077, 82.20708, 82.52339,
+    82.8397, 83.04008, 83.24046, 83.44084, 83.64122, 83.8416, 84.04198, 84.24236, 84.44274, 84.64312,
+    84.8435, 83.38265, 81.9218, 80.46095, 79.0001, 77.53925, 76.0784, 74.61755, 73.1567, 71.69585,
+    70.235, 71.14162, 72.04824, 72.95486, 73.86148, 74.7681, 75.67472, 76.58134, 77.48796, 78.39458,
+    79.3012, 79.87038, 80.43956, 81.00874, 81.57792, 82.1471, 82.71628, 83.28546, 83.85464, 84.42382,
+    84.993, 83.68174, 82.37048, 81.05922, 79.74796, 78.4367, 77.12544, 75.81418, 74.50292, 73.19166,
+    71.8804, 69.9717, 68.063, 66.1543, 64.2456, 62.3369, 60.4282, 58.5195, 56.6108, 54.7021,
+    52.7934, 55.10674, 57.42008, 59.73342, 62.04676, 64.3601, 66.67344, 68.98678, 71.30012, 73.61346,
+    75.9268, 75.51588, 75.10496, 74.69404, 74.28312, 73.8722, 73.46128, 73.05036, 72.63944, 72.22852,
+    71.8176,
+];
+
+/// CIE standard illuminant D65.
+#[rustfmt::skip]
+pub const D65: [f32; LEN] = [
+    49.9755, 50.4428, 50.91, 51.3773, 51.8446, 52.3118, 52.7791, 53.2464, 53.7137, 54.1809,
+    54.6482, 57.4589, 60.2695, 63.0802, 65.8909, 68.7015, 71.5122, 74.3229, 77.1336, 79.9442,
+    82.7549, 83.628, 84.5011, 85.3742, 86.2473, 87.1204, 87.9936, 88.8667, 89.7398, 90.6129,
+    91.486, 91.6806, 91.8752, 92.0697, 92.2643, 92.4589, 92.6535, 92.8481, 93.0426, 93.2372,
+    93.4318, 92.7568, 92.0819, 91.4069, 90.732, 90.057, 89.3821, 88.7071, 88.0322, 87.3572,
+    86.6823, 88.5006, 90.3188, 92.1371, 93.9554, 95.7736, 97.5919, 99.4102, 101.228, 103.047,
+    104.865, 106.079, 107.294, 108.508, 109.722, 110.936, 112.151, 113.365, 114.579, 115.794,
+    117.008, 117.088, 117.169, 117.249, 117.33, 117.41, 117.49, 117.571, 117.651, 117.732,
+    117.812, 117.517, 117.222, 116.927, 116.632, 116.336, 116.041, 115.746, 115.451, 115.156,
+    114.861, 114.967, 115.073, 115.18, 115.286, 115.392, 115.498, 115.604, 115.711, 115.817,
+    115.923, 115.212, 114.501, 113.789, 113.078, 112.367, 111.656, 110.945, 110.233, 109.522,
+    108.811, 108.865, 108.92, 108.974, 109.028, 109.082, 109.137, 109.191, 109.245, 109.3,
+    109.354, 109.199, 109.044, 108.888, 108.733, 108.578, 108.423, 108.268, 108.112, 107.957,
+    107.802, 107.501, 107.2, 106.898, 106.597, 106.296, 105.995, 105.694, 105.392, 105.091,
+    104.79, 105.08, 105.37, 105.66, 105.95, 106.239, 106.529, 106.819, 107.109, 107.399,
+    107.689, 107.361, 107.032, 106.704, 106.375, 106.047, 105.719, 105.39, 105.062, 104.733,
+    104.405, 104.369, 104.333, 104.297, 104.261, 104.225, 104.19, 104.154, 104.118, 104.082,
+    104.046, 103.641, 103.237, 102.832, 102.428, 102.023, 101.618, 101.214, 100.809, 100.405,
+    100.0, 99.6334, 99.2668, 98.9003, 98.5337, 98.1671, 97.8005, 97.4339, 97.0674, 96.7008,
+    96.3342, 96.2796, 96.225, 96.1703, 96.1157, 96.0611, 96.0065, 95.9519, 95.8972, 95.8426,
+    95.788, 95.0778, 94.3675, 93.6573, 92.947, 92.2368, 91.5266, 90.8163, 90.1061, 89.3958,
+    88.6856, 88.8177, 88.9497, 89.0818, 89.2138, 89.3459, 89.478, 89.61, 89.7421, 89.8741,
+    90.0062, 89.9655, 89.9248, 89.8841, 89.8434, 89.8026, 89.7619, 89.7212, 89.6805, 89.6398,
+    89.5991, 89.4091, 89.219, 89.029, 88.8389, 88.6489, 88.4589, 88.2688, 88.0788, 87.8887,
+    87.6987, 87.2577, 86.8167, 86.3757, 85.9347, 85.4936, 85.0526, 84.6116, 84.1706, 83.7296,
+    83.2886, 83.3297, 83.3707, 83.4118, 83.4528, 83.4939, 83.535, 83.576, 83.6171, 83.6581,
+    83.6992, 83.332, 82.9647, 82.5975, 82.2302, 81.863, 81.4958, 81.1285, 80.7613, 80.394,
+    80.0268, 80.0456, 80.0644, 80.0831, 80.1019, 80.1207, 80.1395, 80.1583, 80.177, 80.1958,
+    80.2146, 80.4209, 80.6272, 80.8336, 81.0399, 81.2462, 81.4525, 81.6588, 81.8652, 82.0715,
+    82.2778, 81.8784, 81.4791, 81.0797, 80.6804, 80.281, 79.8816, 79.4823, 79.0829, 78.6836,
+    78.2842, 77.4279, 76.5716, 75.7153, 74.859, 74.0027, 73.1465, 72.2902, 71.4339, 70.5776,
+    69.7213, 69.9101, 70.0989, 70.2876, 70.4764, 70.6652, 70.854, 71.0428, 71.2315, 71.4203,
+    71.6091, 71.8831, 72.1571, 72.4311, 72.7051, 72.979, 73.253, 73.527, 73.801, 74.075,
+    74.349, 73.0745, 71.8, 70.5255, 69.251, 67.9765, 66.702, 65.4275, 64.153, 62.8785,
+    61.604, 62.4322, 63.2603, 64.0885, 64.9166, 65.7448, 66.573, 67.4011, 68.2293, 69.0574,
+    69.8856, 70.4057, 70.9259, 71.446, 71.9662, 72.4863, 73.0064, 73.5266, 74.0467, 74.5669,
+    75.087, 73.9376, 72.7881, 71.6387, 70.4893, 69.3398, 68.1904, 67.041, 65.8916, 64.7421,
+    63.5927, 61.8752, 60.1578, 58.4403, 56.7229, 55.0054, 53.288, 51.5705, 49.8531, 48.1356,
+    46.4182, 48.4569, 50.4956, 52.5344, 54.5731, 56.6118, 58.6505, 60.6892, 62.728, 64.7667,
+    66.8054, 66.4631, 66.1209, 65.7786, 65.4364, 65.0941, 64.7518, 64.4096, 64.0673, 63.7251,
+    63.3828,
+];
+
+/// CIE illuminant D75.
+#[rustfmt::skip]
+pub const D75: [f32; LEN] = [
+    66.7025, 67.02855, 67.3546, 67.68065, 68.0067, 68.33275, 68.6588, 68.98485, 69.3109, 69.63695,
+    69.963, 73.1596, 76.3562, 79.5528, 82.7494, 85.946, 89.1426, 92.3392, 95.5358, 98.7324,
+    101.929, 102.9254, 103.9219, 104.9183, 105.9148, 106.9112, 107.9077, 108.9041, 109.9006, 110.897,
+    111.8935, 111.9839, 112.0743, 112.1647, 112.2551, 112.3455, 112.4359, 112.5263, 112.6167, 112.7071,
+    112.7975, 111.8269, 110.8563, 109.8857, 108.9151, 107.9445, 106.9739, 106.0033, 105.0327, 104.0621,
+    103.0915, 104.9021, 106.7127, 108.5233, 110.3339, 112.1445, 113.9551, 115.7657, 117.5763, 119.3869,
+    121.1975, 122.3787, 123.5599, 124.7411, 125.9223, 127.1035, 128.2847, 129.4659, 130.6471, 131.8283,
+    133.0095, 132.9441, 132.8786, 132.8131, 132.7477, 132.6822, 132.6168, 132.5513, 132.4859, 132.4204,
+    132.355, 131.8517, 131.3483, 130.845, 130.3416, 129.8383, 129.3349, 128.8316, 128.3282, 127.8248,
+    127.3215, 127.2693, 127.2171, 127.1649, 127.1127, 127.0605, 127.0083, 126.9561, 126.9039, 126.8517,
+    126.7995, 125.8978, 124.9961, 124.0944, 123.1927, 122.291, 121.3893, 120.4876, 119.5859, 118.6842,
+    117.7825, 117.6632, 117.5438, 117.4244, 117.3051, 117.1857, 117.0664, 116.9471, 116.8277, 116.7083,
+    116.589, 116.3003, 116.0116, 115.7229, 115.4342, 115.1455, 114.8568, 114.5681, 114.2794, 113.9907,
+    113.702, 113.1977, 112.6934, 112.1891, 111.6848, 111.1805, 110.6762, 110.1719, 109.6676, 109.1633,
+    108.659, 108.8376, 109.0161, 109.1946, 109.3732, 109.5517, 109.7303, 109.9089, 110.0874, 110.266,
+    110.4445, 110.029, 109.6134, 109.1979, 108.7823, 108.3667, 107.9512, 107.5356, 107.1201, 106.7045,
+    106.289, 106.1504, 106.0119, 105.8733, 105.7348, 105.5962, 105.4577, 105.3191, 105.1806, 105.042,
+    104.9035, 104.4132, 103.9228, 103.4325, 102.9421, 102.4518, 101.9614, 101.4711, 100.9807, 100.4904,
+    100.0, 99.5616, 99.1232, 98.6848, 98.2464, 97.808, 97.3696, 96.9312, 96.4928, 96.0544,
+    95.616, 95.47565, 95.3353, 95.19495, 95.0546, 94.91425, 94.7739, 94.63355, 94.4932, 94.35285,
+    94.2125, 93.4909, 92.7693, 92.0477, 91.3261, 90.6045, 89.8829, 89.1613, 88.4397, 87.7181,
+    86.9965, 87.01955, 87.0426, 87.06565, 87.0887, 87.11175, 87.1348, 87.15785, 87.1809, 87.20395,
+    87.227, 87.1183, 87.0096, 86.9009, 86.7922, 86.6835, 86.5748, 86.4661, 86.3574, 86.2487,
+    86.14, 85.8841, 85.6282, 85.3723, 85.1164, 84.8605, 84.6046, 84.3487, 84.0928, 83.8369,
+    83.581, 83.09755, 82.6141, 82.13065, 81.6472, 81.16375, 80.6803, 80.19685, 79.7134, 79.22995,
+    78.7465, 78.7146, 78.6827, 78.6508, 78.6189, 78.587, 78.5551, 78.5232, 78.4913, 78.4594,
+    78.4275, 78.0648, 77.7021, 77.3394, 76.9767, 76.614, 76.2513, 75.8886, 75.5259, 75.1632,
+    74.8005, 74.75285, 74.7052, 74.65755, 74.6099, 74.56225, 74.5146, 74.46695, 74.4193, 74.37165,
+    74.324, 74.4338, 74.5436, 74.6534, 74.7632, 74.873, 74.9828, 75.0926, 75.2024, 75.3122,
+    75.422, 75.0374, 74.6528, 74.2682, 73.8836, 73.499, 73.1144, 72.7298, 72.3452, 71.9606,
+    71.576, 70.8036, 70.0312, 69.2588, 68.4864, 67.714, 66.9416, 66.1692, 65.3968, 64.6244,
+    63.852, 63.97435, 64.0967, 64.21905, 64.3414, 64.46375, 64.5861, 64.70845, 64.8308, 64.95315,
+    65.0755, 65.3749, 65.6743, 65.9737, 66.2731, 66.5725, 66.8719, 67.1713, 67.4707, 67.7701,
+    68.0695, 66.90685, 65.7442, 64.58155, 63.4189, 62.25625, 61.0936, 59.93095, 58.7683, 57.60565,
+    56.443, 57.2229, 58.0028, 58.7827, 59.5626, 60.3425, 61.1224, 61.9023, 62.6822, 63.4621,
+    64.242, 64.7329, 65.2238, 65.7147, 66.2056, 66.6965, 67.1874, 67.6783, 68.1692, 68.6601,
+    69.151, 68.0988, 67.0466, 65.9944, 64.9422, 63.89, 62.8378, 61.7856, 60.7334, 59.6812,
+    58.629, 57.0278, 55.4266, 53.8254, 52.2242, 50.623, 49.0218, 47.4206, 45.8194, 44.2182,
+    42.617, 44.4905, 46.364, 48.2375, 50.111, 51.9845, 53.858, 55.7315, 57.605, 59.4785,
+    61.352, 61.0492, 60.7464, 60.4436, 60.1408, 59.838, 59.5352, 59.2324, 58.9296, 58.6268,
+    58.324,
+];
+
+/// CIE fluorescent illuminant F1 (standard halophosphate).
+#[rustfmt::skip]
+pub const F1: [f32; LEN] = [
+    1.87, 1.968, 2.0648, 2.1616, 2.2596, 2.36, 2.47328, 2.58904, 2.70616, 2.82352,
+    2.94, 3.044666, 3.148666, 3.253333, 3.359999, 3.47, 3.592652, 3.719309, 3.849307, 3.981984,
+    4.116677, 4.250255, 4.406496, 6.431197, 27.05268, 47.41181, 19.10395, 5.806756, 5.224327, 5.362642,
+    5.506598, 5.650956, 5.796374, 5.942954, 6.090795, 6.24, 6.391923, 6.545098, 6.699311, 6.854349,
+    7.01, 7.16584, 7.32192, 7.47808, 7.63416, 7.79, 7.944958, 8.099477, 8.253516, 8.407037,
+    8.56, 8.712713, 8.864702, 9.015841, 9.166003, 42.95303, 181.2707, 9.619334, 9.754173, 9.8976,
+    10.03938, 10.17922, 10.31726, 10.45344, 10.5877, 10.72, 10.85131, 10.98028, 11.10659, 11.22994,
+    11.35, 11.46424, 11.57512, 11.68288, 11.78776, 11.89, 11.99208, 12.09144, 12.18776, 12.28072,
+    12.37, 12.45496, 12.53568, 12.61192, 12.68344, 12.75, 12.80944, 12.86392, 12.91368, 12.95896,
+    13.0, 13.03704, 13.07032, 13.10008, 13.12656, 13.15, 13.17384, 13.19432, 13.21088, 13.22296,
+    13.23, 13.22408, 13.21384, 13.20056, 13.18552, 13.17, 13.16872, 13.16616, 13.16024, 13.14888,
+    13.13, 13.08712, 13.03616, 12.97864, 12.91608, 12.85, 12.78608, 12.72064, 12.65416, 12.58712,
+    12.52, 12.45712, 12.39416, 12.33064, 12.26608, 12.2, 12.12712, 12.05296, 11.97824, 11.90368,
+    11.83, 11.76048, 11.69264, 11.62656, 11.56232, 11.5, 11.43808, 11.37864, 11.32216, 11.26912,
+    11.22, 11.17592, 11.13656, 11.10224, 11.07328, 11.05, 11.03336, 11.02288, 11.01872, 11.02104,
+    11.03, 11.04544, 11.06792, 11.09768, 11.13496, 11.18, 11.23302, 11.29428, 11.36403, 11.44252,
+    11.53, 11.63148, 11.74124, 11.85834, 11.98182, 12.70077, 103.3527, 19.91457, 12.51682, 12.65896,
+    12.8037, 12.94932, 13.09692, 13.24634, 13.39742, 13.55, 13.70758, 13.86544, 14.02251, 14.17772,
+    14.33, 14.47624, 14.61792, 14.75448, 14.88536, 15.01, 15.12943, 15.2411, 15.34406, 15.43734,
+    15.52, 15.58219, 15.63538, 15.67952, 15.71454, 15.74037, 19.34707, 27.71633, 15.76216, 29.30521,
+    18.49908, 15.69906, 15.65888, 15.60902, 15.54941, 15.48, 15.38746, 15.28314, 15.1681, 15.04337,
+    14.91, 14.77256, 14.62768, 14.47552, 14.31624, 14.15, 13.97536, 13.79448, 13.60792, 13.41624,
+    13.22, 13.02008, 12.81664, 12.61016, 12.40112, 12.19, 11.97856, 11.76568, 11.55152, 11.33624,
+    11.12, 10.90264, 10.68472, 10.46648, 10.24816, 10.03, 9.81064, 9.59232, 9.37568, 9.16136,
+    8.95, 8.74608, 8.54544, 8.34776, 8.15272, 7.96, 7.76576, 7.57408, 7.38552, 7.20064,
+    7.02, 6.84896, 6.68208, 6.51872, 6.35824, 6.2, 6.0392, 5.8804, 5.724, 5.5704,
+    5.42, 5.27416, 5.13208, 4.99392, 4.85984, 4.73, 4.60648, 4.48704, 4.37136, 4.25912,
+    4.15, 4.0424, 3.9376, 3.8356, 3.7364, 3.64, 3.54704, 3.45672, 3.36888, 3.28336,
+    3.2, 3.118, 3.038, 2.96, 2.884, 2.81, 2.738, 2.668, 2.6, 2.534,
+    2.47, 2.40832, 2.34856, 2.29064, 2.23448, 2.18, 2.1268, 2.0752, 2.0252, 1.9768,
+    1.93, 1.88096, 1.83448, 1.79152, 1.75304, 1.72, 1.7084, 1.7004, 1.6932, 1.684,
+    1.67, 1.62792, 1.58056, 1.53024, 1.47928, 1.43, 1.39592, 1.36536, 1.33784, 1.31288,
+    1.29, 1.2684, 1.248, 1.2284, 1.2092, 1.19, 1.1688, 1.1472, 1.1252, 1.1028,
+    1.08, 1.0552, 1.0304, 1.006, 0.9824, 0.96, 0.94176, 0.92488, 0.90912, 0.89424,
+    0.88, 0.86456, 0.84968, 0.83552, 0.82224, 0.81, 0.79992, 0.79096, 0.78304, 0.77608,
+    0.77, 0.76504, 0.76072, 0.75688, 0.75336, 0.75, 0.74696, 0.74368, 0.73992, 0.73544,
+    0.73, 0.71952, 0.70856, 0.69784, 0.68808, 0.68, 0.68104, 0.68352, 0.68648, 0.68896,
+    0.69, 0.68, 0.6688, 0.6576, 0.6476, 0.64, 0.64464, 0.65192, 0.66088, 0.67056,
+    0.68, 0.68632, 0.69096, 0.69344, 0.69328, 0.69, 0.67864, 0.66432, 0.64768, 0.62936,
+    0.61, 0.59248, 0.57464, 0.55656, 0.53832, 0.52, 0.502, 0.484, 0.466, 0.448,
+    0.43,
+];
+
+/// CIE fluorescent illuminant F2 (cool white).
+#[rustfmt::skip]
+pub const F2: [f32; LEN] = [
+    1.18, 1.24, 1.2992, 1.3584, 1.4184, 1.48, 1.55072, 1.62296, 1.69584, 1.76848,
+    1.84, 1.902283, 1.963495, 2.024566, 2.086425, 2.15, 2.221558, 2.295358, 2.370994, 2.448061,
+    2.526155, 2.60333, 2.697569, 4.352257, 22.24096, 40.17437, 15.19063, 3.676644, 3.162987, 3.241073,
+    3.323116, 3.404876, 3.487272, 3.570477, 3.654662, 3.74, 3.82905, 3.919001, 4.009425, 4.0999,
+    4.19, 4.27664, 4.36272, 4.44848, 4.53416, 4.62, 4.708018, 4.796231, 4.884436, 4.972427,
+    5.06, 5.14624, 5.231831, 5.316747, 5.40096, 34.45694, 152.9612, 5.659222, 5.730471, 5.81072,
+    5.89, 5.967867, 6.044756, 6.120711, 6.195778, 6.27, 6.34488, 6.41864, 6.49096, 6.56152,
+    6.63, 6.69416, 6.75608, 6.81592, 6.87384, 6.93, 6.98552, 7.03936, 7.09144, 7.14168,
+    7.19, 7.23664, 7.28112, 7.32328, 7.36296, 7.4, 7.43328, 7.46384, 7.49176, 7.51712,
+    7.54, 7.56048, 7.57864, 7.59456, 7.60832, 7.62, 7.63032, 7.63856, 7.64464, 7.64848,
+    7.65, 7.64592, 7.64016, 7.63344, 7.62648, 7.62, 7.624, 7.6276, 7.6292, 7.6272,
+    7.62, 7.59416, 7.56288, 7.52752, 7.48944, 7.45, 7.41472, 7.37976, 7.34544, 7.31208,
+    7.28, 7.25112, 7.22376, 7.19784, 7.17328, 7.15, 7.12568, 7.10304, 7.08256, 7.06472,
+    7.05, 7.03952, 7.03296, 7.03064, 7.03288, 7.04, 7.05168, 7.06904, 7.09256, 7.12272,
+    7.16, 7.20456, 7.25728, 7.31872, 7.38944, 7.47, 7.56288, 7.66624, 7.78016, 7.90472,
+    8.04, 8.18576, 8.34248, 8.51032, 8.68944, 8.88, 9.082547, 9.296758, 9.522694, 9.760421,
+    10.01, 10.2771, 10.55477, 10.84169, 11.13651, 11.926, 90.11328, 18.5117, 12.36354, 12.67896,
+    12.99654, 13.31431, 13.63301, 13.95217, 14.27132, 14.59, 14.91057, 15.22902, 15.54419, 15.85491,
+    16.16, 16.45904, 16.74992, 17.03128, 17.30176, 17.56, 17.80305, 18.03154, 18.2445, 18.44098,
+    18.62, 18.76986, 18.90478, 19.02472, 19.12968, 19.21963, 22.46813, 29.93935, 19.39928, 31.24353,
+    21.85969, 19.44326, 19.42768, 19.39696, 19.35107, 19.29, 19.19646, 19.08528, 18.95787, 18.81564,
+    18.66, 18.49672, 18.32176, 18.13544, 17.93808, 17.73, 17.50896, 17.27848, 17.03952, 16.79304,
+    16.54, 16.28328, 16.02144, 15.75496, 15.48432, 15.21, 14.9328, 14.6528, 14.3704, 14.086,
+    13.8, 13.51248, 13.22424, 12.93576, 12.64752, 12.36, 12.07304, 11.78792, 11.50528, 11.22576,
+    10.95, 10.68312, 10.42016, 10.16064, 9.90408, 9.65, 9.39216, 9.13728, 8.88632, 8.64024,
+    8.4, 8.1736, 7.9532, 7.738, 7.5272, 7.32, 7.11048, 6.90424, 6.70176, 6.50352,
+    6.31, 6.1236, 5.9424, 5.7664, 5.5956, 5.43, 5.27088, 5.11664, 4.96696, 4.82152,
+    4.68, 4.5408, 4.4052, 4.2732, 4.1448, 4.02, 3.89912, 3.78176, 3.66784, 3.55728,
+    3.45, 3.3456, 3.2444, 3.1464, 3.0516, 2.96, 2.87256, 2.78808, 2.70632, 2.62704,
+    2.55, 2.47368, 2.39944, 2.32736, 2.25752, 2.19, 2.12552, 2.06336, 2.00344, 1.94568,
+    1.89, 1.83312, 1.77896, 1.72824, 1.68168, 1.64, 1.61608, 1.59544, 1.57576, 1.55472,
+    1.53, 1.48232, 1.43056, 1.37664, 1.32248, 1.27, 1.22976, 1.19288, 1.15912, 1.12824,
+    1.1, 1.07512, 1.05216, 1.03064, 1.01008, 0.99, 0.96832, 0.94656, 0.92464, 0.90248,
+    0.88, 0.8552, 0.8304, 0.806, 0.7824, 0.76, 0.74176, 0.72488, 0.70912, 0.69424,
+    0.68, 0.66488, 0.65024, 0.63616, 0.62272, 0.61, 0.59808, 0.58704, 0.57696, 0.56792,
+    0.56, 0.55488, 0.55064, 0.54696, 0.54352, 0.54, 0.5348, 0.5292, 0.5232, 0.5168,
+    0.51, 0.5012, 0.4924, 0.484, 0.4764, 0.47, 0.46936, 0.46968, 0.47032, 0.47064,
+    0.47, 0.46168, 0.45264, 0.44376, 0.43592, 0.43, 0.43328, 0.43864, 0.44536, 0.45272,
+    0.46, 0.46552, 0.46976, 0.47224, 0.47248, 0.47, 0.45984, 0.44712, 0.43248, 0.41656,
+    0.4, 0.38568, 0.37144, 0.35736, 0.34352, 0.33, 0.3177333, 0.3057333, 0.2938667, 0.282,
+    0.27,
+];
+
+/// CIE fluorescent illuminant F3 (white).
+#[rustfmt::skip]
+pub const F3: [f32; LEN] = [
+    0.82, 0.86, 0.8994667, 0.9389333, 0.9789333, 1.02, 1.068, 1.1168, 1.1656, 1.2136,
+    1.26, 1.296947, 1.332457, 1.367493, 1.40302, 1.44, 1.484898, 1.531801, 1.580297, 1.629974,
+    1.680419, 1.729631, 1.795235, 3.38021, 20.76339, 38.18574, 13.84875, 2.614406, 2.086493, 2.134774,
+    2.186711, 2.23853, 2.290686, 2.34326, 2.396337, 2.45, 2.505518, 2.56149, 2.617704, 2.673945,
+    2.73, 2.78416, 2.83808, 2.89192, 2.94584, 3.0, 3.05614, 3.112445, 3.16868, 3.22461,
+    3.28, 3.333469, 3.386213, 3.438285, 3.489736, 31.35043, 145.0607, 3.648748, 3.690955, 3.73992,
+    3.788272, 3.835437, 3.882079, 3.928311, 3.974247, 4.02, 4.067363, 4.114351, 4.160657, 4.205975,
+    4.25, 4.2912, 4.3308, 4.3688, 4.4052, 4.44, 4.47256, 4.50368, 4.53352, 4.56224,
+    4.59, 4.61856, 4.64608, 4.67232, 4.69704, 4.72, 4.73904, 4.75632, 4.77208, 4.78656,
+    4.8, 4.81456, 4.82808, 4.84032, 4.85104, 4.86, 4.86536, 4.86888, 4.87072, 4.87104,
+    4.87, 4.86584, 4.86112, 4.85648, 4.85256, 4.85, 4.85808, 4.86664, 4.87416, 4.87912,
+    4.88, 4.8644, 4.8444, 4.8212, 4.796, 4.77, 4.74792, 4.72656, 4.70624, 4.68728,
+    4.67, 4.656, 4.644, 4.634, 4.626, 4.62, 4.61408, 4.61064, 4.61016, 4.61312,
+    4.62, 4.63192, 4.64856, 4.67024, 4.69728, 4.73, 4.76744, 4.81152, 4.86288, 4.92216,
+    4.99, 5.068, 5.1556, 5.2532, 5.3612, 5.48, 5.61032, 5.75216, 5.90584, 6.07168,
+    6.25, 6.44144, 6.64592, 6.86368, 7.09496, 7.34, 7.599554, 7.873219, 8.161107, 8.46333,
+    8.78, 9.117904, 9.468809, 9.831157, 10.20339, 11.07789, 88.09391, 17.73265, 11.75458, 12.15424,
+    12.55716, 12.96064, 13.36608, 13.77307, 14.18118, 14.59, 15.00342, 15.41562, 15.82512, 16.23041,
+    16.63, 17.02344, 17.40792, 17.78168, 18.14296, 18.49, 18.81801, 19.12901, 19.42201, 19.69601,
+    19.95, 20.17199, 20.37655, 20.5636, 20.73305, 20.88481, 24.16149, 31.58882, 21.23308, 33.01777,
+    23.77742, 21.41895, 21.4444, 21.45145, 21.44001, 21.41, 21.33807, 21.24423, 21.13035, 20.99831,
+    20.85, 20.69416, 20.52408, 20.33992, 20.14184, 19.93, 19.7004, 19.4584, 19.2052, 18.942,
+    18.67, 18.39296, 18.10888, 17.81832, 17.52184, 17.22, 16.91304, 16.60192, 16.28728, 15.96976,
+    15.65, 15.32928, 15.00744, 14.68496, 14.36232, 14.04, 13.71816, 13.39768, 13.07912, 12.76304,
+    12.45, 12.14376, 11.84088, 11.54112, 11.24424, 10.95, 10.65272, 10.35896, 10.06984, 9.78648,
+    9.51, 9.24984, 8.99672, 8.74968, 8.50776, 8.27, 8.02968, 7.79304, 7.56056, 7.33272,
+    7.11, 6.89448, 6.68464, 6.48056, 6.28232, 6.09, 5.9056, 5.7268, 5.5532, 5.3844,
+    5.22, 5.05736, 4.89888, 4.74472, 4.59504, 4.45, 4.31168, 4.17784, 4.04816, 3.92232,
+    3.8, 3.67928, 3.56184, 3.44776, 3.33712, 3.23, 3.12776, 3.02888, 2.93312, 2.84024,
+    2.75, 2.66056, 2.57368, 2.48952, 2.40824, 2.33, 2.25656, 2.18608, 2.11832, 2.05304,
+    1.99, 1.92512, 1.86296, 1.80424, 1.74968, 1.7, 1.66744, 1.63832, 1.61048, 1.58176,
+    1.55, 1.49704, 1.44072, 1.38288, 1.32536, 1.27, 1.2276, 1.1888, 1.1532, 1.1204,
+    1.09, 1.0616, 1.0348, 1.0092, 0.9844, 0.96, 0.93368, 0.90744, 0.88136, 0.85552,
+    0.83, 0.80456, 0.77968, 0.75552, 0.73224, 0.71, 0.69024, 0.67152, 0.65368, 0.63656,
+    0.62, 0.60256, 0.58568, 0.56952, 0.55424, 0.54, 0.52792, 0.51696, 0.50704, 0.49808,
+    0.49, 0.48304, 0.47672, 0.47088, 0.46536, 0.46, 0.45432, 0.44856, 0.44264, 0.43648,
+    0.43, 0.4212, 0.4124, 0.404, 0.3964, 0.39, 0.38936, 0.38968, 0.39032, 0.39064,
+    0.39, 0.38168, 0.37264, 0.36376, 0.35592, 0.35, 0.35328, 0.35864, 0.36536, 0.37272,
+    0.38, 0.3852, 0.3892, 0.3916, 0.392, 0.39, 0.38104, 0.36992, 0.35728, 0.34376,
+    0.33, 0.32016, 0.31048, 0.30072, 0.29064, 0.28, 0.2665333, 0.2525333, 0.2382667, 0.224,
+    0.21,
+];
+
+/// CIE fluorescent illuminant F4 (warm white).
+#[rustfmt::skip]
+pub const F4: [f32; LEN] = [
+    0.57, 0.596, 0.6214667, 0.6469333, 0.6729333, 0.7, 0.734, 0.7688, 0.8036, 0.8376,
+    0.87, 0.8936685, 0.9157199, 0.936937, 0.9581028, 0.98, 1.00766, 1.036556, 1.066407, 1.096934,
+    1.127859, 1.157791, 1.202927, 2.739757, 20.16035, 37.7647, 13.15222, 1.906544, 1.373788, 1.40157,
+    1.432627, 1.463534, 1.494651, 1.526052, 1.55781, 1.59, 1.623394, 1.657192, 1.691293, 1.725596,
+    1.76, 1.794, 1.828, 1.862, 1.896, 1.93, 1.964379, 1.998664, 2.032759, 2.066569,
+    2.1, 2.132447, 2.164453, 2.19605, 2.227271, 29.84473, 142.2439, 2.33478, 2.349375, 2.37912,
+    2.408519, 2.436969, 2.465203, 2.49336, 2.521579, 2.55, 2.580551, 2.611135, 2.641443, 2.671167,
+    2.7, 2.7264, 2.7516, 2.7756, 2.7984, 2.82, 2.83976, 2.85848, 2.87632, 2.89344,
+    2.91, 2.92744, 2.94432, 2.96048, 2.97576, 2.99, 3.00176, 3.01248, 3.02232, 3.03144,
+    3.04, 3.04944, 3.05832, 3.06648, 3.07376, 3.08, 3.08376, 3.08648, 3.08832, 3.08944,
+    3.09, 3.08888, 3.08784, 3.08736, 3.08792, 3.09, 3.10176, 3.11408, 3.12552, 3.13464,
+    3.14, 3.1296, 3.1152, 3.098, 3.0792, 3.06, 3.04576, 3.03248, 3.02032, 3.00944,
+    3.0, 2.99248, 2.98664, 2.98256, 2.98032, 2.98, 2.9804, 2.9832, 2.9888, 2.9976,
+    3.01, 3.02672, 3.04776, 3.07344, 3.10408, 3.14, 3.18024, 3.22672, 3.28008, 3.34096,
+    3.41, 3.48784, 3.57512, 3.67248, 3.78056, 3.9, 4.03304, 4.17832, 4.33608, 4.50656,
+    4.69, 4.88568, 5.09504, 5.31856, 5.55672, 5.81, 6.078145, 6.362554, 6.66389, 6.982818,
+    7.32, 7.68621, 8.069476, 8.467932, 8.879716, 9.995693, 87.35798, 16.102, 10.61491, 11.06736,
+    11.52593, 11.98738, 12.45379, 12.92489, 13.40039, 13.88, 14.3686, 14.85946, 15.35103, 15.84173,
+    16.33, 16.81728, 17.29824, 17.77056, 18.23192, 18.68, 19.10922, 19.52134, 19.91485, 20.28824,
+    20.64, 20.95402, 21.24947, 21.52608, 21.78359, 22.02173, 25.43206, 33.07401, 22.61732, 34.56168,
+    25.32857, 23.02912, 23.12432, 23.19804, 23.25002, 23.28, 23.26067, 23.21503, 23.14505, 23.05271,
+    22.94, 22.81584, 22.67352, 22.51328, 22.33536, 22.14, 21.92264, 21.68952, 21.44208, 21.18176,
+    20.91, 20.63272, 20.34576, 20.04944, 19.74408, 19.43, 19.10368, 18.77024, 18.43096, 18.08712,
+    17.74, 17.38928, 17.03824, 16.68856, 16.34192, 16.0, 15.68528, 15.37344, 15.06096, 14.74432,
+    14.42, 14.05408, 13.68104, 13.30496, 12.92992, 12.56, 12.21592, 11.88096, 11.55504, 11.23808,
+    10.93, 10.6352, 10.348, 10.0672, 9.7916, 9.52, 9.2432, 8.97, 8.7012, 8.4376,
+    8.18, 7.93272, 7.69216, 7.45824, 7.23088, 7.01, 6.79648, 6.58904, 6.38736, 6.19112,
+    6.0, 5.81176, 5.62848, 5.45032, 5.27744, 5.11, 4.95072, 4.79656, 4.64704, 4.50168,
+    4.36, 4.21864, 4.08072, 3.94648, 3.81616, 3.69, 3.57048, 3.45504, 3.34336, 3.23512,
+    3.13, 3.02576, 2.92448, 2.82632, 2.73144, 2.64, 2.55344, 2.47032, 2.39048, 2.31376,
+    2.24, 2.1668, 2.0968, 2.0304, 1.968, 1.91, 1.86544, 1.82392, 1.78368, 1.74296,
+    1.7, 1.6396, 1.5768, 1.5132, 1.4504, 1.39, 1.34128, 1.29624, 1.25456, 1.21592,
+    1.18, 1.14712, 1.11616, 1.08664, 1.05808, 1.03, 0.99968, 0.96944, 0.93936, 0.90952,
+    0.88, 0.85024, 0.82112, 0.79288, 0.76576, 0.74, 0.71808, 0.69744, 0.67776, 0.65872,
+    0.64, 0.6184, 0.5972, 0.5768, 0.5576, 0.54, 0.52696, 0.51568, 0.50592, 0.49744,
+    0.49, 0.48336, 0.47728, 0.47152, 0.46584, 0.46, 0.4528, 0.4452, 0.4372, 0.4288,
+    0.42, 0.40888, 0.39784, 0.38736, 0.37792, 0.37, 0.36888, 0.36904, 0.36976, 0.37032,
+    0.37, 0.362, 0.3532, 0.3444, 0.3364, 0.33, 0.33144, 0.33472, 0.33928, 0.34456,
+    0.35, 0.3544, 0.358, 0.3604, 0.3612, 0.36, 0.35288, 0.34384, 0.33336, 0.32192,
+    0.31, 0.30064, 0.29112, 0.28128, 0.27096, 0.26, 0.2465333, 0.2325333, 0.2182667, 0.204,
+    0.19,
+];
+
+/// CIE fluorescent illuminant F5 (daylight).
+#[rustfmt::skip]
+pub const F5: [f32; LEN] = [
+    1.87, 1.966, 2.0608, 2.1556, 2.2516, 2.35, 2.46096, 2.57448, 2.68952, 2.80504,
+    2.92, 3.024853, 3.129193, 3.234106, 3.34068, 3.45, 3.569635, 3.69257, 3.818272, 3.946208,
+    4.075843, 4.204683, 4.355165, 6.306048, 26.20619, 45.86398, 18.53167, 5.702031, 5.140404, 5.272771,
+    5.41043, 5.548091, 5.686715, 5.826465, 5.967506, 6.11, 6.256379, 6.403972, 6.552376, 6.701186,
+    6.85, 6.99648, 7.14264, 7.28856, 7.43432, 7.58, 7.726755, 7.87332, 8.019509, 8.165132,
+    8.31, 8.453459, 8.595902, 8.737258, 8.877455, 40.2539, 167.6348, 9.336981, 9.425362, 9.55864,
+    9.690247, 9.819585, 9.947195, 10.07311, 10.19737, 10.32, 10.44311, 10.56414, 10.68263, 10.79807,
+    10.91, 11.01472, 11.11576, 11.21344, 11.30808, 11.4, 11.49176, 11.58088, 11.66712, 11.75024,
+    11.83, 11.90584, 11.97792, 12.04608, 12.11016, 12.17, 12.22416, 12.27408, 12.31992, 12.36184,
+    12.4, 12.43552, 12.46736, 12.49544, 12.51968, 12.54, 12.556, 12.568, 12.576, 12.58,
+    12.58, 12.57248, 12.56184, 12.54896, 12.53472, 12.52, 12.51656, 12.51168, 12.50352, 12.49024,
+    12.47, 12.42784, 12.37832, 12.32288, 12.26296, 12.2, 12.13896, 12.07688, 12.01432, 11.95184,
+    11.89, 11.83256, 11.77608, 11.72032, 11.66504, 11.61, 11.5524, 11.4952, 11.4388, 11.3836,
+    11.33, 11.27872, 11.22976, 11.18344, 11.14008, 11.1, 11.06288, 11.02984, 11.00136, 10.97792,
+    10.96, 10.94904, 10.94432, 10.94608, 10.95456, 10.97, 10.99328, 11.02384, 11.06176, 11.10712,
+    11.16, 11.22048, 11.28864, 11.36456, 11.44832, 11.54, 11.64113, 11.74997, 11.86625, 11.98969,
+    12.12, 12.25906, 12.40389, 12.55368, 12.70759, 13.59951, 99.62266, 20.54768, 13.34612, 13.50888,
+    13.67185, 13.83409, 13.9955, 14.1556, 14.31393, 14.47, 14.62439, 14.77532, 14.92206, 15.06386,
+    15.2, 15.32936, 15.45168, 15.56632, 15.67264, 15.77, 15.85789, 15.9355, 16.00217, 16.05723,
+    16.1, 16.1216, 16.13289, 16.134, 16.12502, 16.10605, 19.52081, 27.39357, 15.99027, 28.82754,
+    18.48629, 15.78836, 15.7024, 15.60728, 15.50312, 15.39, 15.25864, 15.11712, 14.96628, 14.80696,
+    14.64, 14.46768, 14.28904, 14.10456, 13.91472, 13.72, 13.52216, 13.32008, 13.11392, 12.90384,
+    12.69, 12.47032, 12.24776, 12.02304, 11.79688, 11.57, 11.34536, 11.12088, 10.89672, 10.67304,
+    10.45, 10.22776, 10.00648, 9.78632, 9.56744, 9.35, 9.1332, 8.9184, 8.706, 8.4964,
+    8.29, 8.08976, 7.89288, 7.69912, 7.50824, 7.32, 7.13096, 6.94488, 6.76232, 6.58384,
+    6.41, 6.24616, 6.08688, 5.93152, 5.77944, 5.63, 5.47872, 5.32976, 5.18344, 5.04008,
+    4.9, 4.76448, 4.63264, 4.50456, 4.38032, 4.26, 4.14496, 4.03368, 3.92592, 3.82144,
+    3.72, 3.62104, 3.52472, 3.43088, 3.33936, 3.25, 3.16104, 3.07432, 2.99008, 2.90856,
+    2.83, 2.75688, 2.68664, 2.61896, 2.55352, 2.49, 2.4268, 2.3652, 2.3052, 2.2468,
+    2.19, 2.1348, 2.0812, 2.0292, 1.9788, 1.93, 1.88312, 1.83776, 1.79384, 1.75128,
+    1.71, 1.66576, 1.62368, 1.58472, 1.54984, 1.52, 1.51056, 1.50448, 1.49912, 1.49184,
+    1.48, 1.44176, 1.39848, 1.35232, 1.30544, 1.26, 1.22808, 1.19944, 1.17376, 1.15072,
+    1.13, 1.11192, 1.09536, 1.07984, 1.06488, 1.05, 1.03312, 1.01576, 0.99784, 0.97928,
+    0.96, 0.93768, 0.91504, 0.89256, 0.87072, 0.85, 0.83376, 0.81888, 0.80512, 0.79224,
+    0.78, 0.76688, 0.75424, 0.74216, 0.73072, 0.72, 0.71008, 0.70104, 0.69296, 0.68592,
+    0.68, 0.67688, 0.67464, 0.67296, 0.67152, 0.67, 0.66712, 0.66376, 0.65984, 0.65528,
+    0.65, 0.64136, 0.63248, 0.62392, 0.61624, 0.61, 0.61088, 0.61304, 0.61576, 0.61832,
+    0.62, 0.614, 0.6072, 0.6004, 0.5944, 0.59, 0.59344, 0.59872, 0.60528, 0.61256,
+    0.62, 0.628, 0.6348, 0.6396, 0.6416, 0.64, 0.62696, 0.61048, 0.59152, 0.57104,
+    0.55, 0.5332, 0.5168, 0.5008, 0.4852, 0.47, 0.4557333, 0.4417333, 0.4278667, 0.414,
+    0.4,
+];
+
+/// CIE fluorescent illuminant F6 (lite white).
+#[rustfmt::skip]
+pub const F6: [f32; LEN] = [
+    1.05, 1.102, 1.1532, 1.2044, 1.2564, 1.31, 1.37272, 1.43696, 1.50184, 1.56648,
+    1.63, 1.684419, 1.737734, 1.790839, 1.844629, 1.9, 1.962976, 2.02804, 2.094805, 2.162885,
+    2.231891, 2.299973, 2.383731, 3.9419, 21.0556, 38.2958, 14.27895, 3.280764, 2.793275, 2.861869,
+    2.934023, 3.006327, 3.079038, 3.152195, 3.225836, 3.3, 3.375007, 3.450543, 3.526575, 3.603071,
+    3.68, 3.75784, 3.83592, 3.91408, 3.99216, 4.07, 4.147005, 4.22356, 4.299611, 4.375108,
+    4.45, 4.524097, 4.59752, 4.670253, 4.742279, 32.07341, 143.8372, 4.967626, 5.023171, 5.09136,
+    5.158642, 5.224735, 5.289898, 5.354151, 5.417512, 5.48, 5.542625, 5.604167, 5.664396, 5.723083,
+    5.78, 5.83336, 5.88488, 5.93472, 5.98304, 6.03, 6.07736, 6.12328, 6.16752, 6.20984,
+    6.25, 6.28648, 6.32064, 6.35256, 6.38232, 6.41, 6.436, 6.46, 6.482, 6.502,
+    6.52, 6.536, 6.55, 6.562, 6.572, 6.58, 6.58568, 6.58944, 6.59136, 6.59152,
+    6.59, 6.58496, 6.57888, 6.57232, 6.56584, 6.56, 6.56304, 6.56592, 6.56728, 6.56576,
+    6.56, 6.53872, 6.51296, 6.48384, 6.45248, 6.42, 6.39008, 6.36064, 6.33216, 6.30512,
+    6.28, 6.25888, 6.24024, 6.22416, 6.21072, 6.2, 6.1908, 6.1848, 6.1824, 6.184,
+    6.19, 6.20016, 6.21568, 6.23712, 6.26504, 6.3, 6.34384, 6.39552, 6.45528, 6.52336,
+    6.6, 6.68384, 6.77712, 6.88048, 6.99456, 7.12, 7.25968, 7.41144, 7.57536, 7.75152,
+    7.94, 8.14184, 8.35592, 8.58208, 8.82016, 9.07, 9.332072, 9.605426, 9.889744, 10.18471,
+    10.49, 10.80943, 11.13752, 11.47292, 11.81428, 12.66465, 88.62249, 19.16714, 13.20888, 13.56064,
+    13.91198, 14.26129, 14.60851, 14.95287, 15.29362, 15.63, 15.96311, 16.28987, 16.60909, 16.91954,
+    17.22, 17.50856, 17.78488, 18.04792, 18.29664, 18.53, 18.74593, 18.94468, 19.12547, 19.2875,
+    19.43, 19.54176, 19.63669, 19.71496, 19.77671, 19.8221, 22.87378, 29.97003, 19.86164, 31.1439,
+    22.12984, 19.75947, 19.69464, 19.61468, 19.51975, 19.41, 19.27093, 19.1151, 18.94382, 18.75835,
+    18.56, 18.352, 18.1332, 17.9044, 17.6664, 17.42, 17.16696, 16.90688, 16.64032, 16.36784,
+    16.09, 15.80704, 15.51992, 15.22928, 14.93576, 14.64, 14.34328, 14.04544, 13.74696, 13.44832,
+    13.15, 12.85376, 12.55848, 12.26432, 11.97144, 11.68, 11.38792, 11.09816, 10.81144, 10.52848,
+    10.25, 9.98088, 9.71664, 9.45696, 9.20152, 8.95, 8.69856, 8.45128, 8.20872, 7.97144,
+    7.74, 7.52008, 7.30584, 7.09656, 6.89152, 6.69, 6.48616, 6.28568, 6.08912, 5.89704,
+    5.71, 5.53112, 5.35776, 5.18984, 5.02728, 4.87, 4.71856, 4.57208, 4.43032, 4.29304,
+    4.16, 4.03064, 3.90512, 3.78328, 3.66496, 3.55, 3.4376, 3.3284, 3.2224, 3.1196,
+    3.02, 2.9236, 2.8304, 2.7404, 2.6536, 2.57, 2.49088, 2.41464, 2.34096, 2.26952,
+    2.2, 2.13016, 2.06208, 1.99592, 1.93184, 1.87, 1.81184, 1.75592, 1.70208, 1.65016,
+    1.6, 1.54728, 1.49704, 1.45016, 1.40752, 1.37, 1.35192, 1.33736, 1.32384, 1.30888,
+    1.29, 1.24648, 1.19864, 1.14856, 1.09832, 1.05, 1.01592, 0.98536, 0.95784, 0.93288,
+    0.91, 0.88808, 0.86744, 0.84776, 0.82872, 0.81, 0.79, 0.77, 0.75, 0.73,
+    0.71, 0.68904, 0.66832, 0.64808, 0.62856, 0.61, 0.59424, 0.57952, 0.56568, 0.55256,
+    0.54, 0.52688, 0.51424, 0.50216, 0.49072, 0.48, 0.47008, 0.46104, 0.45296, 0.44592,
+    0.44, 0.4372, 0.4352, 0.4336, 0.432, 0.43, 0.42496, 0.41928, 0.41312, 0.40664,
+    0.4, 0.39272, 0.38576, 0.37944, 0.37408, 0.37, 0.37136, 0.37368, 0.37632, 0.37864,
+    0.38, 0.37368, 0.36664, 0.35976, 0.35392, 0.35, 0.35528, 0.36264, 0.37136, 0.38072,
+    0.39, 0.39816, 0.40488, 0.40952, 0.41144, 0.41, 0.39848, 0.38384, 0.36696, 0.34872,
+    0.33, 0.31488, 0.30024, 0.28616, 0.27272, 0.26, 0.2494667, 0.2394667, 0.2297333, 0.22,
+    0.21,
+];
+
+/// CIE fluorescent illuminant F7 (broadband daylight).
+#[rustfmt::skip]
+pub const F7: [f32; LEN] = [
+    2.56, 2.684, 2.807467, 2.930933, 3.054933, 3.18, 3.30912, 3.43976, 3.57184, 3.70528,
+    3.84, 3.978724, 4.117867, 4.256648, 4.394286, 4.53, 4.657702, 4.783245, 4.907175, 5.030039,
+    5.152384, 5.27784, 5.515609, 8.390235, 24.51156, 40.52991, 24.262, 8.738266, 6.257515, 6.27974,
+    6.405711, 6.533394, 6.661651, 6.790496, 6.919941, 7.05, 7.179966, 7.310755, 7.44256, 7.575577,
+    7.71, 7.8468, 7.9852, 8.1252, 8.2668, 8.41, 8.555748, 8.702859, 8.851096, 9.000222,
+    9.15, 9.299896, 9.450044, 9.600281, 9.750444, 43.42444, 181.2815, 10.24037, 10.34714, 10.49448,
+    10.64074, 10.78598, 10.92975, 11.07183, 11.21199, 11.35, 11.48508, 11.61771, 11.7478, 11.87526,
+    12.0, 12.12192, 12.24096, 12.35704, 12.47008, 12.58, 12.688, 12.7924, 12.8928, 12.9888,
+    13.08, 13.16376, 13.24248, 13.31632, 13.38544, 13.45, 13.51016, 13.56608, 13.61792, 13.66584,
+    13.71, 13.75152, 13.78936, 13.82344, 13.85368, 13.88, 13.90168, 13.91944, 13.93336, 13.94352,
+    13.95, 13.9532, 13.9528, 13.9488, 13.9412, 13.93, 13.91456, 13.89568, 13.87352, 13.84824,
+    13.82, 13.78832, 13.75416, 13.71784, 13.67968, 13.64, 13.59848, 13.55624, 13.51376, 13.47152,
+    13.43, 13.39224, 13.35552, 13.31968, 13.28456, 13.25, 13.21488, 13.18024, 13.14616, 13.11272,
+    13.08, 13.04904, 13.01872, 12.98888, 12.95936, 12.93, 12.90096, 12.87168, 12.84192, 12.81144,
+    12.78, 12.7448, 12.7088, 12.6724, 12.636, 12.6, 12.56544, 12.53192, 12.49968, 12.46896,
+    12.44, 12.41432, 12.39056, 12.36864, 12.34848, 12.33, 12.31128, 12.29454, 12.28016, 12.26852,
+    12.26, 12.25782, 12.25882, 12.26265, 12.269, 13.33581, 112.0878, 21.02764, 12.31101, 12.32544,
+    12.34136, 12.35809, 12.37627, 12.39595, 12.41717, 12.44, 12.46601, 12.49335, 12.52168, 12.55068,
+    12.58, 12.60896, 12.63768, 12.66592, 12.69344, 12.72, 12.74518, 12.76896, 12.79116, 12.81157,
+    12.83, 12.8449, 12.85799, 12.8692, 12.87845, 12.88568, 16.53086, 24.91842, 12.89443, 26.65069,
+    15.65956, 12.88226, 12.8732, 12.86152, 12.84715, 12.83, 12.80478, 12.77584, 12.74351, 12.70812,
+    12.67, 12.63016, 12.58808, 12.54392, 12.49784, 12.45, 12.4012, 12.3508, 12.2988, 12.2452,
+    12.19, 12.1316, 12.072, 12.0116, 11.9508, 11.89, 11.83024, 11.77112, 11.71288, 11.65576,
+    11.6, 11.54744, 11.49632, 11.44648, 11.39776, 11.35, 11.30112, 11.25336, 11.20704, 11.16248,
+    11.12, 11.08376, 11.04928, 11.01592, 10.98304, 10.95, 10.91776, 10.88368, 10.84672, 10.80584,
+    10.76, 10.69824, 10.63192, 10.56248, 10.49136, 10.42, 10.34888, 10.28064, 10.21696, 10.15952,
+    10.11, 10.08288, 10.06384, 10.05136, 10.04392, 10.04, 10.03008, 10.02264, 10.01816, 10.01712,
+    10.02, 10.04328, 10.06744, 10.08896, 10.10432, 10.11, 10.1092, 10.09, 10.0472, 9.9756,
+    9.87, 9.67816, 9.45368, 9.20312, 8.93304, 8.65, 8.36408, 8.07744, 7.79576, 7.52472,
+    7.27, 7.07056, 6.89048, 6.72712, 6.57784, 6.44, 6.30136, 6.17128, 6.04952, 5.93584,
+    5.83, 5.73528, 5.64704, 5.56416, 5.48552, 5.41, 5.3368, 5.2644, 5.1916, 5.1172,
+    5.04, 4.95016, 4.85728, 4.76232, 4.66624, 4.57, 4.47584, 4.38312, 4.29248, 4.20456,
+    4.12, 4.04392, 3.97136, 3.90184, 3.83488, 3.77, 3.70832, 3.64736, 3.58624, 3.52408,
+    3.46, 3.3864, 3.3108, 3.234, 3.1568, 3.08, 3.00568, 2.93304, 2.86256, 2.79472,
+    2.73, 2.6724, 2.618, 2.5664, 2.5172, 2.47, 2.42312, 2.37776, 2.33384, 2.29128,
+    2.25, 2.2096, 2.1704, 2.1324, 2.0956, 2.06, 2.02624, 1.99352, 1.96168, 1.93056,
+    1.9, 1.86888, 1.83824, 1.80816, 1.77872, 1.75, 1.72144, 1.69392, 1.66768, 1.64296,
+    1.62, 1.60192, 1.58536, 1.56984, 1.55488, 1.54, 1.52376, 1.50688, 1.48912, 1.47024,
+    1.45, 1.42656, 1.40168, 1.37552, 1.34824, 1.32, 1.29192, 1.26296, 1.23304, 1.20208,
+    1.17, 1.13544, 1.09992, 1.06368, 1.02696, 0.99, 0.954, 0.918, 0.882, 0.846,
+    0.81,
+];
+
+/// CIE fluorescent illuminant F8 (broadband D50 simulator).
+#[rustfmt::skip]
+pub const F8: [f32; LEN] = [
+    1.21, 1.268, 1.325733, 1.383467, 1.441467, 1.5, 1.56072, 1.62216, 1.68424, 1.74688,
+    1.81, 1.874777, 1.93956, 2.003954, 2.067565, 2.13, 2.187322, 2.243564, 2.299217, 2.354773,
+    2.410726, 2.470143, 2.627436, 4.840083, 16.82651, 28.84431, 17.18811, 5.17203, 3.016918, 2.983812,
+    3.05464, 3.130634, 3.208487, 3.287877, 3.368487, 3.45, 3.526497, 3.604663, 3.68558, 3.770332,
+    3.86, 3.96128, 4.06824, 4.18056, 4.29792, 4.42, 4.544362, 4.673334, 4.807125, 4.945944,
+    5.09, 5.242482, 5.399876, 5.561645, 5.727256, 34.57423, 146.0008, 6.249311, 6.415444, 6.59272,
+    6.771605, 6.95133, 7.132203, 7.314053, 7.496709, 7.68, 7.865363, 8.050617, 8.23519, 8.418509,
+    8.6, 8.77744, 8.95232, 9.12448, 9.29376, 9.46, 9.6256, 9.7872, 9.944, 10.0952,
+    10.24, 10.37216, 10.49768, 10.61712, 10.73104, 10.84, 10.9468, 11.0492, 11.1472, 11.2408,
+    11.33, 11.4148, 11.4952, 11.5712, 11.6428, 11.71, 11.77184, 11.82952, 11.88328, 11.93336,
+    11.98, 12.0244, 12.0656, 12.1036, 12.1384, 12.17, 12.19808, 12.22304, 12.24496, 12.26392,
+    12.28, 12.29168, 12.30104, 12.30856, 12.31472, 12.32, 12.32456, 12.32928, 12.33472, 12.34144,
+    12.35, 12.36448, 12.38104, 12.39936, 12.41912, 12.44, 12.4604, 12.4816, 12.5036, 12.5264,
+    12.55, 12.57632, 12.60296, 12.62944, 12.65528, 12.68, 12.7044, 12.7264, 12.7452, 12.76,
+    12.77, 12.76896, 12.76288, 12.75232, 12.73784, 12.72, 12.70096, 12.67928, 12.65512, 12.62864,
+    12.6, 12.56968, 12.53744, 12.50336, 12.46752, 12.43, 12.38868, 12.3464, 12.30377, 12.26143,
+    12.22, 12.18314, 12.1477, 12.11355, 12.08057, 13.02189, 109.9948, 20.48015, 11.95588, 11.92736,
+    11.90025, 11.8738, 11.84921, 11.82683, 11.80698, 11.79, 11.77887, 11.77062, 11.76495, 11.76151,
+    11.76, 11.75816, 11.75808, 11.75992, 11.76384, 11.77, 11.77904, 11.79052, 11.80448, 11.82096,
+    11.84, 11.86343, 11.88874, 11.91584, 11.94461, 11.97494, 15.73788, 24.31607, 12.0742, 26.25029,
+    15.00188, 12.18357, 12.22176, 12.26064, 12.30008, 12.34, 12.37818, 12.41624, 12.45421, 12.49212,
+    12.53, 12.56768, 12.60544, 12.64336, 12.68152, 12.72, 12.75952, 12.79936, 12.83944, 12.87968,
+    12.92, 12.95936, 12.99888, 13.03872, 13.07904, 13.12, 13.16144, 13.20392, 13.24768, 13.29296,
+    13.34, 13.39192, 13.44536, 13.49984, 13.55488, 13.61, 13.6644, 13.718, 13.7704, 13.8212,
+    13.87, 13.91512, 13.95776, 13.99784, 14.03528, 14.07, 14.1048, 14.136, 14.1628, 14.1844,
+    14.2, 14.19984, 14.19432, 14.18488, 14.17296, 14.16, 14.14584, 14.13392, 14.12608, 14.12416,
+    14.13, 14.16208, 14.20144, 14.24576, 14.29272, 14.34, 14.3808, 14.4184, 14.4516, 14.4792,
+    14.5, 14.51504, 14.52032, 14.51408, 14.49456, 14.46, 14.41888, 14.35664, 14.26896, 14.15152,
+    14.0, 13.76752, 13.50296, 13.21264, 12.90288, 12.58, 12.2516, 11.9224, 11.5984, 11.2856,
+    10.99, 10.75216, 10.53488, 10.33552, 10.15144, 9.98, 9.81088, 9.65104, 9.49976, 9.35632,
+    9.22, 9.09072, 8.96696, 8.84784, 8.73248, 8.62, 8.51176, 8.40408, 8.29552, 8.18464,
+    8.07, 7.94024, 7.80632, 7.66928, 7.53016, 7.39, 7.24984, 7.11072, 6.97368, 6.83976,
+    6.71, 6.59312, 6.48056, 6.37144, 6.26488, 6.16, 6.05528, 5.95064, 5.84536, 5.73872,
+    5.63, 5.5124, 5.3928, 5.272, 5.1508, 5.03, 4.9104, 4.7928, 4.678, 4.5668,
+    4.46, 4.3632, 4.2712, 4.1836, 4.1, 4.02, 3.94224, 3.86752, 3.79568, 3.72656,
+    3.66, 3.59616, 3.53448, 3.47472, 3.41664, 3.36, 3.3036, 3.2484, 3.1944, 3.1416,
+    3.09, 3.03928, 2.98984, 2.94176, 2.89512, 2.85, 2.80616, 2.76408, 2.72392, 2.68584,
+    2.65, 2.61912, 2.59016, 2.56264, 2.53608, 2.51, 2.48456, 2.45848, 2.43112, 2.40184,
+    2.37, 2.33112, 2.28936, 2.24504, 2.19848, 2.15, 2.10056, 2.04968, 1.99752, 1.94424,
+    1.89, 1.83528, 1.77984, 1.72376, 1.66712, 1.61, 1.552267, 1.494267, 1.436133, 1.378,
+    1.32,
+];
+
+/// CIE fluorescent illuminant F9 (broadband cool white).
+#[rustfmt::skip]
+pub const F9: [f32; LEN] = [
+    0.9, 0.944, 0.9877333, 1.031467, 1.075467, 1.12, 1.16704, 1.21472, 1.26288, 1.31136,
+    1.36, 1.409205, 1.458108, 1.506409, 1.553807, 1.6, 1.641775, 1.68247, 1.722514, 1.762333,
+    1.802356, 1.845231, 1.980984, 4.212776, 16.81342, 29.44496, 16.81755, 4.370051, 2.257012, 2.21661,
+    2.268297, 2.324266, 2.381706, 2.440332, 2.499858, 2.56, 2.615122, 2.671629, 2.730575, 2.793015,
+    2.86, 2.93872, 3.02256, 3.11104, 3.20368, 3.3, 3.396755, 3.49692, 3.600709, 3.708332,
+    3.82, 3.938374, 4.060604, 4.186289, 4.315028, 32.40289, 145.0297, 4.728268, 4.851095, 4.98944,
+    5.129136, 5.269345, 5.410608, 5.552853, 5.696008, 5.84, 5.986681, 6.133575, 6.280128, 6.425788,
+    6.57, 6.71064, 6.84912, 6.98528, 7.11896, 7.25, 7.3792, 7.5052, 7.6276, 7.746,
+    7.86, 7.96664, 8.06872, 8.16648, 8.26016, 8.35, 8.4372, 8.5208, 8.6008, 8.6772,
+    8.75, 8.81824, 8.88312, 8.94488, 9.00376, 9.06, 9.11544, 9.16832, 9.21848, 9.26576,
+    9.31, 9.34912, 9.38536, 9.41904, 9.45048, 9.48, 9.50984, 9.53792, 9.56408, 9.58816,
+    9.61, 9.6272, 9.6424, 9.656, 9.6684, 9.68, 9.68992, 9.70016, 9.71144, 9.72448,
+    9.74, 9.76352, 9.78976, 9.81824, 9.84848, 9.88, 9.90912, 9.93936, 9.97104, 10.00448,
+    10.04, 10.08112, 10.12416, 10.16864, 10.21408, 10.26, 10.30624, 10.35192, 10.39648, 10.43936,
+    10.48, 10.51336, 10.54448, 10.57392, 10.60224, 10.63, 10.65904, 10.68832, 10.71808, 10.74856,
+    10.78, 10.81328, 10.84784, 10.88376, 10.92112, 10.96, 10.9989, 11.03987, 11.0834, 11.12995,
+    11.18, 11.23748, 11.29855, 11.36282, 11.42989, 12.40724, 105.5503, 19.76026, 11.71603, 11.79192,
+    11.86951, 11.94808, 12.02835, 12.11037, 12.19423, 12.28, 12.3693, 12.46027, 12.55259, 12.64594,
+    12.74, 12.83448, 12.92904, 13.02336, 13.11712, 13.21, 13.30166, 13.39181, 13.48013, 13.5663,
+    13.65, 13.72872, 13.80523, 13.87944, 13.95123, 14.02049, 17.69491, 26.06735, 14.21202, 27.83907,
+    17.06094, 14.37686, 14.42536, 14.47046, 14.51204, 14.55, 14.57888, 14.60304, 14.62276, 14.63832,
+    14.65, 14.65824, 14.66312, 14.66488, 14.66376, 14.66, 14.6548, 14.6472, 14.6372, 14.6248,
+    14.61, 14.59088, 14.56984, 14.54736, 14.52392, 14.5, 14.47416, 14.44928, 14.42632, 14.40624,
+    14.39, 14.38432, 14.38296, 14.38544, 14.39128, 14.4, 14.40856, 14.41968, 14.43352, 14.45024,
+    14.47, 14.49776, 14.52768, 14.55872, 14.58984, 14.62, 14.65104, 14.67832, 14.70008, 14.71456,
+    14.72, 14.69832, 14.66816, 14.63184, 14.59168, 14.55, 14.50848, 14.47024, 14.43776, 14.41352,
+    14.4, 14.41632, 14.44416, 14.48184, 14.52768, 14.58, 14.62368, 14.67384, 14.73216, 14.80032,
+    14.88, 15.0116, 15.1484, 15.2824, 15.4056, 15.51, 15.60552, 15.66176, 15.66624, 15.60648,
+    15.47, 15.13456, 14.72488, 14.25592, 13.74264, 13.2, 12.6516, 12.1016, 11.5628, 11.048,
+    10.57, 10.21776, 9.90888, 9.63712, 9.39624, 9.18, 8.96392, 8.76456, 8.58024, 8.40928,
+    8.25, 8.09752, 7.95416, 7.81904, 7.69128, 7.57, 7.45976, 7.35288, 7.24712, 7.14024,
+    7.03, 6.89912, 6.76416, 6.62664, 6.48808, 6.35, 6.21648, 6.08584, 5.95896, 5.83672,
+    5.72, 5.61768, 5.52064, 5.42776, 5.33792, 5.25, 5.16096, 5.07208, 4.98272, 4.89224,
+    4.8, 4.70024, 4.59872, 4.49608, 4.39296, 4.29, 4.1872, 4.086, 3.9872, 3.8916,
+    3.8, 3.71832, 3.64096, 3.56744, 3.49728, 3.43, 3.36352, 3.29936, 3.23744, 3.17768,
+    3.12, 3.06432, 3.01056, 2.95864, 2.90848, 2.86, 2.81376, 2.76888, 2.72512, 2.68224,
+    2.64, 2.59624, 2.55312, 2.51088, 2.46976, 2.43, 2.39248, 2.35664, 2.32256, 2.29032,
+    2.26, 2.2336, 2.2088, 2.1852, 2.1624, 2.14, 2.11824, 2.09592, 2.07248, 2.04736,
+    2.02, 1.98632, 1.95016, 1.91184, 1.87168, 1.83, 1.78776, 1.74448, 1.70032, 1.65544,
+    1.61, 1.56544, 1.52032, 1.47448, 1.42776, 1.38, 1.3288, 1.2768, 1.2244, 1.172,
+    1.12,
+];
+
+/// CIE fluorescent illuminant F10 (three-band 5000 K).
+#[rustfmt::skip]
+pub const F10: [f32; LEN] = [
+    1.11, 1.048, 0.9842667, 0.9205333, 0.8585333, 0.8, 0.7536, 0.7124, 0.6764, 0.6456,
+    0.62, 0.5943782, 0.5752619, 0.5639565, 0.5617674, 0.57, 0.5985454, 0.637977, 0.687454, 0.7461353,
+    0.81318, 0.884152, 0.9628616, 1.405241, 17.0995, 37.89824, 7.503944, 1.509145, 1.596446, 1.729676,
+    1.870952, 2.02029, 2.177797, 2.343539, 2.517584, 2.7, 2.886354, 3.082339, 3.289147, 3.50797,
+    3.74, 3.996, 4.2652, 4.5464, 4.8384, 5.14, 5.45749, 5.780308, 6.105381, 6.429636,
+    6.75, 7.051159, 7.345342, 7.632539, 7.912738, 30.9171, 143.5528, 8.738146, 8.971606, 9.21456,
+    9.446296, 9.670756, 9.880652, 10.07369, 10.24757, 10.4, 10.5149, 10.6072, 10.67805, 10.7286,
+    10.76, 10.77864, 10.77912, 10.76128, 10.72496, 10.67, 10.58952, 10.49176, 10.37824, 10.25048,
+    10.11, 9.95992, 9.79976, 9.63064, 9.45368, 9.27, 9.08136, 8.88808, 8.69112, 8.49144,
+    8.29, 8.03912, 7.80056, 7.58744, 7.41288, 7.29, 7.07672, 6.98016, 7.05224, 7.34488,
+    7.91, 9.5432, 11.3668, 13.2468, 15.0492, 16.64, 17.27688, 17.58624, 17.58616, 17.29472,
+    16.73, 15.72096, 14.52208, 13.19872, 11.81624, 10.44, 9.39328, 8.41904, 7.51816, 6.69152,
+    5.94, 5.27728, 4.68824, 4.17056, 3.72192, 3.34, 3.04808, 2.81184, 2.62256, 2.47152,
+    2.35, 2.22528, 2.11864, 2.02736, 1.94872, 1.88, 1.80792, 1.74296, 1.68504, 1.63408,
+    1.59, 1.54344, 1.50592, 1.47968, 1.46696, 1.47, 1.486439, 1.524269, 1.586891, 1.677755,
+    1.800588, 1.996157, 2.230063, 2.521774, 2.943638, 3.696251, 5.235668, 8.38194, 14.30396, 24.12167,
+    38.07537, 54.58885, 70.10629, 80.14191, 81.49502, 74.22431, 107.0535, 53.39648, 41.11681, 36.20327,
+    32.09637, 26.38658, 19.24579, 12.63558, 8.075153, 5.643494, 4.559542, 4.086294, 3.814329, 3.591956,
+    3.380234, 3.174588, 2.977024, 2.791195, 2.620911, 2.47, 2.342361, 2.241731, 2.171922, 2.136741,
+    2.14, 2.178586, 2.264961, 2.404666, 2.603241, 2.866226, 5.280458, 10.46437, 4.195405, 26.20052,
+    9.731837, 5.971755, 6.639498, 10.37356, 31.26354, 18.52766, 9.755359, 10.46378, 11.20516, 11.78841,
+    12.16, 11.81653, 11.26684, 10.56989, 9.784621, 8.97, 8.320258, 7.725251, 7.210115, 6.799986,
+    6.52, 6.612162, 6.83052, 7.145993, 7.529497, 7.951953, 8.281055, 8.616749, 8.95576, 18.25772,
+    137.2218, 17.4283, 10.27944, 25.53531, 140.6632, 14.02345, 11.39093, 11.61, 11.80119, 11.96202,
+    12.09, 12.26336, 12.37871, 12.41338, 12.3447, 12.15, 11.60995, 10.9477, 10.18975, 9.589727,
+    18.26821, 7.876378, 6.804948, 5.976498, 5.179547, 4.43, 3.770989, 3.184386, 2.679288, 2.264793,
+    1.95, 1.84312, 1.82936, 1.89304, 2.01848, 2.19, 2.39896, 2.62088, 2.83832, 3.03384,
+    3.19, 3.17608, 3.11624, 3.02136, 2.90232, 2.77, 2.6708, 2.5712, 2.4732, 2.3788,
+    2.29, 2.22896, 2.17248, 2.11752, 2.06104, 2.0, 1.9032, 1.8028, 1.7028, 1.6072,
+    1.52, 1.46184, 1.41592, 1.38208, 1.36016, 1.35, 1.35368, 1.36824, 1.39296, 1.42712,
+    1.47, 1.53624, 1.60592, 1.67448, 1.73736, 1.79, 1.8192, 1.8312, 1.8236, 1.794,
+    1.74, 1.60128, 1.44784, 1.29176, 1.14512, 1.02, 0.93776, 0.89888, 0.91312, 0.99024,
+    1.14, 1.50944, 1.93672, 2.39728, 2.86656, 3.32, 3.718, 4.0548, 4.3096, 4.4616,
+    4.49, 4.14712, 3.69576, 3.17184, 2.61128, 2.05, 1.65384, 1.29632, 0.98088, 0.71096,
+    0.49, 0.37008, 0.29384, 0.25256, 0.23752, 0.24, 0.22248, 0.21224, 0.20776, 0.20752,
+    0.21, 0.2076, 0.2064, 0.2064, 0.2076, 0.21, 0.21552, 0.22176, 0.22824, 0.23448,
+    0.24, 0.2424, 0.2436, 0.2436, 0.2424, 0.24, 0.23576, 0.23048, 0.22432, 0.21744,
+    0.21, 0.19992, 0.19016, 0.18144, 0.17448, 0.17, 0.17512, 0.18256, 0.19144, 0.20088,
+    0.21, 0.21536, 0.21928, 0.22152, 0.22184, 0.22, 0.21288, 0.20384, 0.19336, 0.18192,
+    0.17, 0.15936, 0.14888, 0.13872, 0.12904, 0.12, 0.1134667, 0.1074667, 0.1017333, 0.096,
+    0.09,
+];
+
+/// CIE fluorescent illuminant F11 (three-band 4000 K (TL84)).
+#[rustfmt::skip]
+pub const F11: [f32; LEN] = [
+    0.91, 0.854, 0.7965333, 0.7390667, 0.6830667, 0.63, 0.58816, 0.55048, 0.51672, 0.48664,
+    0.46, 0.4305212, 0.4055122, 0.3862425, 0.3739819, 0.37, 0.3839103, 0.4065528, 0.4371114, 0.4747696,
+    0.5187114, 0.5648152, 0.6181436, 1.388914, 17.66405, 38.48792, 10.38485, 1.15758, 1.041693, 1.131681,
+    1.227446, 1.329836, 1.437541, 1.550306, 1.667877, 1.79, 1.912443, 2.039923, 2.173183, 2.312962,
+    2.46, 2.61512, 2.77896, 2.95224, 3.13568, 3.33, 3.554235, 3.786211, 4.02207, 4.257953,
+    4.49, 4.69652, 4.895947, 5.088878, 5.275914, 31.38841, 150.8507, 5.833137, 5.987699, 6.1516,
+    6.307901, 6.459179, 6.60058, 6.730551, 6.847542, 6.95, 7.027221, 7.089094, 7.136358, 7.169747,
+    7.19, 7.20144, 7.20032, 7.18648, 7.15976, 7.12, 7.06192, 6.99176, 6.91064, 6.81968,
+    6.72, 6.61368, 6.50064, 6.38176, 6.25792, 6.13, 5.99984, 5.86712, 5.73248, 5.59656,
+    5.46, 5.27672, 5.10576, 4.95944, 4.85008, 4.79, 4.64176, 4.60488, 4.72912, 5.06424,
+    5.66, 7.26824, 9.06112, 10.91288, 12.69776, 14.29, 15.0192, 15.4404, 15.564, 15.4004,
+    14.96, 14.026, 12.8928, 11.6276, 10.2976, 8.97, 7.97696, 7.05448, 6.20352, 5.42504,
+    4.72, 4.10376, 3.55928, 3.08392, 2.67504, 2.33, 2.06888, 1.86064, 1.69696, 1.56952,
+    1.47, 1.36736, 1.28168, 1.21032, 1.15064, 1.1, 1.04552, 0.99736, 0.95544, 0.91968,
+    0.89, 0.85768, 0.83344, 0.81936, 0.81752, 0.83, 0.8537785, 0.8973134, 0.9639668, 1.057139,
+    1.180449, 1.373045, 1.601024, 1.880994, 2.277979, 2.978283, 4.41797, 7.401042, 13.10557, 22.70283,
+    36.51406, 53.0208, 68.6414, 78.78199, 80.13033, 72.72477, 109.3172, 52.09142, 39.51089, 34.8499,
+    30.99092, 25.43007, 18.35869, 11.80585, 7.317934, 4.956282, 3.924594, 3.483599, 3.232816, 3.027163,
+    2.830195, 2.6343, 2.445312, 2.267197, 2.104056, 1.96, 1.833541, 1.735796, 1.672281, 1.648511,
+    1.67, 1.748486, 1.881705, 2.073617, 2.328181, 2.649355, 4.742987, 9.110672, 4.1389, 26.02042,
+    9.746927, 6.077657, 6.79325, 9.853207, 31.22349, 17.8528, 10.04647, 10.8874, 11.67916, 12.31129,
+    12.73, 12.43408, 11.92903, 11.27296, 10.52392, 9.74, 9.10219, 8.514933, 8.00558, 7.601485,
+    7.33, 7.449637, 7.6988, 8.04705, 8.46395, 8.919063, 9.27358, 9.632528, 9.99256, 28.87144,
+    177.2063, 11.04572, 11.37664, 32.72196, 179.563, 17.44358, 12.50657, 12.72291, 12.90884, 13.06199,
+    13.18, 13.34184, 13.44346, 13.46217, 13.37525, 13.16, 12.59676, 11.90902, 11.12333, 10.577,
+    23.33953, 8.807677, 7.623888, 6.759659, 5.917235, 5.11, 4.337797, 3.630929, 3.006163, 2.480264,
+    2.07, 1.93408, 1.91184, 1.98456, 2.13352, 2.34, 2.59936, 2.87528, 3.14552, 3.38784,
+    3.58, 3.5516, 3.4656, 3.3368, 3.18, 3.01, 2.896, 2.7848, 2.6776, 2.5756,
+    2.48, 2.4112, 2.3464, 2.282, 2.2144, 2.14, 2.02, 1.8948, 1.7696, 1.6496,
+    1.54, 1.4684, 1.412, 1.3704, 1.3432, 1.33, 1.32848, 1.34064, 1.36656, 1.40632,
+    1.46, 1.55264, 1.65312, 1.75528, 1.85296, 1.94, 1.99968, 2.03904, 2.05456, 2.04272,
+    2.0, 1.85088, 1.68184, 1.50736, 1.34192, 1.2, 1.1012, 1.0536, 1.0704, 1.1648,
+    1.35, 1.81584, 2.35472, 2.93568, 3.52776, 4.1, 4.60256, 5.02808, 5.35032, 5.54304,
+    5.58, 5.14824, 4.57992, 3.92048, 3.21536, 2.51, 2.01528, 1.56984, 1.17776, 0.84312,
+    0.57, 0.42296, 0.33048, 0.28152, 0.26504, 0.27, 0.24888, 0.23624, 0.23016, 0.22872,
+    0.23, 0.22344, 0.21792, 0.21368, 0.21096, 0.21, 0.21456, 0.22048, 0.22712, 0.23384,
+    0.24, 0.24272, 0.24416, 0.24424, 0.24288, 0.24, 0.23136, 0.22208, 0.21312, 0.20544,
+    0.2, 0.20288, 0.20864, 0.21696, 0.22752, 0.24, 0.25856, 0.27728, 0.29472, 0.30944,
+    0.32, 0.316, 0.3072, 0.2944, 0.2784, 0.26, 0.24, 0.2192, 0.1984, 0.1784,
+    0.16, 0.1488, 0.1396, 0.132, 0.1256, 0.12, 0.1137333, 0.1077333, 0.1018667, 0.096,
+    0.09,
+];
+
+/// CIE fluorescent illuminant F12 (three-band 3000 K).
+#[rustfmt::skip]
+pub const F12: [f32; LEN] = [
+    0.96, 0.896, 0.8309333, 0.7658667, 0.7018667, 0.64, 0.58272, 0.52936, 0.48064, 0.43728,
+    0.4, 0.3740679, 0.3545189, 0.3409359, 0.3329019, 0.33, 0.3313728, 0.3371538, 0.3470361, 0.3607129,
+    0.3778771, 0.3974649, 0.425456, 1.520305, 18.61089, 37.06736, 10.50707, 0.8708013, 0.6144431, 0.6545905,
+    0.697528, 0.7468912, 0.7970415, 0.8467574, 0.8948174, 0.94, 0.9681187, 0.9941582, 1.020138, 1.048079,
+    1.08, 1.12696, 1.17968, 1.23792, 1.30144, 1.37, 1.448512, 1.530295, 1.613823, 1.697567,
+    1.78, 1.85236, 1.92216, 1.989682, 2.055206, 27.17788, 138.8344, 2.258735, 2.307688, 2.36624,
+    2.422099, 2.476661, 2.527474, 2.573796, 2.614885, 2.65, 2.673899, 2.691466, 2.703082, 2.709133,
+    2.71, 2.70696, 2.69928, 2.68712, 2.67064, 2.65, 2.6228, 2.5924, 2.5596, 2.5252,
+    2.49, 2.46024, 2.42992, 2.39848, 2.36536, 2.33, 2.28608, 2.24024, 2.19336, 2.14632,
+    2.1, 2.0188, 1.9492, 1.9012, 1.8848, 1.91, 1.85304, 1.89112, 2.06768, 2.42616,
+    3.01, 4.46808, 6.08704, 7.75896, 9.37592, 10.83, 11.55856, 12.02208, 12.22632, 12.17704,
+    11.88, 11.1208, 10.1804, 9.1196, 7.9992, 6.88, 6.06728, 5.31624, 4.62656, 3.99792,
+    3.43, 2.92568, 2.48064, 2.09376, 1.76392, 1.49, 1.29872, 1.15416, 1.04824, 0.97288,
+    0.92, 0.85752, 0.80736, 0.76744, 0.73568, 0.71, 0.67872, 0.65176, 0.62944, 0.61208,
+    0.6, 0.5852, 0.5784, 0.582, 0.5984, 0.63, 0.6823424, 0.7539007, 0.846299, 0.9612114,
+    1.100578, 1.289627, 1.508763, 1.776753, 2.158927, 2.831348, 4.18968, 6.923169, 12.00255, 20.33625,
+    32.08374, 45.89979, 58.8308, 67.19116, 68.37708, 62.46279, 110.0808, 47.02964, 35.07335, 30.76691,
+    27.16675, 22.37899, 16.4821, 10.97718, 7.095887, 4.968086, 4.004605, 3.597039, 3.385528, 3.226781,
+    3.080324, 2.940757, 2.807502, 2.682912, 2.569549, 2.47, 2.359405, 2.274658, 2.225209, 2.220507,
+    2.27, 2.419886, 2.633678, 2.911637, 3.254025, 3.661104, 5.332879, 8.614227, 5.32811, 25.86175,
+    10.74407, 7.401095, 8.151873, 10.60807, 29.62932, 17.70185, 11.4693, 12.34942, 13.15513, 13.81176,
+    14.27, 14.09066, 13.71181, 13.18164, 12.54831, 11.86, 11.21874, 10.60539, 10.05468, 9.60131,
+    9.28, 9.394385, 9.64303, 9.99342, 10.41304, 10.86937, 11.21263, 11.55689, 11.89896, 48.29118,
+    208.2791, 15.04193, 13.18144, 42.06645, 225.813, 22.2069, 14.17018, 14.34644, 14.49011, 14.59877,
+    14.67, 14.76901, 14.80884, 14.77017, 14.63366, 14.38, 13.81986, 13.14641, 12.38282, 11.64015,
+    30.69887, 9.936219, 9.005382, 8.155055, 7.304344, 6.46, 5.536021, 4.655095, 3.847158, 3.142148,
+    2.57, 2.37064, 2.31152, 2.37008, 2.52376, 2.75, 3.04544, 3.36352, 3.67688, 3.95816,
+    4.18, 4.13264, 4.01672, 3.85048, 3.65216, 3.44, 3.30104, 3.16752, 3.04048, 2.92096,
+    2.81, 2.73296, 2.66048, 2.58752, 2.50904, 2.42, 2.26672, 2.10496, 1.94184, 1.78448,
+    1.64, 1.54688, 1.47304, 1.41776, 1.38032, 1.36, 1.34968, 1.35664, 1.38176, 1.42592,
+    1.49, 1.60944, 1.74192, 1.87968, 2.01496, 2.14, 2.23744, 2.31152, 2.35688, 2.36816,
+    2.34, 2.17424, 1.98152, 1.77968, 1.58656, 1.42, 1.30104, 1.24352, 1.26448, 1.38096,
+    1.61, 2.18816, 2.85808, 3.58192, 4.32184, 5.04, 5.68288, 6.23224, 6.65416, 6.91472,
+    6.98, 6.45512, 5.75736, 4.94304, 4.06848, 3.19, 2.56488, 1.99824, 1.49616, 1.06472,
+    0.71, 0.5168, 0.3928, 0.3244, 0.298, 0.3, 0.27392, 0.25976, 0.25464, 0.25568,
+    0.26, 0.25096, 0.24288, 0.23632, 0.23184, 0.23, 0.23776, 0.24768, 0.25872, 0.26984,
+    0.28, 0.28464, 0.28712, 0.28728, 0.28496, 0.28, 0.2684, 0.2548, 0.24, 0.2248,
+    0.21, 0.198, 0.1876, 0.1792, 0.1732, 0.17, 0.17608, 0.18424, 0.19336, 0.20232,
+    0.21, 0.20952, 0.20696, 0.20264, 0.19688, 0.19, 0.18328, 0.17584, 0.16776, 0.15912,
+    0.15, 0.14048, 0.13064, 0.12056, 0.11032, 0.1, 0.09, 0.08, 0.07, 0.06,
+    0.05,
+];
+
+/// CIE illuminant LED-B1 (phosphor-converted blue LED, 2733 K).
+#[rustfmt::skip]
+pub const LED_B1: [f32; LEN] = [
+    0.002748895, 0.003137529, 0.003581106, 0.004087396, 0.004665264, 0.005324829, 0.006077641, 0.006936885, 0.007917605, 0.009036976,
+    0.0103146, 0.01177285, 0.01343726, 0.01533698, 0.01750527, 0.01998011, 0.02280482, 0.02602887, 0.02970872, 0.03390879,
+    0.03870263, 0.04417419, 0.05041925, 0.05754716, 0.06568272, 0.07496836, 0.08556665, 0.09766312, 0.1114695, 0.1272275,
+    0.145213, 0.1638138, 0.1882171, 0.2160603, 0.247671, 0.2829638, 0.3220771, 0.3656272, 0.413923, 0.4671469,
+    0.5258645, 0.5902351, 0.6612026, 0.7393395, 0.8253066, 0.9196823, 1.022794, 1.135311, 1.258079, 1.392228,
+    1.538682, 1.700218, 1.876532, 2.069709, 2.28284, 2.519606, 2.783629, 3.078044, 3.405172, 3.765977,
+    4.159352, 4.581655, 5.026548, 5.484845, 5.944378, 6.390313, 6.806359, 7.176674, 7.487591, 7.728417,
+    7.8916, 7.973231, 7.974026, 7.89968, 7.759787, 7.566117, 7.33158, 7.069893, 6.79484, 6.518703,
+    6.250842, 5.997329, 5.761389, 5.543866, 5.343526, 5.157678, 4.983274, 4.81797, 4.660483, 4.510392,
+    4.368078, 4.234959, 4.113304, 4.005334, 3.91243, 3.835307, 3.774715, 3.731514, 3.705875, 3.696569,
+    3.701303, 3.717827, 3.74472, 3.781286, 3.827002, 3.881165, 3.942942, 4.01164, 4.086962, 4.168978,
+    4.257789, 4.353178, 4.454568, 4.561209, 4.672354, 4.787373, 4.905839, 5.027481, 5.151928, 5.278499,
+    5.406324, 5.534722, 5.663436, 5.792496, 5.921868, 6.051291, 6.180484, 6.309422, 6.438295, 6.567217,
+    6.6961, 6.824819, 6.953376, 7.081847, 7.210262, 7.33865, 7.467154, 7.595946, 7.725014, 7.854146,
+    7.983257, 8.112645, 8.242759, 8.373699, 8.505199, 8.637171, 8.770063, 8.904445, 9.040351, 9.177267,
+    9.314749, 9.452916, 9.592289, 9.73325, 9.875679, 10.01913, 10.16341, 10.30892, 10.45632, 10.60591,
+    10.75736, 10.91005, 11.06357, 11.21803, 11.37412, 11.53295, 11.6955, 11.86196, 12.03153, 12.20307,
+    12.37599, 12.55062, 12.72779, 12.90801, 13.09108, 13.27629, 13.46298, 13.65112, 13.8411, 14.033,
+    14.226, 14.41898, 14.61174, 14.80524, 15.00069, 15.19859, 15.39858, 15.59976, 15.80084, 16.00079,
+    16.19956, 16.39806, 16.59688, 16.79558, 16.99326, 17.18947, 17.3842, 17.5772, 17.76783, 17.95569,
+    18.14085, 18.32342, 18.50317, 18.67944, 18.85146, 19.01858, 19.18069, 19.33814, 19.49083, 19.63767,
+    19.77737, 19.90986, 20.03633, 20.1577, 20.27335, 20.38152, 20.48079, 20.57105, 20.6533, 20.72858,
+    20.79689, 20.85698, 20.90695, 20.94648, 20.97613, 20.99695, 21.00992, 21.015, 21.01088, 20.99634,
+    20.97125, 20.93645, 20.89274, 20.84028, 20.77868, 20.70734, 20.62653, 20.53704, 20.43981, 20.33598,
+    20.22526, 20.10713, 19.98084, 19.84783, 19.70885, 19.56409, 19.41472, 19.25959, 19.0985, 18.9313,
+    18.75854, 18.58033, 18.39774, 18.21053, 18.01888, 17.82247, 17.62221, 17.41813, 17.21007, 16.99894,
+    16.7837, 16.56604, 16.34489, 16.12154, 15.89471, 15.66354, 15.4308, 15.19644, 14.96139, 14.72547,
+    14.48849, 14.2518, 14.01526, 13.77884, 13.54229, 13.30648, 13.07226, 12.8393, 12.60717, 12.37605,
+    12.14624, 11.91841, 11.69144, 11.46636, 11.24266, 11.01992, 10.80072, 10.58363, 10.36944, 10.15727,
+    9.947612, 9.74004, 9.534411, 9.331889, 9.131659, 8.934236, 8.739265, 8.546686, 8.356502, 8.169154,
+    7.984502, 7.802184, 7.622389, 7.445651, 7.271013, 7.098599, 6.930012, 6.765547, 6.605756, 6.44923,
+    6.296369, 6.146021, 5.998253, 5.853263, 5.710046, 5.568803, 5.429322, 5.29196, 5.15634, 5.022785,
+    4.890717, 4.760825, 4.632758, 4.507394, 4.384083, 4.264296, 4.148795, 4.037306, 3.929631, 3.824842,
+    3.722623, 3.622868, 3.525341, 3.430524, 3.337924, 3.247886, 3.159817, 3.074456, 2.991203, 2.910318,
+    2.832044, 2.755793, 2.681303, 2.609014, 2.538578, 2.469875, 2.402954, 2.337878, 2.274164, 2.212398,
+    2.152266, 2.09368, 2.036566, 1.981044, 1.926696, 1.874064, 1.822699, 1.773, 1.724644, 1.677733,
+    1.632208, 1.587858, 1.545048, 1.503713, 1.463826, 1.424876, 1.387288, 1.350941, 1.315708, 1.281822,
+    1.248852, 1.216828, 1.185622, 1.155327, 1.126078, 1.097976, 1.070388, 1.043621, 1.017483, 0.992019,
+    0.9674941, 0.9436732, 0.9204402, 0.8981003, 0.8765018, 0.8553082, 0.8348015, 0.8147705, 0.7953384, 0.7765527,
+    0.7583798, 0.7408778, 0.7239836, 0.7076903, 0.691991, 0.6768789, 0.662347, 0.6483885, 0.6349965, 0.6221642,
+    0.6098846,
+];
+
+/// CIE illuminant LED-B2 (phosphor-converted blue LED, 2998 K).
+#[rustfmt::skip]
+pub const LED_B2: [f32; LEN] = [
+    0.001021274, 0.001193818, 0.001395514, 0.001631286, 0.001906892, 0.002229062, 0.002605661, 0.003045888, 0.00356049, 0.004162034,
+    0.004865208, 0.005687183, 0.00664803, 0.007771211, 0.00908415, 0.01061891, 0.01241296, 0.01451011, 0.01696157, 0.0198272,
+    0.02317695, 0.02709263, 0.03166984, 0.03702033, 0.04327474, 0.05058577, 0.05913191, 0.06912179, 0.08079928, 0.09444946,
+    0.1104055, 0.1272561, 0.1495937, 0.1755303, 0.2059982, 0.2414328, 0.2812509, 0.3262361, 0.3778011, 0.4361841,
+    0.5018332, 0.5757269, 0.6577931, 0.7492757, 0.8506795, 0.963004, 1.087365, 1.224339, 1.373926, 1.536057,
+    1.710355, 1.898966, 2.101227, 2.319362, 2.55672, 2.818138, 3.109989, 3.439115, 3.811528, 4.23257,
+    4.707965, 5.242943, 5.838412, 6.486945, 7.171841, 7.869433, 8.552245, 9.191119, 9.756633, 10.22124,
+    10.56254, 10.7664, 10.82817, 10.75205, 10.5498, 10.2396, 9.844505, 9.390237, 8.903351, 8.410405,
+    7.936614, 7.502374, 7.119089, 6.787804, 6.501997, 6.252043, 6.028074, 5.820738, 5.621727, 5.425224,
+    5.229194, 5.035052, 4.846159, 4.666764, 4.501871, 4.356805, 4.235728, 4.140301, 4.070112, 4.024444,
+    4.003125, 4.005287, 4.027823, 4.065911, 4.115234, 4.173388, 4.239516, 4.313581, 4.396352, 4.489183,
+    4.592675, 4.705349, 4.82433, 4.947763, 5.076266, 5.211588, 5.354045, 5.501672, 5.651814, 5.803058,
+    5.955432, 6.109221, 6.264013, 6.418584, 6.571192, 6.720288, 6.865712, 7.009308, 7.153592, 7.299118,
+    7.443296, 7.582509, 7.715687, 7.845389, 7.975082, 8.105793, 8.235856, 8.363561, 8.489257, 8.614812,
+    8.741826, 8.870783, 9.00119, 9.131764, 9.261138, 9.389444, 9.518947, 9.651943, 9.787943, 9.9239,
+    10.0577, 10.19081, 10.32681, 10.46742, 10.61058, 10.75289, 10.89356, 11.03528, 11.18125, 11.33189,
+    11.48502, 11.6384, 11.79125, 11.94369, 12.09626, 12.25039, 12.40772, 12.56812, 12.72912, 12.8885,
+    13.0468, 13.20638, 13.36856, 13.53243, 13.69633, 13.8598, 14.0241, 14.19087, 14.36013, 14.52942,
+    14.69564, 14.85781, 15.01789, 15.17861, 15.34106, 15.50417, 15.66573, 15.82357, 15.97668, 16.12638,
+    16.27574, 16.42661, 16.57725, 16.72396, 16.86551, 17.00492, 17.14602, 17.28884, 17.42948, 17.56462,
+    17.69474, 17.82241, 17.94857, 18.07178, 18.19059, 18.30531, 18.41692, 18.52559, 18.63103, 18.73349,
+    18.83272, 18.92636, 19.01159, 19.08912, 19.16373, 19.23929, 19.31354, 19.37955, 19.43282, 19.4757,
+    19.51358, 19.54798, 19.57469, 19.59003, 19.59478, 19.59258, 19.5861, 19.5744, 19.55484, 19.5249,
+    19.48413, 19.4337, 19.37678, 19.31698, 19.25426, 19.18568, 19.10855, 19.02042, 18.9233, 18.82081,
+    18.71724, 18.6088, 18.49291, 18.36735, 18.23457, 18.09866, 17.95952, 17.81587, 17.66556, 17.50681,
+    17.34199, 17.17444, 17.00457, 16.83167, 16.65481, 16.47269, 16.28727, 16.09722, 15.90226, 15.70484,
+    15.50885, 15.30807, 15.10423, 14.90086, 14.69562, 14.48908, 14.27824, 14.06648, 13.85135, 13.63583,
+    13.42168, 13.20228, 12.98237, 12.76375, 12.54528, 12.32815, 12.11278, 11.89698, 11.68259, 11.47117,
+    11.26233, 11.05281, 10.84531, 10.64184, 10.43939, 10.23672, 10.03602, 9.835745, 9.639443, 9.446275,
+    9.252294, 9.054305, 8.861913, 8.67238, 8.485541, 8.3023, 8.120426, 7.941514, 7.764153, 7.589855,
+    7.417143, 7.247067, 7.079608, 6.915168, 6.753595, 6.595591, 6.443894, 6.292191, 6.145317, 5.998551,
+    5.853094, 5.710341, 5.570588, 5.434293, 5.300566, 5.169364, 5.039071, 4.913817, 4.789213, 4.667153,
+    4.548127, 4.430478, 4.314872, 4.203022, 4.092594, 3.986161, 3.883057, 3.780395, 3.680395, 3.581875,
+    3.485678, 3.392332, 3.300321, 3.211818, 3.124771, 3.039127, 2.956521, 2.876703, 2.797822, 2.721941,
+    2.648147, 2.573768, 2.505904, 2.439402, 2.371151, 2.305778, 2.245045, 2.184404, 2.125222, 2.066463,
+    2.010346, 1.955515, 1.901219, 1.849038, 1.79736, 1.74735, 1.698746, 1.651893, 1.606534, 1.562829,
+    1.519615, 1.479087, 1.44043, 1.399864, 1.360422, 1.323363, 1.288756, 1.252321, 1.217898, 1.182752,
+    1.149514, 1.115707, 1.084011, 1.054164, 1.024028, 0.9958589, 0.966742, 0.9416295, 0.9159182, 0.8920515,
+    0.8676965, 0.8445094, 0.8212536, 0.799689, 0.7792962, 0.7595411, 0.7402138, 0.7198466, 0.7011535, 0.6824422,
+    0.665104, 0.6481556, 0.6317712, 0.6159279, 0.6006029, 0.5857734, 0.5714165, 0.5575094, 0.5440293, 0.5309533,
+    0.5182585,
+];
+
+/// CIE illuminant LED-B3 (phosphor-converted blue LED, 4103 K).
+#[rustfmt::skip]
+pub const LED_B3: [f32; LEN] = [
+    0.001918578, 0.002256286, 0.002653437, 0.003120495, 0.003669763, 0.004315714, 0.005075364, 0.005968728, 0.007019339, 0.008254878,
+    0.009707894, 0.01141667, 0.01342621, 0.01578947, 0.0185687, 0.02183712, 0.02568083, 0.03020108, 0.03551695, 0.04176848,
+    0.04912033, 0.05776616, 0.0679337, 0.07989075, 0.09395223, 0.1104885, 0.129935, 0.1528038, 0.179697, 0.2113227,
+    0.2485135, 0.2886127, 0.3408693, 0.4023498, 0.4744324, 0.558376, 0.6552016, 0.766668, 0.8939895, 1.038966,
+    1.203112, 1.388463, 1.596493, 1.829079, 2.087357, 2.372601, 2.685934, 3.02857, 3.401286, 3.804775,
+    4.240946, 4.718872, 5.24074, 5.815813, 6.455613, 7.172349, 7.976695, 8.87566, 9.870573, 10.95476,
+    12.11148, 13.31319, 14.52218, 15.692, 16.76994, 17.70176, 18.43816, 18.94042, 19.18335, 19.15656,
+    18.86512, 18.32966, 17.58485, 16.67632, 15.65662, 14.5807, 13.50129, 12.46458, 11.50633, 10.64942,
+    9.90372, 9.267768, 8.730951, 8.275723, 7.880929, 7.526322, 7.196507, 6.882462, 6.580746, 6.291933,
+    6.019497, 5.769138, 5.547777, 5.361961, 5.216316, 5.112864, 5.051327, 5.02971, 5.044656, 5.091771,
+    5.166346, 5.264143, 5.381671, 5.515944, 5.664276, 5.824361, 5.99455, 6.174051, 6.362735, 6.560534,
+    6.766931, 6.980909, 7.201057, 7.425533, 7.652074, 7.878317, 8.102142, 8.321738, 8.535621, 8.742843,
+    8.943083, 9.136207, 9.32162, 9.498269, 9.665496, 9.823813, 9.974687, 10.11947, 10.25847, 10.39102,
+    10.51649, 10.63515, 10.74821, 10.85682, 10.96124, 11.06099, 11.15571, 11.24612, 11.33397, 11.42118,
+    11.50853, 11.59533, 11.68049, 11.76401, 11.84736, 11.93225, 12.01919, 12.10718, 12.19496, 12.28235,
+    12.37035, 12.46007, 12.55175, 12.64482, 12.73852, 12.8323, 12.92608, 13.02054, 13.11668, 13.21479,
+    13.31384, 13.41222, 13.509, 13.60449, 13.69963, 13.79489, 13.88982, 13.98346, 14.07535, 14.16586,
+    14.25548, 14.34405, 14.43109, 14.51654, 14.60078, 14.68385, 14.76536, 14.84528, 14.92414, 15.00199,
+    15.07784, 15.15059, 15.22032, 15.28819, 15.35524, 15.4215, 15.48616, 15.54812, 15.60678, 15.66244,
+    15.71623, 15.7691, 15.82107, 15.87147, 15.91995, 15.9669, 16.01282, 16.05777, 16.10158, 16.14401,
+    16.18464, 16.2231, 16.25966, 16.29551, 16.33178, 16.36807, 16.40232, 16.43226, 16.45734, 16.47899,
+    16.49903, 16.5176, 16.53301, 16.5435, 16.54883, 16.55011, 16.54823, 16.54297, 16.53315, 16.51762,
+    16.49621, 16.46981, 16.43931, 16.40468, 16.3646, 16.31777, 16.2641, 16.20455, 16.14025, 16.07135,
+    15.99755, 15.91799, 15.83223, 15.74054, 15.64381, 15.5424, 15.43628, 15.3239, 15.2059, 15.08306,
+    14.95767, 14.82881, 14.69581, 14.55748, 14.41392, 14.26567, 14.1145, 13.96113, 13.80527, 13.64611,
+    13.48361, 13.31752, 13.14817, 12.97511, 12.79966, 12.62264, 12.44348, 12.26266, 12.08002, 11.89621,
+    11.7122, 11.52689, 11.34098, 11.15407, 10.96682, 10.77941, 10.59222, 10.4056, 10.22, 10.03402,
+    9.848369, 9.662164, 9.476871, 9.291874, 9.10971, 8.928539, 8.747966, 8.569327, 8.391661, 8.216127,
+    8.041957, 7.869398, 7.699084, 7.530985, 7.365222, 7.20091, 7.038886, 6.879294, 6.722552, 6.568229,
+    6.416028, 6.265688, 6.118802, 5.974384, 5.832419, 5.691266, 5.552601, 5.416581, 5.282893, 5.15166,
+    5.022539, 4.896018, 4.772567, 4.651849, 4.532912, 4.417139, 4.303979, 4.193217, 4.085282, 3.979738,
+    3.876131, 3.77452, 3.675675, 3.577836, 3.48156, 3.386678, 3.294813, 3.204784, 3.116356, 3.030358,
+    2.946436, 2.864912, 2.785137, 2.707043, 2.631341, 2.558588, 2.486793, 2.41681, 2.348304, 2.281782,
+    2.216747, 2.153143, 2.091602, 2.031282, 1.972497, 1.915514, 1.860153, 1.805858, 1.75306, 1.701774,
+    1.651999, 1.604017, 1.557255, 1.512338, 1.468736, 1.426378, 1.38476, 1.344431, 1.305082, 1.267124,
+    1.229861, 1.193626, 1.158678, 1.124777, 1.091366, 1.059561, 1.02781, 0.9972121, 0.967551, 0.9385455,
+    0.9107818, 0.8839043, 0.858061, 0.8325411, 0.8077664, 0.7840501, 0.7611343, 0.7386716, 0.716803, 0.6957974,
+    0.6755071, 0.6558087, 0.6368308, 0.6182131, 0.6003195, 0.5831036, 0.5658864, 0.5494455, 0.5334931, 0.5178827,
+    0.5029226, 0.4882064, 0.4740136, 0.4598935, 0.4467378, 0.4338903, 0.4211399, 0.4086686, 0.3968008, 0.3853357,
+    0.3744662, 0.3636713, 0.3531986, 0.3430382, 0.3331806, 0.3236161, 0.314335, 0.3053278, 0.2965846, 0.288096,
+    0.2798521,
+];
+
+/// CIE illuminant LED-B4 (phosphor-converted blue LED, 5109 K).
+#[rustfmt::skip]
+pub const LED_B4: [f32; LEN] = [
+    0.0006646933, 0.0008050256, 0.0009749854, 0.001180828, 0.001430128, 0.001732062, 0.00209774, 0.002540622, 0.003077006, 0.003726633,
+    0.004513411, 0.005466295, 0.006620354, 0.008018059, 0.009710849, 0.01176102, 0.01424403, 0.01725124, 0.02089334, 0.02530434,
+    0.03064657, 0.03711662, 0.04495257, 0.05444277, 0.0659364, 0.07985638, 0.09671483, 0.117132, 0.1418589, 0.1718052,
+    0.2080721, 0.246171, 0.3009996, 0.367813, 0.4482375, 0.5423476, 0.6531875, 0.7833162, 0.9354267, 1.11073,
+    1.310526, 1.53654, 1.792851, 2.081045, 2.400698, 2.751313, 3.135712, 3.555944, 4.015154, 4.511872,
+    5.041662, 5.608375, 6.21001, 6.854881, 7.552408, 8.311863, 9.142997, 10.05585, 11.05934, 12.16072,
+    13.36591, 14.67755, 16.08901, 17.57742, 19.10177, 20.60853, 22.04033, 23.34087, 24.45432, 25.32556,
+    25.90738, 26.17056, 26.10744, 25.72769, 25.05408, 24.12234, 22.98065, 21.68522, 20.29508, 18.86968,
+    17.46652, 16.13481, 14.90711, 13.79554, 12.79675, 11.90195, 11.1032, 10.39159, 9.752528, 9.16686,
+    8.618662, 8.101028, 7.613932, 7.15853, 6.734908, 6.343337, 5.984883, 5.660516, 5.370674, 5.115515,
+    4.894712, 4.707118, 4.551041, 4.4245, 4.324904, 4.249166, 4.194929, 4.161627, 4.150045, 4.160991,
+    4.19452, 4.250114, 4.327394, 4.426893, 4.550106, 4.698242, 4.870755, 5.065513, 5.280518, 5.515094,
+    5.769334, 6.042786, 6.333655, 6.638721, 6.953798, 7.27494, 7.600067, 7.929405, 8.263689, 8.601923,
+    8.941401, 9.2796, 9.615201, 9.947409, 10.27538, 10.59856, 10.9169, 11.22986, 11.53548, 11.8309,
+    12.11424, 12.38584, 12.64701, 12.8976, 13.13553, 13.3588, 13.56765, 13.76449, 13.95207, 14.13206,
+    14.30442, 14.46784, 14.62078, 14.76296, 14.89582, 15.02138, 15.14052, 15.25258, 15.35653, 15.45235,
+    15.54119, 15.62425, 15.70193, 15.7743, 15.84178, 15.90477, 15.96302, 16.01617, 16.06464, 16.10942,
+    16.15178, 16.1935, 16.23582, 16.27664, 16.3103, 16.3327, 16.34682, 16.36101, 16.38072, 16.40266,
+    16.41854, 16.42433, 16.42465, 16.42711, 16.43333, 16.43694, 16.43087, 16.41478, 16.39442, 16.37536,
+    16.35888, 16.3424, 16.32136, 16.29114, 16.25014, 16.2024, 16.15569, 16.11481, 16.07672, 16.03363,
+    15.98165, 15.92495, 15.87035, 15.81856, 15.76337, 15.69972, 15.62992, 15.56041, 15.49413, 15.42802,
+    15.35719, 15.27949, 15.19627, 15.10984, 15.02121, 14.93021, 14.83725, 14.74331, 14.64762, 14.54675,
+    14.43765, 14.32115, 14.20188, 14.08463, 13.97052, 13.85769, 13.74254, 13.62264, 13.49844, 13.37122,
+    13.24362, 13.11385, 12.98158, 12.84652, 12.71159, 12.57272, 12.43142, 12.28893, 12.14337, 11.99377,
+    11.84351, 11.69383, 11.54911, 11.40659, 11.26266, 11.116, 10.96725, 10.81803, 10.6692, 10.52034,
+    10.37552, 10.23146, 10.08857, 9.94569, 9.799992, 9.651668, 9.501416, 9.351092, 9.203243, 9.055669,
+    8.910116, 8.764712, 8.621563, 8.473109, 8.33269, 8.191694, 8.053373, 7.915203, 7.778093, 7.643308,
+    7.511812, 7.382127, 7.250838, 7.118532, 6.987161, 6.858026, 6.722704, 6.59083, 6.46168, 6.333925,
+    6.20855, 6.083618, 5.958435, 5.835417, 5.720434, 5.604211, 5.489763, 5.377878, 5.267093, 5.159483,
+    5.051947, 4.94712, 4.841856, 4.738164, 4.638751, 4.537601, 4.435454, 4.336621, 4.240465, 4.146188,
+    4.050961, 3.960015, 3.870432, 3.783438, 3.701181, 3.617377, 3.53696, 3.457593, 3.378364, 3.300602,
+    3.22528, 3.151567, 3.078937, 3.008072, 2.937522, 2.866594, 2.794953, 2.725858, 2.659884, 2.59491,
+    2.532028, 2.473498, 2.412477, 2.353833, 2.298461, 2.242402, 2.188897, 2.135746, 2.084485, 2.03476,
+    1.986413, 1.939135, 1.892526, 1.847845, 1.802329, 1.757288, 1.713483, 1.672227, 1.629606, 1.587456,
+    1.548007, 1.509891, 1.47184, 1.437335, 1.400958, 1.366104, 1.331577, 1.300439, 1.269127, 1.237474,
+    1.206532, 1.177417, 1.150053, 1.124977, 1.097594, 1.073139, 1.046653, 1.023362, 0.9978091, 0.97404,
+    0.9512401, 0.9289272, 0.9078182, 0.8867905, 0.8653812, 0.845932, 0.825453, 0.8053633, 0.7856342, 0.7664769,
+    0.7486497, 0.7305429, 0.7170698, 0.7009392, 0.6864774, 0.6729234, 0.6581926, 0.6459921, 0.6318348, 0.6185359,
+    0.606765, 0.5949111, 0.5828256, 0.5709069, 0.5604686, 0.5492441, 0.5362748, 0.5248175, 0.5154084, 0.5057803,
+    0.4962725, 0.4872012, 0.4784205, 0.4699022, 0.4616184, 0.4535408, 0.4456415, 0.4378922, 0.430265, 0.4227317,
+    0.4152642,
+];
+
+/// CIE illuminant LED-B5 (phosphor-converted blue LED, 6598 K).
+#[rustfmt::skip]
+pub const LED_B5: [f32; LEN] = [
+    0.002825336, 0.003317836, 0.003896185, 0.004575349, 0.005372901, 0.006309478, 0.007409313, 0.008700864, 0.01021755, 0.01199862,
+    0.01409014, 0.01654625, 0.01943048, 0.02281746, 0.02679483, 0.03146549, 0.03695028, 0.0433911, 0.05095458, 0.0598364,
+    0.07026632, 0.08251415, 0.09689671, 0.113786, 0.1336189, 0.1569083, 0.1842564, 0.2163704, 0.2540806, 0.2983618,
+    0.3503585, 0.4064433, 0.478949, 0.564475, 0.6642968, 0.780603, 0.9149387, 1.069309, 1.245252, 1.446281,
+    1.674673, 1.93261, 2.220492, 2.541397, 2.898703, 3.295815, 3.734945, 4.217341, 4.744686, 5.318448,
+    5.94031, 6.617749, 7.34694, 8.133718, 8.988004, 9.921219, 10.94393, 12.0665, 13.30072, 14.65862,
+    16.14825, 17.76965, 19.51227, 21.35261, 23.25156, 25.15432, 26.99476, 28.70185, 30.20494, 31.43752,
+    32.34186, 32.87536, 33.01606, 32.76429, 32.13984, 31.17858, 29.93101, 28.46144, 26.84375, 25.15339,
+    23.45907, 21.81751, 20.27082, 18.84546, 17.55265, 16.39122, 15.35171, 14.41933, 13.57578, 12.80169,
+    12.08052, 11.4019, 10.7619, 10.16061, 9.599438, 9.080201, 8.605694, 8.179627, 7.805152, 7.48311,
+    7.211604, 6.986959, 6.804948, 6.661557, 6.553426, 6.477891, 6.432317, 6.413375, 6.417539, 6.44266,
+    6.488824, 6.557315, 6.648751, 6.762266, 6.896294, 7.049829, 7.222765, 7.415207, 7.626631, 7.855546,
+    8.099518, 8.35542, 8.620121, 8.891422, 9.168365, 9.450487, 9.736782, 10.02536, 10.31381, 10.59983,
+    10.88179, 11.15895, 11.43127, 11.69878, 11.96123, 12.21782, 12.4671, 12.70761, 12.93889, 13.16164,
+    13.37629, 13.58173, 13.77607, 13.95863, 14.13081, 14.29472, 14.45116, 14.59911, 14.73706, 14.86478,
+    14.98354, 15.09494, 15.19979, 15.29836, 15.39098, 15.47762, 15.55743, 15.62963, 15.69493, 15.75508,
+    15.81092, 15.86151, 15.90556, 15.94318, 15.97603, 16.00586, 16.03322, 16.05745, 16.07754, 16.09296,
+    16.1041, 16.11226, 16.11901, 16.1254, 16.13114, 16.13467, 16.1342, 16.12913, 16.12038, 16.10919,
+    16.09579, 16.07934, 16.05902, 16.03513, 16.00894, 15.98101, 15.94991, 15.91316, 15.86993, 15.82248,
+    15.77419, 15.72623, 15.67662, 15.62255, 15.5632, 15.49993, 15.43445, 15.36737, 15.2984, 15.22725,
+    15.15388, 15.07846, 15.00145, 14.92322, 14.84336, 14.76058, 14.67384, 14.58363, 14.49175, 14.39943,
+    14.30597, 14.20947, 14.10895, 14.00522, 13.89966, 13.79251, 13.68296, 13.57032, 13.45477, 13.33693,
+    13.21717, 13.09559, 12.972, 12.84607, 12.71747, 12.5863, 12.45319, 12.3188, 12.18347, 12.04716,
+    11.90982, 11.77101, 11.63091, 11.49013, 11.34798, 11.20367, 11.05712, 10.90934, 10.76192, 10.61454,
+    10.46762, 10.32095, 10.17352, 10.02569, 9.877572, 9.729741, 9.581983, 9.434606, 9.287861, 9.14173,
+    8.995837, 8.850334, 8.705811, 8.56124, 8.417348, 8.27247, 8.128661, 7.986349, 7.845721, 7.706379,
+    7.568135, 7.430818, 7.295006, 7.160082, 7.027529, 6.895804, 6.764689, 6.634443, 6.506081, 6.37973,
+    6.254833, 6.130926, 6.00867, 5.885895, 5.766292, 5.647062, 5.53098, 5.416132, 5.302339, 5.190189,
+    5.078979, 4.969657, 4.861904, 4.755457, 4.651516, 4.548642, 4.446328, 4.345207, 4.247482, 4.151279,
+    4.055957, 3.962616, 3.871226, 3.782089, 3.696496, 3.611408, 3.529152, 3.448698, 3.369695, 3.292302,
+    3.215417, 3.141186, 3.067662, 2.996071, 2.925726, 2.856965, 2.788578, 2.722646, 2.659829, 2.597976,
+    2.538273, 2.479606, 2.422379, 2.36694, 2.311903, 2.256975, 2.203891, 2.151258, 2.098879, 2.046937,
+    1.995425, 1.94553, 1.8954, 1.846674, 1.798353, 1.751802, 1.707046, 1.663957, 1.622705, 1.582556,
+    1.543951, 1.505398, 1.468414, 1.431808, 1.395753, 1.360719, 1.326565, 1.293179, 1.260399, 1.228411,
+    1.197572, 1.167166, 1.13746, 1.108361, 1.080229, 1.053249, 1.027124, 1.001309, 0.9763938, 0.9521843,
+    0.9290907, 0.9056792, 0.8836472, 0.8612289, 0.8395916, 0.818303, 0.797512, 0.7773011, 0.7578148, 0.7387064,
+    0.7204497, 0.7021098, 0.6851084, 0.6679874, 0.651466, 0.6351701, 0.6194792, 0.6043624, 0.5894026, 0.5747606,
+    0.560843, 0.546588, 0.5336982, 0.5200639, 0.5071956, 0.4939759, 0.4821362, 0.4708754, 0.4599456, 0.4492169,
+    0.4388495, 0.4284512, 0.4188287, 0.4084783, 0.3991164, 0.3895464, 0.3805068, 0.3717526, 0.362491, 0.3537948,
+    0.3456751, 0.337355, 0.3292611, 0.3214103, 0.3138197, 0.3065061, 0.2994865, 0.2927779, 0.2863972, 0.2803614,
+    0.2746873,
+];
+
+/// CIE illuminant LED-BH1 (hybrid blue and red LED, 2851 K).
+#[rustfmt::skip]
+pub const LED_BH1: [f32; LEN] = [
+    0.003120462, 0.003669725, 0.004315669, 0.005075311, 0.005968665, 0.007019265, 0.008254791, 0.009707791, 0.01141655, 0.01342607,
+    0.0157893, 0.01856851, 0.02183689, 0.02568056, 0.03020076, 0.03551658, 0.04176804, 0.04890497, 0.05678162, 0.06599462,
+    0.07689056, 0.08990485, 0.105482, 0.1240818, 0.1463048, 0.1730021, 0.2052317, 0.2441051, 0.2883059, 0.3443904,
+    0.4106699, 0.4884306, 0.5787676, 0.6824931, 0.8007423, 0.9343254, 1.08408, 1.250198, 1.432583, 1.630606,
+    1.84338, 2.069033, 2.305076, 2.549084, 2.799397, 3.054492, 3.311852, 3.567814, 3.81894, 4.061644,
+    4.292893, 4.564937, 4.841114, 5.12127, 5.405202, 5.6918, 5.978584, 6.262269, 6.539507, 6.806721,
+    7.059239, 7.291093, 7.496236, 7.670005, 7.809097, 7.91017, 7.969178, 7.982553, 7.948817, 7.868798,
+    7.744849, 7.580437, 7.380199, 7.149549, 6.893792, 6.617718, 6.326176, 6.024936, 5.720628, 5.419486,
+    5.125958, 4.842576, 4.571063, 4.313319, 4.071225, 3.845736, 3.636453, 3.442093, 3.261311, 3.093216,
+    2.937449, 2.793963, 2.662701, 2.543213, 2.43444, 2.335073, 2.244447, 2.163125, 2.092433, 2.033387,
+    1.98615, 1.950422, 1.926118, 1.91353, 1.912974, 1.924556, 1.948447, 1.985364, 2.036547, 2.103165,
+    2.185947, 2.285529, 2.402762, 2.538355, 2.69254, 2.865532, 3.058095, 3.271164, 3.505082, 3.759595,
+    4.03427, 4.328116, 4.638547, 4.961517, 5.293779, 5.635329, 5.988807, 6.355562, 6.732207, 7.111437,
+    7.486223, 7.853089, 8.21201, 8.564273, 8.910659, 9.250761, 9.583275, 9.906968, 10.22153, 10.52725,
+    10.82366, 11.10887, 11.38068, 11.63822, 11.88243, 12.11485, 12.33604, 12.54497, 12.73942, 12.9171,
+    13.07681, 13.21935, 13.34736, 13.46376, 13.56982, 13.66497, 13.74878, 13.82287, 13.8903, 13.95265,
+    14.00867, 14.05675, 14.09771, 14.13382, 14.16583, 14.19271, 14.21443, 14.23324, 14.25109, 14.26728,
+    14.27944, 14.28626, 14.28834, 14.2868, 14.28198, 14.27351, 14.26138, 14.24658, 14.23002, 14.21108,
+    14.18799, 14.16034, 14.12988, 14.09841, 14.06582, 14.03097, 13.99353, 13.954, 13.91246, 13.86843,
+    13.82188, 13.77393, 13.72617, 13.67947, 13.63314, 13.58548, 13.53556, 13.48481, 13.43628, 13.39202,
+    13.35164, 13.31379, 13.27841, 13.24703, 13.22131, 13.20173, 13.18731, 13.17693, 13.17113, 13.17265,
+    13.185, 13.21056, 13.25019, 13.30437, 13.37416, 13.46163, 13.5703, 13.70536, 13.87219, 14.07382,
+    14.31065, 14.58373, 14.89789, 15.26065, 15.67798, 16.15206, 16.68393, 17.27757, 17.93991, 18.6771,
+    19.49264, 20.39087, 21.38167, 22.48066, 23.70495, 25.06838, 26.57905, 28.23737, 30.03136, 31.92877,
+    33.87035, 35.77002, 37.52201, 39.0105, 40.12059, 40.75254, 40.8369, 40.34257, 39.27487, 37.66935,
+    35.58774, 33.11587, 30.35989, 27.43907, 24.4764, 21.58911, 18.8811, 16.43692, 14.3151, 12.54122,
+    11.10531, 9.96796, 9.072565, 8.358364, 7.771423, 7.272268, 6.837938, 6.457752, 6.126156, 5.837246,
+    5.584711, 5.358825, 5.154495, 4.969367, 4.800654, 4.644173, 4.495241, 4.35242, 4.217743, 4.093483,
+    3.979551, 3.873634, 3.770895, 3.669256, 3.571133, 3.478887, 3.392474, 3.309723, 3.228858, 3.15013,
+    3.074091, 2.99955, 2.92854, 2.859871, 2.793358, 2.728161, 2.66348, 2.600328, 2.53914, 2.480351,
+    2.422695, 2.367046, 2.310895, 2.256259, 2.204142, 2.153293, 2.10273, 2.053589, 2.004638, 1.956881,
+    1.910586, 1.865571, 1.82094, 1.778387, 1.737269, 1.695691, 1.65512, 1.615449, 1.576144, 1.537696,
+    1.49906, 1.463268, 1.427979, 1.39351, 1.359369, 1.326992, 1.295949, 1.266632, 1.236304, 1.207252,
+    1.179291, 1.151074, 1.124193, 1.098463, 1.072957, 1.046925, 1.021408, 0.9969525, 0.9728657, 0.9495476,
+    0.9271641, 0.9059462, 0.8848487, 0.8645477, 0.8455532, 0.8274917, 0.8094732, 0.791544, 0.7728702, 0.7552954,
+    0.7359575, 0.717142, 0.6991709, 0.6812596, 0.6638958, 0.6466369, 0.6309013, 0.6159762, 0.6009655, 0.5866477,
+    0.572945, 0.5593969, 0.5468087, 0.5353506, 0.5249252, 0.5138809, 0.5027773, 0.4917901, 0.4815225, 0.4711145,
+    0.4604167, 0.4507434, 0.441115, 0.4317296, 0.4229741, 0.4148822, 0.4062836, 0.3971941, 0.3878473, 0.3798019,
+    0.3719897, 0.3642622, 0.3568393, 0.3497341, 0.3429183, 0.3350482, 0.327815, 0.3219146, 0.3152802, 0.3085398,
+    0.3038017, 0.2980083, 0.2923159, 0.2867116, 0.2811826, 0.2757162, 0.2702994, 0.2649195, 0.2595637, 0.2542192,
+    0.2488731,
+];
+
+/// CIE illuminant LED-RGB1 (RGB LED, 2840 K).
+#[rustfmt::skip]
+pub const LED_RGB1: [f32; LEN] = [
+    0.001021246, 0.001193786, 0.001395476, 0.001631242, 0.00190684, 0.002229, 0.00260559, 0.003045804, 0.003560392, 0.00416192,
+    0.004865075, 0.005687028, 0.006647848, 0.007770998, 0.009083902, 0.01061862, 0.01241262, 0.01450972, 0.01696111, 0.01982665,
+    0.02317632, 0.02709189, 0.03166897, 0.03701932, 0.04327356, 0.05058439, 0.05913029, 0.06911989, 0.08079707, 0.09444688,
+    0.1104025, 0.127783, 0.1509211, 0.1775665, 0.2069854, 0.2410033, 0.2801498, 0.3246059, 0.3748255, 0.4306925,
+    0.4965699, 0.5695841, 0.6476066, 0.7369545, 0.8330018, 0.9403142, 1.055812, 1.176592, 1.311474, 1.459994,
+    1.6046, 1.758681, 1.918504, 2.080901, 2.258713, 2.429036, 2.606326, 2.799994, 2.992801, 3.1828,
+    3.38497, 3.583756, 3.796453, 4.051447, 4.317958, 4.58872, 4.878891, 5.168656, 5.44117, 5.666973,
+    5.841671, 5.96046, 6.003086, 5.943631, 5.794924, 5.560186, 5.269438, 4.964728, 4.654752, 4.317977,
+    4.004834, 3.771039, 3.558156, 3.372257, 3.247588, 3.143489, 3.047594, 2.979527, 2.920507, 2.857703,
+    2.801283, 2.753271, 2.717783, 2.687466, 2.673485, 2.679178, 2.699311, 2.739396, 2.815202, 2.913089,
+    3.028074, 3.172872, 3.340273, 3.530459, 3.742257, 3.982508, 4.231451, 4.511341, 4.821122, 5.153736,
+    5.525075, 5.93709, 6.362175, 6.821467, 7.31406, 7.836554, 8.41238, 8.997861, 9.643288, 10.30854,
+    10.95855, 11.64757, 12.39358, 13.11346, 13.87133, 14.61239, 15.32826, 16.04981, 16.72547, 17.36594,
+    18.00871, 18.60224, 19.09179, 19.53052, 19.92676, 20.24452, 20.42581, 20.60094, 20.68704, 20.65178,
+    20.5653, 20.43166, 20.19747, 19.90512, 19.59219, 19.22036, 18.77644, 18.33026, 17.90085, 17.42733,
+    16.93358, 16.45984, 15.95812, 15.49688, 15.03884, 14.58231, 14.1574, 13.77001, 13.37229, 12.97933,
+    12.60733, 12.26385, 11.9489, 11.67566, 11.3766, 11.08829, 10.83224, 10.58762, 10.3606, 10.13334,
+    9.92847, 9.732003, 9.561482, 9.393359, 9.239118, 9.113542, 8.986904, 8.873284, 8.76264, 8.660566,
+    8.600216, 8.536062, 8.459926, 8.405684, 8.361842, 8.312254, 8.277144, 8.246438, 8.230461, 8.222336,
+    8.20755, 8.206882, 8.219189, 8.239544, 8.258112, 8.241554, 8.276944, 8.300743, 8.341152, 8.379368,
+    8.420003, 8.476617, 8.525815, 8.598654, 8.671168, 8.743932, 8.823356, 8.91578, 9.025105, 9.130088,
+    9.24552, 9.368404, 9.509953, 9.658575, 9.822235, 9.997137, 10.17654, 10.37984, 10.60189, 10.82772,
+    11.1016, 11.3907, 11.67368, 12.00216, 12.37045, 12.74097, 13.18336, 13.66806, 14.17864, 14.73578,
+    15.34708, 15.99936, 16.75809, 17.5885, 18.43013, 19.42756, 20.52877, 21.70815, 22.98446, 24.41677,
+    26.008, 27.73063, 29.65177, 31.73326, 33.9929, 36.46875, 39.12424, 41.9073, 44.8927, 48.00033,
+    51.09935, 54.12588, 56.9979, 59.49437, 61.51355, 62.69029, 62.83768, 61.87766, 59.68215, 56.37093,
+    52.07265, 47.30364, 42.19781, 37.22033, 32.68098, 28.62187, 25.03113, 22.01125, 19.45575, 17.29469,
+    15.49935, 13.95851, 12.62648, 11.46799, 10.46745, 9.576804, 8.808332, 8.129258, 7.538085, 7.006474,
+    6.535715, 6.139006, 5.800214, 5.485305, 5.194642, 4.949609, 4.733423, 4.535023, 4.342299, 4.173063,
+    4.013983, 3.868307, 3.732033, 3.603065, 3.484458, 3.372922, 3.268022, 3.159756, 3.058481, 2.976711,
+    2.892094, 2.807126, 2.727243, 2.646331, 2.575359, 2.50632, 2.437286, 2.376805, 2.305053, 2.239207,
+    2.181544, 2.12072, 2.063574, 2.010845, 1.964112, 1.915135, 1.859756, 1.811052, 1.757283, 1.708738,
+    1.665778, 1.61317, 1.564817, 1.533041, 1.48655, 1.441652, 1.402536, 1.365198, 1.322123, 1.285304,
+    1.251936, 1.215389, 1.176965, 1.141069, 1.112694, 1.08527, 1.054112, 1.024945, 0.9984334, 0.9690401,
+    0.9342989, 0.9206973, 0.8874385, 0.8591073, 0.8404926, 0.8184929, 0.7929823, 0.7628609, 0.745117, 0.7342804,
+    0.7074808, 0.6819201, 0.6682712, 0.6454616, 0.6285175, 0.6065118, 0.5821783, 0.5683496, 0.5600408, 0.541146,
+    0.5300933, 0.5195091, 0.501409, 0.4867922, 0.4730631, 0.4511799, 0.4478935, 0.4317378, 0.4204323, 0.4027406,
+    0.3974065, 0.3871247, 0.3744383, 0.3665922, 0.3596059, 0.3488429, 0.3344473, 0.3211517, 0.3211795, 0.3019966,
+    0.3035309, 0.299776, 0.2843803, 0.2822741, 0.2741166, 0.2653611, 0.254215, 0.2569391, 0.2334372, 0.239751,
+    0.2353147, 0.2307383, 0.2189178, 0.2184821, 0.21122, 0.208645, 0.1958244, 0.1984627, 0.1807417, 0.1851862,
+    0.1693515,
+];
+
+/// CIE illuminant LED-V1 (phosphor-converted violet LED, 2724 K).
+#[rustfmt::skip]
+pub const LED_V1: [f32; LEN] = [
+    0.007252573, 0.00974307, 0.01308879, 0.01758342, 0.02362147, 0.03173297, 0.04262992, 0.05726882, 0.07693464, 0.1033536,
+    0.1419442, 0.1908121, 0.2546847, 0.3396985, 0.4530472, 0.6054398, 0.8121116, 1.097815, 1.482132, 1.984848,
+    2.623719, 3.417719, 4.395316, 5.550235, 6.897513, 8.427665, 10.07833, 11.77711, 13.44656, 15.05995,
+    16.51245, 17.64063, 18.36384, 18.6662, 18.50293, 17.89578, 16.9041, 15.63612, 14.20352, 12.70001,
+    11.25549, 9.930709, 8.741583, 7.690541, 6.757688, 5.944496, 5.256224, 4.668085, 4.151538, 3.699431,
+    3.311128, 2.987142, 2.724012, 2.514941, 2.362372, 2.249742, 2.162519, 2.103809, 2.071011, 2.060893,
+    2.068815, 2.086106, 2.120791, 2.170992, 2.228405, 2.292569, 2.362372, 2.437927, 2.518302, 2.599345,
+    2.680088, 2.766714, 2.855784, 2.951733, 3.049781, 3.148262, 3.247439, 3.344488, 3.435579, 3.535737,
+    3.636897, 3.747741, 3.857248, 3.960017, 4.071743, 4.182639, 4.3082, 4.433875, 4.564423, 4.692438,
+    4.827966, 4.972613, 5.118596, 5.276055, 5.418742, 5.569109, 5.720353, 5.889859, 6.069548, 6.229819,
+    6.397512, 6.568252, 6.745975, 6.931519, 7.09883, 7.267155, 7.437044, 7.607655, 7.77599, 7.928536,
+    8.082725, 8.2265, 8.366034, 8.499493, 8.620793, 8.734503, 8.841144, 8.940958, 9.037337, 9.123915,
+    9.205712, 9.280026, 9.351393, 9.417856, 9.478772, 9.535603, 9.582725, 9.631015, 9.674966, 9.714144,
+    9.750368, 9.782227, 9.81479, 9.841083, 9.872623, 9.906354, 9.935581, 9.967532, 9.992682, 10.02647,
+    10.0615, 10.0945, 10.13031, 10.17064, 10.21377, 10.25856, 10.30601, 10.3561, 10.4063, 10.45677,
+    10.51025, 10.56519, 10.62738, 10.68758, 10.75183, 10.82015, 10.89312, 10.96281, 11.03075, 11.10084,
+    11.17423, 11.24112, 11.31918, 11.39865, 11.47877, 11.55287, 11.62958, 11.70076, 11.77233, 11.84743,
+    11.9246, 11.99465, 12.06589, 12.14191, 12.21889, 12.29697, 12.37628, 12.45395, 12.52709, 12.60295,
+    12.68155, 12.75383, 12.82504, 12.89968, 12.98463, 13.06808, 13.1552, 13.24288, 13.33016, 13.42542,
+    13.5227, 13.61859, 13.71596, 13.82654, 13.94144, 14.05852, 14.17716, 14.30133, 14.42533, 14.55648,
+    14.70498, 14.85875, 15.01245, 15.15732, 15.30747, 15.4616, 15.63397, 15.815, 16.00074, 16.18738,
+    16.38287, 16.57824, 16.76794, 16.96164, 17.16691, 17.3883, 17.60689, 17.82374, 18.04686, 18.27213,
+    18.49708, 18.68919, 18.88434, 19.08596, 19.29869, 19.51354, 19.72402, 19.92013, 20.1208, 20.31907,
+    20.51611, 20.71757, 20.92826, 21.13274, 21.32797, 21.522, 21.70718, 21.88785, 22.05271, 22.22255,
+    22.37995, 22.53641, 22.68083, 22.82141, 22.94949, 23.06807, 23.16618, 23.25815, 23.34408, 23.42854,
+    23.50659, 23.5708, 23.61494, 23.65512, 23.68449, 23.70768, 23.72475, 23.72645, 23.71523, 23.69778,
+    23.66692, 23.6231, 23.57102, 23.5067, 23.43412, 23.35465, 23.26355, 23.15544, 23.05198, 22.94778,
+    22.8126, 22.65908, 22.50659, 22.35252, 22.18888, 22.01285, 21.83602, 21.64424, 21.44217, 21.23623,
+    21.03002, 20.80888, 20.58785, 20.36957, 20.14422, 19.91261, 19.67716, 19.44244, 19.19473, 18.94848,
+    18.694, 18.43494, 18.17131, 17.91503, 17.65593, 17.38877, 17.1186, 16.84986, 16.57907, 16.31008,
+    16.04978, 15.78586, 15.50293, 15.22081, 14.94876, 14.68614, 14.42094, 14.1599, 13.89898, 13.63143,
+    13.36237, 13.09521, 12.82504, 12.55081, 12.29356, 12.0403, 11.7877, 11.53271, 11.28551, 11.03983,
+    10.80381, 10.56303, 10.32065, 10.08563, 9.852125, 9.622072, 9.396049, 9.173017, 8.956078, 8.745763,
+    8.538801, 8.33822, 8.144219, 7.945158, 7.753296, 7.568462, 7.387996, 7.194868, 7.014643, 6.847352,
+    6.681554, 6.501092, 6.333823, 6.173452, 6.019035, 5.86281, 5.713032, 5.565006, 5.419474, 5.268423,
+    5.124452, 4.990388, 4.862373, 4.730089, 4.590777, 4.455877, 4.330162, 4.208968, 4.096633, 3.987746,
+    3.879942, 3.769154, 3.664715, 3.560094, 3.459737, 3.365001, 3.276721, 3.187763, 3.10249, 3.017899,
+    2.935579, 2.851817, 2.781845, 2.708274, 2.626648, 2.541572, 2.465594, 2.392517, 2.327233, 2.26301,
+    2.208639, 2.149153, 2.080528, 2.015465, 1.954612, 1.900724, 1.844803, 1.790888, 1.737921, 1.693259,
+    1.651538, 1.609864, 1.566618, 1.524522, 1.483163, 1.43939, 1.392387, 1.345116, 1.303807, 1.259114,
+    1.221084,
+];
+
+/// CIE illuminant LED-V2 (phosphor-converted violet LED, 4070 K).
+#[rustfmt::skip]
+pub const LED_V2: [f32; LEN] = [
+    0.01308879, 0.01758342, 0.02362148, 0.03173297, 0.04262992, 0.05726882, 0.07693464, 0.1033536, 0.1388447, 0.1865233,
+    0.2561174, 0.3471751, 0.4676484, 0.6300921, 0.8505917, 1.146033, 1.535928, 2.050216, 2.696339, 3.501239,
+    4.465602, 5.608868, 6.951697, 8.466047, 10.15522, 11.9792, 13.8419, 15.62897, 17.24967, 18.69311,
+    19.88877, 20.71105, 21.12815, 21.17903, 20.87047, 20.24128, 19.32581, 18.21588, 16.97734, 15.67427,
+    14.38583, 13.16428, 12.00734, 10.91531, 9.888746, 8.948695, 8.129591, 7.420957, 6.789179, 6.240241,
+    5.775268, 5.397005, 5.102499, 4.881243, 4.739394, 4.654226, 4.609087, 4.610198, 4.655939, 4.740426,
+    4.860917, 4.994248, 5.161796, 5.356516, 5.565165, 5.783044, 6.009529, 6.248662, 6.497084, 6.737401,
+    6.970731, 7.20851, 7.437789, 7.667134, 7.884349, 8.104847, 8.314072, 8.514863, 8.688158, 8.874615,
+    9.047602, 9.217301, 9.378496, 9.525523, 9.680107, 9.821335, 9.975129, 10.11231, 10.25185, 10.37968,
+    10.52125, 10.6719, 10.81334, 10.96404, 11.07835, 11.20739, 11.32945, 11.47386, 11.62447, 11.74145,
+    11.86679, 11.99562, 12.13546, 12.27924, 12.39021, 12.49673, 12.59885, 12.70555, 12.80749, 12.89193,
+    12.97953, 13.04684, 13.10618, 13.15805, 13.19915, 13.23309, 13.25991, 13.2805, 13.29432, 13.30029,
+    13.30164, 13.29173, 13.27382, 13.25552, 13.23355, 13.21374, 13.18524, 13.15808, 13.1274, 13.09066,
+    13.052, 13.00799, 12.97074, 12.93055, 12.90486, 12.8777, 12.84629, 12.81533, 12.7826, 12.75873,
+    12.74087, 12.72489, 12.70866, 12.69357, 12.68377, 12.67681, 12.67645, 12.6803, 12.68084, 12.68693,
+    12.69622, 12.70383, 12.71818, 12.73024, 12.74966, 12.77483, 12.80164, 12.8297, 12.858, 12.88662,
+    12.91071, 12.93071, 12.95903, 12.98924, 13.01613, 13.04047, 13.06738, 13.09228, 13.11203, 13.13477,
+    13.16254, 13.1827, 13.20208, 13.2232, 13.24161, 13.26176, 13.28407, 13.30568, 13.31994, 13.33529,
+    13.35142, 13.36368, 13.37631, 13.3883, 13.40339, 13.42095, 13.44366, 13.4661, 13.48026, 13.49849,
+    13.51833, 13.5351, 13.54907, 13.57515, 13.60325, 13.64007, 13.67499, 13.71019, 13.74527, 13.78386,
+    13.82946, 13.87735, 13.92609, 13.97507, 14.02272, 14.07458, 14.13326, 14.20016, 14.26504, 14.33786,
+    14.41584, 14.49568, 14.56518, 14.64282, 14.72404, 14.82023, 14.91072, 14.99754, 15.08056, 15.17124,
+    15.26211, 15.33014, 15.39608, 15.47261, 15.55786, 15.63845, 15.71599, 15.79407, 15.87997, 15.95984,
+    16.03005, 16.10377, 16.18232, 16.25756, 16.32507, 16.39588, 16.45684, 16.52043, 16.57031, 16.62028,
+    16.66694, 16.72391, 16.77163, 16.81555, 16.8485, 16.88249, 16.90779, 16.92134, 16.92756, 16.92735,
+    16.93268, 16.92989, 16.91072, 16.89579, 16.87192, 16.85314, 16.82507, 16.78912, 16.74454, 16.69124,
+    16.62082, 16.55068, 16.48246, 16.4035, 16.31921, 16.23376, 16.14278, 16.03986, 15.93488, 15.83641,
+    15.72477, 15.60589, 15.48026, 15.34666, 15.20794, 15.06933, 14.93195, 14.78042, 14.62228, 14.46284,
+    14.30676, 14.13857, 13.97001, 13.79603, 13.62375, 13.44934, 13.27162, 13.09562, 12.92096, 12.75332,
+    12.5725, 12.37827, 12.18524, 12.0086, 11.82579, 11.63871, 11.45097, 11.26127, 11.0725, 10.8796,
+    10.69255, 10.50712, 10.31774, 10.13177, 9.95756, 9.778594, 9.592991, 9.409048, 9.226226, 9.045842,
+    8.868246, 8.69779, 8.51905, 8.339754, 8.163266, 7.996002, 7.82798, 7.659601, 7.497086, 7.331944,
+    7.170585, 7.002705, 6.838227, 6.68254, 6.526367, 6.36712, 6.210847, 6.061709, 5.919485, 5.78735,
+    5.65228, 5.516814, 5.388005, 5.26378, 5.139834, 5.013156, 4.88654, 4.762009, 4.64935, 4.539206,
+    4.423874, 4.303866, 4.191077, 4.078761, 3.97585, 3.876612, 3.774532, 3.668859, 3.570285, 3.480123,
+    3.390929, 3.297451, 3.20352, 3.115458, 3.033681, 2.95223, 2.872626, 2.794331, 2.717428, 2.645286,
+    2.57614, 2.505394, 2.434119, 2.361958, 2.296491, 2.235917, 2.178628, 2.125727, 2.068086, 2.005492,
+    1.947295, 1.892414, 1.84261, 1.786162, 1.737192, 1.695801, 1.651541, 1.611196, 1.567353, 1.517748,
+    1.467792, 1.432893, 1.39971, 1.360281, 1.322843, 1.295508, 1.262814, 1.222916, 1.185215, 1.148504,
+    1.101759, 1.060052, 1.035873, 1.018513, 0.9904851, 0.9645941, 0.9421688, 0.9165038, 0.888728, 0.8620047,
+    0.8382154,
+];
+
+/// CIE daylight components S0, S1, S2, 5nm from 380nm to 780nm.
+#[rustfmt::skip]
+pub const DAYLIGHT_S: [[f32; 3]; LEN_5NM] = [
+    [63.4, 38.5, 3.0], [64.6, 36.75, 2.1], [65.8, 35.0, 1.2], [80.3, 39.2, 0.05], [94.8, 43.4, -1.1], [99.8, 44.85, -0.8],
+    [104.8, 46.3, -0.5], [105.35, 45.1, -0.6], [105.9, 43.9, -0.7], [101.35, 40.5, -0.95], [96.8, 37.1, -1.2], [105.35, 36.9, -1.9],
+    [113.9, 36.7, -2.6], [119.75, 36.3, -2.75], [125.6, 35.9, -2.9], [125.55, 34.25, -2.85], [125.5, 32.6, -2.8], [123.4, 30.25, -2.7],
+    [121.3, 27.9, -2.6], [121.3, 26.1, -2.6], [121.3, 24.3, -2.6], [117.4, 22.2, -2.2], [113.5, 20.1, -1.8], [113.3, 18.15, -1.65],
+    [113.1, 16.2, -1.5], [111.95, 14.7, -1.4], [110.8, 13.2, -1.3], [108.65, 10.9, -1.25], [106.5, 8.6, -1.2], [107.65, 7.35, -1.1],
+    [108.8, 6.1, -1.0], [107.05, 5.15, -0.75], [105.3, 4.2, -0.5], [104.85, 3.05, -0.4], [104.4, 1.9, -0.3], [102.2, 0.95, -0.15],
+    [100.0, 0.0, 0.0], [98.0, -0.8, 0.1], [96.0, -1.6, 0.2], [95.55, -2.55, 0.35], [95.1, -3.5, 0.5], [92.1, -3.5, 1.3],
+    [89.1, -3.5, 2.1], [89.8, -4.65, 2.65], [90.5, -5.8, 3.2], [90.4, -6.5, 3.65], [90.3, -7.2, 4.1], [89.35, -7.9, 4.4],
+    [88.4, -8.6, 4.7], [86.2, -9.05, 4.9], [84.0, -9.5, 5.1], [84.55, -10.2, 5.9], [85.1, -10.9, 6.7], [83.5, -10.8, 7.0],
+    [81.9, -10.7, 7.3], [82.25, -11.35, 7.95], [82.6, -12.0, 8.6], [83.75, -13.0, 9.2], [84.9, -14.0, 9.8], [83.1, -13.8, 10.0],
+    [81.3, -13.6, 10.2], [76.6, -12.8, 9.25], [71.9, -12.0, 8.3], [73.1, -12.65, 8.95], [74.3, -13.3, 9.6], [75.35, -13.1, 9.05],
+    [76.4, -12.9, 8.5], [69.85, -11.75, 7.75], [63.3, -10.6, 7.0], [67.5, -11.1, 7.3], [71.7, -11.6, 7.6], [74.35, -11.9, 7.8],
+    [77.0, -12.2, 8.0], [71.1, -11.2, 7.35], [65.2, -10.2, 6.7], [56.45, -9.0, 5.95], [47.7, -7.8, 5.2], [58.15, -9.5, 6.3],
+    [68.6, -11.2, 7.4], [66.8, -10.8, 7.1], [65.0, -10.4, 6.8],
+];
+
+/// CIE 13.3 test color samples TCS01-TCS14 (spectral radiance factors),
+/// 5nm from 380nm to 780nm.
+#[rustfmt::skip]
+pub const TCS: [[f32; LEN_5NM]; 14] = [
+    // TCS01: Light greyish red
+    [
+        0.219, 0.239, 0.252, 0.256, 0.256, 0.254, 0.252, 0.248, 0.244, 0.24, 0.237, 0.232, 0.23, 0.226,
+        0.225, 0.222, 0.22, 0.218, 0.216, 0.214, 0.214, 0.214, 0.216, 0.218, 0.223, 0.225, 0.226, 0.226,
+        0.225, 0.225, 0.227, 0.23, 0.236, 0.245, 0.253, 0.262, 0.272, 0.283, 0.298, 0.318, 0.341, 0.367,
+        0.39, 0.409, 0.424, 0.435, 0.442, 0.448, 0.45, 0.451, 0.451, 0.451, 0.451, 0.451, 0.45, 0.45,
+        0.451, 0.451, 0.453, 0.454, 0.455, 0.457, 0.458, 0.46, 0.462, 0.463, 0.464, 0.465, 0.466, 0.466,
+        0.466, 0.466, 0.467, 0.467, 0.467, 0.467, 0.467, 0.467, 0.467, 0.467, 0.467,
+    ],
+    // TCS02: Dark greyish yellow
+    [
+        0.07, 0.079, 0.089, 0.101, 0.111, 0.116, 0.118, 0.12, 0.121, 0.122, 0.122, 0.122, 0.123, 0.124,
+        0.127, 0.128, 0.131, 0.134, 0.138, 0.143, 0.15, 0.159, 0.174, 0.19, 0.207, 0.225, 0.242, 0.253,
+        0.26, 0.264, 0.267, 0.269, 0.272, 0.276, 0.282, 0.289, 0.299, 0.309, 0.322, 0.329, 0.335, 0.339,
+        0.341, 0.341, 0.342, 0.342, 0.342, 0.341, 0.341, 0.339, 0.339, 0.338, 0.338, 0.337, 0.336, 0.335,
+        0.334, 0.332, 0.332, 0.331, 0.331, 0.33, 0.329, 0.328, 0.328, 0.327, 0.326, 0.325, 0.324, 0.324,
+        0.324, 0.323, 0.322, 0.321, 0.32, 0.318, 0.316, 0.315, 0.315, 0.314, 0.314,
+    ],
+    // TCS03: Strong yellow green
+    [
+        0.065, 0.068, 0.07, 0.072, 0.073, 0.073, 0.074, 0.074, 0.074, 0.073, 0.073, 0.073, 0.073, 0.073,
+        0.074, 0.075, 0.077, 0.08, 0.085, 0.094, 0.109, 0.126, 0.148, 0.172, 0.198, 0.221, 0.241, 0.26,
+        0.278, 0.302, 0.339, 0.37, 0.392, 0.399, 0.4, 0.393, 0.38, 0.365, 0.349, 0.332, 0.315, 0.299,
+        0.285, 0.272, 0.264, 0.257, 0.252, 0.247, 0.241, 0.235, 0.229, 0.224, 0.22, 0.217, 0.216, 0.216,
+        0.219, 0.224, 0.23, 0.238, 0.251, 0.269, 0.288, 0.312, 0.34, 0.366, 0.39, 0.412, 0.431, 0.447,
+        0.46, 0.472, 0.481, 0.488, 0.493, 0.497, 0.5, 0.502, 0.505, 0.51, 0.516,
+    ],
+    // TCS04: Moderate yellowish green
+    [
+        0.074, 0.083, 0.093, 0.105, 0.116, 0.121, 0.124, 0.126, 0.128, 0.131, 0.135, 0.139, 0.144, 0.151,
+        0.161, 0.172, 0.186, 0.205, 0.229, 0.254, 0.281, 0.308, 0.332, 0.352, 0.37, 0.383, 0.39, 0.394,
+        0.395, 0.392, 0.385, 0.377, 0.367, 0.354, 0.341, 0.327, 0.312, 0.296, 0.28, 0.263, 0.247, 0.229,
+        0.214, 0.198, 0.185, 0.175, 0.169, 0.164, 0.16, 0.156, 0.154, 0.152, 0.151, 0.149, 0.148, 0.148,
+        0.148, 0.149, 0.151, 0.154, 0.158, 0.162, 0.165, 0.168, 0.17, 0.171, 0.17, 0.168, 0.166, 0.164,
+        0.164, 0.165, 0.168, 0.172, 0.177, 0.181, 0.185, 0.189, 0.192, 0.194, 0.197,
+    ],
+    // TCS05: Light bluish green
+    [
+        0.295, 0.306, 0.31, 0.312, 0.313, 0.315, 0.319, 0.322, 0.326, 0.33, 0.334, 0.339, 0.346, 0.352,
+        0.36, 0.369, 0.381, 0.394, 0.403, 0.41, 0.415, 0.418, 0.419, 0.417, 0.413, 0.409, 0.403, 0.396,
+        0.389, 0.381, 0.372, 0.363, 0.353, 0.342, 0.331, 0.32, 0.308, 0.296, 0.284, 0.271, 0.26, 0.247,
+        0.232, 0.22, 0.21, 0.2, 0.194, 0.189, 0.185, 0.183, 0.18, 0.177, 0.176, 0.175, 0.175, 0.175,
+        0.175, 0.177, 0.18, 0.183, 0.186, 0.189, 0.192, 0.195, 0.199, 0.2, 0.199, 0.198, 0.196, 0.195,
+        0.195, 0.196, 0.197, 0.2, 0.203, 0.205, 0.208, 0.212, 0.215, 0.217, 0.219,
+    ],
+    // TCS06: Light blue
+    [
+        0.151, 0.203, 0.265, 0.339, 0.41, 0.464, 0.492, 0.508, 0.517, 0.524, 0.531, 0.538, 0.544, 0.551,
+        0.556, 0.556, 0.554, 0.549, 0.541, 0.531, 0.519, 0.504, 0.488, 0.469, 0.45, 0.431, 0.414, 0.395,
+        0.377, 0.358, 0.341, 0.325, 0.309, 0.293, 0.279, 0.265, 0.253, 0.241, 0.234, 0.227, 0.225, 0.222,
+        0.221, 0.22, 0.22, 0.22, 0.22, 0.22, 0.223, 0.227, 0.233, 0.239, 0.244, 0.251, 0.258, 0.263,
+        0.268, 0.273, 0.278, 0.281, 0.283, 0.286, 0.291, 0.296, 0.302, 0.313, 0.325, 0.338, 0.351, 0.364,
+        0.376, 0.389, 0.401, 0.413, 0.425, 0.436, 0.447, 0.458, 0.469, 0.477, 0.485,
+    ],
+    // TCS07: Light violet
+    [
+        0.378, 0.459, 0.524, 0.546, 0.551, 0.555, 0.559, 0.56, 0.561, 0.558, 0.556, 0.551, 0.544, 0.535,
+        0.522, 0.506, 0.488, 0.469, 0.448, 0.429, 0.408, 0.385, 0.363, 0.341, 0.324, 0.311, 0.301, 0.291,
+        0.283, 0.273, 0.265, 0.26, 0.257, 0.257, 0.259, 0.26, 0.26, 0.258, 0.256, 0.254, 0.254, 0.259,
+        0.27, 0.284, 0.302, 0.324, 0.344, 0.362, 0.377, 0.389, 0.4, 0.41, 0.42, 0.429, 0.438, 0.445,
+        0.452, 0.457, 0.462, 0.466, 0.468, 0.47, 0.473, 0.477, 0.483, 0.489, 0.496, 0.503, 0.511, 0.518,
+        0.525, 0.532, 0.539, 0.546, 0.553, 0.559, 0.565, 0.57, 0.575, 0.578, 0.581,
+    ],
+    // TCS08: Light reddish purple
+    [
+        0.104, 0.129, 0.17, 0.24, 0.319, 0.416, 0.462, 0.482, 0.49, 0.488, 0.482, 0.473, 0.462, 0.45,
+        0.439, 0.426, 0.413, 0.397, 0.382, 0.366, 0.352, 0.337, 0.325, 0.31, 0.299, 0.289, 0.283, 0.276,
+        0.27, 0.262, 0.256, 0.251, 0.25, 0.251, 0.254, 0.258, 0.264, 0.269, 0.272, 0.274, 0.278, 0.284,
+        0.295, 0.316, 0.348, 0.384, 0.434, 0.482, 0.528, 0.568, 0.604, 0.629, 0.648, 0.663, 0.676, 0.685,
+        0.693, 0.7, 0.705, 0.709, 0.712, 0.715, 0.717, 0.719, 0.721, 0.72, 0.719, 0.722, 0.725, 0.727,
+        0.729, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73,
+    ],
+    // TCS09: Strong red
+    [
+        0.066, 0.062, 0.058, 0.055, 0.052, 0.052, 0.051, 0.05, 0.05, 0.049, 0.048, 0.047, 0.046, 0.044,
+        0.042, 0.041, 0.038, 0.035, 0.033, 0.031, 0.03, 0.029, 0.028, 0.028, 0.028, 0.029, 0.03, 0.03,
+        0.031, 0.031, 0.032, 0.032, 0.033, 0.034, 0.035, 0.037, 0.041, 0.044, 0.048, 0.052, 0.06, 0.076,
+        0.102, 0.136, 0.19, 0.256, 0.336, 0.418, 0.505, 0.581, 0.641, 0.682, 0.717, 0.74, 0.758, 0.77,
+        0.781, 0.79, 0.797, 0.803, 0.809, 0.814, 0.819, 0.824, 0.828, 0.83, 0.831, 0.833, 0.835, 0.836,
+        0.836, 0.837, 0.838, 0.839, 0.839, 0.839, 0.839, 0.839, 0.839, 0.839, 0.839,
+    ],
+    // TCS10: Strong yellow
+    [
+        0.05, 0.054, 0.059, 0.063, 0.066, 0.067, 0.068, 0.069, 0.069, 0.07, 0.072, 0.073, 0.076, 0.078,
+        0.083, 0.088, 0.095, 0.103, 0.113, 0.125, 0.142, 0.162, 0.189, 0.219, 0.262, 0.305, 0.365, 0.416,
+        0.465, 0.509, 0.546, 0.581, 0.61, 0.634, 0.653, 0.666, 0.678, 0.687, 0.693, 0.698, 0.701, 0.704,
+        0.705, 0.705, 0.706, 0.707, 0.707, 0.707, 0.708, 0.708, 0.71, 0.711, 0.712, 0.714, 0.716, 0.718,
+        0.72, 0.722, 0.725, 0.729, 0.731, 0.735, 0.739, 0.742, 0.746, 0.748, 0.749, 0.751, 0.753, 0.754,
+        0.755, 0.755, 0.755, 0.755, 0.756, 0.757, 0.758, 0.759, 0.759, 0.759, 0.759,
+    ],
+    // TCS11: Strong green
+    [
+        0.111, 0.121, 0.127, 0.129, 0.127, 0.121, 0.116, 0.112, 0.108, 0.105, 0.104, 0.104, 0.105, 0.106,
+        0.11, 0.115, 0.123, 0.134, 0.148, 0.167, 0.192, 0.219, 0.252, 0.291, 0.325, 0.347, 0.356, 0.353,
+        0.346, 0.333, 0.314, 0.294, 0.271, 0.248, 0.227, 0.206, 0.188, 0.17, 0.153, 0.138, 0.125, 0.114,
+        0.106, 0.1, 0.096, 0.092, 0.09, 0.087, 0.085, 0.082, 0.08, 0.079, 0.078, 0.078, 0.078, 0.078,
+        0.081, 0.083, 0.088, 0.093, 0.102, 0.112, 0.125, 0.141, 0.161, 0.182, 0.203, 0.223, 0.242, 0.257,
+        0.27, 0.282, 0.292, 0.302, 0.31, 0.314, 0.317, 0.323, 0.33, 0.334, 0.338,
+    ],
+    // TCS12: Strong blue
+    [
+        0.12, 0.103, 0.09, 0.082, 0.076, 0.068, 0.064, 0.065, 0.075, 0.093, 0.123, 0.16, 0.207, 0.256,
+        0.3, 0.331, 0.346, 0.347, 0.341, 0.328, 0.307, 0.282, 0.257, 0.23, 0.204, 0.178, 0.154, 0.129,
+        0.109, 0.09, 0.075, 0.062, 0.051, 0.041, 0.035, 0.029, 0.025, 0.022, 0.019, 0.017, 0.017, 0.017,
+        0.016, 0.016, 0.016, 0.016, 0.016, 0.016, 0.016, 0.016, 0.018, 0.018, 0.018, 0.018, 0.019, 0.02,
+        0.023, 0.024, 0.026, 0.03, 0.035, 0.043, 0.056, 0.074, 0.097, 0.128, 0.166, 0.21, 0.257, 0.305,
+        0.354, 0.401, 0.446, 0.485, 0.52, 0.551, 0.577, 0.599, 0.618, 0.633, 0.645,
+    ],
+    // TCS13: Light yellowish pink (complexion)
+    [
+        0.104, 0.127, 0.161, 0.211, 0.264, 0.313, 0.341, 0.352, 0.359, 0.361, 0.364, 0.365, 0.367, 0.369,
+        0.372, 0.374, 0.376, 0.379, 0.384, 0.389, 0.397, 0.405, 0.416, 0.429, 0.443, 0.454, 0.461, 0.466,
+        0.469, 0.471, 0.474, 0.476, 0.483, 0.49, 0.506, 0.526, 0.553, 0.582, 0.618, 0.651, 0.68, 0.701,
+        0.717, 0.729, 0.736, 0.742, 0.745, 0.747, 0.748, 0.748, 0.748, 0.748, 0.748, 0.748, 0.748, 0.748,
+        0.747, 0.747, 0.747, 0.747, 0.747, 0.747, 0.747, 0.746, 0.746, 0.746, 0.745, 0.744, 0.743, 0.744,
+        0.745, 0.748, 0.75, 0.75, 0.749, 0.748, 0.748, 0.747, 0.747, 0.747, 0.747,
+    ],
+    // TCS14: Moderate olive green (leaf)
+    [
+        0.036, 0.036, 0.037, 0.038, 0.039, 0.039, 0.04, 0.041, 0.042, 0.042, 0.043, 0.044, 0.044, 0.045,
+        0.045, 0.046, 0.047, 0.048, 0.05, 0.052, 0.055, 0.057, 0.062, 0.067, 0.075, 0.083, 0.092, 0.1,
+        0.108, 0.121, 0.133, 0.142, 0.15, 0.154, 0.155, 0.152, 0.147, 0.14, 0.133, 0.125, 0.118, 0.112,
+        0.106, 0.101, 0.098, 0.095, 0.093, 0.09, 0.089, 0.087, 0.086, 0.085, 0.084, 0.084, 0.084, 0.084,
+        0.085, 0.087, 0.092, 0.096, 0.102, 0.11, 0.123, 0.137, 0.152, 0.169, 0.188, 0.207, 0.226, 0.243,
+        0.26, 0.277, 0.294, 0.31, 0.325, 0.339, 0.353, 0.366, 0.379, 0.39, 0.399,
+    ],
+];
+
+/// CIE 1951 scotopic luminous efficiency V'(λ), interpolated linearly from
+/// the 10nm CIE table. CIE S 026 uses it as the rhodopic action spectrum.
+#[rustfmt::skip]
+pub const SCOTOPIC: [f32; LEN] = [
+    0.000589, 0.000751, 0.000913, 0.001075, 0.001237, 0.001399, 0.001561, 0.001723, 0.001885, 0.002047,
+    0.002209, 0.0029171, 0.0036252, 0.0043333, 0.0050414, 0.0057495, 0.0064576, 0.0071657, 0.0078738, 0.0085819,
+    0.00929, 0.011845, 0.0144, 0.016955, 0.01951, 0.022065, 0.02462, 0.027175, 0.02973, 0.032285,
+    0.03484, 0.041016, 0.047192, 0.053368, 0.059544, 0.06572, 0.071896, 0.078072, 0.084248, 0.090424,
+    0.0966, 0.10692, 0.11724, 0.12756, 0.13788, 0.1482, 0.15852, 0.16884, 0.17916, 0.18948,
+    0.1998, 0.21263, 0.22546, 0.23829, 0.25112, 0.26395, 0.27678, 0.28961, 0.30244, 0.31527,
+    0.3281, 0.34079, 0.35348, 0.36617, 0.37886, 0.39155, 0.40424, 0.41693, 0.42962, 0.44231,
+    0.455, 0.4662, 0.4774, 0.4886, 0.4998, 0.511, 0.5222, 0.5334, 0.5446, 0.5558,
+    0.567, 0.5779, 0.5888, 0.5997, 0.6106, 0.6215, 0.6324, 0.6433, 0.6542, 0.6651,
+    0.676, 0.6877, 0.6994, 0.7111, 0.7228, 0.7345, 0.7462, 0.7579, 0.7696, 0.7813,
+    0.793, 0.8041, 0.8152, 0.8263, 0.8374, 0.8485, 0.8596, 0.8707, 0.8818, 0.8929,
+    0.904, 0.9118, 0.9196, 0.9274, 0.9352, 0.943, 0.9508, 0.9586, 0.9664, 0.9742,
+    0.982, 0.9835, 0.985, 0.9865, 0.988, 0.9895, 0.991, 0.9925, 0.994, 0.9955,
+    0.997, 0.9908, 0.9846, 0.9784, 0.9722, 0.966, 0.9598, 0.9536, 0.9474, 0.9412,
+    0.935, 0.9226, 0.9102, 0.8978, 0.8854, 0.873, 0.8606, 0.8482, 0.8358, 0.8234,
+    0.811, 0.7949, 0.7788, 0.7627, 0.7466, 0.7305, 0.7144, 0.6983, 0.6822, 0.6661,
+    0.65, 0.6331, 0.6162, 0.5993, 0.5824, 0.5655, 0.5486, 0.5317, 0.5148, 0.4979,
+    0.481, 0.46578, 0.45056, 0.43534, 0.42012, 0.4049, 0.38968, 0.37446, 0.35924, 0.34402,
+    0.3288, 0.31668, 0.30456, 0.29244, 0.28032, 0.2682, 0.25608, 0.24396, 0.23184, 0.21972,
+    0.2076, 0.19896, 0.19032, 0.18168, 0.17304, 0.1644, 0.15576, 0.14712, 0.13848, 0.12984,
+    0.1212, 0.11563, 0.11006, 0.10449, 0.09892, 0.09335, 0.08778, 0.08221, 0.07664, 0.07107,
+    0.0655, 0.062265, 0.05903, 0.055795, 0.05256, 0.049325, 0.04609, 0.042855, 0.03962, 0.036385,
+    0.03315, 0.031428, 0.029706, 0.027984, 0.026262, 0.02454, 0.022818, 0.021096, 0.019374, 0.017652,
+    0.01593, 0.015074, 0.014218, 0.013362, 0.012506, 0.01165, 0.010794, 0.009938, 0.009082, 0.008226,
+    0.00737, 0.0069665, 0.006563, 0.0061595, 0.005756, 0.0053525, 0.004949, 0.0045455, 0.004142, 0.0037385,
+    0.003335, 0.0031512, 0.0029674, 0.0027836, 0.0025998, 0.002416, 0.0022322, 0.0020484, 0.0018646, 0.0016808,
+    0.001497, 0.001415, 0.001333, 0.001251, 0.001169, 0.001087, 0.001005, 0.000923, 0.000841, 0.000759,
+    0.000677, 0.00064059, 0.00060418, 0.00056777, 0.00053136, 0.00049495, 0.00045854, 0.00042213, 0.00038572, 0.00034931,
+    0.0003129, 0.00029641, 0.00027992, 0.00026343, 0.00024694, 0.00023045, 0.00021396, 0.00019747, 0.00018098, 0.00016449,
+    0.000148, 0.00014035, 0.0001327, 0.00012505, 0.0001174, 0.00010975, 0.0001021, 9.4450e-05, 8.6800e-05, 7.9150e-05,
+    7.1500e-05, 6.7883e-05, 6.4266e-05, 6.0649e-05, 5.7032e-05, 5.3415e-05, 4.9798e-05, 4.6181e-05, 4.2564e-05, 3.8947e-05,
+    3.5330e-05, 3.3577e-05, 3.1824e-05, 3.0071e-05, 2.8318e-05, 2.6565e-05, 2.4812e-05, 2.3059e-05, 2.1306e-05, 1.9553e-05,
+    1.7800e-05, 1.6934e-05, 1.6068e-05, 1.5202e-05, 1.4336e-05, 1.3470e-05, 1.2604e-05, 1.1738e-05, 1.0872e-05, 1.0006e-05,
+    9.1400e-06, 8.7040e-06, 8.2680e-06, 7.8320e-06, 7.3960e-06, 6.9600e-06, 6.5240e-06, 6.0880e-06, 5.6520e-06, 5.2160e-06,
+    4.7800e-06, 4.5566e-06, 4.3332e-06, 4.1098e-06, 3.8864e-06, 3.6630e-06, 3.4396e-06, 3.2162e-06, 2.9928e-06, 2.7694e-06,
+    2.5460e-06, 2.4293e-06, 2.3126e-06, 2.1959e-06, 2.0792e-06, 1.9625e-06, 1.8458e-06, 1.7291e-06, 1.6124e-06, 1.4957e-06,
+    1.3790e-06, 1.3171e-06, 1.2552e-06, 1.1933e-06, 1.1314e-06, 1.0695e-06, 1.0076e-06, 9.4570e-07, 8.8380e-07, 8.2190e-07,
+    7.6000e-07, 7.2650e-07, 6.9300e-07, 6.5950e-07, 6.2600e-07, 5.9250e-07, 5.5900e-07, 5.2550e-07, 4.9200e-07, 4.5850e-07,
+    4.2500e-07, 4.0660e-07, 3.8820e-07, 3.6980e-07, 3.5140e-07, 3.3300e-07, 3.1460e-07, 2.9620e-07, 2.7780e-07, 2.5940e-07,
+    2.4100e-07, 2.3080e-07, 2.2060e-07, 2.1040e-07, 2.0020e-07, 1.9000e-07, 1.7980e-07, 1.6960e-07, 1.5940e-07, 1.4920e-07,
+    1.3900e-07,
+];
+
+/// Melanopic action spectrum s_mel(λ), normalized to a peak of 1.
+/// Reconstructed as in Lucas et al. (2014): a Govardovskii A1 opsin template
+/// filtered by the lens. The template (λmax = 476.4nm) and the lens
+/// density (0.52 × [`CIE2006_OCULAR`], extrapolated below 390nm) are
+/// fitted to the CIE S 026 peak near 490nm and D65 efficacy of 1.3262 mW/lm.
+/// It approximates the tabulated S 026 function; melanopic quantities of
+/// broadband sources agree to a few percent.
+#[rustfmt::skip]
+pub const MELANOPIC: [f32; LEN] = [
+    0.00464554, 0.00511444, 0.00563536, 0.0062147, 0.0068597, 0.00757855, 0.00838051, 0.00927607, 0.0102771, 0.011397,
+    0.0126509, 0.0140561, 0.0156332, 0.0174008, 0.0193791, 0.0215892, 0.0240532, 0.0267941, 0.0298349, 0.0331986,
+    0.0369075, 0.0409829, 0.0454472, 0.050322, 0.055627, 0.0613787, 0.0675898, 0.0742656, 0.0814041, 0.088995,
+    0.0970188, 0.105454, 0.114306, 0.123595, 0.133343, 0.143585, 0.154343, 0.165574, 0.177201, 0.189126,
+    0.201229, 0.213401, 0.225666, 0.238101, 0.250809, 0.263923, 0.277558, 0.291655, 0.306092, 0.320722,
+    0.335377, 0.349913, 0.364385, 0.37892, 0.393666, 0.408797, 0.424445, 0.440485, 0.456701, 0.472849,
+    0.48866, 0.503922, 0.518776, 0.533481, 0.548327, 0.563639, 0.579649, 0.596094, 0.612549, 0.628551,
+    0.643595, 0.657303, 0.669911, 0.681839, 0.693542, 0.705498, 0.718106, 0.731356, 0.745129, 0.759295,
+    0.773712, 0.788221, 0.802619, 0.816687, 0.830197, 0.842914, 0.854655, 0.865453, 0.875406, 0.884617,
+    0.893191, 0.901236, 0.908849, 0.916119, 0.923136, 0.929987, 0.936758, 0.943535, 0.950387, 0.95736,
+    0.96448, 0.971695, 0.978704, 0.985153, 0.990689, 0.994968, 0.997768, 0.999312, 0.999941, 1.0,
+    0.999836, 0.999672, 0.99922, 0.998069, 0.995812, 0.99205, 0.986489, 0.979235, 0.970508, 0.96055,
+    0.949611, 0.937911, 0.925494, 0.912361, 0.898517, 0.883962, 0.868721, 0.852877, 0.836508, 0.81967,
+    0.802406, 0.784754, 0.766792, 0.748614, 0.730318, 0.712004, 0.693733, 0.67543, 0.656989, 0.638316,
+    0.619325, 0.599975, 0.580357, 0.560587, 0.540776, 0.521025, 0.501423, 0.482036, 0.462918, 0.444119,
+    0.425679, 0.407639, 0.390032, 0.372888, 0.356234, 0.34009, 0.324459, 0.309296, 0.294547, 0.280169,
+    0.266129, 0.252408, 0.239021, 0.225987, 0.213364, 0.201222, 0.189561, 0.178377, 0.167666, 0.157423,
+    0.147643, 0.138319, 0.129442, 0.121004, 0.112995, 0.105406, 0.0982251, 0.0914404, 0.0850397, 0.0790104,
+    0.0733391, 0.0680123, 0.0630162, 0.0583368, 0.0539599, 0.0498764, 0.0460708, 0.0425178, 0.0392096, 0.0361347,
+    0.0332797, 0.0306319, 0.0281788, 0.0259082, 0.0238087, 0.0218691, 0.0200788, 0.0184276, 0.0169059, 0.0155045,
+    0.0142148, 0.0130287, 0.0119385, 0.0109369, 0.0100174, 0.00917348, 0.00839936, 0.00768953, 0.0070389, 0.00644272,
+    0.00589662, 0.00539653, 0.00493867, 0.00451959, 0.00413605, 0.00378512, 0.00346407, 0.00317038, 0.00290175, 0.00265606,
+    0.00243136, 0.00222588, 0.00203797, 0.00186613, 0.00170898, 0.00156527, 0.00143383, 0.00131363, 0.00120368, 0.0011031,
+    0.0010111, 0.000926927, 0.00084991, 0.000779432, 0.000714929, 0.000655889, 0.00060184, 0.000552354, 0.000507038, 0.000465534,
+    0.000427516, 0.000392684, 0.000360767, 0.000331514, 0.000304699, 0.000280114, 0.00025757, 0.000236891, 0.000217922, 0.000200516,
+    0.000184542, 0.000169879, 0.000156416, 0.000144053, 0.000132697, 0.000122265, 0.000112678, 0.000103867, 9.5767e-05, 8.8319e-05,
+    8.1469e-05, 7.5168e-05, 6.9370e-05, 6.4034e-05, 5.9121e-05, 5.4599e-05, 5.0434e-05, 4.6597e-05, 4.3062e-05, 3.9804e-05,
+    3.6802e-05, 3.4033e-05, 3.1480e-05, 2.9125e-05, 2.6953e-05, 2.4948e-05, 2.3097e-05, 2.1389e-05, 1.9811e-05, 1.8354e-05,
+    1.7008e-05, 1.5764e-05, 1.4614e-05, 1.3551e-05, 1.2568e-05, 1.1660e-05, 1.0819e-05, 1.0041e-05, 9.3207e-06, 8.6542e-06,
+    8.0371e-06, 7.4656e-06, 6.9363e-06, 6.4458e-06, 5.9914e-06, 5.5701e-06, 5.1796e-06, 4.8174e-06, 4.4816e-06, 4.1700e-06,
+    3.8809e-06, 3.6126e-06, 3.3635e-06, 3.1323e-06, 2.9175e-06, 2.7181e-06, 2.5328e-06, 2.3606e-06, 2.2005e-06, 2.0517e-06,
+    1.9134e-06, 1.7848e-06, 1.6651e-06, 1.5538e-06, 1.4501e-06, 1.3537e-06, 1.2640e-06, 1.1804e-06, 1.1025e-06, 1.0300e-06,
+    9.6246e-07, 8.9951e-07, 8.4084e-07, 7.8614e-07, 7.3515e-07, 6.8759e-07, 6.4323e-07, 6.0184e-07, 5.6322e-07, 5.2718e-07,
+    4.9354e-07, 4.6213e-07, 4.3279e-07, 4.0540e-07, 3.7980e-07, 3.5589e-07, 3.3355e-07, 3.1266e-07, 2.9313e-07, 2.7487e-07,
+    2.5780e-07, 2.4183e-07, 2.2689e-07, 2.1291e-07, 1.9982e-07, 1.8758e-07, 1.7611e-07, 1.6537e-07, 1.5532e-07, 1.4590e-07,
+    1.3707e-07, 1.2881e-07, 1.2106e-07, 1.1379e-07, 1.0698e-07, 1.0060e-07, 9.4609e-08, 8.8991e-08, 8.3722e-08, 7.8777e-08,
+    7.4136e-08, 6.9780e-08, 6.5691e-08, 6.1852e-08, 5.8246e-08, 5.4859e-08, 5.1678e-08, 4.8689e-08, 4.5880e-08, 4.3240e-08,
+    4.0758e-08, 3.8425e-08, 3.6231e-08, 3.4168e-08, 3.2227e-08, 3.0401e-08, 2.8683e-08, 2.7066e-08, 2.5544e-08, 2.4112e-08,
+    2.2763e-08, 2.1493e-08, 2.0297e-08, 1.9170e-08, 1.8109e-08, 1.7109e-08, 1.6166e-08, 1.5278e-08, 1.4441e-08, 1.3651e-08,
+    1.2907e-08, 1.2205e-08, 1.1542e-08, 1.0918e-08, 1.0328e-08, 9.7722e-09, 9.2472e-09, 8.7518e-09, 8.2840e-09, 7.8424e-09,
+    7.4253e-09,
+];
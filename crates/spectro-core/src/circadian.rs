@@ -0,0 +1,349 @@
+//! CIE S 026 α-opic Quantities
+//!
+//! Non-visual effects of light, such as circadian entrainment and melatonin
+//! suppression, are driven mainly by the melanopsin-containing retinal
+//! ganglion cells. CIE S 026:2018 characterizes a light exposure by the
+//! irradiance weighted with each of the five photoreceptor action spectra:
+//!
+//! - S-, M- and L-cone: the CIE 2006 10° cone fundamentals
+//! - rhodopic: the scotopic V'(λ)
+//! - melanopic: [`cie_data::MELANOPIC`]
+//!
+//! Each α-opic irradiance is restated as the illuminance of CIE D65 with the
+//! same effect, the α-opic equivalent daylight (D65) illuminance (EDI), or
+//! as its ratio to the photopic illuminance of the source relative to D65,
+//! the daylight efficacy ratio (DER). The WELL Building Standard v2 uses the
+//! older equivalent melanopic lux (EML), proportional to melanopic EDI.
+//!
+//! Healthcare lighting is often specified instead with the circadian light
+//! (CLA 2.0) and circadian stimulus (CS) of Rea et al. (2021). CLA adds to
+//! the melanopic response a spectrally opponent blue-yellow signal from the
+//! S-cones, active only when the light is "blue", and subtracts rod
+//! inhibition; CS maps CLA, exposure duration and field of view to the
+//! predicted nocturnal melatonin suppression, from 0 to 0.7. The model's
+//! spectra are taken here from the CIE 2006 2° S-cone fundamental and
+//! macular pigment in place of the Smith-Pokorny tables of the original, so
+//! CLA differs from published values by a few percent.
+
+use crate::photometry::KM;
+use crate::spectrum::{Quantity, SpectralData};
+use crate::{cie_data, colorimetry, Observer, Result, SpectroError};
+
+/// Melanopic EDI of one equivalent melanopic lux (WELL v2).
+pub const MEDI_PER_EML: f32 = 0.9058;
+
+/// Photoreceptor types of CIE S 026.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphaOpic {
+    SCone,
+    MCone,
+    LCone,
+    Rhodopic,
+    Melanopic,
+}
+
+impl AlphaOpic {
+    /// All five, in the order used by [`AlphaOpicMetrics`].
+    pub const ALL: [AlphaOpic; 5] = [
+        AlphaOpic::SCone,
+        AlphaOpic::MCone,
+        AlphaOpic::LCone,
+        AlphaOpic::Rhodopic,
+        AlphaOpic::Melanopic,
+    ];
+
+    /// Short name for reports.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AlphaOpic::SCone => "S-cone-opic",
+            AlphaOpic::MCone => "M-cone-opic",
+            AlphaOpic::LCone => "L-cone-opic",
+            AlphaOpic::Rhodopic => "Rhodopic",
+            AlphaOpic::Melanopic => "Melanopic",
+        }
+    }
+
+    /// Action spectrum s_α(λ), normalized to a peak of 1; zero outside
+    /// 380-780nm.
+    pub fn action_at(&self, wavelength: f32) -> f32 {
+        let lms =
+            |c: usize| colorimetry::interpolate_1nm(wavelength, |i| cie_data::CIE2006_LMS_10[i][c]);
+        match self {
+            AlphaOpic::SCone => lms(2),
+            AlphaOpic::MCone => lms(1),
+            AlphaOpic::LCone => lms(0),
+            AlphaOpic::Rhodopic => {
+                colorimetry::interpolate_1nm(wavelength, |i| cie_data::SCOTOPIC[i])
+            }
+            AlphaOpic::Melanopic => {
+                colorimetry::interpolate_1nm(wavelength, |i| cie_data::MELANOPIC[i])
+            }
+        }
+    }
+
+    /// α-opic efficacy of luminous radiation of D65, K_α,v^D65, in mW/lm.
+    pub fn d65_efficacy(&self) -> f32 {
+        match self {
+            AlphaOpic::SCone => 0.8173,
+            AlphaOpic::MCone => 1.4558,
+            AlphaOpic::LCone => 1.6289,
+            AlphaOpic::Rhodopic => 1.4497,
+            AlphaOpic::Melanopic => 1.3262,
+        }
+    }
+}
+
+/// α-opic quantities of a light source, indexed as [`AlphaOpic::ALL`].
+///
+/// Units follow the input: for irradiance, `illuminance` and `edi` are in
+/// lux and `irradiance` in mW/m²; for radiance, cd/m² and mW/(sr·m²). For
+/// relative spectra only the DERs are meaningful.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlphaOpicMetrics {
+    /// Photopic illuminance (or luminance).
+    pub illuminance: f32,
+    /// α-opic irradiance (or radiance).
+    pub irradiance: [f32; 5],
+    /// α-opic equivalent daylight (D65) illuminance.
+    pub edi: [f32; 5],
+    /// α-opic daylight efficacy ratio.
+    pub der: [f32; 5],
+}
+
+impl AlphaOpicMetrics {
+    /// Melanopic equivalent daylight (D65) illuminance.
+    pub fn melanopic_edi(&self) -> f32 {
+        self.edi[AlphaOpic::Melanopic as usize]
+    }
+
+    /// Melanopic daylight efficacy ratio.
+    pub fn melanopic_der(&self) -> f32 {
+        self.der[AlphaOpic::Melanopic as usize]
+    }
+
+    /// Equivalent melanopic lux as used by WELL v2.
+    pub fn eml(&self) -> f32 {
+        self.melanopic_edi() / MEDI_PER_EML
+    }
+}
+
+/// Calculate the α-opic quantities of an emissive spectrum.
+///
+/// # Errors
+///
+/// Returns [`SpectroError::Data`] for reflectance or transmittance, for data
+/// that fails [`SpectralData::validate`], and for spectra with no
+/// photopic content.
+pub fn calculate_alpha_opic(spd: &SpectralData) -> Result<AlphaOpicMetrics> {
+    if !spd.quantity.is_emissive() {
+        return Err(SpectroError::Data(
+            "α-opic quantities need a light source spectrum".into(),
+        ));
+    }
+    let illuminance = spd.try_to_xyz_emissive_ext(Observer::CIE1931_2)?.y;
+    if !illuminance.is_finite() || illuminance <= 0.0 {
+        return Err(SpectroError::Data(
+            "α-opic quantities need a spectrum with photopic content".into(),
+        ));
+    }
+
+    // Photopic illuminance as if the values were W/(m²·nm), so relative
+    // spectra get the right ratios and keep the scale of their Y
+    let illuminance_w = illuminance * KM / spd.quantity.luminous_efficacy();
+    let mut irradiance = [0.0f32; 5];
+    let mut edi = [0.0f32; 5];
+    let mut der = [0.0f32; 5];
+    for (i, alpha) in AlphaOpic::ALL.iter().enumerate() {
+        let sum = integrate(spd, |wl| alpha.action_at(wl));
+        // W to mW, against K_α,v in mW/lm
+        der[i] = 1000.0 * sum / illuminance_w / alpha.d65_efficacy();
+        edi[i] = der[i] * illuminance;
+        irradiance[i] = edi[i] * alpha.d65_efficacy();
+    }
+
+    Ok(AlphaOpicMetrics {
+        illuminance,
+        irradiance,
+        edi,
+        der,
+    })
+}
+
+/// CLA 2.0 scale, set so that 1000 lx of CIE A gives a CLA of about 1000
+/// without rod inhibition.
+const CLA_SCALE: f32 = 1548.0;
+/// Weight of V(λ) against the S-cones in the blue-yellow signal.
+const K_BY: f32 = 0.2616;
+/// Gain of the blue-yellow signal.
+const A_BY: f32 = 0.21;
+/// Rod inhibition when the blue-yellow channel is active, and when not.
+const A_ROD: [f32; 2] = [2.30, 1.60];
+/// S-cone weight in the rod inhibition term, likewise.
+const G_ROD: [f32; 2] = [1.00, 0.16];
+/// Scotopic irradiance at which rod inhibition saturates, in W/m².
+const ROD_SATURATION: f32 = 6.5;
+/// Peak macular pigment density of the 2° field.
+const MACULAR_DENSITY: f32 = 0.35;
+
+/// Exposure conditions for [`CircadianStimulus`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircadianExposure {
+    /// Duration of the exposure in hours.
+    pub duration: f32,
+    /// Fraction of the visual field that is lit: 1 for a uniform full field,
+    /// about 0.5 for a typical room.
+    pub field_factor: f32,
+}
+
+impl Default for CircadianExposure {
+    fn default() -> Self {
+        Self {
+            duration: 1.0,
+            field_factor: 1.0,
+        }
+    }
+}
+
+/// Circadian light and circadian stimulus (Rea et al., 2021).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircadianStimulus {
+    /// Blue-yellow opponent signal ∫S/mp − k·∫V/mp, in W/m²; positive for
+    /// "blue" light.
+    pub blue_yellow: f32,
+    /// Circadian light, CLA 2.0.
+    pub cla: f32,
+    /// Circadian stimulus, 0 to 0.7.
+    pub cs: f32,
+}
+
+/// Calculate CLA 2.0 and CS of a spectral irradiance at the eye.
+///
+/// # Errors
+///
+/// Returns [`SpectroError::Data`] unless the data is absolute spectral
+/// irradiance that passes [`SpectralData::validate`].
+pub fn calculate_circadian_stimulus(
+    spd: &SpectralData,
+    exposure: &CircadianExposure,
+) -> Result<CircadianStimulus> {
+    if spd.quantity != Quantity::Irradiance {
+        return Err(SpectroError::Data(
+            "Circadian stimulus needs spectral irradiance".into(),
+        ));
+    }
+    spd.validate()?;
+
+    let macular = |wl: f32| {
+        10f32.powf(
+            MACULAR_DENSITY * colorimetry::interpolate_1nm(wl, |i| cie_data::CIE2006_MACULAR[i]),
+        )
+    };
+    let melanopic = integrate(spd, |wl| AlphaOpic::Melanopic.action_at(wl));
+    let s_cone = integrate(spd, |wl| {
+        colorimetry::interpolate_1nm(wl, |i| cie_data::CIE2006_LMS_2[i][2]) * macular(wl)
+    });
+    let photopic = integrate(spd, |wl| {
+        colorimetry::interpolate_1nm(wl, |i| cie_data::CIE1931_2[i][1]) * macular(wl)
+    });
+    let rhodopic = integrate(spd, |wl| AlphaOpic::Rhodopic.action_at(wl));
+
+    let blue_yellow = s_cone - K_BY * photopic;
+    let yellow = usize::from(blue_yellow < 0.0);
+    let rod = A_ROD[yellow] * rhodopic / (photopic + G_ROD[yellow] * s_cone)
+        * (1.0 - (-rhodopic / ROD_SATURATION).exp());
+    let response = if yellow == 0 {
+        melanopic + A_BY * blue_yellow - rod
+    } else {
+        melanopic - rod
+    };
+    let cla = (CLA_SCALE * response).max(0.0);
+
+    let dose = exposure.duration * exposure.field_factor * cla / 355.7;
+    let cs = 0.7 * (1.0 - 1.0 / (1.0 + dose.max(0.0).powf(1.1026)));
+
+    Ok(CircadianStimulus {
+        blue_yellow,
+        cla,
+        cs,
+    })
+}
+
+/// Σ P(λ)·s(λ)·Δλ over the samples of a spectrum.
+fn integrate(spd: &SpectralData, action: impl Fn(f32) -> f32) -> f32 {
+    spd.wavelengths
+        .iter()
+        .zip(&spd.values)
+        .zip(spd.band_widths())
+        .map(|((&wl, &v), dw)| v * action(wl) * dw)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Illuminant;
+
+    fn irradiance(illuminant: Illuminant, lux: f32) -> SpectralData {
+        let wavelengths: Vec<f32> = (0..cie_data::LEN).map(|i| 380.0 + i as f32).collect();
+        let values = illuminant.spd_1nm().to_vec();
+        let spd =
+            SpectralData::from_grid(wavelengths.clone(), values, Quantity::Irradiance).unwrap();
+        let scale = lux / spd.try_to_xyz_emissive_ext(Observer::CIE1931_2).unwrap().y;
+        let values = spd.values.iter().map(|v| v * scale).collect();
+        SpectralData::from_grid(wavelengths, values, Quantity::Irradiance).unwrap()
+    }
+
+    #[test]
+    fn test_d65_alpha_opic() {
+        // D65 has a DER of 1 for every photoreceptor by definition
+        let m = calculate_alpha_opic(&irradiance(Illuminant::D65, 1000.0)).unwrap();
+        assert!((m.illuminance - 1000.0).abs() < 0.1);
+        for (alpha, der) in AlphaOpic::ALL.iter().zip(m.der) {
+            assert!((der - 1.0).abs() < 0.002, "{} {}", alpha.name(), der);
+        }
+        assert!((m.melanopic_edi() - 1000.0).abs() < 2.0);
+        assert!((m.irradiance[4] - 1326.2).abs() < 3.0);
+        assert!((m.eml() * MEDI_PER_EML - m.melanopic_edi()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_relative_and_tungsten() {
+        let abs = calculate_alpha_opic(&irradiance(Illuminant::A, 500.0)).unwrap();
+        // Incandescent light is poor at driving melanopsin
+        assert!(
+            (0.42..0.5).contains(&abs.melanopic_der()),
+            "{}",
+            abs.melanopic_der()
+        );
+
+        let mut rel = irradiance(Illuminant::A, 500.0);
+        rel.quantity = Quantity::RelativePower;
+        let rel = calculate_alpha_opic(&rel).unwrap();
+        assert!((rel.melanopic_der() - abs.melanopic_der()).abs() < 1e-5);
+
+        let mut refl = irradiance(Illuminant::D65, 1.0);
+        refl.quantity = Quantity::Reflectance;
+        assert!(calculate_alpha_opic(&refl).is_err());
+    }
+
+    #[test]
+    fn test_circadian_stimulus() {
+        // Rea et al. give a CLA 2.0 of about 813 for 1000 lx of CIE A
+        let a = irradiance(Illuminant::A, 1000.0);
+        let m = calculate_circadian_stimulus(&a, &CircadianExposure::default()).unwrap();
+        assert!((m.cla - 813.0).abs() < 40.0, "{}", m.cla);
+        assert!((0.45..0.55).contains(&m.cs), "{}", m.cs);
+
+        // Daylight drives the blue-yellow channel; a half field for half an
+        // hour is a smaller stimulus
+        let d65 = irradiance(Illuminant::D65, 1000.0);
+        let full = calculate_circadian_stimulus(&d65, &CircadianExposure::default()).unwrap();
+        let exposure = CircadianExposure {
+            duration: 0.5,
+            field_factor: 0.5,
+        };
+        let part = calculate_circadian_stimulus(&d65, &exposure).unwrap();
+        assert!(full.blue_yellow > 0.0 && full.cla > m.cla);
+        assert!(part.cla == full.cla && part.cs < full.cs && full.cs < 0.7);
+    }
+}
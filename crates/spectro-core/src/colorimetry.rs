@@ -1,73 +1,195 @@
-use crate::{Illuminant, Observer};
+use crate::{cie_data, Illuminant, Observer};
 
-/// CIE 1931 2-degree Standard Observer CMFs (380-780nm, 10nm steps)
+/// CIE 1931 2-degree Standard Observer CMFs (380-780nm, 10nm steps).
+/// Every tenth row of [`cie_data::CIE1931_2`](crate::cie_data::CIE1931_2).
 pub const X_BAR_2: [f32; 41] = [
-    0.0014, 0.0042, 0.0143, 0.0435, 0.1344, 0.2839, 0.3483, 0.3362, 0.2908, 0.1954, 0.0956, 0.0320,
-    0.0049, 0.0093, 0.0633, 0.1655, 0.2904, 0.4334, 0.5945, 0.7621, 0.9163, 1.0263, 1.0622, 1.0026,
-    0.8524, 0.6424, 0.4479, 0.2835, 0.1649, 0.0874, 0.0468, 0.0227, 0.0114, 0.0058, 0.0029, 0.0014,
-    0.00069, 0.00033, 0.00017, 0.00008, 0.00004,
+    0.001368,
+    0.004243,
+    0.01431,
+    0.04351,
+    0.13438,
+    0.2839,
+    0.34828,
+    0.3362,
+    0.2908,
+    0.19536,
+    0.09564,
+    0.03201,
+    0.0049,
+    0.0093,
+    0.06327,
+    0.1655,
+    0.2904,
+    0.43345,
+    0.5945,
+    0.7621,
+    0.9163,
+    1.0263,
+    1.0622,
+    1.0026,
+    0.85445,
+    0.6424,
+    0.4479,
+    0.2835,
+    0.1649,
+    0.0874,
+    0.04677,
+    0.0227,
+    0.0113592,
+    0.00579035,
+    0.00289933,
+    0.00143997,
+    0.000690079,
+    0.000332301,
+    0.000166151,
+    0.0000831,
+    0.0000415,
 ];
 
 pub const Y_BAR_2: [f32; 41] = [
-    0.0000, 0.0001, 0.0004, 0.0012, 0.0040, 0.0116, 0.0230, 0.0380, 0.0600, 0.0910, 0.1390, 0.2080,
-    0.3230, 0.5030, 0.7100, 0.8620, 0.9540, 0.9950, 0.9950, 0.9520, 0.8700, 0.7570, 0.6310, 0.5030,
-    0.3810, 0.2650, 0.1750, 0.1070, 0.0610, 0.0320, 0.0170, 0.0082, 0.0041, 0.0021, 0.0010,
-    0.00052, 0.00025, 0.00012, 0.00006, 0.00003, 0.00001,
+    0.000039, 0.00012, 0.000396, 0.00121, 0.004, 0.0116, 0.023, 0.038, 0.06, 0.09098, 0.13902,
+    0.20802, 0.323, 0.503, 0.71, 0.862, 0.954, 0.99495, 0.995, 0.952, 0.87, 0.757, 0.631, 0.503,
+    0.381, 0.265, 0.175, 0.107, 0.061, 0.032, 0.017, 0.00821, 0.004102, 0.002091, 0.001047,
+    0.00052, 0.0002492, 0.00012, 0.00006, 0.00003, 0.000015,
 ];
 
 pub const Z_BAR_2: [f32; 41] = [
-    0.0065, 0.0201, 0.0679, 0.2074, 0.6456, 1.3856, 1.7471, 1.7721, 1.5794, 1.1143, 0.5701, 0.1970,
-    0.0415, 0.0052, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000,
-    0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000,
-    0.0000, 0.0000, 0.0000, 0.0000, 0.0000,
+    0.00645, 0.02005, 0.06785, 0.2074, 0.6456, 1.3856, 1.74706, 1.77211, 1.6692, 1.28764, 0.81295,
+    0.46518, 0.272, 0.1582, 0.07825, 0.04216, 0.0203, 0.00875, 0.0039, 0.0021, 0.00165, 0.0011,
+    0.0008, 0.00034, 0.00019, 0.00005, 0.00002, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0,
 ];
 
-/// CIE 1964 10-degree Standard Observer CMFs (380-780nm, 10nm steps)
+/// CIE 1964 10-degree Standard Observer CMFs (380-780nm, 10nm steps).
+/// Every tenth row of [`cie_data::CIE1964_10`](crate::cie_data::CIE1964_10).
 #[allow(clippy::approx_constant)]
 pub const X_BAR_10: [f32; 41] = [
-    0.0002, 0.0011, 0.0061, 0.0315, 0.1241, 0.3023, 0.5045, 0.6931, 0.8177, 0.7530, 0.5314, 0.3345,
-    0.1570, 0.0538, 0.0331, 0.1117, 0.2230, 0.4243, 0.6627, 0.8690, 1.0107, 1.0743, 1.0257, 0.8724,
-    0.6553, 0.4456, 0.2800, 0.1622, 0.0869, 0.0434, 0.0218, 0.0107, 0.0053, 0.0026, 0.0013, 0.0006,
-    0.0003, 0.0001, 0.0000, 0.0000, 0.0000,
+    0.000159952,
+    0.0023616,
+    0.0191097,
+    0.084736,
+    0.204492,
+    0.314679,
+    0.383734,
+    0.370702,
+    0.302273,
+    0.195618,
+    0.080507,
+    0.016172,
+    0.003816,
+    0.037465,
+    0.117749,
+    0.236491,
+    0.376772,
+    0.529826,
+    0.705224,
+    0.878655,
+    1.01416,
+    1.11852,
+    1.12399,
+    1.03048,
+    0.856297,
+    0.647467,
+    0.431567,
+    0.268329,
+    0.152568,
+    0.0812606,
+    0.0408508,
+    0.0199413,
+    0.00957688,
+    0.00455263,
+    0.00217496,
+    0.00104476,
+    0.000508258,
+    0.000250969,
+    0.00012639,
+    0.0000645,
+    0.0000334,
 ];
 pub const Y_BAR_10: [f32; 41] = [
-    0.0000, 0.0000, 0.0002, 0.0010, 0.0041, 0.0105, 0.0207, 0.0407, 0.0702, 0.1120, 0.1852, 0.2904,
-    0.4190, 0.5764, 0.7435, 0.8872, 0.9666, 0.9983, 0.9873, 0.9331, 0.8420, 0.7163, 0.5596, 0.4203,
-    0.3021, 0.2003, 0.1245, 0.0713, 0.0380, 0.0189, 0.0094, 0.0046, 0.0023, 0.0111, 0.0006, 0.0003,
-    0.0001, 0.0000, 0.0000, 0.0000, 0.0000,
+    0.0000174, 0.0002534, 0.0020044, 0.008756, 0.021391, 0.038676, 0.062077, 0.089456, 0.128201,
+    0.18519, 0.253589, 0.339133, 0.460777, 0.606741, 0.761757, 0.875211, 0.961988, 0.991761,
+    0.99734, 0.955552, 0.868934, 0.777405, 0.658341, 0.527963, 0.398057, 0.283493, 0.179828,
+    0.107633, 0.060281, 0.0318004, 0.0159051, 0.0077488, 0.00371774, 0.00176847, 0.00084619,
+    0.00040741, 0.00019873, 0.0000984, 0.0000497, 0.0000255, 0.0000132,
 ];
 pub const Z_BAR_10: [f32; 41] = [
-    0.0007, 0.0045, 0.0259, 0.1343, 0.5285, 1.3003, 2.1932, 3.0334, 3.5534, 3.2392, 2.2235, 1.3400,
-    0.5752, 0.1866, 0.0427, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000,
-    0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000,
-    0.0000, 0.0000, 0.0000, 0.0000, 0.0000,
+    0.000704776,
+    0.0104822,
+    0.0860109,
+    0.389366,
+    0.972542,
+    1.55348,
+    1.96728,
+    1.9948,
+    1.74537,
+    1.31756,
+    0.772125,
+    0.415254,
+    0.218502,
+    0.112044,
+    0.060709,
+    0.030451,
+    0.013676,
+    0.003988,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
 ];
 
-/// CIE 2015 Physiologically-based LMS Color Matching Functions (2-degree observer, 10nm)
-/// These represent the real cone response of the human eye.
+/// CIE 2006 2° cone fundamentals (l̄, m̄, s̄) at 10nm, 380-780nm: every tenth
+/// row of [`cie_data::CIE2006_LMS_2`]. These are the real cone responses of
+/// the human eye from which the CIE 2015 observers are derived.
+#[rustfmt::skip]
 pub const L_BAR_2015: [f32; 41] = [
-    0.0001, 0.0004, 0.0019, 0.0084, 0.0292, 0.0544, 0.0652, 0.0660, 0.0536, 0.0336, 0.0253, 0.0435,
-    0.0906, 0.1834, 0.3541, 0.5363, 0.7024, 0.8358, 0.9328, 0.9859, 1.0000, 0.9575, 0.8524, 0.7081,
-    0.5480, 0.3952, 0.2644, 0.1651, 0.0967, 0.0538, 0.0284, 0.0143, 0.0068, 0.0031, 0.0014, 0.0006,
-    0.0003, 0.0001, 0.0001, 0.0000, 0.0000,
+    0.0, 0.0004150036, 0.002408363, 0.008721268, 0.01844805, 0.02818772, 0.04025626, 0.04986396, 0.06471644, 0.09947551,
+    0.1401451, 0.1915562, 0.2889594, 0.4436829, 0.6285611, 0.7706299, 0.8810109, 0.9401977, 0.9814446, 0.9999932,
+    0.9694292, 0.9276727, 0.8339821, 0.7057132, 0.5542244, 0.4007109, 0.2657842, 0.1651409, 0.09300849, 0.04986609,
+    0.02537904, 0.01217011, 0.005897489, 0.002804466, 0.001326865, 0.000639373, 0.0003108691, 0.0001545492, 7.799121e-5, 3.992945e-5,
+    2.073214e-5,
 ];
+#[rustfmt::skip]
 pub const M_BAR_2015: [f32; 41] = [
-    0.0000, 0.0001, 0.0006, 0.0028, 0.0121, 0.0298, 0.0450, 0.0526, 0.0519, 0.0440, 0.0494, 0.0772,
-    0.1345, 0.2319, 0.3802, 0.5312, 0.6724, 0.7974, 0.8926, 0.9515, 0.9757, 0.9592, 0.8995, 0.7963,
-    0.6621, 0.5134, 0.3698, 0.2486, 0.1557, 0.0917, 0.0511, 0.0270, 0.0135, 0.0064, 0.0030, 0.0013,
-    0.0006, 0.0003, 0.0001, 0.0001, 0.0000,
+    0.0, 0.0003683489, 0.002269906, 0.008793686, 0.02166483, 0.03945659, 0.06477821, 0.08705235, 0.1162721, 0.1758927,
+    0.235754, 0.3036296, 0.4277645, 0.6155202, 0.8166096, 0.935687, 0.9952165, 0.9771934, 0.9177499, 0.8135093,
+    0.6532735, 0.4925986, 0.3344285, 0.2052732, 0.1166415, 0.06211205, 0.03142825, 0.01544799, 0.00730256, 0.003436668,
+    0.001637338, 0.0007610489, 0.0003653162, 0.0001744018, 8.417162e-5, 4.160478e-5, 2.088596e-5, 1.082007e-5, 5.690928e-6, 3.026828e-6,
+    1.634331e-6,
 ];
+#[rustfmt::skip]
 pub const S_BAR_2015: [f32; 41] = [
-    0.0019, 0.0101, 0.0469, 0.1648, 0.4449, 0.8443, 0.9930, 0.8970, 0.6171, 0.3392, 0.1505, 0.0532,
-    0.1042, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000,
-    0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000, 0.0000,
-    0.0000, 0.0000, 0.0000, 0.0000, 0.0000,
+    0.0, 0.009547286, 0.05664977, 0.2330083, 0.5436179, 0.8025548, 0.9910203, 0.9553928, 0.786704, 0.646359,
+    0.3903334, 0.2118668, 0.1228389, 0.06082094, 0.02920328, 0.01260129, 0.005089, 0.001958955, 0.0007401739, 0.0002818,
+    0.0001094539, 4.390238e-5, 1.834589e-5, 8.034877e-6, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0,
 ];
 
 /// CIE Standard Illuminants.
 /// IMPORTANT: Use the correct observer angle (2° or 10°) matching your CMFs.
 pub mod illuminant {
     use super::XYZ;
+    use crate::cie_data::{self, LEN, LEN_5NM};
 
     // ==================== 2-DEGREE OBSERVER ====================
     /// D50 (Horizon Light, Print Industry - 2°)
@@ -100,6 +222,12 @@ pub mod illuminant {
         y: 1.0,
         z: 0.35585,
     };
+    /// Illuminant C (Average Daylight, ASTM E308 - 2°)
+    pub const C: XYZ = XYZ {
+        x: 0.98074,
+        y: 1.0,
+        z: 1.18232,
+    };
     /// F2 (Cool White Fluorescent - 2°)
     pub const F2: XYZ = XYZ {
         x: 0.99186,
@@ -150,6 +278,12 @@ pub mod illuminant {
         y: 1.0,
         z: 0.35200,
     };
+    /// Illuminant C (10-degree observer)
+    pub const C_10: XYZ = XYZ {
+        x: 0.97285,
+        y: 1.0,
+        z: 1.16145,
+    };
 
     /// CIE 2018 LED Series Illuminants (2-degree).
     /// These replace old F-series for modern lighting analysis.
@@ -235,57 +369,329 @@ pub mod illuminant {
             16.10, 18.54, 19.50,
         ];
     }
+
+    /// Planckian radiator at `cct` kelvin, 1nm from 380nm to 780nm,
+    /// normalized to 100 at 560nm.
+    pub fn planckian_1nm(cct: f32) -> [f32; LEN] {
+        let c2 = 1.4388e-2_f64;
+        let planck = |nm: f64| {
+            let wl = nm * 1e-9;
+            wl.powi(-5) / ((c2 / (wl * cct as f64)).exp() - 1.0)
+        };
+        let norm = 100.0 / planck(560.0);
+        let mut spd = [0.0f32; LEN];
+        for (i, val) in spd.iter_mut().enumerate() {
+            *val = (planck(cie_data::START as f64 + i as f64) * norm) as f32;
+        }
+        spd
+    }
+
+    /// CIE daylight illuminant at `cct` kelvin, 1nm from 380nm to 780nm.
+    /// Computed from the 5nm S0, S1, S2 components with M1 and M2 rounded
+    /// to three decimals (CIE 015), then interpolated linearly. The
+    /// chromaticity locus is defined for 4000-25000K.
+    pub fn daylight_1nm(cct: f32) -> [f32; LEN] {
+        let t = cct as f64;
+        let xd = if t <= 7000.0 {
+            -4.6070e9 / t.powi(3) + 2.9678e6 / t.powi(2) + 0.09911e3 / t + 0.244063
+        } else {
+            -2.0064e9 / t.powi(3) + 1.9018e6 / t.powi(2) + 0.24748e3 / t + 0.237040
+        };
+        let yd = -3.000 * xd * xd + 2.870 * xd - 0.275;
+
+        let m = 0.0241 + 0.2562 * xd - 0.7341 * yd;
+        let m1 = ((-1.3515 - 1.7703 * xd + 5.9114 * yd) / m * 1000.0).round() / 1000.0;
+        let m2 = ((0.0300 - 31.4424 * xd + 30.0717 * yd) / m * 1000.0).round() / 1000.0;
+
+        let mut spd = [0.0f32; LEN_5NM];
+        for (val, s) in spd.iter_mut().zip(&cie_data::DAYLIGHT_S) {
+            *val = (s[0] as f64 + m1 * s[1] as f64 + m2 * s[2] as f64) as f32;
+        }
+        super::upsample_5nm(&spd)
+    }
 }
 
 impl Illuminant {
-    /// Get the relative spectral power distribution for this illuminant.
-    pub fn get_spd(&self) -> &'static [f32; 41] {
+    /// Every illuminant, in declaration order.
+    pub const ALL: [Illuminant; 27] = [
+        Illuminant::D50,
+        Illuminant::D55,
+        Illuminant::D65,
+        Illuminant::D75,
+        Illuminant::A,
+        Illuminant::C,
+        Illuminant::F1,
+        Illuminant::F2,
+        Illuminant::F3,
+        Illuminant::F4,
+        Illuminant::F5,
+        Illuminant::F6,
+        Illuminant::F7,
+        Illuminant::F8,
+        Illuminant::F9,
+        Illuminant::F10,
+        Illuminant::F11,
+        Illuminant::F12,
+        Illuminant::LED_B1,
+        Illuminant::LED_B2,
+        Illuminant::LED_B3,
+        Illuminant::LED_B4,
+        Illuminant::LED_B5,
+        Illuminant::LED_BH1,
+        Illuminant::LED_RGB1,
+        Illuminant::LED_V1,
+        Illuminant::LED_V2,
+    ];
+
+    /// Short description for menus and reports.
+    pub fn description(&self) -> &'static str {
         match self {
-            Illuminant::A => &illuminant::spd::A,
-            Illuminant::D50 => &illuminant::spd::D50,
-            Illuminant::D55 => &illuminant::spd::D65, // Fallback to D65 if D55 SPD missing
-            Illuminant::D65 => &illuminant::spd::D65,
-            Illuminant::D75 => &illuminant::spd::D65, // Fallback
-            Illuminant::F2 => &illuminant::spd::F2,
-            Illuminant::F7 => &illuminant::spd::F7,
-            Illuminant::F11 => &illuminant::spd::F11,
+            Illuminant::D50 => "D50 (Print Industry)",
+            Illuminant::D55 => "D55 (Mid-Morning Daylight)",
+            Illuminant::D65 => "D65 (Daylight, sRGB)",
+            Illuminant::D75 => "D75 (North Sky Daylight)",
+            Illuminant::A => "A (Tungsten 2856K)",
+            Illuminant::C => "C (Average Daylight, legacy)",
+            Illuminant::F1 => "F1 (Daylight Fluorescent)",
+            Illuminant::F2 => "F2 (Cool White Fluorescent)",
+            Illuminant::F3 => "F3 (White Fluorescent)",
+            Illuminant::F4 => "F4 (Warm White Fluorescent)",
+            Illuminant::F5 => "F5 (Daylight Fluorescent)",
+            Illuminant::F6 => "F6 (Lite White Fluorescent)",
+            Illuminant::F7 => "F7 (Daylight Fluorescent)",
+            Illuminant::F8 => "F8 (D50 Simulator)",
+            Illuminant::F9 => "F9 (Cool White Deluxe)",
+            Illuminant::F10 => "F10 (Three-Band 5000K)",
+            Illuminant::F11 => "F11 (TL84)",
+            Illuminant::F12 => "F12 (Three-Band 3000K)",
+            Illuminant::LED_B1 => "LED-B1 (Phosphor 2733K)",
+            Illuminant::LED_B2 => "LED-B2 (Phosphor 2998K)",
+            Illuminant::LED_B3 => "LED-B3 (Phosphor 4103K)",
+            Illuminant::LED_B4 => "LED-B4 (Phosphor 5109K)",
+            Illuminant::LED_B5 => "LED-B5 (Phosphor 6598K)",
+            Illuminant::LED_BH1 => "LED-BH1 (Hybrid 2851K)",
+            Illuminant::LED_RGB1 => "LED-RGB1 (RGB 2840K)",
+            Illuminant::LED_V1 => "LED-V1 (Violet 2724K)",
+            Illuminant::LED_V2 => "LED-V2 (Violet 4070K)",
         }
     }
 
+    /// Relative spectral power distribution, 1nm from 380nm to 780nm.
+    pub fn spd_1nm(&self) -> &'static [f32; cie_data::LEN] {
+        match self {
+            Illuminant::D50 => &cie_data::D50,
+            Illuminant::D55 => &cie_data::D55,
+            Illuminant::D65 => &cie_data::D65,
+            Illuminant::D75 => &cie_data::D75,
+            Illuminant::A => &cie_data::A,
+            Illuminant::C => &cie_data::C,
+            Illuminant::F1 => &cie_data::F1,
+            Illuminant::F2 => &cie_data::F2,
+            Illuminant::F3 => &cie_data::F3,
+            Illuminant::F4 => &cie_data::F4,
+            Illuminant::F5 => &cie_data::F5,
+            Illuminant::F6 => &cie_data::F6,
+            Illuminant::F7 => &cie_data::F7,
+            Illuminant::F8 => &cie_data::F8,
+            Illuminant::F9 => &cie_data::F9,
+            Illuminant::F10 => &cie_data::F10,
+            Illuminant::F11 => &cie_data::F11,
+            Illuminant::F12 => &cie_data::F12,
+            Illuminant::LED_B1 => &cie_data::LED_B1,
+            Illuminant::LED_B2 => &cie_data::LED_B2,
+            Illuminant::LED_B3 => &cie_data::LED_B3,
+            Illuminant::LED_B4 => &cie_data::LED_B4,
+            Illuminant::LED_B5 => &cie_data::LED_B5,
+            Illuminant::LED_BH1 => &cie_data::LED_BH1,
+            Illuminant::LED_RGB1 => &cie_data::LED_RGB1,
+            Illuminant::LED_V1 => &cie_data::LED_V1,
+            Illuminant::LED_V2 => &cie_data::LED_V2,
+        }
+    }
+
+    /// Relative spectral power distribution, 5nm from 380nm to 780nm.
+    pub fn spd_5nm(&self) -> [f32; 81] {
+        subsample(self.spd_1nm())
+    }
+
+    /// Relative spectral power distribution on the [`WAVELENGTHS`](crate::WAVELENGTHS)
+    /// grid (380-780nm, 10nm).
+    pub fn get_spd(&self) -> [f32; 41] {
+        subsample(self.spd_1nm())
+    }
+
+    /// Relative spectral power at any wavelength, interpolated from
+    /// [`Illuminant::spd_1nm`]; zero outside 380-780nm.
+    pub fn spd_at(&self, wavelength: f32) -> f32 {
+        let spd = self.spd_1nm();
+        interpolate_1nm(wavelength, |i| spd[i])
+    }
+
+    /// White point (Y = 1) for the given observer. Illuminants without a
+    /// published value are integrated from their 1nm SPD.
     pub fn get_white_point(&self, observer: Observer) -> XYZ {
-        match observer {
+        let published = match observer {
             Observer::CIE1931_2 => match self {
-                Illuminant::D50 => illuminant::D50,
-                Illuminant::D55 => illuminant::D55,
-                Illuminant::D65 => illuminant::D65,
-                Illuminant::D75 => illuminant::D75,
-                Illuminant::A => illuminant::A,
-                Illuminant::F2 => illuminant::F2,
-                Illuminant::F7 => illuminant::F7,
-                Illuminant::F11 => illuminant::F11,
+                Illuminant::D50 => Some(illuminant::D50),
+                Illuminant::D55 => Some(illuminant::D55),
+                Illuminant::D65 => Some(illuminant::D65),
+                Illuminant::D75 => Some(illuminant::D75),
+                Illuminant::A => Some(illuminant::A),
+                Illuminant::C => Some(illuminant::C),
+                Illuminant::F2 => Some(illuminant::F2),
+                Illuminant::F7 => Some(illuminant::F7),
+                Illuminant::F11 => Some(illuminant::F11),
+                _ => None,
             },
             Observer::CIE1964_10 => match self {
-                Illuminant::D50 => illuminant::D50_10,
-                Illuminant::D55 => illuminant::D55_10,
-                Illuminant::D65 => illuminant::D65_10,
-                Illuminant::D75 => illuminant::D75_10,
-                Illuminant::A => illuminant::A_10,
-                // Fallback to 2-degree if 10-degree constants are missing
-                Illuminant::F2 => illuminant::F2,
-                Illuminant::F7 => illuminant::F7,
-                Illuminant::F11 => illuminant::F11,
+                Illuminant::D50 => Some(illuminant::D50_10),
+                Illuminant::D55 => Some(illuminant::D55_10),
+                Illuminant::D65 => Some(illuminant::D65_10),
+                Illuminant::D75 => Some(illuminant::D75_10),
+                Illuminant::A => Some(illuminant::A_10),
+                Illuminant::C => Some(illuminant::C_10),
+                _ => None,
             },
-        }
+            Observer::CIE2015_2 | Observer::CIE2015_10 => None,
+        };
+        published.unwrap_or_else(|| {
+            let spd = self.spd_1nm();
+            let cmfs = observer.cmfs_1nm();
+            let mut xyz = [0.0f32; 3];
+            for (s, cmf) in spd.iter().zip(cmfs) {
+                for (acc, c) in xyz.iter_mut().zip(cmf) {
+                    *acc += s * c;
+                }
+            }
+            XYZ {
+                x: xyz[0] / xyz[1],
+                y: 1.0,
+                z: xyz[2] / xyz[1],
+            }
+        })
     }
 }
 
 impl Observer {
-    pub fn get_cmfs(&self) -> (&'static [f32; 41], &'static [f32; 41], &'static [f32; 41]) {
+    /// Every observer, in the order offered to users.
+    pub const ALL: [Observer; 4] = [
+        Observer::CIE1931_2,
+        Observer::CIE1964_10,
+        Observer::CIE2015_2,
+        Observer::CIE2015_10,
+    ];
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Observer::CIE1931_2 => "2° (CIE 1931 Standard)",
+            Observer::CIE1964_10 => "10° (CIE 1964 Large Field)",
+            Observer::CIE2015_2 => "2° (CIE 2015 Cone Fundamentals)",
+            Observer::CIE2015_10 => "10° (CIE 2015 Cone Fundamentals)",
+        }
+    }
+
+    /// Color matching functions (x̄, ȳ, z̄), 10nm from 380nm to 780nm.
+    pub fn get_cmfs(&self) -> ([f32; 41], [f32; 41], [f32; 41]) {
         match self {
-            Observer::CIE1931_2 => (&X_BAR_2, &Y_BAR_2, &Z_BAR_2),
-            Observer::CIE1964_10 => (&X_BAR_10, &Y_BAR_10, &Z_BAR_10),
+            Observer::CIE1931_2 => (X_BAR_2, Y_BAR_2, Z_BAR_2),
+            Observer::CIE1964_10 => (X_BAR_10, Y_BAR_10, Z_BAR_10),
+            _ => {
+                let cmfs: [[f32; 3]; 41] = subsample(self.cmfs_1nm());
+                (cmfs.map(|c| c[0]), cmfs.map(|c| c[1]), cmfs.map(|c| c[2]))
+            }
         }
     }
+
+    /// Color matching functions (x̄, ȳ, z̄), 1nm from 380nm to 780nm.
+    pub fn cmfs_1nm(&self) -> &'static [[f32; 3]; cie_data::LEN] {
+        match self {
+            Observer::CIE1931_2 => &cie_data::CIE1931_2,
+            Observer::CIE1964_10 => &cie_data::CIE1964_10,
+            Observer::CIE2015_2 => &cie_data::CIE2015_2,
+            Observer::CIE2015_10 => &cie_data::CIE2015_10,
+        }
+    }
+
+    /// Cone fundamentals (l̄, m̄, s̄), 1nm from 380nm to 780nm, for the
+    /// observers derived from them.
+    pub fn lms_1nm(&self) -> Option<&'static [[f32; 3]; cie_data::LEN]> {
+        match self {
+            Observer::CIE2015_2 => Some(&cie_data::CIE2006_LMS_2),
+            Observer::CIE2015_10 => Some(&cie_data::CIE2006_LMS_10),
+            _ => None,
+        }
+    }
+
+    /// CIE 170-2 matrix taking the cone fundamentals to x̄, ȳ, z̄, for the
+    /// observers derived from them.
+    #[rustfmt::skip]
+    #[allow(clippy::excessive_precision)]
+    pub fn lms_to_xyz(&self) -> Option<[[f32; 3]; 3]> {
+        match self {
+            Observer::CIE2015_2 => Some([
+                [1.94735469, -1.41445123, 0.36476327],
+                [0.68990272, 0.34832189, 0.0],
+                [0.0, 0.0, 1.93485343],
+            ]),
+            Observer::CIE2015_10 => Some([
+                [1.93986443, -1.34664359, 0.43044935],
+                [0.69283932, 0.34967567, 0.0],
+                [0.0, 0.0, 2.14687945],
+            ]),
+            _ => None,
+        }
+    }
+
+    /// Color matching functions, 5nm from 380nm to 780nm.
+    pub fn cmfs_5nm(&self) -> [[f32; 3]; 81] {
+        subsample(self.cmfs_1nm())
+    }
+
+    /// Color matching functions (x̄, ȳ, z̄) at any wavelength, interpolated
+    /// from [`Observer::cmfs_1nm`]; zero outside 380-780nm.
+    pub fn cmf_at(&self, wavelength: f32) -> (f32, f32, f32) {
+        let cmfs = self.cmfs_1nm();
+        (
+            interpolate_1nm(wavelength, |i| cmfs[i][0]),
+            interpolate_1nm(wavelength, |i| cmfs[i][1]),
+            interpolate_1nm(wavelength, |i| cmfs[i][2]),
+        )
+    }
+}
+
+/// Every (400 / (N - 1))-th row of a 1nm table, e.g. N = 81 for 5nm.
+fn subsample<T: Copy + Default, const N: usize>(table: &[T; cie_data::LEN]) -> [T; N] {
+    let step = (cie_data::LEN - 1) / (N - 1);
+    let mut out = [T::default(); N];
+    for (i, v) in out.iter_mut().enumerate() {
+        *v = table[i * step];
+    }
+    out
+}
+
+/// Linearly interpolate a 5nm table (380-780nm) to 1nm.
+pub(crate) fn upsample_5nm(table: &[f32; cie_data::LEN_5NM]) -> [f32; cie_data::LEN] {
+    let mut out = [0.0f32; cie_data::LEN];
+    for (i, v) in out.iter_mut().enumerate() {
+        let (j, x) = (i / 5, (i % 5) as f32 / 5.0);
+        *v = if x == 0.0 {
+            table[j]
+        } else {
+            table[j] + x * (table[j + 1] - table[j])
+        };
+    }
+    out
+}
+
+/// Linearly interpolate a 1nm table starting at 380nm; zero outside it.
+pub(crate) fn interpolate_1nm(wavelength: f32, value: impl Fn(usize) -> f32) -> f32 {
+    let t = wavelength - cie_data::START;
+    if !(0.0..=(cie_data::LEN - 1) as f32).contains(&t) {
+        return 0.0;
+    }
+    let i = (t.floor() as usize).min(cie_data::LEN - 2);
+    let x = t - i as f32;
+    value(i) + x * (value(i + 1) - value(i))
 }
 
 /// ASTM E308 Weighting Factors for D65/2° at 10nm.
@@ -358,8 +764,9 @@ pub mod weighting {
     pub const SUM_WY_D50_2_10: f32 = 100.000;
 }
 
-/// Bradford chromatic adaptation transform.
-/// Converts XYZ from one illuminant to another using the Bradford cone response model.
+/// Chromatic adaptation transforms.
+/// Converts XYZ from one illuminant to another using the Bradford cone response model,
+/// or CMCCAT2000 where a method prescribes it.
 ///
 /// Reference: Lindbloom (http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html)
 /// Note: The Bradford matrix used here is the "Sharp" variant commonly used in ICC profiles.
@@ -434,6 +841,39 @@ pub mod chromatic_adaptation {
                 + m_inv[2][2] * lms_adapted[2],
         }
     }
+
+    /// Apply CMCCAT2000 to adapt XYZ from source to destination white point,
+    /// with adapting luminances `la1` and `la2` in cd/m² and an average
+    /// surround (F = 1).
+    ///
+    /// Reference: Li, Luo, Rigg and Hunt (2002), "CMC 2000 Chromatic
+    /// Adaptation Transform: CMCCAT2000", Color Res. Appl. 27(1).
+    #[allow(clippy::excessive_precision)]
+    pub fn cmccat2000_adapt(xyz: XYZ, src_wp: XYZ, dst_wp: XYZ, la1: f32, la2: f32) -> XYZ {
+        #[rustfmt::skip]
+        let m = [
+            [ 0.7982,  0.3389, -0.1371],
+            [-0.5918,  1.5512,  0.0406],
+            [ 0.0008,  0.0239,  0.9753],
+        ];
+        #[rustfmt::skip]
+        let m_inv = [
+            [ 1.0764500, -0.2376624,  0.1612123],
+            [ 0.4109643,  0.5543418,  0.0346939],
+            [-0.0109538, -0.0133894,  1.0243431],
+        ];
+        let to_rgb = |v: XYZ| m.map(|r| r[0] * v.x + r[1] * v.y + r[2] * v.z);
+
+        let d = (0.08 * (0.5 * (la1 + la2)).log10() + 0.76 - 0.45 * (la1 - la2) / (la1 + la2))
+            .clamp(0.0, 1.0);
+        let alpha = d * src_wp.y / dst_wp.y;
+        let (rgb, rgb_w, rgb_wr) = (to_rgb(xyz), to_rgb(src_wp), to_rgb(dst_wp));
+        let rgb_c: [f32; 3] =
+            std::array::from_fn(|i| rgb[i] * (alpha * rgb_wr[i] / rgb_w[i] + 1.0 - d));
+
+        let [x, y, z] = m_inv.map(|r| r[0] * rgb_c[0] + r[1] * rgb_c[1] + r[2] * rgb_c[2]);
+        XYZ { x, y, z }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -459,6 +899,17 @@ pub struct LMS {
     pub s: f32,
 }
 
+impl LMS {
+    /// Cone-fundamental-based chromaticity (l, m) = (L, M) / (L + M + S).
+    pub fn to_chromaticity(&self) -> (f32, f32) {
+        let sum = self.l + self.m + self.s;
+        if sum <= 0.0 {
+            return (0.0, 0.0);
+        }
+        (self.l / sum, self.m / sum)
+    }
+}
+
 /// Jzazbz: A modern perceptually uniform color space (Safdar et al., 2017).
 /// Designed for HDR content with excellent uniformity across the entire
 /// luminance range (0-10,000 nits). Euclidean distance in this space
@@ -473,23 +924,30 @@ pub struct Jzazbz {
     pub bz: f32,
 }
 
+/// CCT and signed Duv of a light source, with the Ohno (2013) method shared
+/// with [`XYZ::to_cct`]. Gives NaN for both when no CCT can be determined.
 pub fn calculate_cct(spd: &crate::spectrum::SpectralData) -> (f32, f32) {
-    let xyz = spd.to_xyz();
-    let sum = xyz.x + xyz.y + xyz.z;
-    if sum == 0.0 {
-        return (0.0, 0.0);
-    }
-    let x = xyz.x / sum;
-    let y = xyz.y / sum;
-
-    // McCamy's formula
-    let n = (x - 0.3320) / (0.1858 - y);
-    let cct = 449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5524.3;
-
-    (cct, 0.0)
+    spd.to_xyz_emissive_2()
+        .to_cct_duv()
+        .map_or((f32::NAN, f32::NAN), |r| (r.cct, r.duv))
 }
 
 impl XYZ {
+    /// Cone excitations for tristimulus values computed with an observer
+    /// derived from the cone fundamentals; `None` for the other observers.
+    pub fn to_lms(&self, observer: Observer) -> Option<LMS> {
+        let m = observer.lms_to_xyz()?;
+        // Invert the CIE 170-2 matrix: s̄ feeds only z̄, and ȳ has no s̄ term
+        let s = self.z / m[2][2];
+        let x = self.x - m[0][2] * s;
+        let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        Some(LMS {
+            l: (x * m[1][1] - m[0][1] * self.y) / det,
+            m: (m[0][0] * self.y - m[1][0] * x) / det,
+            s,
+        })
+    }
+
     /// Convert XYZ to CIE L*a*b* using the given white point.
     /// Uses precise CIE constants for continuity at the threshold.
     pub fn to_lab(&self, wp: XYZ) -> Lab {
@@ -845,130 +1303,152 @@ impl Jzazbz {
 /// Color Rendering Index (CRI) and other light quality metrics.
 pub mod metrics {
     use super::*;
+    use crate::cie_data::{LEN, TCS};
     use crate::spectrum::SpectralData;
+    use crate::{Result, SpectroError};
+
+    /// Largest distance DC between the test source and its reference
+    /// illuminant in CIE 1960 (u, v) for which CIE 13.3 considers the
+    /// indices meaningful.
+    pub const DC_TOLERANCE: f32 = 5.4e-3;
+
+    /// CIE 13.3 color rendering indices.
+    #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct CriMetrics {
+        /// General color rendering index Ra, the mean of R1-R8.
+        pub ra: f32,
+        /// Special color rendering indices R1-R14, for TCS01-TCS14.
+        pub ri: [f32; 14],
+        /// CCT of the test source, which selects the reference illuminant.
+        pub cct: f32,
+        /// Distance between test source and reference illuminant in CIE 1960
+        /// (u, v).
+        pub dc: f32,
+    }
 
-    /// Test Color Samples (TCS) for CRI calculation (380-780nm, 10nm).
-    /// Only including TCS01-TCS08 (for Ra) and TCS09 (for R9).
-    #[rustfmt::skip]
-    pub const TCS: [[f32; 41]; 9] = [
-        // TCS01: Light greyish red
-        [0.22, 0.25, 0.26, 0.25, 0.24, 0.24, 0.23, 0.23, 0.22, 0.22, 0.21, 0.22, 0.22, 0.23, 0.23, 0.23, 0.24, 0.25, 0.27, 0.30, 0.34, 0.39, 0.42, 0.44, 0.45, 0.45, 0.45, 0.45, 0.45, 0.45, 0.46, 0.46, 0.46, 0.46, 0.46, 0.47, 0.47, 0.47, 0.47, 0.47, 0.47],
-        // TCS02: Dark greyish yellow
-        [0.07, 0.09, 0.11, 0.12, 0.12, 0.12, 0.12, 0.12, 0.13, 0.13, 0.14, 0.15, 0.17, 0.21, 0.24, 0.26, 0.27, 0.27, 0.28, 0.30, 0.32, 0.34, 0.34, 0.34, 0.34, 0.34, 0.34, 0.34, 0.34, 0.33, 0.33, 0.33, 0.33, 0.33, 0.32, 0.32, 0.32, 0.32, 0.32, 0.31, 0.31],
-        // TCS03: Strong yellow green
-        [0.07, 0.07, 0.07, 0.07, 0.07, 0.07, 0.07, 0.08, 0.09, 0.11, 0.15, 0.20, 0.24, 0.28, 0.34, 0.39, 0.40, 0.38, 0.35, 0.30, 0.26, 0.25, 0.24, 0.22, 0.22, 0.22, 0.23, 0.25, 0.29, 0.34, 0.39, 0.43, 0.46, 0.48, 0.49, 0.50, 0.51, 0.52, 0.52, 0.53, 0.54],
-        // TCS04: Moderate yellowish green
-        [0.07, 0.09, 0.12, 0.12, 0.13, 0.14, 0.14, 0.16, 0.19, 0.23, 0.28, 0.33, 0.37, 0.39, 0.39, 0.38, 0.34, 0.31, 0.28, 0.25, 0.21, 0.18, 0.16, 0.16, 0.15, 0.15, 0.15, 0.15, 0.16, 0.17, 0.17, 0.17, 0.17, 0.17, 0.18, 0.19, 0.19, 0.20, 0.21, 0.23, 0.25],
-        // TCS05: Light bluish green
-        [0.30, 0.31, 0.31, 0.32, 0.33, 0.34, 0.36, 0.38, 0.40, 0.42, 0.42, 0.41, 0.40, 0.39, 0.37, 0.35, 0.31, 0.28, 0.25, 0.22, 0.19, 0.19, 0.18, 0.18, 0.18, 0.18, 0.18, 0.19, 0.19, 0.20, 0.20, 0.20, 0.20, 0.20, 0.21, 0.21, 0.22, 0.22, 0.23, 0.24, 0.27],
-        // TCS06: Light blue
-        [0.15, 0.27, 0.41, 0.49, 0.52, 0.53, 0.54, 0.56, 0.55, 0.54, 0.52, 0.49, 0.45, 0.41, 0.36, 0.31, 0.25, 0.23, 0.23, 0.22, 0.22, 0.22, 0.22, 0.23, 0.24, 0.26, 0.27, 0.28, 0.28, 0.29, 0.30, 0.33, 0.35, 0.38, 0.40, 0.43, 0.45, 0.47, 0.49, 0.51, 0.53],
-        // TCS07: Light violet
-        [0.38, 0.52, 0.55, 0.56, 0.56, 0.56, 0.54, 0.51, 0.47, 0.43, 0.39, 0.34, 0.31, 0.30, 0.27, 0.26, 0.26, 0.26, 0.26, 0.25, 0.26, 0.28, 0.32, 0.36, 0.39, 0.41, 0.43, 0.44, 0.45, 0.47, 0.47, 0.48, 0.49, 0.50, 0.51, 0.53, 0.54, 0.55, 0.57, 0.58, 0.59],
-        // TCS08: Light reddish purple
-        [0.10, 0.17, 0.32, 0.46, 0.49, 0.48, 0.45, 0.43, 0.40, 0.37, 0.34, 0.31, 0.29, 0.28, 0.26, 0.25, 0.26, 0.27, 0.27, 0.28, 0.32, 0.38, 0.48, 0.57, 0.63, 0.66, 0.69, 0.70, 0.71, 0.71, 0.72, 0.72, 0.72, 0.72, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73],
-        // TCS09: Strong red (R9)
-        [0.066, 0.058, 0.052, 0.051, 0.050, 0.048, 0.046, 0.041, 0.035, 0.030, 0.028, 0.028, 0.030, 0.031, 0.032, 0.033, 0.041, 0.048, 0.060, 0.102, 0.190, 0.336, 0.505, 0.641, 0.717, 0.758, 0.781, 0.797, 0.809, 0.819, 0.828, 0.831, 0.835, 0.836, 0.838, 0.839, 0.839, 0.839, 0.839, 0.839, 0.838],
-    ];
+    impl CriMetrics {
+        /// Special color rendering index R9 (strong red).
+        pub fn r9(&self) -> f32 {
+            self.ri[8]
+        }
+
+        /// Whether DC is within [`DC_TOLERANCE`].
+        pub fn is_valid(&self) -> bool {
+            self.dc <= DC_TOLERANCE
+        }
+    }
 
-    /// Calculate CRI Ra and R9 for a given spectral power distribution.
-    pub fn calculate_cri(spd: &SpectralData) -> (f32, f32) {
-        let xyz = spd.to_xyz_emissive_2();
-        let cct = xyz.to_cct();
+    /// Calculate the CIE 13.3 color rendering indices of a light source.
+    ///
+    /// The SPD is resampled to 1nm over 380-780nm and the 5nm test color
+    /// samples are interpolated linearly to match. The reference illuminant
+    /// is a Planckian radiator below 5000K and CIE daylight above, at the
+    /// CCT of the test source (see [`crate::cct`]). Test colors are von Kries
+    /// adapted to the reference in CIE 1960 (c, d) and compared in CIE 1964
+    /// U*V*W*, giving Rᵢ = 100 − 4.6·ΔEᵢ.
+    pub fn calculate_cri(spd: &SpectralData) -> Result<CriMetrics> {
+        let spd = spd.resample(cie_data::START, 780.0, 1.0);
+        if spd.values.len() != LEN {
+            return Err(SpectroError::Data(
+                "CRI needs spectral data covering 380-780nm".into(),
+            ));
+        }
+        let cmfs = Observer::CIE1931_2.cmfs_1nm();
+        let samples = TCS.map(|tcs| upsample_5nm(&tcs));
+
+        let (test_white, test_samples) = tristimulus(&spd.values, cmfs, &samples);
+        let cct = XYZ {
+            x: test_white[0] as f32,
+            y: test_white[1] as f32,
+            z: test_white[2] as f32,
+        }
+        .to_cct_duv()
+        .ok_or_else(|| SpectroError::Data("CCT of the source cannot be determined".into()))?
+        .cct;
 
-        // 1. Generate reference SPD
-        let ref_spd = if cct < 5000.0 {
-            generate_planckian(cct)
+        let reference = if cct < 5000.0 {
+            illuminant::planckian_1nm(cct)
         } else {
-            generate_daylight(cct)
+            illuminant::daylight_1nm(cct)
         };
-
-        // 2. Calculate Ri for each TCS
-        let mut ris = [0.0f32; 9];
-        for i in 0..9 {
-            ris[i] = calculate_ri(spd, &ref_spd, &TCS[i]);
+        let (ref_white, ref_samples) = tristimulus(&reference, cmfs, &samples);
+
+        let (uk, vk) = uv(test_white);
+        let (ur, vr) = uv(ref_white);
+        let (ck, dk) = cd(uk, vk);
+        let (cr, dr) = cd(ur, vr);
+
+        let mut ri = [0.0f32; 14];
+        for (r, (test, reference)) in ri.iter_mut().zip(test_samples.iter().zip(&ref_samples)) {
+            // Von Kries adaptation of the test color to the reference white
+            let (cki, dki) = {
+                let (u, v) = uv(*test);
+                cd(u, v)
+            };
+            let c = cr / ck * cki;
+            let d = dr / dk * dki;
+            let den = 16.518 + 1.481 * c - d;
+            let adapted = ((10.872 + 0.404 * c - 4.0 * d) / den, 5.520 / den);
+
+            let (u, v) = uv(*reference);
+            let test_uvw = uvw(adapted, test[1], (ur, vr));
+            let ref_uvw = uvw((u, v), reference[1], (ur, vr));
+            let de = ((test_uvw[0] - ref_uvw[0]).powi(2)
+                + (test_uvw[1] - ref_uvw[1]).powi(2)
+                + (test_uvw[2] - ref_uvw[2]).powi(2))
+            .sqrt();
+            *r = (100.0 - 4.6 * de) as f32;
         }
 
-        // Ra is average of first 8
-        let ra = ris[0..8].iter().sum::<f32>() / 8.0;
-        let r9 = ris[8];
-
-        (ra, r9)
+        Ok(CriMetrics {
+            ra: ri[..8].iter().sum::<f32>() / 8.0,
+            ri,
+            cct,
+            dc: ((uk - ur).powi(2) + (vk - vr).powi(2)).sqrt() as f32,
+        })
     }
 
-    fn calculate_ri(test_spd: &SpectralData, ref_spd: &[f32; 41], tcs: &[f32; 41]) -> f32 {
-        let (xb, yb, zb) = Observer::CIE1931_2.get_cmfs();
-
-        let calc_xyz = |spd_vals: &[f32], tcs_vals: &[f32]| -> XYZ {
-            let mut x = 0.0;
-            let mut y = 0.0;
-            let mut z = 0.0;
-            for i in 0..41 {
-                let val = spd_vals[i] * tcs_vals[i];
-                x += val * xb[i];
-                y += val * yb[i];
-                z += val * zb[i];
+    /// XYZ of the source and of each test color sample under it, scaled so
+    /// the source has Y = 100.
+    fn tristimulus(
+        spd: &[f32],
+        cmfs: &[[f32; 3]; LEN],
+        samples: &[[f32; LEN]; 14],
+    ) -> ([f64; 3], [[f64; 3]; 14]) {
+        let integrate = |reflectance: &dyn Fn(usize) -> f64| {
+            let mut xyz = [0.0f64; 3];
+            for (i, (&s, cmf)) in spd.iter().zip(cmfs).enumerate() {
+                for (acc, &c) in xyz.iter_mut().zip(cmf) {
+                    *acc += s as f64 * reflectance(i) * c as f64;
+                }
             }
-            XYZ { x, y, z }
-        };
-
-        let test_xyz = calc_xyz(&test_spd.values, tcs);
-        let ref_xyz = calc_xyz(ref_spd, tcs);
-
-        // Simplified CRI calculation (skipping full Von Kries for brevity,
-        // but using Lab Delta E as a proxy for Ri)
-        // Ri = 100 - 4.6 * Delta E
-        let test_lab = test_xyz.to_lab(test_spd.to_xyz_emissive_2());
-
-        let mut ref_white_x = 0.0;
-        let mut ref_white_y = 0.0;
-        let mut ref_white_z = 0.0;
-        for i in 0..41 {
-            ref_white_x += ref_spd[i] * xb[i];
-            ref_white_y += ref_spd[i] * yb[i];
-            ref_white_z += ref_spd[i] * zb[i];
-        }
-        let ref_white = XYZ {
-            x: ref_white_x,
-            y: ref_white_y,
-            z: ref_white_z,
+            xyz
         };
-        let ref_lab = ref_xyz.to_lab(ref_white);
-
-        let de = test_lab.delta_e_76(&ref_lab);
-        100.0 - 4.6 * de
+        let white = integrate(&|_| 1.0);
+        let k = 100.0 / white[1];
+        let scale = |xyz: [f64; 3]| xyz.map(|v| v * k);
+        let samples = std::array::from_fn(|n| scale(integrate(&|i| samples[n][i] as f64)));
+        (scale(white), samples)
     }
 
-    fn generate_planckian(cct: f32) -> [f32; 41] {
-        let mut spd = [0.0f32; 41];
-        let c1 = 3.741771e-16_f32;
-        let c2 = 1.4388e-2_f32;
-        for (i, val) in spd.iter_mut().enumerate() {
-            let wl = (380 + i * 10) as f32 * 1e-9_f32;
-            *val = c1 / (wl.powi(5) * ((c2 / (wl * cct)).exp() - 1.0));
-        }
-        spd
+    /// CIE 1960 (u, v) chromaticity.
+    fn uv(xyz: [f64; 3]) -> (f64, f64) {
+        let den = xyz[0] + 15.0 * xyz[1] + 3.0 * xyz[2];
+        (4.0 * xyz[0] / den, 6.0 * xyz[1] / den)
     }
 
-    fn generate_daylight(cct: f32) -> [f32; 41] {
-        // Simplified D-series generator
-        let xd = if cct <= 7000.0 {
-            -4.6070e9 / cct.powi(3) + 2.9678e6 / cct.powi(2) + 0.09911e3 / cct + 0.244063
-        } else {
-            -2.0064e9 / cct.powi(3) + 1.9018e6 / cct.powi(2) + 0.24748e3 / cct + 0.237040
-        };
-
-        let yd = -3.000 * xd * xd + 2.870 * xd - 0.275;
-
-        let m1 = (-1.3515 - 1.7703 * xd + 5.9114 * yd) / (0.0241 + 0.2562 * xd - 0.7341 * yd);
-        let m2 = (0.0300 - 31.4424 * xd + 30.0717 * yd) / (0.0241 + 0.2562 * xd - 0.7341 * yd);
+    /// CIE 13.3 (c, d) coordinates of the von Kries transform.
+    fn cd(u: f64, v: f64) -> (f64, f64) {
+        (
+            (4.0 - u - 10.0 * v) / v,
+            (1.708 * v + 0.404 - 1.481 * u) / v,
+        )
+    }
 
-        let mut spd = [0.0f32; 41];
-        for (i, val) in spd.iter_mut().enumerate() {
-            // Using D65 as a base and applying M1, M2 (simplified)
-            *val = illuminant::spd::D65[i] * (1.0 + m1 * 0.01 + m2 * 0.01);
-        }
-        spd
+    /// CIE 1964 U*V*W* relative to the white (u0, v0).
+    fn uvw((u, v): (f64, f64), y: f64, (u0, v0): (f64, f64)) -> [f64; 3] {
+        let w = 25.0 * y.cbrt() - 17.0;
+        [13.0 * w * (u - u0), 13.0 * w * (v - v0), w]
     }
 }
 
@@ -1045,6 +1525,7 @@ pub mod appearance {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::spectrum::SpectralData;
 
     #[test]
     fn test_d65_white_point_from_weighting() {
@@ -1083,4 +1564,108 @@ mod tests {
         assert!(lab.a.abs() < 1e-4);
         assert!(lab.b.abs() < 1e-4);
     }
+
+    #[test]
+    fn test_1nm_tables() {
+        // Integrating the 1nm tables reproduces the published white points
+        for (illum, published) in [
+            (Illuminant::D65, illuminant::D65),
+            (Illuminant::D55, illuminant::D55),
+            (Illuminant::A, illuminant::A),
+        ] {
+            let spd = illum.spd_1nm();
+            let cmfs = Observer::CIE1931_2.cmfs_1nm();
+            let y: f32 = spd.iter().zip(cmfs).map(|(s, c)| s * c[1]).sum();
+            let x: f32 = spd.iter().zip(cmfs).map(|(s, c)| s * c[0]).sum();
+            assert!((x / y - published.x).abs() < 2e-3, "{:?}: {}", illum, x / y);
+        }
+
+        // 5nm and 10nm tables are rows of the 1nm data
+        assert_eq!(
+            Observer::CIE1931_2.cmfs_5nm()[34][1],
+            cie_data::CIE1931_2[170][1]
+        );
+        assert_eq!(Illuminant::F11.get_spd()[20], cie_data::F11[200]);
+        assert_eq!(
+            Observer::CIE1964_10.cmf_at(555.0).1,
+            cie_data::CIE1964_10[175][1]
+        );
+        assert_eq!(Observer::CIE1931_2.cmf_at(300.0), (0.0, 0.0, 0.0));
+
+        let wp = Illuminant::LED_B3.get_white_point(Observer::CIE1964_10);
+        assert!(wp.y == 1.0 && wp.x > 0.9 && wp.x < 1.1);
+    }
+
+    #[test]
+    fn test_cie2015_observers() {
+        for observer in [Observer::CIE2015_2, Observer::CIE2015_10] {
+            // The XYZ functions are the CIE 170-2 transform of the cones
+            let m = observer.lms_to_xyz().unwrap();
+            let lms = observer.lms_1nm().unwrap();
+            for (c, xyz) in lms.iter().zip(observer.cmfs_1nm()) {
+                for row in 0..3 {
+                    let v: f32 = (0..3).map(|k| m[row][k] * c[k]).sum();
+                    assert!((v - xyz[row]).abs() < 1e-5);
+                }
+            }
+
+            // XYZ -> LMS inverts the transform
+            let (l, m_, s) = (0.6, 0.4, 0.2);
+            let xyz = XYZ {
+                x: m[0][0] * l + m[0][1] * m_ + m[0][2] * s,
+                y: m[1][0] * l + m[1][1] * m_,
+                z: m[2][2] * s,
+            };
+            let back = xyz.to_lms(observer).unwrap();
+            assert!((back.l - l).abs() < 1e-5 && (back.m - m_).abs() < 1e-5);
+            assert!((back.to_chromaticity().0 - 0.5).abs() < 1e-5);
+
+            // Perfect white under D65 integrates to the white point
+            let white = SpectralData::new(vec![1.0; 41]);
+            let wp = Illuminant::D65.get_white_point(observer);
+            let xyz = white.to_xyz_ext(Illuminant::D65, observer);
+            assert!((xyz.y - 100.0).abs() < 0.01);
+            assert!(
+                (xyz.x - wp.x * 100.0).abs() < 0.1,
+                "{:?}: {:?}",
+                observer,
+                xyz
+            );
+        }
+        let xyz = XYZ {
+            x: 0.5,
+            y: 0.5,
+            z: 0.5,
+        };
+        assert!(xyz.to_lms(Observer::CIE1931_2).is_none());
+    }
+
+    #[test]
+    fn test_cie_13_3_cri() {
+        use crate::spectrum::Quantity;
+        let source = |illum: Illuminant| {
+            let wavelengths = (0..cie_data::LEN).map(|i| 380.0 + i as f32).collect();
+            let spd = illum.spd_1nm().to_vec();
+            SpectralData::from_grid(wavelengths, spd, Quantity::RelativePower).unwrap()
+        };
+
+        // Sources that are their own reference render every sample perfectly
+        let a = metrics::calculate_cri(&source(Illuminant::A)).unwrap();
+        assert!((a.cct - 2856.0).abs() < 2.0 && a.is_valid());
+        assert!(a.ri.iter().all(|r| (r - 100.0).abs() < 0.1), "{:?}", a);
+        let d65 = metrics::calculate_cri(&source(Illuminant::D65)).unwrap();
+        assert!(d65.ri.iter().all(|r| (r - 100.0).abs() < 0.5), "{:?}", d65);
+
+        // Published Ra of the CIE fluorescent illuminants
+        for (illum, ra) in [
+            (Illuminant::F2, 64.0),
+            (Illuminant::F7, 90.0),
+            (Illuminant::F11, 83.0),
+        ] {
+            let cri = metrics::calculate_cri(&source(illum)).unwrap();
+            assert!((cri.ra - ra).abs() < 1.0, "{:?}: {:?}", illum, cri);
+        }
+        let f2 = metrics::calculate_cri(&source(Illuminant::F2)).unwrap();
+        assert!(f2.r9() < 0.0 && f2.is_valid());
+    }
 }
@@ -0,0 +1,390 @@
+//! NIST Color Quality Scale (CQS) 9.0
+//!
+//! Davis and Ohno (2010), "Color quality scale", Opt. Eng. 49(3), as
+//! implemented in the NIST CQS 9.0 spreadsheet. Fifteen saturated Munsell
+//! samples ([`VS`]) are compared in CIELAB under the test source and a
+//! reference of the same CCT (Planckian below 5000K, CIE daylight above):
+//!
+//! - The test colors are adapted to the reference white with CMCCAT2000
+//!   (complete adaptation) and both are expressed relative to that white.
+//! - Chroma increases are not penalized: when a sample gains chroma, its
+//!   ΔE*ab is reduced to the hue and lightness difference.
+//! - **Qa** combines the rms of those ΔE into a 0-100 scale and multiplies
+//!   it by the CCT factor, which penalizes sources below 3500K.
+//! - **Qf** is the same without the saturation reward, **Qp** rewards the
+//!   mean chroma gain, and **Qg** is the a*b* gamut area of the samples
+//!   relative to their area under D65.
+//!
+//! Integration runs over 380-780nm at 1nm with the CIE 1931 2° observer,
+//! the 5nm samples interpolated linearly.
+
+use crate::cie_data::{self, LEN, LEN_5NM};
+use crate::colorimetry::chromatic_adaptation::cmccat2000_adapt;
+use crate::colorimetry::{illuminant, upsample_5nm, Lab, XYZ};
+use crate::spectrum::SpectralData;
+use crate::{Illuminant, Observer, Result, SpectroError};
+
+/// Number of CQS samples.
+pub const N_VS: usize = 15;
+
+/// Scaling factors between rms ΔE*ab and Qa, Qf and Qp.
+const SCALE_QA: f32 = 3.1;
+const SCALE_QF: f32 = 2.928;
+const SCALE_QP: f32 = 3.6;
+
+/// Adapting luminance in cd/m², high enough for complete adaptation.
+const ADAPTING_LUMINANCE: f32 = 1000.0;
+
+/// NIST CQS 9.0 indices of a light source.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CqsMetrics {
+    /// General color quality scale Qa.
+    pub qa: f32,
+    /// Color fidelity scale Qf.
+    pub qf: f32,
+    /// Color preference scale Qp.
+    pub qp: f32,
+    /// Gamut area scale Qg.
+    pub qg: f32,
+    /// Per-sample Qa,i for VS1-VS15.
+    pub qa_i: [f32; N_VS],
+    /// CCT of the test source.
+    pub cct: f32,
+    /// CCT factor applied to Qa and Qf.
+    pub cct_factor: f32,
+}
+
+/// CCT factor M_CCT, which lowers the scores of sources below 3500K for
+/// their smaller gamut.
+pub fn cct_factor(cct: f32) -> f32 {
+    if cct >= 3500.0 {
+        return 1.0;
+    }
+    let t = cct as f64;
+    (t.powi(3) * 9.2672e-11 - t.powi(2) * 8.3959e-7 + t * 0.00255 - 1.612) as f32
+}
+
+/// Calculate the CQS 9.0 indices of a light source.
+pub fn calculate_cqs(spd: &SpectralData) -> Result<CqsMetrics> {
+    let spd = spd.resample(cie_data::START, 780.0, 1.0);
+    if spd.values.len() != LEN {
+        return Err(SpectroError::Data(
+            "CQS needs spectral data covering 380-780nm".into(),
+        ));
+    }
+    let samples = VS.map(|vs| upsample_5nm(&vs));
+
+    let (test_white, test_xyz) = tristimulus(&spd.values, &samples);
+    let cct = test_white
+        .to_cct_duv()
+        .ok_or_else(|| SpectroError::Data("CCT of the source cannot be determined".into()))?
+        .cct;
+    let reference = if cct < 5000.0 {
+        illuminant::planckian_1nm(cct)
+    } else {
+        illuminant::daylight_1nm(cct)
+    };
+    let (ref_white, ref_xyz) = tristimulus(&reference, &samples);
+
+    let test_lab: [Lab; N_VS] = test_xyz.map(|xyz| {
+        cmccat2000_adapt(
+            xyz,
+            test_white,
+            ref_white,
+            ADAPTING_LUMINANCE,
+            ADAPTING_LUMINANCE,
+        )
+        .to_lab(ref_white)
+    });
+    let ref_lab = ref_xyz.map(|xyz| xyz.to_lab(ref_white));
+
+    let mut delta_e = [0.0f32; N_VS];
+    let mut delta_e_sat = [0.0f32; N_VS];
+    let mut chroma_gain = 0.0;
+    for i in 0..N_VS {
+        let (t, r) = (test_lab[i], ref_lab[i]);
+        delta_e[i] = ((t.l - r.l).powi(2) + (t.a - r.a).powi(2) + (t.b - r.b).powi(2)).sqrt();
+        let delta_c = t.a.hypot(t.b) - r.a.hypot(r.b);
+        delta_e_sat[i] = if delta_c > 0.0 {
+            chroma_gain += delta_c;
+            (delta_e[i].powi(2) - delta_c.powi(2)).max(0.0).sqrt()
+        } else {
+            delta_e[i]
+        };
+    }
+    let rms = |de: &[f32; N_VS]| (de.iter().map(|e| e * e).sum::<f32>() / N_VS as f32).sqrt();
+    let rms_sat = rms(&delta_e_sat);
+
+    let m = cct_factor(cct);
+    let d65_area = gamut_area(&d65_lab());
+    Ok(CqsMetrics {
+        qa: m * scale(rms_sat, SCALE_QA),
+        qf: m * scale(rms(&delta_e), SCALE_QF),
+        qp: 100.0 - SCALE_QP * (rms_sat - chroma_gain / N_VS as f32),
+        qg: 100.0 * gamut_area(&test_lab) / d65_area,
+        qa_i: delta_e_sat.map(|de| m * scale(de, SCALE_QA)),
+        cct,
+        cct_factor: m,
+    })
+}
+
+/// Convert a color difference to the 0-100 scale, which compresses
+/// negative scores towards zero.
+fn scale(delta_e: f32, factor: f32) -> f32 {
+    10.0 * (((100.0 - factor * delta_e) / 10.0).exp() + 1.0).ln()
+}
+
+/// XYZ of the source and of each sample under it, scaled so the source has
+/// Y = 100.
+fn tristimulus(spd: &[f32], samples: &[[f32; LEN]; N_VS]) -> (XYZ, [XYZ; N_VS]) {
+    let cmfs = Observer::CIE1931_2.cmfs_1nm();
+    let integrate = |reflectance: &dyn Fn(usize) -> f64| {
+        let mut xyz = [0.0f64; 3];
+        for (i, (&s, cmf)) in spd.iter().zip(cmfs).enumerate() {
+            for (acc, &c) in xyz.iter_mut().zip(cmf) {
+                *acc += s as f64 * reflectance(i) * c as f64;
+            }
+        }
+        xyz
+    };
+    let white = integrate(&|_| 1.0);
+    let k = 100.0 / white[1];
+    let to_xyz = |v: [f64; 3]| XYZ {
+        x: (v[0] * k) as f32,
+        y: (v[1] * k) as f32,
+        z: (v[2] * k) as f32,
+    };
+    let samples = std::array::from_fn(|n| to_xyz(integrate(&|i| samples[n][i] as f64)));
+    (to_xyz(white), samples)
+}
+
+/// CIELAB of the samples under D65, the basis of Qg.
+fn d65_lab() -> [Lab; N_VS] {
+    let (white, xyz) = tristimulus(Illuminant::D65.spd_1nm(), &VS.map(|vs| upsample_5nm(&vs)));
+    xyz.map(|v| v.to_lab(white))
+}
+
+/// Area of the a*b* polygon through the samples, in sample order.
+fn gamut_area(lab: &[Lab; N_VS]) -> f32 {
+    let mut area = 0.0;
+    for i in 0..N_VS {
+        let j = (i + 1) % N_VS;
+        area += lab[i].a * lab[j].b - lab[j].a * lab[i].b;
+    }
+    0.5 * area.abs()
+}
+
+/// Saturated samples VS1-VS15 (5nm, 380-780nm): spectral
+/// reflectance of the matte Munsell chips measured at the University of
+/// Eastern Finland.
+#[rustfmt::skip]
+pub const VS: [[f32; LEN_5NM]; N_VS] = [
+    // VS1: 7.5P 4/10
+    [
+        0.11142, 0.13666, 0.1743, 0.21642, 0.25476, 0.27788, 0.28708, 0.28876, 0.28428, 0.2768,
+        0.26716, 0.25624, 0.24174, 0.22878, 0.21456, 0.19896, 0.18626, 0.173, 0.16078, 0.1506,
+        0.13962, 0.12926, 0.11928, 0.11134, 0.10586, 0.10154, 0.09722, 0.09368, 0.08944, 0.08394,
+        0.0807, 0.07856, 0.07756, 0.07818, 0.07918, 0.0794, 0.07988, 0.08096, 0.08052, 0.07998,
+        0.07986, 0.08134, 0.08444, 0.0912, 0.10316, 0.12054, 0.14724, 0.17704, 0.21178, 0.24632,
+        0.27838, 0.30714, 0.3323, 0.35528, 0.37444, 0.39172, 0.40584, 0.41694, 0.42942, 0.4387,
+        0.44466, 0.44984, 0.45532, 0.46034, 0.46696, 0.47146, 0.47754, 0.47976, 0.48338, 0.48812,
+        0.4917, 0.49474, 0.49828, 0.49868, 0.50234, 0.50368, 0.50616, 0.5077, 0.51176, 0.51316,
+        0.51148,
+    ],
+    // VS2: 10PB 4/10
+    [
+        0.10736, 0.13122, 0.1676, 0.21086, 0.25226, 0.27938, 0.29576, 0.30482, 0.31106, 0.31676,
+        0.3206, 0.32446, 0.32464, 0.31836, 0.30852, 0.29728, 0.28748, 0.27386, 0.258, 0.24058,
+        0.21878, 0.19466, 0.1726, 0.15652, 0.1432, 0.1297, 0.11828, 0.10756, 0.09874, 0.09342,
+        0.0909, 0.0895, 0.08928, 0.08958, 0.08844, 0.08666, 0.0839, 0.0835, 0.08518, 0.08742,
+        0.09152, 0.09728, 0.10422, 0.10712, 0.10986, 0.10926, 0.10786, 0.10278, 0.09984, 0.10062,
+        0.1037, 0.11064, 0.11864, 0.12928, 0.14296, 0.15812, 0.17144, 0.18326, 0.1914, 0.1977,
+        0.19746, 0.19782, 0.1997, 0.20348, 0.20756, 0.21266, 0.2199, 0.22642, 0.23372, 0.24338,
+        0.25494, 0.26784, 0.28638, 0.30658, 0.32692, 0.34856, 0.36638, 0.38142, 0.39878, 0.40826,
+        0.41974,
+    ],
+    // VS3: 5PB 4/12
+    [
+        0.08644, 0.09902, 0.1206, 0.14582, 0.16932, 0.19166, 0.2085, 0.21702, 0.22372, 0.2359,
+        0.2504, 0.26726, 0.29454, 0.32302, 0.34394, 0.35508, 0.35868, 0.35696, 0.35002, 0.33582,
+        0.31822, 0.29504, 0.27452, 0.2509, 0.22838, 0.2055, 0.18324, 0.16148, 0.14206, 0.12426,
+        0.10908, 0.09612, 0.08738, 0.07812, 0.07122, 0.06432, 0.05808, 0.05748, 0.05546, 0.05384,
+        0.0529, 0.05354, 0.05342, 0.05328, 0.05372, 0.05432, 0.05398, 0.05622, 0.05524, 0.05608,
+        0.05724, 0.05732, 0.05878, 0.05952, 0.06072, 0.06064, 0.0607, 0.0602, 0.06004, 0.06068,
+        0.0595, 0.058, 0.057, 0.05798, 0.05914, 0.06098, 0.06218, 0.06452, 0.06578, 0.0679,
+        0.07168, 0.07372, 0.0789, 0.0826, 0.08922, 0.09802, 0.10546, 0.11374, 0.1244, 0.13652,
+        0.15128,
+    ],
+    // VS4: 7.5B 5/10
+    [
+        0.08152, 0.09904, 0.1251, 0.16016, 0.19294, 0.22148, 0.24088, 0.2482, 0.26134, 0.27662,
+        0.28838, 0.3096, 0.33244, 0.35146, 0.36762, 0.38378, 0.40174, 0.41886, 0.43082, 0.4367,
+        0.43612, 0.42862, 0.4193, 0.4053, 0.38816, 0.3663, 0.34238, 0.31556, 0.2871, 0.25948,
+        0.23322, 0.20774, 0.18414, 0.1616, 0.14276, 0.12404, 0.107, 0.09814, 0.08978, 0.08242,
+        0.07818, 0.07436, 0.0725, 0.068, 0.0668, 0.06634, 0.06532, 0.06618, 0.06488, 0.06494,
+        0.06492, 0.066, 0.06598, 0.06658, 0.06784, 0.0689, 0.06912, 0.06784, 0.06936, 0.07024,
+        0.06912, 0.06722, 0.0663, 0.06706, 0.0667, 0.0666, 0.06712, 0.06978, 0.07216, 0.07616,
+        0.08242, 0.09018, 0.1042, 0.12262, 0.14932, 0.18004, 0.21204, 0.24336, 0.26796, 0.28392,
+        0.2965,
+    ],
+    // VS5: 10BG 6/8
+    [
+        0.11784, 0.13576, 0.16796, 0.20162, 0.23002, 0.251, 0.2635, 0.26924, 0.2767, 0.28424,
+        0.2954, 0.3065, 0.31876, 0.33268, 0.35128, 0.37212, 0.40292, 0.43452, 0.4636, 0.48776,
+        0.50894, 0.52, 0.52282, 0.51798, 0.50788, 0.49106, 0.46978, 0.4473, 0.42126, 0.39232,
+        0.36466, 0.3362, 0.30824, 0.28066, 0.25342, 0.2272, 0.20296, 0.1869, 0.17112, 0.15886,
+        0.14826, 0.14, 0.13058, 0.12264, 0.11662, 0.11102, 0.10736, 0.10536, 0.10346, 0.10108,
+        0.10076, 0.0996, 0.09844, 0.09786, 0.09894, 0.10026, 0.10148, 0.1009, 0.10376, 0.10698,
+        0.10796, 0.10786, 0.10772, 0.10852, 0.10882, 0.10682, 0.1072, 0.10774, 0.11002, 0.11332,
+        0.11824, 0.12442, 0.13476, 0.14682, 0.1608, 0.17342, 0.18342, 0.1905, 0.19644, 0.19554,
+        0.19812,
+    ],
+    // VS6: 2.5BG 6/10
+    [
+        0.08856, 0.09958, 0.1169, 0.13288, 0.14434, 0.15086, 0.15708, 0.16068, 0.16506, 0.16894,
+        0.17688, 0.18346, 0.19008, 0.20152, 0.21338, 0.22954, 0.2549, 0.28524, 0.3186, 0.35768,
+        0.39818, 0.43506, 0.46046, 0.47366, 0.47488, 0.46852, 0.45514, 0.43662, 0.41706, 0.3943,
+        0.372, 0.34618, 0.32154, 0.29464, 0.26994, 0.2452, 0.2209, 0.19914, 0.17998, 0.16186,
+        0.14516, 0.13002, 0.1162, 0.10456, 0.09646, 0.09022, 0.08736, 0.08404, 0.08332, 0.08142,
+        0.0807, 0.08102, 0.07926, 0.0784, 0.07894, 0.07936, 0.08066, 0.08168, 0.08578, 0.0902,
+        0.0936, 0.0958, 0.09968, 0.10386, 0.10536, 0.1057, 0.10522, 0.10312, 0.10208, 0.10182,
+        0.10328, 0.10636, 0.11118, 0.11712, 0.12324, 0.1293, 0.13296, 0.13628, 0.13782, 0.13794,
+        0.13866,
+    ],
+    // VS7: 2.5G 6/12
+    [
+        0.07254, 0.0755, 0.07986, 0.08404, 0.08606, 0.08518, 0.08552, 0.08618, 0.08616, 0.08688,
+        0.08814, 0.08974, 0.09272, 0.09414, 0.09726, 0.10108, 0.10842, 0.12016, 0.13854, 0.16466,
+        0.1981, 0.23668, 0.28046, 0.32634, 0.36854, 0.4017, 0.42024, 0.42532, 0.42158, 0.40962,
+        0.39542, 0.37736, 0.35574, 0.3313, 0.3067, 0.2807, 0.25444, 0.23244, 0.21052, 0.19028,
+        0.17162, 0.15256, 0.13542, 0.11908, 0.10636, 0.095, 0.0868, 0.08112, 0.07742, 0.07434,
+        0.07302, 0.07116, 0.0696, 0.06884, 0.0681, 0.0673, 0.06828, 0.06658, 0.06866, 0.07034,
+        0.07234, 0.07292, 0.07346, 0.07736, 0.0804, 0.08166, 0.08332, 0.08268, 0.08326, 0.08402,
+        0.08456, 0.0865, 0.08922, 0.09242, 0.09694, 0.10032, 0.1034, 0.10792, 0.10992, 0.11168,
+        0.11598,
+    ],
+    // VS8: 7.5GY 7/10
+    [
+        0.06442, 0.0654, 0.0681, 0.06874, 0.0701, 0.07076, 0.0709, 0.07006, 0.07012, 0.07008,
+        0.07048, 0.0712, 0.072, 0.07204, 0.07362, 0.07722, 0.08242, 0.09196, 0.10674, 0.129,
+        0.16064, 0.19954, 0.24586, 0.29662, 0.3454, 0.38728, 0.41966, 0.44106, 0.4546, 0.46058,
+        0.4636, 0.4642, 0.45966, 0.45202, 0.44366, 0.43162, 0.41676, 0.4041, 0.3868, 0.37038,
+        0.35118, 0.32976, 0.30716, 0.2819, 0.26034, 0.23884, 0.22384, 0.21176, 0.20314, 0.19698,
+        0.19274, 0.1887, 0.18406, 0.1802, 0.1764, 0.17342, 0.17192, 0.17036, 0.17338, 0.17592,
+        0.17912, 0.18356, 0.18774, 0.19578, 0.20158, 0.20532, 0.20658, 0.20664, 0.20332, 0.20078,
+        0.20056, 0.20214, 0.20762, 0.21362, 0.22076, 0.22992, 0.23766, 0.24374, 0.24836, 0.25568,
+        0.26574,
+    ],
+    // VS9: 2.5GY 8/10
+    [
+        0.06418, 0.06648, 0.06936, 0.06878, 0.0705, 0.0708, 0.07302, 0.07352, 0.07492, 0.07636,
+        0.07678, 0.07728, 0.07928, 0.08202, 0.08518, 0.09162, 0.09796, 0.10616, 0.11604, 0.1285,
+        0.14078, 0.1533, 0.17066, 0.19102, 0.21838, 0.25688, 0.31136, 0.37932, 0.45288, 0.52634,
+        0.58486, 0.62486, 0.64612, 0.654, 0.65444, 0.64806, 0.63634, 0.62474, 0.60876, 0.59284,
+        0.57538, 0.55458, 0.53062, 0.50278, 0.4786, 0.45298, 0.4322, 0.4168, 0.4061, 0.39818,
+        0.39364, 0.38714, 0.38092, 0.37444, 0.36942, 0.36404, 0.3631, 0.36056, 0.36444, 0.36866,
+        0.3745, 0.38008, 0.38626, 0.39406, 0.40158, 0.40332, 0.40768, 0.40634, 0.40218, 0.39992,
+        0.39822, 0.39934, 0.40836, 0.41688, 0.42516, 0.43648, 0.44466, 0.45094, 0.45822, 0.46254,
+        0.4751,
+    ],
+    // VS10: 5Y 8.5/12
+    [
+        0.0529, 0.05174, 0.05368, 0.05252, 0.05394, 0.05228, 0.05366, 0.05392, 0.05614, 0.05524,
+        0.05708, 0.05724, 0.06038, 0.0653, 0.0683, 0.0704, 0.07964, 0.08448, 0.09586, 0.10886,
+        0.12316, 0.13964, 0.16102, 0.19088, 0.23016, 0.28212, 0.34634, 0.4165, 0.48886, 0.54768,
+        0.59656, 0.63018, 0.6528, 0.66494, 0.675, 0.68474, 0.6862, 0.69608, 0.70176, 0.70668,
+        0.70842, 0.71358, 0.71548, 0.71758, 0.71794, 0.72112, 0.7227, 0.72328, 0.72766, 0.73068,
+        0.73314, 0.7334, 0.7367, 0.73926, 0.73956, 0.74198, 0.74642, 0.74454, 0.74276, 0.74562,
+        0.74706, 0.74638, 0.74876, 0.7525, 0.75502, 0.75544, 0.75866, 0.76026, 0.761, 0.76404,
+        0.76568, 0.76698, 0.77292, 0.7707, 0.77328, 0.77494, 0.773, 0.7754, 0.77756, 0.77742,
+        0.77764,
+    ],
+    // VS11: 10YR 7/12
+    [
+        0.0467, 0.04506, 0.04632, 0.04676, 0.04792, 0.04706, 0.04662, 0.04698, 0.04752, 0.0483,
+        0.05024, 0.04922, 0.04704, 0.0473, 0.04792, 0.04902, 0.05048, 0.05072, 0.04954, 0.05004,
+        0.05154, 0.05318, 0.05504, 0.05752, 0.0629, 0.07486, 0.0994, 0.13744, 0.18202, 0.22762,
+        0.26438, 0.29384, 0.32236, 0.35676, 0.39418, 0.43402, 0.46276, 0.4891, 0.5071, 0.52006,
+        0.5288, 0.53654, 0.54186, 0.5468, 0.54926, 0.5512, 0.5543, 0.55674, 0.55892, 0.55908,
+        0.56058, 0.5619, 0.56254, 0.56436, 0.56718, 0.56786, 0.56808, 0.56686, 0.56866, 0.57156,
+        0.57214, 0.5703, 0.5705, 0.5751, 0.5773, 0.57766, 0.58106, 0.58144, 0.58194, 0.58396,
+        0.58838, 0.58868, 0.58816, 0.58838, 0.59, 0.59224, 0.59478, 0.59516, 0.59862, 0.59352,
+        0.59526,
+    ],
+    // VS12: 5YR 7/12
+    [
+        0.06902, 0.06954, 0.07228, 0.07574, 0.07788, 0.07842, 0.07764, 0.0757, 0.07522, 0.0747,
+        0.0761, 0.07604, 0.07658, 0.0768, 0.0762, 0.07546, 0.07736, 0.07826, 0.07918, 0.0786,
+        0.07902, 0.08008, 0.08088, 0.08486, 0.0915, 0.10426, 0.12594, 0.15086, 0.1724, 0.18486,
+        0.18962, 0.19396, 0.20462, 0.22692, 0.26676, 0.32682, 0.39448, 0.46342, 0.51908, 0.56148,
+        0.59114, 0.61646, 0.63666, 0.64868, 0.6584, 0.66404, 0.66894, 0.67108, 0.67442, 0.67646,
+        0.6788, 0.67878, 0.67976, 0.6811, 0.68332, 0.68392, 0.68804, 0.68544, 0.6868, 0.68892,
+        0.68666, 0.6871, 0.68752, 0.6907, 0.6922, 0.6939, 0.69642, 0.69608, 0.69928, 0.70366,
+        0.70298, 0.70432, 0.70608, 0.70262, 0.70526, 0.70726, 0.70862, 0.70718, 0.71, 0.70824,
+        0.70948,
+    ],
+    // VS13: 10R 6/12
+    [
+        0.08222, 0.08416, 0.08716, 0.08778, 0.08806, 0.0873, 0.08552, 0.0839, 0.08386, 0.08162,
+        0.08178, 0.0823, 0.08282, 0.08118, 0.0812, 0.08142, 0.0818, 0.08206, 0.08162, 0.08202,
+        0.08248, 0.08052, 0.08124, 0.08216, 0.08498, 0.08778, 0.0902, 0.0913, 0.09208, 0.09258,
+        0.09432, 0.09876, 0.10502, 0.11454, 0.1342, 0.16632, 0.21032, 0.27274, 0.33022, 0.38116,
+        0.42542, 0.46866, 0.50868, 0.54252, 0.5713, 0.5906, 0.60514, 0.61258, 0.61802, 0.62278,
+        0.6244, 0.62766, 0.63008, 0.63308, 0.6357, 0.63716, 0.6409, 0.64016, 0.64058, 0.64292,
+        0.6425, 0.64432, 0.64486, 0.64898, 0.64928, 0.65274, 0.65574, 0.65648, 0.65888, 0.66024,
+        0.66316, 0.6635, 0.669, 0.6674, 0.6695, 0.66998, 0.67246, 0.67268, 0.67762, 0.67498,
+        0.67674,
+    ],
+    // VS14: 5R 4/14
+    [
+        0.05192, 0.05152, 0.05066, 0.0502, 0.04974, 0.04948, 0.04944, 0.04934, 0.05008, 0.05004,
+        0.05004, 0.04906, 0.0489, 0.0489, 0.04654, 0.0451, 0.04356, 0.04282, 0.04144, 0.04116,
+        0.04046, 0.03944, 0.03944, 0.03922, 0.03956, 0.03926, 0.0398, 0.04016, 0.04018, 0.03972,
+        0.03954, 0.03984, 0.03934, 0.03916, 0.0403, 0.04134, 0.04272, 0.0466, 0.04972, 0.05442,
+        0.06178, 0.07404, 0.09544, 0.1279, 0.17804, 0.24456, 0.3224, 0.4044, 0.47452, 0.5328,
+        0.57746, 0.60908, 0.63124, 0.6482, 0.66298, 0.67336, 0.68346, 0.68982, 0.69256, 0.70112,
+        0.70736, 0.71178, 0.7167, 0.7222, 0.72816, 0.73244, 0.73478, 0.73718, 0.74018, 0.74304,
+        0.74774, 0.75044, 0.7529, 0.7565, 0.75846, 0.76034, 0.75978, 0.76418, 0.7661, 0.76158,
+        0.76668,
+    ],
+    // VS15: 7.5RP 4/12
+    [
+        0.09222, 0.10212, 0.11364, 0.1282, 0.13578, 0.13726, 0.13752, 0.13522, 0.13496, 0.13216,
+        0.12882, 0.1252, 0.12018, 0.11464, 0.10948, 0.10462, 0.10076, 0.09564, 0.09124, 0.08636,
+        0.08148, 0.07558, 0.07194, 0.06944, 0.0671, 0.06474, 0.06154, 0.0587, 0.05636, 0.05278,
+        0.05156, 0.0521, 0.05204, 0.05212, 0.05234, 0.05178, 0.05152, 0.05458, 0.05686, 0.06118,
+        0.06868, 0.08144, 0.10088, 0.12764, 0.15976, 0.20014, 0.24478, 0.2948, 0.34338, 0.39104,
+        0.43508, 0.47108, 0.50068, 0.52702, 0.54436, 0.55938, 0.57366, 0.58336, 0.58914, 0.59636,
+        0.59746, 0.60094, 0.60516, 0.61268, 0.62024, 0.63008, 0.63684, 0.6443, 0.65088, 0.65946,
+        0.66594, 0.67142, 0.67772, 0.67914, 0.68176, 0.6855, 0.68994, 0.69674, 0.69964, 0.69752,
+        0.70112,
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spectrum::Quantity;
+
+    fn source(illuminant: Illuminant) -> SpectralData {
+        let wavelengths = (0..LEN).map(|i| 380.0 + i as f32).collect();
+        let values = illuminant.spd_1nm().to_vec();
+        SpectralData::from_grid(wavelengths, values, Quantity::RelativePower).unwrap()
+    }
+
+    #[test]
+    fn test_cqs() {
+        // Sources that are their own reference score 100, times the CCT factor
+        let d65 = calculate_cqs(&source(Illuminant::D65)).unwrap();
+        assert!(d65.qa > 99.5 && d65.qf > 99.5 && (d65.qg - 100.0).abs() < 0.5);
+        let a = calculate_cqs(&source(Illuminant::A)).unwrap();
+        assert!((a.cct_factor - 0.981).abs() < 1e-3, "{}", a.cct_factor);
+        assert!((a.qa - 100.0 * a.cct_factor).abs() < 0.3 && (a.qp - 100.0).abs() < 0.3);
+        assert!(a.qg < 100.0);
+
+        // A cool white fluorescent desaturates most samples
+        let f2 = calculate_cqs(&source(Illuminant::F2)).unwrap();
+        assert!(f2.qa > 50.0 && f2.qa < 75.0 && f2.qg < 90.0, "{:?}", f2);
+        assert!(f2.qa_i.iter().all(|q| *q <= 100.0));
+    }
+}
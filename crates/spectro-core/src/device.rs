@@ -4,8 +4,9 @@
 //! interface for all supported spectrometer devices, regardless of their
 //! underlying hardware or communication protocol.
 
-use crate::spectrum::SpectralData;
-use crate::{MeasurementMode, Result};
+use crate::flicker::Waveform;
+use crate::spectrum::{Quantity, SpectralData};
+use crate::{MeasurementMode, Result, SpectroError};
 
 /// Information about a spectrometer device.
 #[derive(Debug, Clone)]
@@ -111,6 +112,62 @@ pub trait Spectrometer {
     /// calibration), or if the measurement fails.
     fn measure(&mut self, mode: MeasurementMode) -> Result<SpectralData>;
 
+    /// Captures the bare light source as the 100% reference for
+    /// [`MeasurementMode::Transmissive`].
+    ///
+    /// `source` is the mode used to read the light source (Emissive or
+    /// Ambient); later transmissive readings are taken in the same mode and
+    /// divided by this reference.
+    ///
+    /// # Errors
+    ///
+    /// The default implementation reports that transmissive measurement is
+    /// unsupported.
+    fn capture_reference(&mut self, source: MeasurementMode) -> Result<SpectralData> {
+        Err(SpectroError::Mode(format!(
+            "Transmission reference capture is not supported in {:?} mode",
+            source
+        )))
+    }
+
+    /// Returns the user correction factor applied to readings of
+    /// `quantity`, as set by [`Spectrometer::set_reference_factor`].
+    fn reference_factor(&self, quantity: Quantity) -> f32 {
+        let _ = quantity;
+        1.0
+    }
+
+    /// Sets a user correction factor for absolute readings of `quantity`
+    /// (irradiance or radiance), so the device agrees with a trusted
+    /// reference meter. See [`crate::photometry::reference_factor`].
+    ///
+    /// # Errors
+    ///
+    /// The default implementation reports that user correction is unsupported.
+    fn set_reference_factor(&mut self, quantity: Quantity, factor: f32) -> Result<()> {
+        let _ = factor;
+        Err(SpectroError::Mode(format!(
+            "Reference meter correction is not supported for {:?} readings",
+            quantity
+        )))
+    }
+
+    /// Samples photometric intensity as fast as the device allows.
+    ///
+    /// Used for temporal light modulation (flicker) analysis. The returned
+    /// waveform is in relative units; see [`crate::flicker`].
+    ///
+    /// # Errors
+    ///
+    /// The default implementation reports that rapid sampling is unsupported.
+    fn measure_series(&mut self, mode: MeasurementMode, samples: usize) -> Result<Waveform> {
+        let _ = samples;
+        Err(SpectroError::Mode(format!(
+            "Rapid sampling is not supported in {:?} mode",
+            mode
+        )))
+    }
+
     /// Returns the supported measurement modes for this device.
     fn supported_modes(&self) -> Vec<MeasurementMode>;
 
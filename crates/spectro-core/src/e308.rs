@@ -0,0 +1,290 @@
+//! ASTM E308 Tristimulus Weighting Factors
+//!
+//! ASTM E308 tabulates weights W(λ) so that X = Σ Wx(λ)·R(λ) for reflectance
+//! or transmittance sampled at 10nm or 20nm intervals:
+//!
+//! - **Table 5** is for instrument data that has *not* been corrected for
+//!   spectral bandpass; the weights compensate for a triangular bandpass as
+//!   wide as the interval.
+//! - **Table 6** is for bandpass-corrected data (or instruments with a
+//!   negligible bandpass).
+//!
+//! The weights are generated with the ASTM E2022 procedure, which E308 uses
+//! for its own tables: the 1nm illuminant × CMF products from
+//! [`cie_data`] are distributed onto the measurement wavelengths with
+//! Lagrange interpolation coefficients (cubic inside, quadratic in the end
+//! intervals). Table 5 additionally folds in the Stearns & Stearns bandpass
+//! correction. Weights span 380-780nm and are normalized so Σ Wy = 100.
+//!
+//! For D65 and D50 with the 2° observer at 10nm, Table 5 is taken from the
+//! published values in [`crate::colorimetry::weighting`] instead.
+
+use crate::{cie_data, Illuminant, Observer};
+
+/// Stearns & Stearns (1988) bandpass coefficient for a triangular bandpass
+/// equal to the measurement interval.
+pub const STEARNS_ALPHA: f64 = 0.083;
+
+/// Measurement interval of an E308 table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interval {
+    Nm10,
+    Nm20,
+}
+
+impl Interval {
+    /// Interval in nm.
+    pub fn nm(&self) -> f32 {
+        match self {
+            Interval::Nm10 => 10.0,
+            Interval::Nm20 => 20.0,
+        }
+    }
+
+    fn step(&self) -> usize {
+        self.nm() as usize
+    }
+}
+
+/// Which E308 weight set to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum E308Table {
+    /// Table 5: data not corrected for bandpass.
+    Table5,
+    /// Table 6: bandpass-corrected data.
+    Table6,
+}
+
+/// Tristimulus weighting factors on a regular wavelength grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weights {
+    /// Wavelength of the first weight, in nm.
+    pub start: f32,
+    /// Grid spacing, in nm.
+    pub interval: f32,
+    pub x: Vec<f32>,
+    pub y: Vec<f32>,
+    pub z: Vec<f32>,
+}
+
+impl Weights {
+    /// Wavelength of weight `i`, in nm.
+    pub fn wavelength(&self, i: usize) -> f32 {
+        self.start + i as f32 * self.interval
+    }
+
+    pub fn len(&self) -> usize {
+        self.y.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.y.is_empty()
+    }
+}
+
+/// The E308 weights for an illuminant, observer and interval: the published
+/// table where one is shipped, generated otherwise.
+pub fn weights(
+    illuminant: Illuminant,
+    observer: Observer,
+    interval: Interval,
+    table: E308Table,
+) -> Weights {
+    published(illuminant, observer, interval, table)
+        .unwrap_or_else(|| generate(illuminant, observer, interval, table))
+}
+
+/// Published Table 5 weights from [`crate::colorimetry::weighting`].
+fn published(
+    illuminant: Illuminant,
+    observer: Observer,
+    interval: Interval,
+    table: E308Table,
+) -> Option<Weights> {
+    use crate::colorimetry::weighting as w;
+
+    if observer != Observer::CIE1931_2 || interval != Interval::Nm10 || table != E308Table::Table5 {
+        return None;
+    }
+    let (x, y, z) = match illuminant {
+        Illuminant::D65 => (&w::WX_D65_2_10, &w::WY_D65_2_10, &w::WZ_D65_2_10),
+        Illuminant::D50 => (&w::WX_D50_2_10, &w::WY_D50_2_10, &w::WZ_D50_2_10),
+        _ => return None,
+    };
+    Some(Weights {
+        start: cie_data::START,
+        interval: interval.nm(),
+        x: x.to_vec(),
+        y: y.to_vec(),
+        z: z.to_vec(),
+    })
+}
+
+/// Generate the E308 weights with the ASTM E2022 procedure.
+fn generate(
+    illuminant: Illuminant,
+    observer: Observer,
+    interval: Interval,
+    table: E308Table,
+) -> Weights {
+    let step = interval.step();
+    let n = (cie_data::LEN - 1) / step + 1;
+    let spd = illuminant.spd_1nm();
+    let cmfs = observer.cmfs_1nm();
+
+    let mut w = vec![[0.0f64; 3]; n];
+    let mut sum_y = 0.0f64;
+    for (k, (&s, cmf)) in spd.iter().zip(cmfs).enumerate() {
+        let s = s as f64;
+        sum_y += s * cmf[1] as f64;
+
+        // Position on the measurement grid and the nodes of its interval
+        let pos = k as f64 / step as f64;
+        let j = (k / step).min(n - 2);
+        let nodes = if j == 0 {
+            0..3
+        } else if j == n - 2 {
+            n - 3..n
+        } else {
+            j - 1..j + 3
+        };
+
+        for m in nodes.clone() {
+            let coef: f64 = nodes
+                .clone()
+                .filter(|&q| q != m)
+                .map(|q| (pos - q as f64) / (m as f64 - q as f64))
+                .product();
+            for (acc, &c) in w[m].iter_mut().zip(cmf) {
+                *acc += s * c as f64 * coef;
+            }
+        }
+    }
+
+    if table == E308Table::Table5 {
+        w = fold_bandpass(&w);
+    }
+
+    let k = 100.0 / sum_y;
+    let column = |c: usize| w.iter().map(|v| (v[c] * k) as f32).collect();
+    Weights {
+        start: cie_data::START,
+        interval: interval.nm(),
+        x: column(0),
+        y: column(1),
+        z: column(2),
+    }
+}
+
+/// Table 5 weights from Table 6 weights, Wᵀ·B, where B is the Stearns
+/// correction R'ᵢ = (1 + 2α)Rᵢ − α(Rᵢ₋₁ + Rᵢ₊₁), with (1 + α) at the ends.
+fn fold_bandpass(w: &[[f64; 3]]) -> Vec<[f64; 3]> {
+    let n = w.len();
+    (0..n)
+        .map(|j| {
+            let mut out = [0.0f64; 3];
+            for (c, o) in out.iter_mut().enumerate() {
+                let diagonal = if j == 0 || j == n - 1 {
+                    1.0 + STEARNS_ALPHA
+                } else {
+                    1.0 + 2.0 * STEARNS_ALPHA
+                };
+                *o = diagonal * w[j][c];
+                if j > 0 {
+                    *o -= STEARNS_ALPHA * w[j - 1][c];
+                }
+                if j + 1 < n {
+                    *o -= STEARNS_ALPHA * w[j + 1][c];
+                }
+            }
+            out
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight_sums_match_white_point() {
+        for interval in [Interval::Nm10, Interval::Nm20] {
+            for table in [E308Table::Table5, E308Table::Table6] {
+                let w = weights(Illuminant::D65, Observer::CIE1931_2, interval, table);
+                assert_eq!(w.len(), if interval == Interval::Nm10 { 41 } else { 21 });
+                let sum = |v: &[f32]| v.iter().sum::<f32>();
+                assert!((sum(&w.y) - 100.0).abs() < 0.01);
+                // Published weights are rounded to 3 decimals
+                assert!(
+                    (sum(&w.x) - 95.04).abs() < 0.03,
+                    "{:?} X {}",
+                    table,
+                    sum(&w.x)
+                );
+                assert!(
+                    (sum(&w.z) - 108.86).abs() < 0.03,
+                    "{:?} Z {}",
+                    table,
+                    sum(&w.z)
+                );
+            }
+        }
+
+        // Bandpass compensation sharpens the weights: negative lobes appear
+        // next to steep CMF flanks
+        let t5 = weights(
+            Illuminant::A,
+            Observer::CIE1964_10,
+            Interval::Nm20,
+            E308Table::Table5,
+        );
+        let t6 = weights(
+            Illuminant::A,
+            Observer::CIE1964_10,
+            Interval::Nm20,
+            E308Table::Table6,
+        );
+        assert_ne!(t5, t6);
+        assert!(t5.y.iter().cloned().fold(f32::MAX, f32::min) < 0.0);
+    }
+
+    #[test]
+    fn test_generated_weights_match_published() {
+        use crate::colorimetry::weighting as w;
+
+        // Table 5 is served from the published values
+        let shipped = weights(
+            Illuminant::D65,
+            Observer::CIE1931_2,
+            Interval::Nm10,
+            E308Table::Table5,
+        );
+        assert_eq!(shipped.x, w::WX_D65_2_10.to_vec());
+
+        // The E2022 procedure reproduces them entry by entry
+        for (ill, wx, wy) in [
+            (Illuminant::D65, w::WX_D65_2_10, w::WY_D65_2_10),
+            (Illuminant::D50, w::WX_D50_2_10, w::WY_D50_2_10),
+        ] {
+            let gen = generate(ill, Observer::CIE1931_2, Interval::Nm10, E308Table::Table5);
+            for i in 0..41 {
+                assert!(
+                    (gen.x[i] - wx[i]).abs() < 0.15,
+                    "{:?} X at {}nm: {} vs {}",
+                    ill,
+                    gen.wavelength(i),
+                    gen.x[i],
+                    wx[i]
+                );
+                assert!(
+                    (gen.y[i] - wy[i]).abs() < 0.1,
+                    "{:?} Y at {}nm: {} vs {}",
+                    ill,
+                    gen.wavelength(i),
+                    gen.y[i],
+                    wy[i]
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Flash (Strobe) Measurement
+//!
+//! A photographic flash lasts a few milliseconds, so it cannot be read with a
+//! single steady-state exposure. Instead the instrument is armed to record a
+//! burst of back-to-back frames; [`integrate_flash`] finds the frames that
+//! contain the flash, subtracts the ambient background measured in the other
+//! frames and integrates what remains into a spectral exposure.
+//! [`analyze_flash`] then derives the photographic quantities.
+
+use crate::colorimetry::calculate_cct;
+use crate::colorimetry::metrics::calculate_cri;
+use crate::spectrum::SpectralData;
+use crate::tm30::{try_calculate_tm30, TM30Metrics};
+use crate::{Result, SpectroError};
+
+/// A frame counts as part of the flash when its luminance exceeds the
+/// background by this factor of the background noise (median absolute
+/// deviation), and by at least [`MIN_RELATIVE_RISE`] of the background.
+const DETECTION_SIGMA: f32 = 8.0;
+const MIN_RELATIVE_RISE: f32 = 0.05;
+
+/// Flash measurement results.
+#[derive(Debug, Clone)]
+pub struct FlashMetrics {
+    /// Integrated photometric exposure in lux·s.
+    pub exposure: f32,
+    pub cct: f32,
+    pub duv: f32,
+    /// CIE General Color Rendering Index.
+    pub ra: f32,
+    pub r9: f32,
+    /// TM-30 metrics, `None` if the spectrum does not support them.
+    pub tm30: Option<TM30Metrics>,
+}
+
+/// Integrate a flash event from a burst of ambient-mode frames.
+///
+/// `frames` are irradiance spectra (all on the same wavelength grid) taken
+/// back to back, each lasting `frame_time` seconds. The background is the
+/// per-band mean of the frames that do not contain the flash. The result is
+/// the background-subtracted spectral exposure (irradiance × seconds), with
+/// the frames' quantity, so for calibrated irradiance its Y is the exposure
+/// in lux·s.
+///
+/// # Errors
+///
+/// Returns an error if no flash is found, if every frame is lit (the
+/// background cannot be estimated) or if the frames are on different
+/// wavelength grids.
+pub fn integrate_flash(frames: &[SpectralData], frame_time: f32) -> Result<SpectralData> {
+    if frames.is_empty() {
+        return Err(SpectroError::Data("No frames captured".into()));
+    }
+    if frames.iter().any(|f| !f.same_grid(&frames[0])) {
+        return Err(SpectroError::Data(
+            "Flash frames are on different wavelength grids".into(),
+        ));
+    }
+
+    let levels: Vec<f32> = frames.iter().map(|f| f.to_xyz_emissive_2().y).collect();
+    let background_level = median(&levels);
+    let deviations: Vec<f32> = levels
+        .iter()
+        .map(|l| (l - background_level).abs())
+        .collect();
+    let noise = median(&deviations);
+    let threshold = background_level
+        + (DETECTION_SIGMA * noise).max(MIN_RELATIVE_RISE * background_level.abs());
+
+    let is_flash: Vec<bool> = levels.iter().map(|&l| l > threshold && l > 0.0).collect();
+    let flash_frames = is_flash.iter().filter(|&&f| f).count();
+    if flash_frames == 0 {
+        return Err(SpectroError::Mode(
+            "No flash detected during the capture window".into(),
+        ));
+    }
+    if flash_frames == frames.len() {
+        return Err(SpectroError::Mode(
+            "Flash spanned the whole capture window; no ambient background".into(),
+        ));
+    }
+
+    let bands = frames[0].values.len();
+    let mut background = vec![0.0f32; bands];
+    for frame in frames.iter().zip(&is_flash).filter(|(_, &f)| !f) {
+        for (b, v) in background.iter_mut().zip(&frame.0.values) {
+            *b += v;
+        }
+    }
+    let background_count = (frames.len() - flash_frames) as f32;
+    for b in background.iter_mut() {
+        *b /= background_count;
+    }
+
+    let mut exposure = vec![0.0f32; bands];
+    for frame in frames.iter().zip(&is_flash).filter(|(_, &f)| f) {
+        for ((e, v), b) in exposure.iter_mut().zip(&frame.0.values).zip(&background) {
+            *e += (v - b) * frame_time;
+        }
+    }
+
+    let mut data = SpectralData::with_quantity(exposure, frames[0].quantity);
+    data.wavelengths = frames[0].wavelengths.clone();
+    data.metadata = frames[0].metadata.clone();
+    data.metadata.averaged_frames = None;
+    Ok(data)
+}
+
+/// Calculate exposure, CCT, Duv, CRI and TM-30 for an integrated flash.
+pub fn analyze_flash(exposure: &SpectralData) -> FlashMetrics {
+    let (cct, duv) = calculate_cct(exposure);
+    let (ra, r9) = calculate_cri(exposure).map_or((f32::NAN, f32::NAN), |cri| (cri.ra, cri.r9()));
+
+    FlashMetrics {
+        exposure: exposure.to_xyz_emissive_2().y,
+        cct,
+        duv,
+        ra,
+        r9,
+        tm30: try_calculate_tm30(exposure).ok(),
+    }
+}
+
+fn median(values: &[f32]) -> f32 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        0.5 * (sorted[mid - 1] + sorted[mid])
+    } else {
+        sorted[mid]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spectrum::Quantity;
+
+    #[test]
+    fn test_flash_background_subtraction() {
+        let ambient = [1.0f32; 36];
+        let mut frames: Vec<SpectralData> = (0..20)
+            .map(|i| {
+                // Small frame-to-frame noise on the background
+                let n = if i % 2 == 0 { 0.01 } else { -0.01 };
+                SpectralData::with_quantity(
+                    ambient.iter().map(|v| v + n).collect(),
+                    Quantity::RelativePower,
+                )
+            })
+            .collect();
+        // The flash lands in two frames
+        frames[7].values.iter_mut().for_each(|v| *v += 50.0);
+        frames[8].values.iter_mut().for_each(|v| *v += 30.0);
+
+        let exposure = integrate_flash(&frames, 0.01).unwrap();
+        // (50 + 30) × 10 ms per band, on the frames' 380-730nm grid
+        assert!((exposure.values[10] - 0.8).abs() < 1e-3);
+        assert_eq!(exposure.wavelengths.last(), Some(&730.0));
+
+        let steady: Vec<SpectralData> = frames
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != 7 && *i != 8)
+            .map(|(_, f)| f.clone())
+            .collect();
+        assert!(integrate_flash(&steady, 0.01).is_err());
+    }
+}
@@ -0,0 +1,645 @@
+//! Temporal Light Modulation (Flicker) Metrics
+//!
+//! Evaluates a luminance-over-time series with the metrics commonly found in
+//! luminaire evaluation reports:
+//!
+//! - **Percent flicker** and **flicker index** (IES Lighting Handbook).
+//! - **IEEE 1789-2015** risk region (no observable effect / low risk / high risk).
+//! - **SVM**, the stroboscopic visibility measure (CIE TN 006:2016).
+//! - **Pst LM**, the short-term light flickermeter value (IEC TR 61547-1).
+//!
+//! The series can come from rapid sampling on the device
+//! ([`Spectrometer::measure_series`](crate::device::Spectrometer::measure_series))
+//! or from an imported waveform file ([`Waveform::from_csv`]). The device
+//! samples at a few tens of Hz, well below the 100/120 Hz ripple of mains
+//! lighting, so metrics that depend on frequency are reported only when the
+//! record can resolve them; see [`Waveform::nyquist`].
+
+use crate::{Result, SpectroError};
+use std::f64::consts::PI;
+
+/// A uniformly sampled luminance (or illuminance) waveform.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Waveform {
+    /// Sampling rate in Hz.
+    pub sample_rate: f32,
+    /// Photometric samples in arbitrary (but linear) units.
+    pub samples: Vec<f32>,
+}
+
+impl Waveform {
+    pub fn new(samples: Vec<f32>, sample_rate: f32) -> Self {
+        Self {
+            sample_rate,
+            samples,
+        }
+    }
+
+    /// Parse a waveform exported by an oscilloscope or photometer.
+    ///
+    /// Each data line holds `time,value` (time in seconds). Tabs, semicolons
+    /// and whitespace are accepted as separators; blank lines, `#` comments
+    /// and a non-numeric header line are skipped. The sampling rate is taken
+    /// from the average time step, which must be uniform to within 1%.
+    pub fn from_csv(text: &str) -> Result<Self> {
+        let mut times = Vec::new();
+        let mut samples = Vec::new();
+
+        for (line_no, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line
+                .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+                .filter(|f| !f.is_empty())
+                .collect();
+            if fields.len() < 2 {
+                return Err(SpectroError::Data(format!(
+                    "line {}: expected `time,value`",
+                    line_no + 1
+                )));
+            }
+            match (fields[0].parse::<f64>(), fields[1].parse::<f32>()) {
+                (Ok(t), Ok(v)) => {
+                    times.push(t);
+                    samples.push(v);
+                }
+                // Allow a single header line before the data
+                _ if samples.is_empty() => continue,
+                _ => {
+                    return Err(SpectroError::Data(format!(
+                        "line {}: invalid number",
+                        line_no + 1
+                    )))
+                }
+            }
+        }
+
+        if samples.len() < 2 {
+            return Err(SpectroError::Data(
+                "waveform needs at least two samples".into(),
+            ));
+        }
+
+        let dt = (times[times.len() - 1] - times[0]) / (times.len() - 1) as f64;
+        if dt <= 0.0 {
+            return Err(SpectroError::Data("time column must increase".into()));
+        }
+        for pair in times.windows(2) {
+            if ((pair[1] - pair[0]) - dt).abs() > dt * 0.01 {
+                return Err(SpectroError::Data(
+                    "waveform is not uniformly sampled".into(),
+                ));
+            }
+        }
+
+        Ok(Self::new(samples, (1.0 / dt) as f32))
+    }
+
+    /// Record length in seconds.
+    pub fn duration(&self) -> f32 {
+        self.samples.len() as f32 / self.sample_rate
+    }
+
+    /// Highest frequency the record can represent, half the sampling rate.
+    /// Modulation above it is aliased to a lower frequency.
+    pub fn nyquist(&self) -> f32 {
+        0.5 * self.sample_rate
+    }
+}
+
+/// IEEE 1789-2015 flicker risk region for a given modulation and frequency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum FlickerRisk {
+    /// Below the "no observable effect" boundary.
+    NoObservableEffect,
+    /// Below the low-risk boundary.
+    LowRisk,
+    /// Above the low-risk boundary.
+    HighRisk,
+}
+
+impl FlickerRisk {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FlickerRisk::NoObservableEffect => "No Observable Effect",
+            FlickerRisk::LowRisk => "Low Risk",
+            FlickerRisk::HighRisk => "High Risk",
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FlickerMetrics {
+    /// Percent flicker (modulation depth), 0–100%.
+    pub percent_flicker: f32,
+    /// Flicker index, 0–1.
+    pub flicker_index: f32,
+    /// Frequency of the dominant modulation component in Hz, or `None` if
+    /// the light is steady or the component sits at the Nyquist frequency,
+    /// where it is likely aliased.
+    pub frequency: Option<f32>,
+    /// IEEE 1789 risk region at the dominant frequency, or `None` if the
+    /// frequency is unknown for a modulated light.
+    pub risk: Option<FlickerRisk>,
+    /// Stroboscopic visibility measure (1.0 = visibility threshold), or
+    /// `None` if the record resolves nothing in 80 Hz-2 kHz.
+    pub svm: Option<f32>,
+    /// Short-term light flickermeter value, if the record is long enough.
+    pub pst_lm: Option<f32>,
+}
+
+/// Calculate all temporal light modulation metrics for a waveform.
+pub fn calculate_flicker(waveform: &Waveform) -> FlickerMetrics {
+    let percent = percent_flicker(&waveform.samples);
+    let frequency = Some(dominant_frequency(waveform))
+        .filter(|&f| percent > 0.0 && f < waveform.nyquist() - 0.5 * frequency_step(waveform));
+    let risk = if percent > 0.0 {
+        frequency.map(|f| ieee1789_risk(percent, f))
+    } else {
+        Some(FlickerRisk::NoObservableEffect)
+    };
+
+    FlickerMetrics {
+        percent_flicker: percent,
+        flicker_index: flicker_index(&waveform.samples),
+        frequency,
+        risk,
+        svm: calculate_svm(waveform),
+        pst_lm: calculate_pst_lm(waveform),
+    }
+}
+
+/// Percent flicker: 100 × (max − min) / (max + min).
+pub fn percent_flicker(samples: &[f32]) -> f32 {
+    let max = samples.iter().cloned().fold(f32::MIN, f32::max);
+    let min = samples.iter().cloned().fold(f32::MAX, f32::min);
+    if samples.is_empty() || max + min <= 0.0 {
+        return 0.0;
+    }
+    100.0 * (max - min) / (max + min)
+}
+
+/// Flicker index: area above the mean divided by the total area.
+///
+/// Computed over the whole record, so it is exact when the record spans an
+/// integer number of periods and a close estimate otherwise.
+pub fn flicker_index(samples: &[f32]) -> f32 {
+    let total: f64 = samples.iter().map(|&v| v as f64).sum();
+    if samples.is_empty() || total <= 0.0 {
+        return 0.0;
+    }
+    let mean = total / samples.len() as f64;
+    let above: f64 = samples.iter().map(|&v| (v as f64 - mean).max(0.0)).sum();
+    (above / total) as f32
+}
+
+/// Frequency of the strongest non-DC Fourier component in Hz.
+///
+/// The result is at most [`Waveform::nyquist`]; faster modulation shows up
+/// at its alias.
+pub fn dominant_frequency(waveform: &Waveform) -> f32 {
+    let amps = amplitude_spectrum(&waveform.samples);
+    let df = frequency_step(waveform);
+    amps.iter()
+        .enumerate()
+        .skip(1)
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(k, _)| k as f32 * df)
+        .unwrap_or(0.0)
+}
+
+/// Classify a modulation against the IEEE 1789-2015 recommended practice.
+///
+/// Boundaries (modulation in %, frequency in Hz):
+/// - Low risk: `0.025·f` below 90 Hz, `0.08·f` up to 1250 Hz, unrestricted above.
+/// - No observable effect: `0.01·f` below 90 Hz, `0.0333·f` up to 3000 Hz,
+///   unrestricted above.
+pub fn ieee1789_risk(percent_flicker: f32, frequency: f32) -> FlickerRisk {
+    let noe_limit = if frequency < 90.0 {
+        0.01 * frequency
+    } else if frequency <= 3000.0 {
+        0.0333 * frequency
+    } else {
+        f32::INFINITY
+    };
+    let low_risk_limit = if frequency < 90.0 {
+        0.025 * frequency
+    } else if frequency <= 1250.0 {
+        0.08 * frequency
+    } else {
+        f32::INFINITY
+    };
+
+    if percent_flicker <= noe_limit {
+        FlickerRisk::NoObservableEffect
+    } else if percent_flicker <= low_risk_limit {
+        FlickerRisk::LowRisk
+    } else {
+        FlickerRisk::HighRisk
+    }
+}
+
+/// Stroboscopic visibility measure (CIE TN 006:2016).
+///
+/// `SVM = (Σ (Cm / Tm)^3.7)^(1/3.7)` over the Fourier components between
+/// 80 Hz and 2 kHz, where `Cm` is the component amplitude relative to the DC
+/// level and `Tm` the visibility threshold at that frequency. Components the
+/// sampling rate cannot resolve are simply absent, so a record whose Nyquist
+/// frequency is below 2 kHz only bounds the SVM from below.
+///
+/// Returns `None` if no component falls in 80 Hz-2 kHz, as for the device's
+/// own slow series, or the record has no DC level.
+pub fn calculate_svm(waveform: &Waveform) -> Option<f32> {
+    const F_MIN: f64 = 80.0;
+    const F_MAX: f64 = 2000.0;
+
+    let amps = amplitude_spectrum(&waveform.samples);
+    if amps.is_empty() || amps[0] <= 0.0 {
+        return None;
+    }
+    let df = frequency_step(waveform) as f64;
+
+    let terms: Vec<f64> = amps
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, &a)| (k as f64 * df, a / amps[0]))
+        .filter(|(f, _)| (F_MIN..=F_MAX).contains(f))
+        .map(|(f, c)| (c / svm_threshold(f)).powf(3.7))
+        .collect();
+    if terms.is_empty() {
+        return None;
+    }
+    Some(terms.iter().sum::<f64>().powf(1.0 / 3.7) as f32)
+}
+
+/// Spacing of the Fourier components, `1 / duration`, in Hz.
+fn frequency_step(waveform: &Waveform) -> f32 {
+    waveform.sample_rate / waveform.samples.len().max(1) as f32
+}
+
+/// Visibility threshold of the stroboscopic effect for a sinusoidal modulation.
+fn svm_threshold(f: f64) -> f64 {
+    1.0 / (1.0 + (-0.00518 * (f - 306.6)).exp()) + 20.0 * (-f / 10.0).exp()
+}
+
+// ============================================================================
+// Pst LM (IEC TR 61547-1 light flickermeter)
+// ============================================================================
+
+/// Peak relative light modulation at 8.8 Hz that yields an instantaneous
+/// flicker sensation of 1 (the perceptibility threshold).
+const PINST_REFERENCE_MODULATION: f64 = 0.003;
+
+/// Time allowed for the weighting filters to settle before statistics start.
+const PST_SETTLE_SECONDS: f64 = 1.0;
+
+/// Short-term flicker severity of the light signal (Pst LM).
+///
+/// The signal is normalized to its mean, passed through the IEC 61000-4-15
+/// band-pass and eye-brain weighting filters, squared and smoothed with a
+/// 300 ms first-order low-pass to give the instantaneous flicker sensation.
+/// Pst LM then follows from the multipoint percentile formula. The standard
+/// observation period is 10 minutes; shorter records are evaluated over
+/// whatever is available after [`PST_SETTLE_SECONDS`].
+///
+/// Returns `None` if the record is too short or sampled below 70 Hz (the
+/// weighting filter extends to 35 Hz).
+pub fn calculate_pst_lm(waveform: &Waveform) -> Option<f32> {
+    let fs = waveform.sample_rate as f64;
+    let settle = (PST_SETTLE_SECONDS * fs).ceil() as usize;
+    if fs < 70.0 || waveform.samples.len() < settle * 2 {
+        return None;
+    }
+
+    let mean =
+        waveform.samples.iter().map(|&v| v as f64).sum::<f64>() / waveform.samples.len() as f64;
+    if mean <= 0.0 {
+        return None;
+    }
+
+    // Block 3: band-pass (0.05 Hz high-pass, 35 Hz 6th-order Butterworth
+    // low-pass) followed by the eye-brain weighting filter.
+    let w = |f: f64| 2.0 * PI * f;
+    let wc = w(35.0);
+    let (k, lambda, w1, w2, w3, w4) = (
+        1.74802,
+        w(4.05981),
+        w(9.15494),
+        w(2.27979),
+        w(1.22535),
+        w(21.9),
+    );
+    let mut chain = vec![Biquad::from_analog(
+        [0.0, 1.0, 0.0],
+        [0.0, 1.0, w(0.05)],
+        fs,
+    )];
+    for q in [0.517_638, std::f64::consts::FRAC_1_SQRT_2, 1.931_852] {
+        chain.push(Biquad::from_analog(
+            [0.0, 0.0, wc * wc],
+            [1.0, wc / q, wc * wc],
+            fs,
+        ));
+    }
+    chain.push(Biquad::from_analog(
+        [0.0, k * w1, 0.0],
+        [1.0, 2.0 * lambda, w1 * w1],
+        fs,
+    ));
+    chain.push(Biquad::from_analog(
+        [0.0, 1.0 / w2, 1.0],
+        [1.0 / (w3 * w4), 1.0 / w3 + 1.0 / w4, 1.0],
+        fs,
+    ));
+    // Block 4: squaring followed by a 300 ms sliding mean.
+    let mut smoothing = Biquad::from_analog([0.0, 0.0, 1.0], [0.0, 0.3, 1.0], fs);
+
+    // Scale so that the 8.8 Hz reference modulation yields P_inst = 1.
+    let gain_8_8 = weighting_gain(w(8.8), wc, k, lambda, w1, w2, w3, w4);
+    let scale = 2.0 / (PINST_REFERENCE_MODULATION * gain_8_8).powi(2);
+
+    let mut p_inst = Vec::with_capacity(waveform.samples.len());
+    for (i, &v) in waveform.samples.iter().enumerate() {
+        let mut x = v as f64 / mean - 1.0;
+        for stage in chain.iter_mut() {
+            x = stage.process(x);
+        }
+        let p = smoothing.process(x * x) * scale;
+        if i >= settle {
+            p_inst.push(p);
+        }
+    }
+
+    // Block 5: multipoint percentile evaluation (levels exceeded p% of the time)
+    p_inst.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    let level = |percent: f64| {
+        let idx = ((percent / 100.0) * p_inst.len() as f64) as usize;
+        p_inst[idx.min(p_inst.len() - 1)]
+    };
+    let p0_1 = level(0.1);
+    let p1s = (level(0.7) + level(1.0) + level(1.5)) / 3.0;
+    let p3s = (level(2.2) + level(3.0) + level(4.0)) / 3.0;
+    let p10s = (level(6.0) + level(8.0) + level(10.0) + level(13.0) + level(17.0)) / 5.0;
+    let p50s = (level(30.0) + level(50.0) + level(80.0)) / 3.0;
+
+    let pst = (0.0314 * p0_1 + 0.0525 * p1s + 0.0657 * p3s + 0.28 * p10s + 0.08 * p50s).sqrt();
+    Some(pst as f32)
+}
+
+/// Magnitude of the complete block 3 response at angular frequency `wf`.
+#[allow(clippy::too_many_arguments)]
+fn weighting_gain(
+    wf: f64,
+    wc: f64,
+    k: f64,
+    lambda: f64,
+    w1: f64,
+    w2: f64,
+    w3: f64,
+    w4: f64,
+) -> f64 {
+    let butterworth = 1.0 / (1.0 + (wf / wc).powi(12)).sqrt();
+    let eye = k * w1 * wf / ((w1 * w1 - wf * wf).powi(2) + (2.0 * lambda * wf).powi(2)).sqrt();
+    let shaping = (1.0 + (wf / w2).powi(2)).sqrt()
+        / ((1.0 + (wf / w3).powi(2)).sqrt() * (1.0 + (wf / w4).powi(2)).sqrt());
+    butterworth * eye * shaping
+}
+
+/// Second-order IIR section in direct form II transposed.
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 3],
+    z: [f64; 2],
+}
+
+impl Biquad {
+    /// Discretize `(b0 s² + b1 s + b2) / (a0 s² + a1 s + a2)` with the
+    /// bilinear transform.
+    fn from_analog(b: [f64; 3], a: [f64; 3], fs: f64) -> Self {
+        let k = 2.0 * fs;
+        let k2 = k * k;
+        let bz = [
+            b[0] * k2 + b[1] * k + b[2],
+            2.0 * (b[2] - b[0] * k2),
+            b[0] * k2 - b[1] * k + b[2],
+        ];
+        let az = [
+            a[0] * k2 + a[1] * k + a[2],
+            2.0 * (a[2] - a[0] * k2),
+            a[0] * k2 - a[1] * k + a[2],
+        ];
+        Self {
+            b: [bz[0] / az[0], bz[1] / az[0], bz[2] / az[0]],
+            a: [1.0, az[1] / az[0], az[2] / az[0]],
+            z: [0.0; 2],
+        }
+    }
+
+    fn process(&mut self, x: f64) -> f64 {
+        let y = self.b[0] * x + self.z[0];
+        self.z[0] = self.b[1] * x - self.a[1] * y + self.z[1];
+        self.z[1] = self.b[2] * x - self.a[2] * y;
+        y
+    }
+}
+
+// ============================================================================
+// Fourier analysis
+// ============================================================================
+
+/// Single-sided amplitude spectrum: DC level first, then the amplitude of
+/// each harmonic of `1 / duration` up to the Nyquist frequency.
+fn amplitude_spectrum(samples: &[f32]) -> Vec<f64> {
+    let n = samples.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let input: Vec<(f64, f64)> = samples.iter().map(|&v| (v as f64, 0.0)).collect();
+    let spectrum = dft(&input);
+
+    (0..=n / 2)
+        .map(|k| {
+            let (re, im) = spectrum[k];
+            let mag = (re * re + im * im).sqrt() / n as f64;
+            if k == 0 || (n.is_multiple_of(2) && k == n / 2) {
+                mag
+            } else {
+                2.0 * mag
+            }
+        })
+        .collect()
+}
+
+/// Discrete Fourier transform of arbitrary length (Bluestein's algorithm on
+/// top of a radix-2 FFT).
+fn dft(x: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let n = x.len();
+    if n.is_power_of_two() {
+        let mut data = x.to_vec();
+        fft_pow2(&mut data, false);
+        return data;
+    }
+
+    let m = (2 * n - 1).next_power_of_two();
+    // Chirp w_k = exp(-iπk²/n); k² is reduced mod 2n to keep the phase exact.
+    let chirp: Vec<(f64, f64)> = (0..n)
+        .map(|k| {
+            let phase = PI * ((k * k) % (2 * n)) as f64 / n as f64;
+            (phase.cos(), -phase.sin())
+        })
+        .collect();
+
+    let mut a = vec![(0.0, 0.0); m];
+    for k in 0..n {
+        a[k] = cmul(x[k], chirp[k]);
+    }
+    let mut b = vec![(0.0, 0.0); m];
+    b[0] = conj(chirp[0]);
+    for k in 1..n {
+        b[k] = conj(chirp[k]);
+        b[m - k] = conj(chirp[k]);
+    }
+
+    fft_pow2(&mut a, false);
+    fft_pow2(&mut b, false);
+    for (ai, bi) in a.iter_mut().zip(b.iter()) {
+        *ai = cmul(*ai, *bi);
+    }
+    fft_pow2(&mut a, true);
+
+    (0..n).map(|k| cmul(a[k], chirp[k])).collect()
+}
+
+/// In-place iterative radix-2 FFT. The inverse transform is normalized.
+fn fft_pow2(data: &mut [(f64, f64)], inverse: bool) {
+    let n = data.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2.0 * PI / len as f64;
+        let step = (angle.cos(), angle.sin());
+        for start in (0..n).step_by(len) {
+            let mut w = (1.0, 0.0);
+            for k in 0..len / 2 {
+                let u = data[start + k];
+                let v = cmul(data[start + k + len / 2], w);
+                data[start + k] = (u.0 + v.0, u.1 + v.1);
+                data[start + k + len / 2] = (u.0 - v.0, u.1 - v.1);
+                w = cmul(w, step);
+            }
+        }
+        len <<= 1;
+    }
+
+    if inverse {
+        for v in data.iter_mut() {
+            v.0 /= n as f64;
+            v.1 /= n as f64;
+        }
+    }
+}
+
+fn cmul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+fn conj(a: (f64, f64)) -> (f64, f64) {
+    (a.0, -a.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine_wave(freq: f32, modulation: f32, fs: f32, seconds: f32) -> Waveform {
+        let n = (fs * seconds) as usize;
+        let samples = (0..n)
+            .map(|i| {
+                let t = i as f32 / fs;
+                1.0 + modulation * (2.0 * std::f32::consts::PI * freq * t).sin()
+            })
+            .collect();
+        Waveform::new(samples, fs)
+    }
+
+    #[test]
+    fn test_sine_modulation() {
+        // 100 Hz, 30% modulation, sampled at 10 kHz for 1 s (not a power of two)
+        let wf = sine_wave(100.0, 0.3, 10000.0, 1.0);
+        let m = calculate_flicker(&wf);
+
+        assert!((m.percent_flicker - 30.0).abs() < 0.1);
+        // Flicker index of a sine is modulation / π
+        assert!((m.flicker_index - 0.3 / std::f32::consts::PI).abs() < 0.002);
+        assert!((m.frequency.unwrap() - 100.0).abs() < 0.5);
+        // 30% at 100 Hz is above the 8% low-risk limit
+        assert_eq!(m.risk, Some(FlickerRisk::HighRisk));
+        // C = 0.3 against a threshold of ~0.256 at 100 Hz
+        let svm = m.svm.unwrap();
+        assert!((svm - 1.17).abs() < 0.02, "SVM {}", svm);
+    }
+
+    #[test]
+    fn test_steady_light() {
+        let wf = Waveform::new(vec![5.0; 4096], 2000.0);
+        let m = calculate_flicker(&wf);
+        assert_eq!(m.percent_flicker, 0.0);
+        assert_eq!(m.risk, Some(FlickerRisk::NoObservableEffect));
+        assert!(m.svm.unwrap() < 1e-6);
+        assert!(m.pst_lm.unwrap() < 1e-3);
+    }
+
+    #[test]
+    fn test_slow_sampling() {
+        // 100 Hz flicker seen by the device at 30 Hz
+        let wf = sine_wave(100.0, 0.3, 30.0, 10.0);
+        let m = calculate_flicker(&wf);
+        assert!(m.svm.is_none());
+        assert!(m.pst_lm.is_none());
+        // Aliased to 10 Hz, which the record cannot tell from the real thing
+        assert!((dominant_frequency(&wf) - 10.0).abs() < 0.2);
+
+        // A component at the Nyquist frequency is refused
+        let samples = (0..300)
+            .map(|i| if i % 2 == 0 { 1.3 } else { 0.7 })
+            .collect();
+        let m = calculate_flicker(&Waveform::new(samples, 30.0));
+        assert!(m.percent_flicker > 29.0);
+        assert!(m.frequency.is_none() && m.risk.is_none());
+    }
+
+    #[test]
+    fn test_pst_reference_point() {
+        // The reference modulation at 8.8 Hz holds P_inst at 1 throughout,
+        // so every percentile is 1 and Pst LM = √0.5096.
+        let wf = sine_wave(8.8, PINST_REFERENCE_MODULATION as f32, 1000.0, 20.0);
+        let pst = calculate_pst_lm(&wf).unwrap();
+        assert!((pst - 0.714).abs() < 0.05, "Pst LM {}", pst);
+    }
+
+    #[test]
+    fn test_waveform_csv() {
+        let text = "time,lux\n0.000,10\n0.001,12\n0.002,10\n0.003,8\n";
+        let wf = Waveform::from_csv(text).unwrap();
+        assert_eq!(wf.samples.len(), 4);
+        assert!((wf.sample_rate - 1000.0).abs() < 0.1);
+
+        assert!(Waveform::from_csv("0,1\n0.001,2\n0.005,3\n").is_err());
+    }
+}
@@ -0,0 +1,230 @@
+//! Horticultural Lighting Metrics
+//!
+//! Plants respond to the number of photons they absorb rather than to
+//! luminous or radiant power, so grow lights are specified in photon units
+//! (ANSI/ASABE S640):
+//!
+//! - PPFD, photosynthetic photon flux density: photons over 400-700nm, in
+//!   µmol/(m²·s).
+//! - YPF, yield photon flux: photons over 360-760nm weighted by the relative
+//!   quantum efficiency of photosynthesis (McCree, 1972).
+//! - Photon flux in the blue, green, red and far-red bands, and the
+//!   red:far-red ratio that drives shade avoidance through phytochrome.
+//!
+//! The ColorMunki stops at 730nm, half way through the far-red band and in
+//! the middle of the 725-735nm far-red window of the R:FR ratio;
+//! [`HorticultureMetrics::far_red_truncated`] flags such readings.
+
+use crate::spectrum::SpectralData;
+use crate::{Result, SpectroError};
+
+/// Micromoles of photons per joule at 1nm, 10⁻³ / (h·c·N_A).
+pub const MICROMOL_PER_JOULE_NM: f32 = 0.008_359_34;
+
+/// Range of photosynthetically active radiation, in nm.
+pub const PAR_RANGE: (f32, f32) = (400.0, 700.0);
+
+/// Red and far-red windows of the R:FR ratio (Smith, 1982), in nm.
+pub const RED_FAR_RED_WINDOWS: [(f32, f32); 2] = [(655.0, 665.0), (725.0, 735.0)];
+
+/// First wavelength of [`MCCREE_RQE`], in nm.
+const MCCREE_START: f32 = 360.0;
+
+/// Relative quantum efficiency of photosynthesis, the average for field
+/// crops of McCree (1972), at 10nm over 360-760nm with a peak of 1. Read
+/// from the published curve; YPF is good to about 1%.
+#[rustfmt::skip]
+const MCCREE_RQE: [f32; 41] = [
+    0.28, 0.36, 0.45, 0.55, 0.63, 0.68, 0.71, 0.72, 0.73, 0.72,
+    0.70, 0.68, 0.67, 0.67, 0.68, 0.70, 0.72, 0.75, 0.78, 0.81,
+    0.84, 0.88, 0.92, 0.96, 0.99, 1.00, 1.00, 0.99, 0.96, 0.94,
+    0.95, 0.96, 0.92, 0.75, 0.50, 0.28, 0.14, 0.07, 0.03, 0.01,
+    0.0,
+];
+
+/// Spectral bands of ANSI/ASABE S640.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhotonBand {
+    Blue,
+    Green,
+    Red,
+    FarRed,
+}
+
+impl PhotonBand {
+    /// All four, in the order used by [`HorticultureMetrics`].
+    pub const ALL: [PhotonBand; 4] = [
+        PhotonBand::Blue,
+        PhotonBand::Green,
+        PhotonBand::Red,
+        PhotonBand::FarRed,
+    ];
+
+    /// Short name for reports.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PhotonBand::Blue => "Blue",
+            PhotonBand::Green => "Green",
+            PhotonBand::Red => "Red",
+            PhotonBand::FarRed => "Far-red",
+        }
+    }
+
+    /// Wavelength range in nm.
+    pub fn range(&self) -> (f32, f32) {
+        match self {
+            PhotonBand::Blue => (400.0, 500.0),
+            PhotonBand::Green => (500.0, 600.0),
+            PhotonBand::Red => (600.0, 700.0),
+            PhotonBand::FarRed => (700.0, 800.0),
+        }
+    }
+}
+
+/// Photon quantities of a light source.
+///
+/// For spectral irradiance the fluxes are in µmol/(m²·s); for radiance,
+/// µmol/(m²·s·sr). Relative spectra give relative values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HorticultureMetrics {
+    /// Photosynthetic photon flux density over [`PAR_RANGE`].
+    pub ppfd: f32,
+    /// Yield photon flux.
+    pub ypf: f32,
+    /// Photon flux in each band, indexed as [`PhotonBand::ALL`].
+    pub bands: [f32; 4],
+    /// Red:far-red photon ratio over [`RED_FAR_RED_WINDOWS`], or `None`
+    /// without far-red data.
+    pub red_far_red: Option<f32>,
+    /// Last wavelength of the measurement, in nm.
+    pub measured_to: f32,
+}
+
+impl HorticultureMetrics {
+    /// Photon flux of a band.
+    pub fn band(&self, band: PhotonBand) -> f32 {
+        self.bands[band as usize]
+    }
+
+    /// Each band's share of the photons over 400-800nm.
+    pub fn fractions(&self) -> [f32; 4] {
+        let total: f32 = self.bands.iter().sum();
+        self.bands
+            .map(|b| if total > 0.0 { b / total } else { 0.0 })
+    }
+
+    /// Whether the measurement ends inside the far-red window of R:FR, so
+    /// the far-red flux, its fraction and R:FR rest on extrapolated values.
+    pub fn far_red_truncated(&self) -> bool {
+        self.measured_to < RED_FAR_RED_WINDOWS[1].1
+    }
+}
+
+/// Calculate the photon quantities of an emissive spectrum.
+///
+/// # Errors
+///
+/// Returns [`SpectroError::Data`] for reflectance or transmittance and for
+/// data that fails [`SpectralData::validate`].
+pub fn calculate_horticulture(spd: &SpectralData) -> Result<HorticultureMetrics> {
+    if !spd.quantity.is_emissive() {
+        return Err(SpectroError::Data(
+            "Photon quantities need a light source spectrum".into(),
+        ));
+    }
+    spd.validate()?;
+
+    let flat = |_: f32| 1.0;
+    let ppfd = photon_flux(spd, PAR_RANGE, flat);
+    let ypf = photon_flux(spd, (MCCREE_START, 760.0), mccree_rqe);
+    let bands = PhotonBand::ALL.map(|band| photon_flux(spd, band.range(), flat));
+    let [red, far_red] = RED_FAR_RED_WINDOWS.map(|window| photon_flux(spd, window, flat));
+    let red_far_red = (far_red > 0.0).then(|| red / far_red);
+
+    Ok(HorticultureMetrics {
+        ppfd,
+        ypf,
+        bands,
+        red_far_red,
+        measured_to: spd.wavelengths[spd.wavelengths.len() - 1],
+    })
+}
+
+/// McCree relative quantum efficiency, linearly interpolated; zero outside
+/// 360-760nm.
+fn mccree_rqe(wavelength: f32) -> f32 {
+    let t = (wavelength - MCCREE_START) / 10.0;
+    if !(0.0..=(MCCREE_RQE.len() - 1) as f32).contains(&t) {
+        return 0.0;
+    }
+    let i = (t.floor() as usize).min(MCCREE_RQE.len() - 2);
+    let x = t - i as f32;
+    MCCREE_RQE[i] + x * (MCCREE_RQE[i + 1] - MCCREE_RQE[i])
+}
+
+/// Photon flux over a wavelength range, each sample counted for the part of
+/// its band (half way to its neighbours) inside the range.
+fn photon_flux(spd: &SpectralData, (lo, hi): (f32, f32), weight: impl Fn(f32) -> f32) -> f32 {
+    spd.wavelengths
+        .iter()
+        .zip(&spd.values)
+        .zip(spd.band_widths())
+        .enumerate()
+        .map(|(i, ((&wl, &v), dw))| {
+            let wl_lo = if i == 0 {
+                wl - 0.5 * dw
+            } else {
+                0.5 * (wl + spd.wavelengths[i - 1])
+            };
+            let wl_hi = wl_lo + dw;
+            let overlap = (wl_hi.min(hi) - wl_lo.max(lo)).max(0.0);
+            v * wl * MICROMOL_PER_JOULE_NM * weight(wl) * overlap
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spectrum::Quantity;
+    use crate::{cie_data, Illuminant, WAVELENGTHS};
+
+    #[test]
+    fn test_flat_spectrum() {
+        // 1 W/(m²·nm) over 380-780nm
+        let wavelengths: Vec<f32> = (0..cie_data::LEN).map(|i| 380.0 + i as f32).collect();
+        let values = vec![1.0; cie_data::LEN];
+        let spd = SpectralData::from_grid(wavelengths, values, Quantity::Irradiance).unwrap();
+        let m = calculate_horticulture(&spd).unwrap();
+
+        assert!((m.ppfd - 550.0 * 300.0 * MICROMOL_PER_JOULE_NM).abs() < 0.5);
+        assert!(
+            (m.band(PhotonBand::Blue) + m.band(PhotonBand::Green) + m.band(PhotonBand::Red)
+                - m.ppfd)
+                .abs()
+                < 0.5
+        );
+        assert!(
+            (0.8..0.95).contains(&(m.ypf / m.ppfd)),
+            "{}",
+            m.ypf / m.ppfd
+        );
+        assert!((m.fractions().iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        assert!((m.red_far_red.unwrap() - 660.0 / 730.0).abs() < 1e-3);
+        assert!(!m.far_red_truncated());
+    }
+
+    #[test]
+    fn test_munki_range() {
+        // Tungsten on the instrument's 380-730nm grid
+        let wavelengths = WAVELENGTHS[..36].to_vec();
+        let values = wavelengths
+            .iter()
+            .map(|&wl| Illuminant::A.spd_at(wl))
+            .collect();
+        let spd = SpectralData::from_grid(wavelengths, values, Quantity::Irradiance).unwrap();
+        let m = calculate_horticulture(&spd).unwrap();
+        assert!(m.far_red_truncated());
+        assert!(m.band(PhotonBand::Red) > m.band(PhotonBand::Blue));
+    }
+}
@@ -4,7 +4,7 @@
 //!
 //! This crate provides a safe, ergonomic interface for interacting with
 //! ColorMunki (Original and Design) devices, supporting reflective, emissive,
-//! and ambient measurement modes.
+//! ambient and projector measurement modes.
 //!
 //! ## Quick Start
 //!
@@ -69,6 +69,10 @@ pub enum SpectroError {
     /// Measurement mode mismatch.
     #[error("Mode Mismatch: {0}")]
     Mode(String),
+
+    /// Invalid or malformed input data.
+    #[error("Data Error: {0}")]
+    Data(String),
 }
 
 /// A specialized [`Result`] type for spectrometer operations.
@@ -86,22 +90,41 @@ pub const WAVELENGTHS: [f32; 41] = [
     770.0, 780.0,
 ];
 
+/// Luminance of one foot-lambert in cd/m² (1/π cd/ft²).
+pub const CANDELAS_PER_FOOTLAMBERT: f32 = 3.426_259;
+
 // ============================================================================
 // Public Modules
 // ============================================================================
 
+pub mod bandpass;
 pub mod cam02;
+pub mod cct;
+pub mod cie2006;
+pub mod cie224;
+pub mod cie_data;
+pub mod circadian;
 pub mod colorimetry;
+pub mod cqs;
 pub mod device;
+pub mod e308;
+pub mod flash;
+pub mod flicker;
+pub mod horticulture;
 pub mod i18n;
 pub mod icc;
+pub mod metadata;
 pub mod munki;
 pub mod persistence;
+pub mod photometry;
 pub mod spectrum;
 pub mod sprague;
+pub mod ssi;
+pub mod tlci;
 pub mod tm30;
 pub mod tm30_data;
 pub mod tm30_data_cmf;
+pub mod tm30_report;
 pub mod transport;
 
 // ============================================================================
@@ -109,7 +132,7 @@ pub mod transport;
 // ============================================================================
 
 pub use device::{BoxedSpectrometer, DeviceInfo, DevicePosition, DeviceStatus, Spectrometer};
-pub use spectrum::{MeasurementMode as SpectrumMeasurementMode, SpectralData};
+pub use spectrum::{Quantity, SpectralData};
 pub use transport::{Transport, UsbTransport};
 
 // ============================================================================
@@ -130,9 +153,42 @@ pub enum MeasurementMode {
     /// Ambient light measurement.
     /// Requires the diffuser attachment to be in place.
     Ambient,
+
+    /// Projector (tele) measurement of a screen from a distance.
+    /// Uses a longer integration time with its own dark reference, so
+    /// calibration is required; the dial must be in the Projector position.
+    Projector,
+
+    /// Flash (strobe) measurement.
+    /// Arms the device for a short capture window, integrates the single
+    /// flash fired during it and subtracts the ambient background. Uses the
+    /// Ambient dial position.
+    Flash,
+
+    /// Transmissive measurement (filters, gels, film).
+    /// Measures through the sample in the mode used to capture the light
+    /// source reference (Emissive or Ambient) and returns spectral
+    /// transmittance; a reference must be captured first.
+    Transmissive,
+}
+
+impl MeasurementMode {
+    /// The physical quantity a reading in this mode produces.
+    pub fn quantity(&self) -> Quantity {
+        match self {
+            MeasurementMode::Reflective => Quantity::Reflectance,
+            MeasurementMode::Transmissive => Quantity::Transmittance,
+            MeasurementMode::Emissive | MeasurementMode::Projector => Quantity::Radiance,
+            // A flash reading is an irradiance integrated over time
+            MeasurementMode::Ambient | MeasurementMode::Flash => Quantity::Irradiance,
+        }
+    }
 }
 
 /// Standard CIE Illuminants.
+///
+/// Spectral data is in [`cie_data`] (1nm, 380-780nm).
+#[allow(non_camel_case_types)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Illuminant {
     D50,
@@ -140,9 +196,28 @@ pub enum Illuminant {
     D65,
     D75,
     A,
+    C,
+    F1,
     F2,
+    F3,
+    F4,
+    F5,
+    F6,
     F7,
+    F8,
+    F9,
+    F10,
     F11,
+    F12,
+    LED_B1,
+    LED_B2,
+    LED_B3,
+    LED_B4,
+    LED_B5,
+    LED_BH1,
+    LED_RGB1,
+    LED_V1,
+    LED_V2,
 }
 
 /// Standard CIE Observers.
@@ -152,6 +227,10 @@ pub enum Observer {
     CIE1931_2,
     /// CIE 1964 10° Supplementary Standard Observer (Large field of view > 4°)
     CIE1964_10,
+    /// CIE 2015 2° observer, derived from the CIE 2006 cone fundamentals
+    CIE2015_2,
+    /// CIE 2015 10° observer, derived from the CIE 2006 cone fundamentals
+    CIE2015_10,
 }
 
 // ============================================================================
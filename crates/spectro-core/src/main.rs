@@ -2,14 +2,33 @@
 //!
 //! This is the interactive command-line interface for the spectro-rs library.
 
-use dialoguer::{theme::ColorfulTheme, Select};
+use dialoguer::{theme::ColorfulTheme, Input, Select};
 use spectro_rs::{
-    colorimetry::XYZ, device::DevicePosition, discover, i18n, t, MeasurementMode, Result,
+    circadian,
+    colorimetry::XYZ,
+    device::DevicePosition,
+    discover, flash, flicker,
+    horticulture::{self, PhotonBand},
+    i18n, persistence,
+    photometry::{self, Photometric},
+    t, tlci, Illuminant, MeasurementMode, Observer, Quantity, Result,
 };
 
 fn main() -> Result<()> {
     i18n::init_i18n();
 
+    // Flicker of an imported waveform needs no device
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(i) = args.iter().position(|a| a == "--waveform") {
+        let path = args
+            .get(i + 1)
+            .ok_or_else(|| spectro_rs::SpectroError::Data("--waveform needs a file name".into()))?;
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| spectro_rs::SpectroError::Data(format!("{}: {}", path, e)))?;
+        print_flicker(&flicker::Waveform::from_csv(&text)?);
+        return Ok(());
+    }
+
     // --- Original CLI Logic ---
     println!("{}", t!("welcome"));
     println!("{}", t!("scanning"));
@@ -30,12 +49,25 @@ fn main() -> Result<()> {
     println!("  Serial: {}", info.serial);
     println!("  Firmware: {}", info.firmware);
 
+    // TLCI needs the EBU camera and chart data installed by the user
+    let tlci_model = persistence::load_tlci_model().unwrap_or_else(|e| {
+        println!("\x1b[31mTLCI model: {}\x1b[0m", e);
+        None
+    });
+    let mut last_ambient = None;
+
     loop {
         let selections = &[
             t!("menu-measure").to_string(),
             t!("menu-measure-emissive").to_string(),
             t!("menu-measure-ambient").to_string(),
+            t!("menu-measure-projector").to_string(),
+            t!("menu-measure-flash").to_string(),
+            t!("menu-measure-transmissive").to_string(),
+            t!("menu-transmission-reference").to_string(),
+            t!("menu-measure-flicker").to_string(),
             t!("menu-calibrate").to_string(),
+            t!("menu-reference-meter").to_string(),
             t!("menu-exit").to_string(),
         ];
 
@@ -47,15 +79,18 @@ fn main() -> Result<()> {
             .unwrap();
 
         match selection {
-            0..=2 => {
+            0..=5 => {
                 let mode = match selection {
                     0 => MeasurementMode::Reflective,
                     1 => MeasurementMode::Emissive,
-                    _ => MeasurementMode::Ambient,
+                    2 => MeasurementMode::Ambient,
+                    3 => MeasurementMode::Projector,
+                    4 => MeasurementMode::Flash,
+                    _ => MeasurementMode::Transmissive,
                 };
 
-                // Check dial position for ambient mode
-                if mode == MeasurementMode::Ambient {
+                // Check dial position for ambient and flash modes
+                if mode == MeasurementMode::Ambient || mode == MeasurementMode::Flash {
                     let status = device.status()?;
                     if status.position != DevicePosition::Ambient
                         && status.position != DevicePosition::Surface
@@ -66,13 +101,82 @@ fn main() -> Result<()> {
                     }
                 }
 
-                // Check calibration for reflective mode
-                if mode == MeasurementMode::Reflective && !device.is_calibrated(mode) {
-                    println!("\n\x1b[31m[Warning]\x1b[0m Reflective mode needs calibration first.");
+                // Check dial position for projector mode
+                if mode == MeasurementMode::Projector
+                    && device.status()?.position != DevicePosition::Projector
+                {
+                    println!(
+                        "\n\x1b[33m[Notice]\x1b[0m Please turn the dial to the \x1b[1mProjector\x1b[0m position."
+                    );
+                }
+
+                // Check calibration (or the transmission reference)
+                if mode == MeasurementMode::Transmissive && !device.is_calibrated(mode) {
+                    println!(
+                        "\n\x1b[31m[Warning]\x1b[0m {}",
+                        t!("transmission-reference-missing")
+                    );
                     continue;
                 }
+                if !device.is_calibrated(mode) {
+                    println!(
+                        "\n\x1b[31m[Warning]\x1b[0m {:?} mode needs calibration first.",
+                        mode
+                    );
+                    continue;
+                }
+
+                if mode == MeasurementMode::Flash {
+                    println!("\n\x1b[1;33m{}\x1b[0m", t!("flash-armed"));
+                }
 
                 match device.measure(mode) {
+                    Ok(spec) if mode == MeasurementMode::Flash => {
+                        let m = flash::analyze_flash(&spec);
+                        println!("\n\x1b[32m{}\x1b[0m", t!("spectral-success"));
+                        println!("\x1b[36mFlash Exposure:\x1b[0m {:.3} lux·s", m.exposure);
+                        println!(
+                            "\x1b[36mCCT:\x1b[0m {:.0} K  \x1b[36mDuv:\x1b[0m {:.4}",
+                            m.cct, m.duv
+                        );
+                        println!("\x1b[36mCRI:\x1b[0m Ra {:.1}, R9 {:.1}", m.ra, m.r9);
+                        if let Some(tm30) = &m.tm30 {
+                            println!("\x1b[36mTM-30:\x1b[0m Rf {:.1}, Rg {:.1}", tm30.rf, tm30.rg);
+                        }
+                        println!();
+                    }
+                    Ok(spec) if mode == MeasurementMode::Transmissive => {
+                        println!("\n\x1b[32m{}\x1b[0m", t!("spectral-success"));
+                        for source in [Illuminant::D65, Illuminant::A] {
+                            let xyz = spec.to_xyz_ext(source, Observer::CIE1931_2);
+                            let lab = spec.to_lab_ext(source, Observer::CIE1931_2);
+                            println!(
+                                "\x1b[36m{:?}:\x1b[0m Transmittance Y {:.2} %, visual density {:.3}, L:{:.2}, a:{:.2}, b:{:.2}",
+                                source,
+                                xyz.y,
+                                spec.visual_density(source, Observer::CIE1931_2),
+                                lab.l,
+                                lab.a,
+                                lab.b
+                            );
+                        }
+
+                        println!("\n\x1b[90mOptical density (420-730nm):\x1b[0m");
+                        for (wl, d) in spec
+                            .wavelengths
+                            .iter()
+                            .zip(spec.optical_density())
+                            .filter(|(wl, _)| (420.0..=730.0).contains(*wl))
+                        {
+                            println!(
+                                "{:3.0}nm  T {:6.2} %  D {:.3}",
+                                wl,
+                                100.0 * 10f32.powf(-d),
+                                d
+                            );
+                        }
+                        println!();
+                    }
                     Ok(spec) => {
                         println!("\n\x1b[32m{}\x1b[0m", t!("spectral-success"));
 
@@ -87,16 +191,15 @@ fn main() -> Result<()> {
                         };
                         let lab = norm_xyz.to_lab(wp);
 
-                        if mode == MeasurementMode::Emissive {
-                            println!(
-                                "\x1b[36mMonitor Mode:\x1b[0m Screen brightness (nits): {:.2} cd/m²",
-                                norm_xyz.y
-                            );
-                        } else if mode == MeasurementMode::Ambient {
-                            println!(
-                                "\x1b[36mAmbient Mode:\x1b[0m Lighting intensity (relative): {:.2}",
-                                norm_xyz.y
-                            );
+                        if let Some(p) = spec.photometric() {
+                            let label = match mode {
+                                MeasurementMode::Emissive => "Monitor Mode:\x1b[0m Luminance",
+                                MeasurementMode::Projector => {
+                                    "Projector Mode:\x1b[0m Screen luminance"
+                                }
+                                _ => "Ambient Mode:\x1b[0m Illuminance",
+                            };
+                            println!("\x1b[36m{}: {} ({})", label, p, p.imperial());
                         }
 
                         println!(
@@ -117,39 +220,132 @@ fn main() -> Result<()> {
                         if mode != MeasurementMode::Reflective {
                             let cct = norm_xyz.to_cct();
                             println!("\x1b[36mEstimated CCT:\x1b[0m {:.0} K", cct);
+                            if let (Some(sp), Some(ler)) =
+                                (spec.sp_ratio(), spec.luminous_efficacy_of_radiation())
+                            {
+                                println!(
+                                    "\x1b[36mS/P Ratio:\x1b[0m {:.2}  \x1b[36mLER:\x1b[0m {:.0} lm/W",
+                                    sp, ler
+                                );
+                            }
+                            if let Some(p) = spec.photometric().filter(|p| !p.unit.is_illuminance())
+                            {
+                                if let Some(mes) = spec.mesopic(p.value) {
+                                    println!(
+                                        "\x1b[36mMesopic Luminance:\x1b[0m {:.3} cd/m² (m = {:.2})",
+                                        mes.luminance, mes.m
+                                    );
+                                }
+                            }
 
-                            // Spectral Centroid (weighted average wavelength)
-                            let total_power: f32 = spec.values.iter().skip(4).sum();
-                            let centroid: f32 = spec
-                                .values
+                            if mode == MeasurementMode::Ambient {
+                                if let Ok(a) = circadian::calculate_alpha_opic(&spec) {
+                                    println!(
+                                        "\x1b[36mMelanopic EDI:\x1b[0m {:.1} lx (DER {:.3}, {:.1} EML)",
+                                        a.melanopic_edi(),
+                                        a.melanopic_der(),
+                                        a.eml()
+                                    );
+                                }
+                                let exposure = circadian::CircadianExposure::default();
+                                if let Ok(c) =
+                                    circadian::calculate_circadian_stimulus(&spec, &exposure)
+                                {
+                                    println!(
+                                        "\x1b[36mCircadian Light:\x1b[0m CLA {:.0}, CS {:.3} ({} h, full field)",
+                                        c.cla, c.cs, exposure.duration
+                                    );
+                                }
+                                if let Ok(h) = horticulture::calculate_horticulture(&spec) {
+                                    println!(
+                                        "\x1b[36mPPFD:\x1b[0m {:.1} µmol/m²/s (YPF {:.1})",
+                                        h.ppfd, h.ypf
+                                    );
+                                    let fractions: Vec<String> = PhotonBand::ALL
+                                        .iter()
+                                        .zip(h.fractions())
+                                        .map(|(b, f)| format!("{} {:.1}%", b.name(), 100.0 * f))
+                                        .collect();
+                                    println!(
+                                        "\x1b[36mPhoton Fractions:\x1b[0m {}",
+                                        fractions.join(", ")
+                                    );
+                                    if let Some(ratio) = h.red_far_red {
+                                        println!("\x1b[36mR:FR:\x1b[0m {:.2}", ratio);
+                                    }
+                                    if h.far_red_truncated() {
+                                        println!(
+                                            "\x1b[90mFar-red is truncated at {:.0}nm\x1b[0m",
+                                            h.measured_to
+                                        );
+                                    }
+                                }
+
+                                match &tlci_model {
+                                    Some(model) => {
+                                        if let Ok(m) = tlci::calculate_tlci(&spec, model) {
+                                            let (patch, de) = m.worst_patch();
+                                            println!(
+                                                "\x1b[36mTLCI-2012:\x1b[0m Qa {:.0} (worst patch {}, ΔE00 {:.2})",
+                                                m.qa,
+                                                patch + 1,
+                                                de
+                                            );
+                                        }
+                                        if let Some(Ok(m)) = last_ambient
+                                            .as_ref()
+                                            .map(|prev| tlci::calculate_tlmf(&spec, prev, model))
+                                        {
+                                            println!(
+                                                "\x1b[36mTLMF-2013 vs previous reading:\x1b[0m Qa {:.0}",
+                                                m.qa
+                                            );
+                                        }
+                                    }
+                                    None => {
+                                        if let Ok(path) = persistence::tlci_model_path() {
+                                            println!(
+                                                "\x1b[90mTLCI: no camera model at {}\x1b[0m",
+                                                path.display()
+                                            );
+                                        }
+                                    }
+                                }
+                                last_ambient = Some(spec.clone());
+                            }
+
+                            // Skip noise below 420nm
+                            let visible: Vec<(f32, f32)> = spec
+                                .wavelengths
                                 .iter()
-                                .enumerate()
-                                .skip(4) // Start from 420nm
-                                .map(|(i, v)| (380 + i * 10) as f32 * v)
-                                .sum::<f32>()
+                                .cloned()
+                                .zip(spec.values.iter().cloned())
+                                .filter(|(wl, _)| *wl >= 420.0)
+                                .collect();
+
+                            // Spectral Centroid (weighted average wavelength)
+                            let total_power: f32 = visible.iter().map(|(_, v)| v).sum();
+                            let centroid: f32 = visible.iter().map(|(wl, v)| wl * v).sum::<f32>()
                                 / total_power.max(1e-6);
                             println!("\x1b[36mSpectral Centroid:\x1b[0m {:.1} nm", centroid);
 
-                            // Peak detection (skip noise below 420nm)
-                            let peak_idx = spec
-                                .values
+                            // Peak detection
+                            let peak_wl = visible
                                 .iter()
-                                .enumerate()
-                                .skip(4)
                                 .max_by(|a, b| {
-                                    a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal)
+                                    a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal)
                                 })
-                                .map(|(i, _)| i)
-                                .unwrap_or(0);
-                            println!("\x1b[36mPeak Wavelength:\x1b[0m {} nm", 380 + peak_idx * 10);
+                                .map(|(wl, _)| *wl)
+                                .unwrap_or(0.0);
+                            println!("\x1b[36mPeak Wavelength:\x1b[0m {:.0} nm", peak_wl);
 
                             // Simple ASCII spectrum visualization
-                            println!("\n\x1b[90mSpectrum (420-730nm):\x1b[0m");
-                            let max_val =
-                                spec.values.iter().skip(4).cloned().fold(0.0f32, f32::max);
-                            for (i, v) in spec.values.iter().enumerate().skip(4) {
+                            let last_wl = visible.last().map(|(wl, _)| *wl).unwrap_or(420.0);
+                            println!("\n\x1b[90mSpectrum (420-{:.0}nm):\x1b[0m", last_wl);
+                            let max_val = visible.iter().map(|(_, v)| *v).fold(0.0f32, f32::max);
+                            for &(wl, v) in &visible {
                                 let bar_len = ((v / max_val.max(1e-6)) * 30.0) as usize;
-                                let wl = 380 + i * 10;
+                                let wl = wl.round() as u32;
                                 let color = match wl {
                                     420..=450 => "\x1b[34m",       // Blue
                                     451..=500 => "\x1b[36m",       // Cyan
@@ -171,7 +367,43 @@ fn main() -> Result<()> {
                     Err(e) => println!("Error: {}", e),
                 }
             }
-            3 => {
+            6 => {
+                // Transmission reference: read the bare light source in the
+                // mode the transmissive readings will use.
+                let source = if device.status()?.position == DevicePosition::Ambient {
+                    MeasurementMode::Ambient
+                } else {
+                    MeasurementMode::Emissive
+                };
+                println!("\n{}", t!("transmission-reference-prompt"));
+                let mut input = String::new();
+                let _ = std::io::stdin().read_line(&mut input);
+
+                match device.capture_reference(source) {
+                    Ok(reference) => println!(
+                        "\x1b[32m{}\x1b[0m ({:?}, Y = {:.2})\n",
+                        t!("transmission-reference-captured"),
+                        source,
+                        reference.to_xyz().y
+                    ),
+                    Err(e) => println!("\x1b[31mError: {}\x1b[0m\n", e),
+                }
+            }
+            7 => {
+                // Temporal light modulation: Ambient if the diffuser is in place,
+                // otherwise sample the source directly in emissive mode.
+                let mode = if device.status()?.position == DevicePosition::Ambient {
+                    MeasurementMode::Ambient
+                } else {
+                    MeasurementMode::Emissive
+                };
+
+                match device.measure_series(mode, 512) {
+                    Ok(waveform) => print_flicker(&waveform),
+                    Err(e) => println!("Error: {}", e),
+                }
+            }
+            8 => {
                 // Calibrate
                 println!("\n{}", t!("calibration-required"));
                 println!("{}", t!("dial-white-dot"));
@@ -190,9 +422,112 @@ fn main() -> Result<()> {
                     Err(e) => println!("\x1b[31mError: {}\x1b[0m\n", e),
                 }
             }
-            4 => break,
+            9 => {
+                // Match absolute readings to a reference meter: illuminance
+                // with the diffuser in place, luminance otherwise.
+                let (mode, quantity) = if device.status()?.position == DevicePosition::Ambient {
+                    (MeasurementMode::Ambient, Quantity::Irradiance)
+                } else {
+                    (MeasurementMode::Emissive, Quantity::Radiance)
+                };
+                let symbol = if quantity == Quantity::Irradiance {
+                    "lx"
+                } else {
+                    "cd/m²"
+                };
+
+                let reading: f32 = match Input::with_theme(&ColorfulTheme::default())
+                    .with_prompt(format!("{} ({})", t!("reference-meter-prompt"), symbol))
+                    .interact_text()
+                {
+                    Ok(v) => v,
+                    Err(e) => {
+                        println!("Error: {}", e);
+                        continue;
+                    }
+                };
+
+                if reading == 0.0 {
+                    match device.set_reference_factor(quantity, 1.0) {
+                        Ok(_) => println!("\x1b[32m{}\x1b[0m\n", t!("reference-meter-reset")),
+                        Err(e) => println!("\x1b[31mError: {}\x1b[0m\n", e),
+                    }
+                    continue;
+                }
+
+                let measured = device.measure(mode).map(|spec| spec.photometric());
+                let reference = if quantity == Quantity::Irradiance {
+                    Photometric::illuminance(reading)
+                } else {
+                    Photometric::luminance(reading)
+                };
+                let result = match measured {
+                    Ok(Some(measured)) => photometry::reference_factor(measured, reference)
+                        .and_then(|correction| {
+                            let factor = device.reference_factor(quantity) * correction;
+                            device
+                                .set_reference_factor(quantity, factor)
+                                .map(|_| factor)
+                        }),
+                    Ok(None) => Err(spectro_rs::SpectroError::Mode(
+                        "Reading has no absolute units".into(),
+                    )),
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(factor) => println!(
+                        "\x1b[32m{}\x1b[0m {:.4}\n",
+                        t!("reference-meter-saved"),
+                        factor
+                    ),
+                    Err(e) => println!("\x1b[31mError: {}\x1b[0m\n", e),
+                }
+            }
+            10 => break,
             _ => unreachable!(),
         }
     }
     Ok(())
 }
+
+/// Print the temporal light modulation metrics of a waveform.
+fn print_flicker(waveform: &flicker::Waveform) {
+    let m = flicker::calculate_flicker(waveform);
+    println!(
+        "\n\x1b[90m{} samples at {:.1} Hz ({:.2} s)\x1b[0m",
+        waveform.samples.len(),
+        waveform.sample_rate,
+        waveform.duration()
+    );
+    // Mains ripple is at 100 or 120 Hz
+    let slow = waveform.nyquist() < 120.0;
+    if slow {
+        println!(
+            "\x1b[33mWarning:\x1b[0m sampling at {:.1} Hz cannot resolve flicker above {:.1} Hz; \
+             faster modulation is aliased. Import an oscilloscope capture with --waveform <file>.",
+            waveform.sample_rate,
+            waveform.nyquist()
+        );
+    }
+    let na = || "n/a".to_string();
+    let alias = if slow { " (may be aliased)" } else { "" };
+    println!("\x1b[36mPercent Flicker:\x1b[0m {:.1} %", m.percent_flicker);
+    println!("\x1b[36mFlicker Index:\x1b[0m {:.3}", m.flicker_index);
+    println!(
+        "\x1b[36mDominant Frequency:\x1b[0m {}",
+        m.frequency
+            .map_or_else(na, |f| format!("{:.1} Hz{}", f, alias))
+    );
+    println!(
+        "\x1b[36mIEEE 1789:\x1b[0m {}",
+        m.risk.map_or_else(na, |r| format!("{}{}", r.name(), alias))
+    );
+    match m.svm {
+        Some(svm) => println!("\x1b[36mSVM:\x1b[0m {:.2}", svm),
+        None => println!("\x1b[36mSVM:\x1b[0m n/a (nothing resolved in 80-2000 Hz)"),
+    }
+    match m.pst_lm {
+        Some(pst) => println!("\x1b[36mPst LM:\x1b[0m {:.2}\n", pst),
+        None => println!("\x1b[36mPst LM:\x1b[0m n/a (record too short or sampled below 70 Hz)\n"),
+    }
+}
@@ -0,0 +1,149 @@
+//! Measurement Metadata
+//!
+//! [`MeasurementMetadata`] travels with every [`SpectralData`](crate::SpectralData)
+//! reading: where and when it was taken, the instrument settings, the
+//! ISO 13655 measurement condition and anything the user wants to record
+//! about the sample. Fields the source does not know are left as `None`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::bandpass::BandpassCorrection;
+
+/// ISO 13655 measurement condition, describing the UV content of the
+/// instrument illumination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MeasurementCondition {
+    /// Illuminant A (tungsten), UV content not controlled.
+    M0,
+    /// D50 illumination including UV (fluorescent whitening agents excited).
+    M1,
+    /// UV-excluded illumination.
+    M2,
+}
+
+impl MeasurementCondition {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MeasurementCondition::M0 => "M0",
+            MeasurementCondition::M1 => "M1",
+            MeasurementCondition::M2 => "M2",
+        }
+    }
+}
+
+/// Illumination/viewing geometry of a reflectance measurement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MeasurementGeometry {
+    /// 45° illumination, 0° viewing (45°:0°).
+    Deg45_0,
+    /// 0° illumination, 45° viewing (0°:45°).
+    Deg0_45,
+    /// Diffuse illumination, 8° viewing (d:8°).
+    Diffuse8,
+}
+
+impl MeasurementGeometry {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MeasurementGeometry::Deg45_0 => "45/0",
+            MeasurementGeometry::Deg0_45 => "0/45",
+            MeasurementGeometry::Diffuse8 => "d/8",
+        }
+    }
+}
+
+/// Material behind the sample during a reflectance measurement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Backing {
+    White,
+    Black,
+}
+
+impl Backing {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Backing::White => "White",
+            Backing::Black => "Black",
+        }
+    }
+}
+
+/// Sensor gain used for a reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Gain {
+    Normal,
+    High,
+}
+
+/// Context recorded with a measurement.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MeasurementMetadata {
+    /// Time of the measurement (UNIX timestamp, seconds).
+    pub timestamp: Option<u64>,
+    pub device_model: Option<String>,
+    pub device_serial: Option<String>,
+    /// Time of the calibration in effect (UNIX timestamp, seconds).
+    pub calibration_timestamp: Option<u64>,
+    /// Integration time of each frame, in seconds.
+    pub integration_time: Option<f32>,
+    pub gain: Option<Gain>,
+    /// Number of frames averaged into the reading.
+    pub averaged_frames: Option<u32>,
+    /// Per-band standard deviation across the averaged frames.
+    pub std_dev: Option<Vec<f32>>,
+    pub condition: Option<MeasurementCondition>,
+    pub geometry: Option<MeasurementGeometry>,
+    pub backing: Option<Backing>,
+    /// Bandpass correction applied to the values, `None` if uncorrected.
+    pub bandpass: Option<BandpassCorrection>,
+    pub sample_name: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl MeasurementMetadata {
+    /// Metadata stamped with the current time.
+    pub fn now() -> Self {
+        Self {
+            timestamp: Some(unix_now()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Current UNIX time in seconds.
+pub(crate) fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spectrum::{Quantity, SpectralData};
+
+    #[test]
+    fn test_metadata_json_round_trip() {
+        let mut data = SpectralData::with_quantity(vec![0.5; 36], Quantity::Reflectance);
+        data.metadata = MeasurementMetadata {
+            device_serial: Some("A1".into()),
+            gain: Some(Gain::High),
+            averaged_frames: Some(3),
+            std_dev: Some(vec![0.01; 36]),
+            condition: Some(MeasurementCondition::M0),
+            geometry: Some(MeasurementGeometry::Deg45_0),
+            backing: Some(Backing::Black),
+            sample_name: Some("Patch 1".into()),
+            ..MeasurementMetadata::now()
+        };
+        let json = serde_json::to_string(&data).unwrap();
+        let restored: SpectralData = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.metadata, data.metadata);
+
+        // Spectra saved before metadata existed still load
+        let legacy = r#"{"wavelengths":[380.0],"values":[0.5],"quantity":"Reflectance"}"#;
+        let restored: SpectralData = serde_json::from_str(legacy).unwrap();
+        assert_eq!(restored.metadata, MeasurementMetadata::default());
+    }
+}
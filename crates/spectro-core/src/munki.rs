@@ -5,7 +5,10 @@
 //! devices (Original and Design models).
 
 use crate::device::{DeviceInfo, DevicePosition, DeviceStatus, Spectrometer};
-use crate::spectrum::SpectralData;
+use crate::flicker::Waveform;
+use crate::metadata::{Gain, MeasurementCondition, MeasurementGeometry, MeasurementMetadata};
+use crate::persistence::MeterFactors;
+use crate::spectrum::{Quantity, SpectralData};
 use crate::transport::Transport;
 use crate::{MeasurementMode, Result};
 use std::convert::TryInto;
@@ -25,6 +28,28 @@ const MMF_HIGHGAIN: u8 = 0x04;
 // Interrupt endpoint for data reads
 const EP_DATA_IN: u8 = 0x81;
 
+/// Projector readings integrate up to this many times longer than contact
+/// readings. A screen viewed from a distance through the tele aperture
+/// delivers far less light than a display under the contact aperture.
+const PROJECTOR_MAX_INT_MULTIPLIER: u32 = 8;
+
+/// Peak dark-corrected raw level that projector readings aim for, leaving
+/// headroom below [`RAW_SATURATION`] for brighter patches.
+const PROJECTOR_TARGET_LEVEL: f64 = 40000.0;
+
+/// Frames averaged into each spot reading.
+const SPOT_FRAMES: u32 = 3;
+
+/// Length of the capture window while armed for a flash, in seconds.
+const FLASH_WINDOW_SEC: f64 = 3.0;
+
+/// Raw sensor values at or above this level are treated as saturated.
+const RAW_SATURATION: u16 = 0xFFF0;
+
+/// The EEPROM ambient and emissive coefficients yield mW/(m²·nm) and
+/// mW/(sr·m²·nm) (as in ArgyllCMS); spectra are reported in watts.
+const MW_TO_W: f32 = 1e-3;
+
 /// Firmware information from the ColorMunki device.
 #[derive(Debug, Clone)]
 pub struct MunkiFirmwareInfo {
@@ -50,6 +75,7 @@ pub struct MunkiConfig {
     pub white_ref: Vec<f32>,
     pub emis_coef: Vec<f32>,
     pub amb_coef: Vec<f32>,
+    pub proj_coef: Vec<f32>,
 }
 
 /// ColorMunki spectrometer driver.
@@ -77,7 +103,17 @@ pub struct Munki<T: Transport> {
     config: MunkiConfig,
     firmware: MunkiFirmwareInfo,
     dark_ref: Option<Vec<u16>>,
+    /// Dark references at projector gain, at the shortest and longest
+    /// projector integration times.
+    dark_ref_projector: Option<(Vec<u16>, Vec<u16>)>,
     white_cal_factors: Option<Vec<f32>>,
+    /// When the dark/white calibration in use was taken (UNIX seconds).
+    cal_timestamp: Option<u64>,
+    /// Light source reference for transmissive readings, with the mode it
+    /// was captured in.
+    transmission_ref: Option<(MeasurementMode, SpectralData)>,
+    /// User corrections against a reference meter.
+    meter_factors: MeterFactors,
 }
 
 impl<T: Transport> Munki<T> {
@@ -96,7 +132,9 @@ impl<T: Transport> Munki<T> {
 
         // Try to load existing calibration data for this device
         let mut dark_ref = None;
+        let mut dark_ref_projector = None;
         let mut white_cal_factors = None;
+        let mut cal_timestamp = None;
 
         if let Ok(Some(cal)) = crate::persistence::load_calibration(&config.serial_number) {
             // Basic validation: ensure the lengths match what we expect
@@ -106,16 +144,30 @@ impl<T: Transport> Munki<T> {
                     config.serial_number
                 );
                 dark_ref = Some(cal.dark_ref);
+                dark_ref_projector = cal
+                    .dark_ref_projector_min
+                    .zip(cal.dark_ref_projector)
+                    .filter(|(min, max)| min.len() == 137 && max.len() == 137);
                 white_cal_factors = Some(cal.white_cal_factors);
+                cal_timestamp = Some(cal.timestamp);
             }
         }
 
+        let meter_factors = crate::persistence::load_meter_factors(&config.serial_number)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+
         Ok(Self {
             transport,
             config,
             firmware,
             dark_ref,
+            dark_ref_projector,
             white_cal_factors,
+            cal_timestamp,
+            transmission_ref: None,
+            meter_factors,
         })
     }
 
@@ -283,6 +335,14 @@ impl<T: Transport> Munki<T> {
             )));
         }
 
+        // Projector (tele aperture) coefficients follow, as in ArgyllCMS
+        let mut proj_coef = Vec::with_capacity(36);
+        for i in 0..36 {
+            proj_coef.push(f32::from_bits(u32::from_le_bytes(
+                data[5400 + i * 4..5400 + i * 4 + 4].try_into().unwrap(),
+            )));
+        }
+
         Ok(MunkiConfig {
             cal_version,
             serial_number,
@@ -295,6 +355,7 @@ impl<T: Transport> Munki<T> {
             white_ref,
             emis_coef,
             amb_coef,
+            proj_coef,
         })
     }
 
@@ -314,6 +375,16 @@ impl<T: Transport> Munki<T> {
         Ok((buf[0], buf[1]))
     }
 
+    /// Ensures the dial is in one of the `allowed` raw positions.
+    fn check_dial(&self, allowed: &[u8], message: &str) -> Result<()> {
+        let (pos, _) = self.get_raw_status()?;
+        if allowed.contains(&pos) {
+            Ok(())
+        } else {
+            Err(crate::SpectroError::Mode(message.into()))
+        }
+    }
+
     fn trigger_measure(&self, int_clocks: u32, num_meas: u32, mode_flags: u8) -> Result<()> {
         let mut pbuf = [0u8; 12];
         pbuf[0] = if (mode_flags & MMF_LAMP) != 0 { 1 } else { 0 };
@@ -368,11 +439,92 @@ impl<T: Transport> Munki<T> {
         Ok(readings)
     }
 
-    fn measure_spot(&self, lamp: bool, high_gain: bool) -> Result<Vec<u16>> {
-        let tick_sec = self.firmware.tick_duration as f64 * 1e-6;
-        let int_time_sec =
-            (self.firmware.min_int_count * self.firmware.tick_duration) as f64 * 1e-6;
-        let int_clocks = (int_time_sec / tick_sec).round() as u32;
+    /// Longest projector integration time in device clocks.
+    fn max_projector_int_clocks(&self) -> u32 {
+        self.firmware.min_int_count * PROJECTOR_MAX_INT_MULTIPLIER
+    }
+
+    /// Converts device clocks to seconds.
+    fn clocks_to_sec(&self, int_clocks: u32) -> f64 {
+        (int_clocks * self.firmware.tick_duration) as f64 * 1e-6
+    }
+
+    /// Projector integration time that brings a trial reading, with a
+    /// dark-corrected peak of `peak` at `trial_clocks`, to
+    /// [`PROJECTOR_TARGET_LEVEL`].
+    fn projector_int_clocks(&self, trial_clocks: u32, peak: f64) -> u32 {
+        let (min, max) = (self.firmware.min_int_count, self.max_projector_int_clocks());
+        if peak <= 0.0 {
+            return max;
+        }
+        let clocks = trial_clocks as f64 * PROJECTOR_TARGET_LEVEL / peak;
+        (clocks.floor() as u32).clamp(min, max)
+    }
+
+    /// Projector dark reference at `int_clocks`, interpolated between the
+    /// references at the shortest and longest integration times.
+    fn projector_dark(&self, int_clocks: u32) -> Option<Vec<u16>> {
+        let (dark_min, dark_max) = self.dark_ref_projector.as_ref()?;
+        let (min, max) = (self.firmware.min_int_count, self.max_projector_int_clocks());
+        let t = if max > min {
+            (int_clocks.clamp(min, max) - min) as f64 / (max - min) as f64
+        } else {
+            0.0
+        };
+        Some(
+            dark_min
+                .iter()
+                .zip(dark_max)
+                .map(|(&a, &b)| (a as f64 + t * (b as f64 - a as f64)).round() as u16)
+                .collect(),
+        )
+    }
+
+    /// Takes a projector reading, lengthening the integration time from a
+    /// trial reading at the shortest one so the signal fills the sensor.
+    fn measure_projector(&self) -> Result<SpectralData> {
+        let trial_clocks = self.firmware.min_int_count;
+        let trial = self.measure_spot(false, true, trial_clocks)?;
+        if trial[6..134].iter().any(|&v| v >= RAW_SATURATION) {
+            return Err(crate::SpectroError::Device(
+                "Projector reading saturated the sensor; use Emissive mode".into(),
+            ));
+        }
+
+        let dark = self.projector_dark(trial_clocks).ok_or_else(|| {
+            crate::SpectroError::Calibration("Projector mode requires calibration first".into())
+        })?;
+        let peak = trial[6..134]
+            .iter()
+            .zip(&dark[6..134])
+            .map(|(&v, &d)| v as f64 - d as f64)
+            .fold(0.0, f64::max);
+
+        let int_clocks = self.projector_int_clocks(trial_clocks, peak);
+        let raw = if int_clocks == trial_clocks {
+            trial
+        } else {
+            self.measure_spot(false, true, int_clocks)?
+        };
+        self.process_spectrum(&raw, true, MeasurementMode::Projector, int_clocks)
+    }
+
+    fn measure_spot(&self, lamp: bool, high_gain: bool, int_clocks: u32) -> Result<Vec<u16>> {
+        self.measure_frames(lamp, high_gain, int_clocks, 1)?
+            .into_iter()
+            .next()
+            .ok_or(crate::SpectroError::Device("No data".into()))
+    }
+
+    /// Takes `num_meas` back-to-back frames.
+    fn measure_frames(
+        &self,
+        lamp: bool,
+        high_gain: bool,
+        int_clocks: u32,
+        num_meas: u32,
+    ) -> Result<Vec<Vec<u16>>> {
+        let int_time_sec = self.clocks_to_sec(int_clocks) * num_meas as f64;
 
         let mut flags = 0;
         if lamp {
@@ -382,16 +534,16 @@ impl<T: Transport> Munki<T> {
             flags |= MMF_HIGHGAIN;
         }
 
-        self.trigger_measure(int_clocks, 1, flags)?;
+        self.trigger_measure(int_clocks, num_meas, flags)?;
         // Wait for measurement to complete.
         // ArgyllCMS uses ~150ms safety margin; we use 200ms for extra robustness.
         std::thread::sleep(Duration::from_millis((int_time_sec * 1000.0) as u64 + 200));
 
-        let readings = self.read_measurement(1)?;
-        readings
-            .into_iter()
-            .next()
-            .ok_or(crate::SpectroError::Device("No data".into()))
+        let readings = self.read_measurement(num_meas)?;
+        if readings.is_empty() {
+            return Err(crate::SpectroError::Device("No data".into()));
+        }
+        Ok(readings)
     }
 
     fn process_spectrum(
@@ -399,9 +551,9 @@ impl<T: Transport> Munki<T> {
         raw_137: &[u16],
         high_gain: bool,
         mode: MeasurementMode,
+        int_clocks: u32,
     ) -> Result<SpectralData> {
-        let int_time_sec =
-            (self.firmware.min_int_count * self.firmware.tick_duration) as f64 * 1e-6;
+        let int_time_sec = self.clocks_to_sec(int_clocks);
         let offset = 6;
         let mut linearized = Vec::with_capacity(128);
         let polys = if high_gain {
@@ -411,9 +563,17 @@ impl<T: Transport> Munki<T> {
         };
         let scale = 1.0 / int_time_sec;
 
+        // The dark signal depends on gain and integration time, so projector
+        // readings use the reference captured at projector settings.
+        let dark_ref = if mode == MeasurementMode::Projector {
+            self.projector_dark(int_clocks)
+        } else {
+            self.dark_ref.clone()
+        };
+
         for i in 0..128 {
             let mut val = raw_137[offset + i] as f64;
-            if let Some(dark) = &self.dark_ref {
+            if let Some(dark) = &dark_ref {
                 val -= dark[offset + i] as f64;
             }
 
@@ -424,10 +584,11 @@ impl<T: Transport> Munki<T> {
             linearized.push((lval * scale) as f32);
         }
 
-        let (mtx_index, mtx_coef) = if mode == MeasurementMode::Emissive {
-            (&self.config.emtx_index, &self.config.emtx_coef)
-        } else {
-            (&self.config.rmtx_index, &self.config.rmtx_coef)
+        let (mtx_index, mtx_coef) = match mode {
+            MeasurementMode::Emissive | MeasurementMode::Projector => {
+                (&self.config.emtx_index, &self.config.emtx_coef)
+            }
+            _ => (&self.config.rmtx_index, &self.config.rmtx_coef),
         };
 
         let mut values = Vec::with_capacity(36);
@@ -446,18 +607,75 @@ impl<T: Transport> Munki<T> {
                         sum *= factors[w];
                     }
                 }
-                MeasurementMode::Ambient => {
-                    sum *= self.config.amb_coef[w];
+                // Transmissive readings are taken in their source mode and
+                // never reach this point with their own tag.
+                MeasurementMode::Ambient
+                | MeasurementMode::Flash
+                | MeasurementMode::Transmissive => {
+                    sum *= self.config.amb_coef[w] * MW_TO_W * self.meter_factors.irradiance;
                 }
                 MeasurementMode::Emissive => {
-                    sum *= self.config.emis_coef[w];
+                    sum *= self.config.emis_coef[w] * MW_TO_W * self.meter_factors.radiance;
+                }
+                MeasurementMode::Projector => {
+                    sum *= self.config.proj_coef[w] * MW_TO_W * self.meter_factors.radiance;
                 }
             }
 
             values.push(sum);
         }
 
-        Ok(SpectralData::new(values))
+        let mut data = SpectralData::with_quantity(values, mode.quantity());
+        data.metadata = self.reading_metadata(mode, high_gain, int_time_sec);
+        Ok(data)
+    }
+
+    /// Instrument context for a single-frame reading in `mode`.
+    fn reading_metadata(
+        &self,
+        mode: MeasurementMode,
+        high_gain: bool,
+        int_time_sec: f64,
+    ) -> MeasurementMetadata {
+        let mut metadata = MeasurementMetadata::now();
+        metadata.device_model = Some("ColorMunki".into());
+        metadata.device_serial = Some(self.config.serial_number.clone());
+        metadata.calibration_timestamp = self.cal_timestamp;
+        metadata.integration_time = Some(int_time_sec as f32);
+        metadata.gain = Some(if high_gain { Gain::High } else { Gain::Normal });
+        metadata.averaged_frames = Some(1);
+        if mode == MeasurementMode::Reflective {
+            // Tungsten lamp without a UV filter, 45°:0° optics
+            metadata.condition = Some(MeasurementCondition::M0);
+            metadata.geometry = Some(MeasurementGeometry::Deg45_0);
+        }
+        metadata
+    }
+
+    /// Records a burst of ambient frames over [`FLASH_WINDOW_SEC`] and
+    /// integrates the flash fired during it.
+    fn measure_flash(&self) -> Result<SpectralData> {
+        let int_clocks = self.firmware.min_int_count;
+        let int_time_sec = self.clocks_to_sec(int_clocks);
+        let num_frames = (FLASH_WINDOW_SEC / int_time_sec).ceil() as u32;
+
+        self.trigger_measure(int_clocks, num_frames, 0)?;
+        let readings = self.read_measurement(num_frames)?;
+
+        if readings
+            .iter()
+            .any(|raw| raw[6..134].iter().any(|&v| v >= RAW_SATURATION))
+        {
+            return Err(crate::SpectroError::Device(
+                "Flash saturated the sensor; increase the distance or lower the flash power".into(),
+            ));
+        }
+
+        let frames = readings
+            .iter()
+            .map(|raw| self.process_spectrum(raw, false, MeasurementMode::Ambient, int_clocks))
+            .collect::<Result<Vec<_>>>()?;
+        crate::flash::integrate_flash(&frames, int_time_sec as f32)
     }
 
     fn perform_calibration(&mut self) -> Result<()> {
@@ -469,15 +687,23 @@ impl<T: Transport> Munki<T> {
         }
 
         // Dark frame calibration (lamp off)
-        let raw_dark = self.measure_spot(false, false)?;
+        let raw_dark = self.measure_spot(false, false, self.firmware.min_int_count)?;
         self.dark_ref = Some(raw_dark);
 
+        // Projector dark frames (high gain) at both ends of the integration
+        // range; readings in between interpolate
+        let dark_projector_min = self.measure_spot(false, true, self.firmware.min_int_count)?;
+        let dark_projector_max = self.measure_spot(false, true, self.max_projector_int_clocks())?;
+        self.dark_ref_projector = Some((dark_projector_min, dark_projector_max));
+
         // White tile calibration (lamp on)
-        let raw_white = self.measure_spot(true, false)?;
+        let white_clocks = self.firmware.min_int_count;
+        let raw_white = self.measure_spot(true, false, white_clocks)?;
 
         // Process without white calibration factors
         let old_factors = self.white_cal_factors.take();
-        let spec = self.process_spectrum(&raw_white, false, MeasurementMode::Reflective)?;
+        let spec =
+            self.process_spectrum(&raw_white, false, MeasurementMode::Reflective, white_clocks)?;
         self.white_cal_factors = old_factors;
 
         // Compute calibration factors
@@ -493,12 +719,19 @@ impl<T: Transport> Munki<T> {
         }
 
         self.white_cal_factors = Some(factors);
+        self.cal_timestamp = Some(crate::metadata::unix_now());
 
         // Persist calibration data
         if let Some(dark) = &self.dark_ref {
             if let Some(white) = &self.white_cal_factors {
-                let _ =
-                    crate::persistence::save_calibration(&self.config.serial_number, dark, white);
+                let _ = crate::persistence::save_calibration(
+                    &self.config.serial_number,
+                    dark,
+                    self.dark_ref_projector
+                        .as_ref()
+                        .map(|(min, max)| (min.as_slice(), max.as_slice())),
+                    white,
+                );
             }
         }
 
@@ -552,24 +785,125 @@ impl<T: Transport> Spectrometer for Munki<T> {
             ));
         }
 
-        // Validate dial position for ambient mode
-        if mode == MeasurementMode::Ambient {
-            let (pos, _) = self.get_raw_status()?;
-            if pos != 1 && pos != 3 {
-                return Err(crate::SpectroError::Mode(
-                    "Ambient mode requires dial in Ambient position".into(),
-                ));
-            }
+        if mode == MeasurementMode::Projector && self.dark_ref_projector.is_none() {
+            return Err(crate::SpectroError::Calibration(
+                "Projector mode requires calibration first".into(),
+            ));
+        }
+
+        // Validate dial position for ambient, flash and projector modes
+        if mode == MeasurementMode::Ambient || mode == MeasurementMode::Flash {
+            self.check_dial(&[1, 3], "Ambient mode requires dial in Ambient position")?;
+        }
+        if mode == MeasurementMode::Projector {
+            self.check_dial(&[0], "Projector mode requires dial in Projector position")?;
+        }
+
+        if mode == MeasurementMode::Flash {
+            return self.measure_flash();
+        }
+        if mode == MeasurementMode::Projector {
+            return self.measure_projector();
+        }
+
+        if mode == MeasurementMode::Transmissive {
+            let (source, reference) = self.transmission_ref.clone().ok_or_else(|| {
+                crate::SpectroError::Calibration(
+                    "Transmissive mode requires a light source reference first".into(),
+                )
+            })?;
+            let sample = self.measure(source)?;
+            return SpectralData::transmittance(&sample, &reference);
         }
 
         let (lamp, high_gain) = match mode {
             MeasurementMode::Reflective => (true, false),
-            MeasurementMode::Emissive => (false, true),
-            MeasurementMode::Ambient => (false, false),
+            MeasurementMode::Emissive | MeasurementMode::Projector => (false, true),
+            MeasurementMode::Ambient | MeasurementMode::Flash | MeasurementMode::Transmissive => {
+                (false, false)
+            }
+        };
+
+        // Several frames are averaged to lower the noise; their spread is
+        // kept in the metadata
+        let int_clocks = self.firmware.min_int_count;
+        let frames = self
+            .measure_frames(lamp, high_gain, int_clocks, SPOT_FRAMES)?
+            .iter()
+            .map(|raw| self.process_spectrum(raw, high_gain, mode, int_clocks))
+            .collect::<Result<Vec<_>>>()?;
+        SpectralData::average(&frames)
+    }
+
+    fn capture_reference(&mut self, source: MeasurementMode) -> Result<SpectralData> {
+        if source != MeasurementMode::Emissive && source != MeasurementMode::Ambient {
+            return Err(crate::SpectroError::Mode(
+                "Transmission reference must be measured in Emissive or Ambient mode".into(),
+            ));
+        }
+
+        let reference = self.measure(source)?;
+        self.transmission_ref = Some((source, reference.clone()));
+        Ok(reference)
+    }
+
+    fn reference_factor(&self, quantity: Quantity) -> f32 {
+        match quantity {
+            Quantity::Irradiance => self.meter_factors.irradiance,
+            Quantity::Radiance => self.meter_factors.radiance,
+            _ => 1.0,
+        }
+    }
+
+    fn set_reference_factor(&mut self, quantity: Quantity, factor: f32) -> Result<()> {
+        if !(factor.is_finite() && factor > 0.0) {
+            return Err(crate::SpectroError::Data(format!(
+                "Invalid reference factor {}",
+                factor
+            )));
+        }
+        match quantity {
+            Quantity::Irradiance => self.meter_factors.irradiance = factor,
+            Quantity::Radiance => self.meter_factors.radiance = factor,
+            _ => {
+                return Err(crate::SpectroError::Mode(
+                    "Reference factors apply to irradiance or radiance readings".into(),
+                ))
+            }
+        }
+        crate::persistence::save_meter_factors(&self.config.serial_number, &self.meter_factors)
+    }
+
+    fn measure_series(&mut self, mode: MeasurementMode, samples: usize) -> Result<Waveform> {
+        let high_gain = match mode {
+            MeasurementMode::Emissive => true,
+            MeasurementMode::Ambient => {
+                self.check_dial(&[1, 3], "Ambient mode requires dial in Ambient position")?;
+                false
+            }
+            _ => {
+                return Err(crate::SpectroError::Mode(
+                    "Rapid sampling requires Emissive or Ambient mode".into(),
+                ))
+            }
         };
+        let flags = if high_gain { MMF_HIGHGAIN } else { 0 };
+
+        // Back-to-back frames at the minimum integration time. The sampling
+        // rate is therefore only a few tens of Hz, enough for low-frequency
+        // modulation; mains-rate flicker needs an imported waveform.
+        let int_clocks = self.firmware.min_int_count;
+        let int_time_sec = self.clocks_to_sec(int_clocks);
+        self.trigger_measure(int_clocks, samples as u32, flags)?;
+        let readings = self.read_measurement(samples as u32)?;
+
+        let mut series = Vec::with_capacity(readings.len());
+        for raw in &readings {
+            let spec = self.process_spectrum(raw, high_gain, mode, int_clocks)?;
+            series.push(spec.to_xyz_emissive_2().y);
+        }
 
-        let raw = self.measure_spot(lamp, high_gain)?;
-        self.process_spectrum(&raw, high_gain, mode)
+        Ok(Waveform::new(series, (1.0 / int_time_sec) as f32))
     }
 
     fn supported_modes(&self) -> Vec<MeasurementMode> {
@@ -577,14 +911,151 @@ impl<T: Transport> Spectrometer for Munki<T> {
             MeasurementMode::Reflective,
             MeasurementMode::Emissive,
             MeasurementMode::Ambient,
+            MeasurementMode::Projector,
+            MeasurementMode::Flash,
+            MeasurementMode::Transmissive,
         ]
     }
 
     fn is_calibrated(&self, mode: MeasurementMode) -> bool {
         match mode {
             MeasurementMode::Reflective => self.white_cal_factors.is_some(),
-            // Emissive and Ambient don't require prior calibration
-            MeasurementMode::Emissive | MeasurementMode::Ambient => true,
+            MeasurementMode::Projector => self.dark_ref_projector.is_some(),
+            MeasurementMode::Transmissive => self.transmission_ref.is_some(),
+            // Emissive, Ambient and Flash don't require prior calibration
+            MeasurementMode::Emissive | MeasurementMode::Ambient | MeasurementMode::Flash => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::mock::MockTransport;
+    use crate::SpectroError;
+
+    /// A device with identity linearisation and a one-to-one wavelength
+    /// matrix, projector coefficients of 2 and emissive ones of 1.
+    fn test_munki(dark_ref_projector: Option<(Vec<u16>, Vec<u16>)>) -> Munki<MockTransport> {
+        let matrix = (0..36 * 16)
+            .map(|i| if i % 16 == 0 { 1.0 } else { 0.0 })
+            .collect::<Vec<_>>();
+        Munki {
+            transport: MockTransport::new(),
+            config: MunkiConfig {
+                cal_version: 1,
+                serial_number: "TEST".into(),
+                rmtx_index: vec![0; 36],
+                rmtx_coef: matrix.clone(),
+                emtx_index: vec![0; 36],
+                emtx_coef: matrix,
+                lin_normal: vec![0.0, 1.0, 0.0, 0.0],
+                lin_high: vec![0.0, 1.0, 0.0, 0.0],
+                white_ref: vec![1.0; 36],
+                emis_coef: vec![1.0; 36],
+                amb_coef: vec![1.0; 36],
+                proj_coef: vec![2.0; 36],
+            },
+            firmware: MunkiFirmwareInfo {
+                fw_rev_major: 1,
+                fw_rev_minor: 0,
+                tick_duration: 1,
+                min_int_count: 100,
+                num_eeprom_blocks: 0,
+                eeprom_block_size: 0,
+            },
+            dark_ref: None,
+            dark_ref_projector,
+            white_cal_factors: None,
+            cal_timestamp: None,
+            transmission_ref: None,
+            meter_factors: MeterFactors::default(),
+        }
+    }
+
+    fn raw_frame(level: u16) -> Vec<u8> {
+        (0..137).flat_map(|_| level.to_le_bytes()).collect()
+    }
+
+    fn triggered_clocks(munki: &Munki<MockTransport>) -> Vec<u32> {
+        munki
+            .transport
+            .control_write_log
+            .borrow()
+            .iter()
+            .filter(|(request, ..)| *request == CMD_TRIGGER_MEASURE)
+            .map(|(_, _, _, data)| u32::from_le_bytes(data[4..8].try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_mode_requirements() {
+        let mut munki = test_munki(None);
+        assert!(matches!(
+            munki.measure(MeasurementMode::Projector),
+            Err(SpectroError::Calibration(_))
+        ));
+        assert!(!munki.is_calibrated(MeasurementMode::Projector));
+
+        let mut munki = test_munki(Some((vec![0; 137], vec![0; 137])));
+        assert!(munki.is_calibrated(MeasurementMode::Projector));
+        // Ambient dial position
+        munki.transport.queue_control_read(vec![3, 0]);
+        assert!(matches!(
+            munki.measure(MeasurementMode::Projector),
+            Err(SpectroError::Mode(_))
+        ));
+        // Projector dial position
+        munki.transport.queue_control_read(vec![0, 0]);
+        assert!(matches!(
+            munki.measure(MeasurementMode::Ambient),
+            Err(SpectroError::Mode(_))
+        ));
+        munki.transport.queue_control_read(vec![0, 0]);
+        assert_eq!(munki.status().unwrap().position, DevicePosition::Projector);
+        // Nothing was triggered by the rejected readings
+        assert!(triggered_clocks(&munki).is_empty());
+    }
+
+    #[test]
+    fn test_projector_integration_time() {
+        let munki = test_munki(Some((vec![100; 137], vec![200; 137])));
+        assert_eq!(munki.projector_int_clocks(100, 0.0), 800);
+        assert_eq!(munki.projector_int_clocks(100, 20000.0), 200);
+        assert_eq!(munki.projector_int_clocks(100, 60000.0), 100);
+        assert_eq!(munki.projector_dark(450).unwrap()[6], 150);
+
+        // A dim screen: the trial reading peaks at 4000 above dark, so the
+        // reading is retaken at the longest integration time
+        let mut munki = munki;
+        munki.transport.queue_control_read(vec![0, 0]);
+        munki.transport.queue_interrupt_read(raw_frame(4100));
+        munki.transport.queue_interrupt_read(raw_frame(32200));
+        let spd = munki.measure(MeasurementMode::Projector).unwrap();
+        assert_eq!(triggered_clocks(&munki), vec![100, 800]);
+
+        // 32000 counts over 800µs, times the projector coefficient, in watts
+        let expected = 32000.0 / 800e-6 * 2.0 * MW_TO_W;
+        assert!((spd.values[0] - expected).abs() / expected < 1e-5);
+        assert!((spd.metadata.integration_time.unwrap() - 800e-6).abs() < 1e-9);
+        assert_eq!(spd.quantity, Quantity::Radiance);
+    }
+
+    #[test]
+    fn test_spot_reading_averages_frames() {
+        let mut munki = test_munki(None);
+        for level in [900, 1000, 1100] {
+            munki.transport.queue_interrupt_read(raw_frame(level));
         }
+        let spd = munki.measure(MeasurementMode::Emissive).unwrap();
+        assert_eq!(triggered_clocks(&munki), vec![100]);
+
+        // Counts per second, times the emissive coefficient, in watts
+        let scale = 1.0 / 100e-6 * MW_TO_W;
+        assert!((spd.values[0] - 1000.0 * scale).abs() < 1e-2);
+        assert_eq!(spd.metadata.averaged_frames, Some(3));
+        let std_dev = spd.metadata.std_dev.as_ref().unwrap();
+        assert_eq!(std_dev.len(), 36);
+        assert!((std_dev[0] - 100.0 * scale).abs() < 1e-2);
     }
 }
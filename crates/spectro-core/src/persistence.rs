@@ -18,10 +18,39 @@ pub struct CalibrationData {
     pub timestamp: u64,
     /// Dark reference readings.
     pub dark_ref: Vec<u16>,
+    /// Dark reference readings at projector gain and the longest projector
+    /// integration time.
+    #[serde(default)]
+    pub dark_ref_projector: Option<Vec<u16>>,
+    /// Dark reference readings at projector gain and the shortest projector
+    /// integration time.
+    #[serde(default)]
+    pub dark_ref_projector_min: Option<Vec<u16>>,
     /// White calibration scaling factors.
     pub white_cal_factors: Vec<f32>,
 }
 
+/// Correction factors that match photometric readings to a reference meter.
+///
+/// Applied on top of the EEPROM ambient and emissive coefficients; 1.0 means
+/// the factory calibration is used unchanged.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MeterFactors {
+    /// Factor for irradiance readings (ambient, flash).
+    pub irradiance: f32,
+    /// Factor for radiance readings (emissive, projector).
+    pub radiance: f32,
+}
+
+impl Default for MeterFactors {
+    fn default() -> Self {
+        Self {
+            irradiance: 1.0,
+            radiance: 1.0,
+        }
+    }
+}
+
 /// Gets the directory where calibration data should be stored.
 fn get_config_dir() -> Result<PathBuf> {
     let dirs = ProjectDirs::from("com", "tinnci", "spectro-rs")
@@ -43,15 +72,36 @@ fn get_cal_path(serial: &str) -> Result<PathBuf> {
     Ok(path)
 }
 
+/// Gets the path to the camera and chart data for TLCI.
+pub fn tlci_model_path() -> Result<PathBuf> {
+    let mut path = get_config_dir()?;
+    path.push("tlci_model.json");
+    Ok(path)
+}
+
+/// Gets the path to the reference meter factors for a specific device serial.
+fn get_meter_path(serial: &str) -> Result<PathBuf> {
+    let mut path = get_config_dir()?;
+    path.push(format!("meter_{}.json", serial));
+    Ok(path)
+}
+
 /// Saves calibration data for a device.
-pub fn save_calibration(serial: &str, dark_ref: &[u16], factors: &[f32]) -> Result<()> {
+///
+/// `dark_ref_projector` holds the projector dark references at the shortest
+/// and longest projector integration times.
+pub fn save_calibration(
+    serial: &str,
+    dark_ref: &[u16],
+    dark_ref_projector: Option<(&[u16], &[u16])>,
+    factors: &[f32],
+) -> Result<()> {
     let data = CalibrationData {
         serial: serial.to_string(),
-        timestamp: std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs(),
+        timestamp: crate::metadata::unix_now(),
         dark_ref: dark_ref.to_vec(),
+        dark_ref_projector: dark_ref_projector.map(|(_, max)| max.to_vec()),
+        dark_ref_projector_min: dark_ref_projector.map(|(min, _)| min.to_vec()),
         white_cal_factors: factors.to_vec(),
     };
 
@@ -80,3 +130,52 @@ pub fn load_calibration(serial: &str) -> Result<Option<CalibrationData>> {
 
     Ok(Some(data))
 }
+
+/// Saves reference meter correction factors for a device.
+pub fn save_meter_factors(serial: &str, factors: &MeterFactors) -> Result<()> {
+    let path = get_meter_path(serial)?;
+    let json = serde_json::to_string_pretty(factors)
+        .map_err(|e| SpectroError::Device(format!("Serialization error: {}", e)))?;
+
+    fs::write(path, json)
+        .map_err(|e| SpectroError::Device(format!("Failed to write meter factors: {}", e)))?;
+
+    Ok(())
+}
+
+/// Loads reference meter correction factors for a device if they exist.
+pub fn load_meter_factors(serial: &str) -> Result<Option<MeterFactors>> {
+    let path = get_meter_path(serial)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(path)
+        .map_err(|e| SpectroError::Device(format!("Failed to read meter factors: {}", e)))?;
+
+    let factors: MeterFactors = serde_json::from_str(&json)
+        .map_err(|e| SpectroError::Device(format!("Deserialization error: {}", e)))?;
+
+    Ok(Some(factors))
+}
+
+/// Loads the TLCI camera and chart data if it has been installed.
+///
+/// The file holds a [`TlciModel`](crate::tlci::TlciModel) as JSON, built
+/// from the EBU Tech 3355 camera sensitivities and ColorChecker
+/// reflectances.
+pub fn load_tlci_model() -> Result<Option<crate::tlci::TlciModel>> {
+    let path = tlci_model_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(path)
+        .map_err(|e| SpectroError::Device(format!("Failed to read TLCI model: {}", e)))?;
+
+    let model: crate::tlci::TlciModel = serde_json::from_str(&json)
+        .map_err(|e| SpectroError::Data(format!("Deserialization error: {}", e)))?;
+    model.validate()?;
+
+    Ok(Some(model))
+}
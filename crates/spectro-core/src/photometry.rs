@@ -0,0 +1,233 @@
+//! Radiometric and Photometric Units
+//!
+//! Calibrated readings carry spectral irradiance (ambient, flash) or spectral
+//! radiance (emissive, projector) in SI units; see
+//! [`Quantity`](crate::spectrum::Quantity). Weighting by V(λ) and the
+//! maximum luminous efficacy [`KM`] gives illuminance in lux or luminance in
+//! cd/m²; [`Photometric`] converts these to foot-candles and foot-lamberts.
+//!
+//! Below a few cd/m² the rods take over from the cones. Scotopic quantities
+//! weight by V'(λ) ([`cie_data::SCOTOPIC`](crate::cie_data::SCOTOPIC)) and
+//! [`KM_SCOTOPIC`]; their ratio to the photopic value is the S/P ratio of the
+//! source. CIE 191 mesopic photometry blends the two by adaptation level, see
+//! [`mesopic_luminance`].
+
+use crate::{Result, SpectroError, CANDELAS_PER_FOOTLAMBERT};
+use serde::{Deserialize, Serialize};
+
+/// Maximum luminous efficacy of radiation for photopic vision, in lm/W.
+pub const KM: f32 = 683.0;
+
+/// Maximum luminous efficacy of radiation for scotopic vision, in lm/W.
+pub const KM_SCOTOPIC: f32 = 1700.0;
+
+/// Photopic luminance above which CIE 191 vision is fully photopic, in cd/m².
+pub const MESOPIC_UPPER: f32 = 5.0;
+
+/// Mesopic luminance below which CIE 191 vision is fully scotopic, in cd/m².
+pub const MESOPIC_LOWER: f32 = 0.005;
+
+/// V'(λ) at 555nm, the peak of V(λ).
+const SCOTOPIC_AT_555: f32 = 0.402;
+
+/// Illuminance of one foot-candle in lux (1 lm/ft²).
+pub const LUX_PER_FOOTCANDLE: f32 = 10.763_91;
+
+/// Photometric units for illuminance and luminance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PhotometricUnit {
+    /// Illuminance, lm/m².
+    Lux,
+    /// Illuminance, lm/ft².
+    FootCandle,
+    /// Luminance, cd/m² (nits).
+    CandelaPerSquareMeter,
+    /// Luminance, (1/π) cd/ft².
+    FootLambert,
+}
+
+impl PhotometricUnit {
+    /// Unit symbol for reports.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PhotometricUnit::Lux => "lx",
+            PhotometricUnit::FootCandle => "fc",
+            PhotometricUnit::CandelaPerSquareMeter => "cd/m²",
+            PhotometricUnit::FootLambert => "fL",
+        }
+    }
+
+    /// Whether this unit measures illuminance (as opposed to luminance).
+    pub fn is_illuminance(&self) -> bool {
+        matches!(self, PhotometricUnit::Lux | PhotometricUnit::FootCandle)
+    }
+
+    /// Size of one of this unit in the SI unit of its quantity (lx or cd/m²).
+    fn si_factor(&self) -> f32 {
+        match self {
+            PhotometricUnit::Lux | PhotometricUnit::CandelaPerSquareMeter => 1.0,
+            PhotometricUnit::FootCandle => LUX_PER_FOOTCANDLE,
+            PhotometricUnit::FootLambert => CANDELAS_PER_FOOTLAMBERT,
+        }
+    }
+}
+
+/// A photometric quantity with its unit.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Photometric {
+    pub value: f32,
+    pub unit: PhotometricUnit,
+}
+
+impl Photometric {
+    /// Illuminance in lux.
+    pub fn illuminance(lux: f32) -> Self {
+        Self {
+            value: lux,
+            unit: PhotometricUnit::Lux,
+        }
+    }
+
+    /// Luminance in cd/m².
+    pub fn luminance(cd_m2: f32) -> Self {
+        Self {
+            value: cd_m2,
+            unit: PhotometricUnit::CandelaPerSquareMeter,
+        }
+    }
+
+    /// Convert to another unit of the same quantity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when converting between illuminance and luminance.
+    pub fn to(&self, unit: PhotometricUnit) -> Result<Self> {
+        if self.unit.is_illuminance() != unit.is_illuminance() {
+            return Err(SpectroError::Data(format!(
+                "Cannot convert {} to {}",
+                self.unit.symbol(),
+                unit.symbol()
+            )));
+        }
+        Ok(Self {
+            value: self.value * self.unit.si_factor() / unit.si_factor(),
+            unit,
+        })
+    }
+
+    /// The same quantity in its imperial unit (foot-candles or foot-lamberts).
+    pub fn imperial(&self) -> Self {
+        let unit = if self.unit.is_illuminance() {
+            PhotometricUnit::FootCandle
+        } else {
+            PhotometricUnit::FootLambert
+        };
+        // Same quantity, so the conversion cannot fail
+        self.to(unit).unwrap_or(*self)
+    }
+}
+
+impl std::fmt::Display for Photometric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2} {}", self.value, self.unit.symbol())
+    }
+}
+
+/// Correction factor that brings `measured` into agreement with a
+/// `reference` meter reading of the same source.
+///
+/// # Errors
+///
+/// Returns an error if the readings are of different quantities or either
+/// is not positive.
+pub fn reference_factor(measured: Photometric, reference: Photometric) -> Result<f32> {
+    let reference = reference.to(measured.unit)?;
+    if measured.value <= 0.0 || reference.value <= 0.0 {
+        return Err(SpectroError::Data(
+            "Reference and measured readings must be positive".into(),
+        ));
+    }
+    Ok(reference.value / measured.value)
+}
+
+/// Mesopic luminance in the CIE 191 recommended system.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Mesopic {
+    /// Mesopic luminance in cd/m².
+    pub luminance: f32,
+    /// Adaptation coefficient: 1 for photopic vision, 0 for scotopic.
+    pub m: f32,
+}
+
+/// CIE 191:2010 mesopic luminance for a photopic luminance (cd/m²) and the
+/// S/P ratio of the light.
+///
+/// The mesopic luminous efficiency is m·V(λ) + (1 − m)·V'(λ), and the
+/// adaptation coefficient m = 0.7670 + 0.3334·log₁₀(L_mes) depends on the
+/// result, so it is found by iteration from m = 0.5.
+pub fn mesopic_luminance(photopic: f32, sp_ratio: f32) -> Mesopic {
+    let scotopic = photopic * sp_ratio;
+    if photopic >= MESOPIC_UPPER {
+        return Mesopic {
+            luminance: photopic,
+            m: 1.0,
+        };
+    }
+
+    let luminance = |m: f32| {
+        (m * photopic + (1.0 - m) * scotopic * SCOTOPIC_AT_555) / (m + (1.0 - m) * SCOTOPIC_AT_555)
+    };
+    let mut m = 0.5f32;
+    for _ in 0..100 {
+        let next = (0.7670 + 0.3334 * luminance(m).max(f32::MIN_POSITIVE).log10()).clamp(0.0, 1.0);
+        let converged = (next - m).abs() < 1e-6;
+        m = next;
+        if converged {
+            break;
+        }
+    }
+    let luminance = luminance(m);
+    if luminance <= MESOPIC_LOWER {
+        return Mesopic {
+            luminance: scotopic,
+            m: 0.0,
+        };
+    }
+    Mesopic { luminance, m }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_conversions() {
+        let e = Photometric::illuminance(107.639);
+        assert!((e.imperial().value - 10.0).abs() < 1e-3);
+
+        let l = Photometric::luminance(48.0 * CANDELAS_PER_FOOTLAMBERT);
+        assert!((l.to(PhotometricUnit::FootLambert).unwrap().value - 48.0).abs() < 1e-3);
+        assert!(l.to(PhotometricUnit::Lux).is_err());
+
+        let fc = Photometric {
+            value: 50.0,
+            unit: PhotometricUnit::FootCandle,
+        };
+        let factor = reference_factor(Photometric::illuminance(500.0), fc).unwrap();
+        assert!((factor - 1.076_391).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_mesopic_luminance() {
+        // Photopic above 5 cd/m², and neutral when S/P = 1
+        assert_eq!(mesopic_luminance(10.0, 2.0).luminance, 10.0);
+        let neutral = mesopic_luminance(0.5, 1.0);
+        assert!((neutral.luminance - 0.5).abs() < 1e-5);
+
+        // A bluish source looks brighter at night, a yellowish one dimmer
+        let cool = mesopic_luminance(0.1, 2.0);
+        let warm = mesopic_luminance(0.1, 0.6);
+        assert!(cool.luminance > 0.1 && warm.luminance < 0.1);
+        assert!(cool.m > 0.0 && cool.m < 1.0);
+    }
+}
@@ -1,55 +1,336 @@
-use crate::colorimetry::{weighting, XYZ, X_BAR_10, X_BAR_2, Y_BAR_10, Y_BAR_2, Z_BAR_10, Z_BAR_2};
-use crate::WAVELENGTHS;
+use crate::bandpass::BandpassCorrection;
+use crate::cct::{self, CctDuv};
+use crate::cie_data;
+use crate::colorimetry::{self, Lab, XYZ};
+use crate::e308::{self, E308Table, Interval};
+use crate::metadata::MeasurementMetadata;
+use crate::photometry::{self, Mesopic, Photometric, KM, KM_SCOTOPIC};
 use crate::{Illuminant, Observer};
+use crate::{Result, SpectroError};
 
-/// Measurement mode determines the calculation method for XYZ conversion.
+/// The physical quantity held in [`SpectralData::values`].
+///
+/// The quantity selects the XYZ integration path: reflectance and
+/// transmittance factors are weighted by an illuminant (ASTM E308) and
+/// normalized to Y = 100, while spectral power is integrated directly
+/// against the CMFs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
-pub enum MeasurementMode {
-    /// Reflective measurement (objects like paper, color patches)
-    /// Uses ASTM E308 weighting factors which include D65 SPD
+pub enum Quantity {
+    /// Reflectance factor (paper, color patches), dimensionless.
     #[default]
-    Reflective,
-    /// Emissive measurement (light sources like displays, lamps)
-    /// Uses direct CMF integration
-    Emissive,
+    Reflectance,
+    /// Transmittance factor (filters, gels, film), dimensionless.
+    Transmittance,
+    /// Spectral radiance in W/(sr·m²·nm); Y is luminance in cd/m².
+    Radiance,
+    /// Spectral irradiance in W/(m²·nm); Y is illuminance in lux.
+    Irradiance,
+    /// Spectral power distribution of unknown absolute scale; Y is the
+    /// unscaled integral Σ S(λ)·ȳ(λ)·Δλ.
+    RelativePower,
 }
 
+impl Quantity {
+    /// Whether the values are spectral power (as opposed to a factor that
+    /// needs an illuminant).
+    pub fn is_emissive(&self) -> bool {
+        matches!(
+            self,
+            Quantity::Radiance | Quantity::Irradiance | Quantity::RelativePower
+        )
+    }
+
+    /// Unit symbol for reports.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Quantity::Reflectance | Quantity::Transmittance => "",
+            Quantity::Radiance => "W/sr/m²/nm",
+            Quantity::Irradiance => "W/m²/nm",
+            Quantity::RelativePower => "rel.",
+        }
+    }
+
+    /// Factor applied to ∫ S(λ) ȳ(λ) dλ to obtain Y.
+    ///
+    /// Absolute quantities are scaled by [`KM`] so Y is in cd/m² or lux;
+    /// everything else keeps the unscaled integral.
+    pub fn luminous_efficacy(&self) -> f32 {
+        match self {
+            Quantity::Radiance | Quantity::Irradiance => KM,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Transmittance floor used when converting to optical density, so that
+/// opaque bands report a finite density of 5.0.
+const MIN_TRANSMITTANCE: f32 = 1e-5;
+
+/// Reference bands weaker than this fraction of the reference peak carry too
+/// little signal to divide by; their transmittance is reported as zero.
+const MIN_REFERENCE_FRACTION: f32 = 1e-3;
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SpectralData {
     pub wavelengths: Vec<f32>,
     pub values: Vec<f32>,
-    /// Measurement mode affects XYZ calculation method
-    pub mode: MeasurementMode,
+    /// Physical quantity of `values`; selects the XYZ calculation method
+    pub quantity: Quantity,
+    /// Instrument settings and sample details recorded with the reading
+    #[serde(default)]
+    pub metadata: MeasurementMetadata,
 }
 
+/// Tolerance in nm when comparing wavelengths of two grids.
+const GRID_TOLERANCE: f32 = 1e-3;
+
+/// Returned by the infallible XYZ conversions when the data is invalid.
+const INVALID_XYZ: XYZ = XYZ {
+    x: f32::NAN,
+    y: f32::NAN,
+    z: f32::NAN,
+};
+
 impl SpectralData {
-    pub fn new(mut values: Vec<f32>) -> Self {
-        // Pad with zeros if less than 41 points (common for 380-730nm devices like ColorMunki)
-        while values.len() < 41 {
-            values.push(0.0);
-        }
+    /// Create reflectance data on a 10nm grid starting at 380nm, one
+    /// wavelength per value.
+    pub fn new(values: Vec<f32>) -> Self {
+        Self::with_quantity(values, Quantity::default())
+    }
+
+    /// Create spectral data holding an explicit quantity, on a 10nm grid
+    /// starting at 380nm (the ColorMunki's 36 bands end at 730nm).
+    pub fn with_quantity(values: Vec<f32>, quantity: Quantity) -> Self {
+        let wavelengths = (0..values.len()).map(|i| 380.0 + 10.0 * i as f32).collect();
         Self {
-            wavelengths: WAVELENGTHS.to_vec(),
+            wavelengths,
             values,
-            mode: MeasurementMode::default(),
+            quantity,
+            metadata: MeasurementMetadata::default(),
         }
     }
 
-    /// Create spectral data with explicit measurement mode
-    pub fn with_mode(mut values: Vec<f32>, mode: MeasurementMode) -> Self {
-        while values.len() < 41 {
-            values.push(0.0);
+    /// Create spectral data on an explicit wavelength grid, e.g. 1nm, 5nm
+    /// or an imported 360-830nm range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the grid is not valid; see [`SpectralData::validate`].
+    pub fn from_grid(wavelengths: Vec<f32>, values: Vec<f32>, quantity: Quantity) -> Result<Self> {
+        let data = Self {
+            wavelengths,
+            values,
+            quantity,
+            metadata: MeasurementMetadata::default(),
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Check that the spectrum can be integrated: at least two samples, one
+    /// value per wavelength, finite numbers and strictly increasing
+    /// wavelengths.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpectroError::Data`] describing the first problem found.
+    pub fn validate(&self) -> Result<()> {
+        if self.wavelengths.len() != self.values.len() {
+            return Err(SpectroError::Data(format!(
+                "{} values for {} wavelengths",
+                self.values.len(),
+                self.wavelengths.len()
+            )));
         }
-        Self {
-            wavelengths: WAVELENGTHS.to_vec(),
+        if self.wavelengths.len() < 2 {
+            return Err(SpectroError::Data(
+                "Spectrum needs at least two wavelengths".into(),
+            ));
+        }
+        if self
+            .wavelengths
+            .iter()
+            .chain(&self.values)
+            .any(|v| !v.is_finite())
+        {
+            return Err(SpectroError::Data(
+                "Spectrum contains non-finite values".into(),
+            ));
+        }
+        if let Some(w) = self.wavelengths.windows(2).find(|w| w[1] <= w[0]) {
+            return Err(SpectroError::Data(format!(
+                "Wavelengths must increase ({}nm followed by {}nm)",
+                w[0], w[1]
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether `other` is sampled at the same wavelengths.
+    pub fn same_grid(&self, other: &SpectralData) -> bool {
+        self.wavelengths.len() == other.wavelengths.len()
+            && self.values.len() == other.values.len()
+            && self
+                .wavelengths
+                .iter()
+                .zip(&other.wavelengths)
+                .all(|(a, b)| (a - b).abs() < GRID_TOLERANCE)
+    }
+
+    /// Width of the band each sample stands for: half the distance between
+    /// its neighbours, or the adjacent spacing at either end. On a uniform
+    /// grid every band is one step wide.
+    pub(crate) fn band_widths(&self) -> Vec<f32> {
+        let wl = &self.wavelengths;
+        let n = wl.len();
+        (0..n)
+            .map(|i| match i {
+                0 => wl[1] - wl[0],
+                i if i == n - 1 => wl[i] - wl[i - 1],
+                i => 0.5 * (wl[i + 1] - wl[i - 1]),
+            })
+            .collect()
+    }
+
+    /// The grid spacing, if every step is the same.
+    fn uniform_step(&self) -> Option<f32> {
+        let step = self.wavelengths[1] - self.wavelengths[0];
+        self.wavelengths
+            .windows(2)
+            .all(|w| (w[1] - w[0] - step).abs() < GRID_TOLERANCE)
+            .then_some(step)
+    }
+
+    /// ASTM E308 interval and first/last weight index when the data lies on
+    /// a contiguous part of a 10nm or 20nm grid starting at 380nm.
+    fn e308_range(&self) -> Option<(Interval, usize, usize)> {
+        [Interval::Nm10, Interval::Nm20]
+            .into_iter()
+            .find_map(|interval| {
+                let step = interval.nm();
+                let offset = (self.wavelengths[0] - cie_data::START) / step;
+                if offset < -GRID_TOLERANCE || (offset - offset.round()).abs() > GRID_TOLERANCE {
+                    return None;
+                }
+                let first = offset.round() as usize;
+                let last = first + self.wavelengths.len() - 1;
+                let on_grid = self.wavelengths.iter().enumerate().all(|(i, w)| {
+                    (w - (cie_data::START + (first + i) as f32 * step)).abs() < GRID_TOLERANCE
+                });
+                let in_range = last < (cie_data::LEN - 1) / step as usize + 1;
+                (on_grid && in_range).then_some((interval, first, last))
+            })
+    }
+
+    /// Remove the instrument bandpass from the values before tristimulus
+    /// integration, recording the method in the metadata.
+    ///
+    /// Apply this to data on the native instrument grid; reflective XYZ of the
+    /// result uses ASTM E308 Table 6 weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is invalid, not uniformly spaced, already
+    /// corrected, or the bandwidth does not suit the grid.
+    pub fn correct_bandpass(&self, method: BandpassCorrection) -> Result<Self> {
+        self.validate()?;
+        if let Some(applied) = self.metadata.bandpass {
+            return Err(SpectroError::Data(format!(
+                "Bandpass correction ({}) already applied",
+                applied
+            )));
+        }
+        let step = self.uniform_step().ok_or_else(|| {
+            SpectroError::Data("Bandpass correction needs uniformly spaced data".into())
+        })?;
+        let mut corrected = self.clone();
+        corrected.values = method.apply(&self.values, step)?;
+        corrected.metadata.bandpass = Some(method);
+        Ok(corrected)
+    }
+
+    /// Compute spectral transmittance from a sample reading taken through a
+    /// filter and a reference reading of the bare light source.
+    ///
+    /// Both spectra must come from the same measurement mode. Bands where the
+    /// reference is too weak to divide by are set to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the readings are on different wavelength grids.
+    pub fn transmittance(sample: &SpectralData, reference: &SpectralData) -> Result<Self> {
+        if !sample.same_grid(reference) {
+            return Err(SpectroError::Data(
+                "Sample and reference are on different wavelength grids".into(),
+            ));
+        }
+        let peak = reference.values.iter().cloned().fold(0.0f32, f32::max);
+        let floor = peak * MIN_REFERENCE_FRACTION;
+        let values = sample
+            .values
+            .iter()
+            .zip(&reference.values)
+            .map(|(&s, &r)| if r > floor && r > 0.0 { s / r } else { 0.0 })
+            .collect();
+
+        Ok(Self {
+            wavelengths: reference.wavelengths.clone(),
             values,
-            mode,
+            quantity: Quantity::Transmittance,
+            metadata: sample.metadata.clone(),
+        })
+    }
+
+    /// Average several readings of the same sample.
+    ///
+    /// The result carries the first frame's metadata with `averaged_frames`
+    /// and the per-band standard deviation filled in.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `frames` is empty or the frames are on different
+    /// wavelength grids.
+    pub fn average(frames: &[SpectralData]) -> Result<Self> {
+        let first = frames
+            .first()
+            .ok_or_else(|| SpectroError::Data("No frames to average".into()))?;
+        if frames.iter().any(|f| !f.same_grid(first)) {
+            return Err(SpectroError::Data(
+                "Cannot average spectra on different wavelength grids".into(),
+            ));
         }
+
+        let n = frames.len() as f32;
+        let bands = first.values.len();
+        let mean: Vec<f32> = (0..bands)
+            .map(|i| frames.iter().map(|f| f.values[i]).sum::<f32>() / n)
+            .collect();
+        let std_dev: Vec<f32> = (0..bands)
+            .map(|i| {
+                let var = frames
+                    .iter()
+                    .map(|f| (f.values[i] - mean[i]).powi(2))
+                    .sum::<f32>()
+                    / (n - 1.0).max(1.0);
+                var.sqrt()
+            })
+            .collect();
+
+        let mut metadata = first.metadata.clone();
+        metadata.averaged_frames = Some(frames.len() as u32);
+        metadata.std_dev = Some(std_dev);
+
+        Ok(Self {
+            wavelengths: first.wavelengths.clone(),
+            values: mean,
+            quantity: first.quantity,
+            metadata,
+        })
     }
 
-    /// Set the measurement mode
-    pub fn set_mode(&mut self, mode: MeasurementMode) {
-        self.mode = mode;
+    /// Set the physical quantity
+    pub fn set_quantity(&mut self, quantity: Quantity) {
+        self.quantity = quantity;
     }
 
     /// Convert to XYZ using the standard 2-degree observer and D65.
@@ -60,64 +341,128 @@ impl SpectralData {
 
     /// Convert to XYZ using specified illuminant and observer.
     ///
-    /// For reflective measurements, uses ASTM E308 weighting factors when available.
-    /// Currently supported: D65/2°, D50/2°.
+    /// For reflectance and transmittance on a 10nm or 20nm grid, uses the
+    /// ASTM E308 weights for `source` and `obs` (see [`e308`]), from Table 6
+    /// once [`correct_bandpass`](Self::correct_bandpass) has been applied and
+    /// Table 5 before; otherwise the weights are built from the illuminant and
+    /// CMFs at the sample wavelengths. Spectral power ignores `source` and is
+    /// integrated directly.
+    ///
+    /// Returns NaN components if the data fails [`SpectralData::validate`];
+    /// use [`SpectralData::try_to_xyz_ext`] to get the error instead.
     pub fn to_xyz_ext(&self, source: Illuminant, obs: Observer) -> XYZ {
-        match self.mode {
-            MeasurementMode::Reflective => {
-                match (source, obs) {
-                    (Illuminant::D65, Observer::CIE1931_2) => self.to_xyz_reflective_weighted(
-                        &weighting::WX_D65_2_10,
-                        &weighting::WY_D65_2_10,
-                        &weighting::WZ_D65_2_10,
-                        weighting::SUM_WY_D65_2_10,
-                    ),
-                    (Illuminant::D50, Observer::CIE1931_2) => self.to_xyz_reflective_weighted(
-                        &weighting::WX_D50_2_10,
-                        &weighting::WY_D50_2_10,
-                        &weighting::WZ_D50_2_10,
-                        weighting::SUM_WY_D50_2_10,
-                    ),
-                    // For other combinations, calculate weighting factors dynamically
-                    _ => {
-                        let spd = source.get_spd();
-                        let (xb, yb, zb) = obs.get_cmfs();
-                        let mut wx = [0.0f32; 41];
-                        let mut wy = [0.0f32; 41];
-                        let mut wz = [0.0f32; 41];
-                        let mut sum_wy = 0.0f32;
-
-                        for i in 0..41 {
-                            wx[i] = spd[i] * xb[i];
-                            wy[i] = spd[i] * yb[i];
-                            wz[i] = spd[i] * zb[i];
-                            sum_wy += wy[i];
-                        }
+        self.try_to_xyz_ext(source, obs).unwrap_or(INVALID_XYZ)
+    }
 
-                        self.to_xyz_reflective_weighted(&wx, &wy, &wz, sum_wy)
-                    }
+    /// Convert to XYZ, reporting invalid wavelength grids as errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data fails [`SpectralData::validate`] or does
+    /// not overlap the visible range.
+    pub fn try_to_xyz_ext(&self, source: Illuminant, obs: Observer) -> Result<XYZ> {
+        if self.quantity.is_emissive() {
+            self.try_to_xyz_emissive_ext(obs)
+        } else {
+            self.try_to_xyz_reflective(source, obs)
+        }
+    }
+
+    /// Reflectance or transmittance factor to XYZ, normalized so a perfect
+    /// diffuser has Y = 100 over the measured range.
+    fn try_to_xyz_reflective(&self, source: Illuminant, obs: Observer) -> Result<XYZ> {
+        self.validate()?;
+
+        let xyz = match self.e308_range() {
+            Some((interval, first, last)) => {
+                // Table 5 compensates for the bandpass of uncorrected data
+                let table = match self.metadata.bandpass {
+                    Some(_) => E308Table::Table6,
+                    None => E308Table::Table5,
+                };
+                let w = e308::weights(source, obs, interval, table);
+                // ASTM E308 truncation: weights outside the measured range
+                // are added to the end bands
+                let truncate = |table: &[f32]| {
+                    let mut w = table[first..=last].to_vec();
+                    w[0] += table[..first].iter().sum::<f32>();
+                    w[last - first] += table[last + 1..].iter().sum::<f32>();
+                    w
+                };
+                let sum_wy = w.y.iter().sum();
+                self.to_xyz_reflective_weighted(
+                    &truncate(&w.x),
+                    &truncate(&w.y),
+                    &truncate(&w.z),
+                    sum_wy,
+                )
+            }
+            // Otherwise calculate weighting factors at the sample wavelengths
+            None => {
+                let n = self.wavelengths.len();
+                let mut wx = Vec::with_capacity(n);
+                let mut wy = Vec::with_capacity(n);
+                let mut wz = Vec::with_capacity(n);
+                for (&wl, dw) in self.wavelengths.iter().zip(self.band_widths()) {
+                    let s = source.spd_at(wl) * dw;
+                    let (xb, yb, zb) = obs.cmf_at(wl);
+                    wx.push(s * xb);
+                    wy.push(s * yb);
+                    wz.push(s * zb);
                 }
+                let sum_wy: f32 = wy.iter().sum();
+                if sum_wy <= 0.0 {
+                    return Err(SpectroError::Data(
+                        "Spectrum lies outside the range of the colour-matching functions".into(),
+                    ));
+                }
+                self.to_xyz_reflective_weighted(&wx, &wy, &wz, sum_wy)
             }
-            MeasurementMode::Emissive => self.to_xyz_emissive_ext(obs),
+        };
+        Ok(xyz)
+    }
+
+    /// Convert reflectance or transmittance to CIELAB under the given
+    /// illuminant, relative to that illuminant's white point.
+    pub fn to_lab_ext(&self, source: Illuminant, obs: Observer) -> Lab {
+        let xyz = self.to_xyz_ext(source, obs);
+        XYZ {
+            x: xyz.x / 100.0,
+            y: xyz.y / 100.0,
+            z: xyz.z / 100.0,
         }
+        .to_lab(source.get_white_point(obs))
     }
 
-    /// Convert reflectance to XYZ using provided weighting factors.
-    fn to_xyz_reflective_weighted(
-        &self,
-        wx: &[f32; 41],
-        wy: &[f32; 41],
-        wz: &[f32; 41],
-        sum_wy: f32,
-    ) -> XYZ {
+    /// Spectral optical density, D(λ) = −log10 T(λ).
+    ///
+    /// Intended for transmittance data; bands at or near zero transmittance
+    /// are capped at a density of 5.0.
+    pub fn optical_density(&self) -> Vec<f32> {
+        self.values
+            .iter()
+            .map(|t| -t.max(MIN_TRANSMITTANCE).log10())
+            .collect()
+    }
+
+    /// Visual (luminous) density, −log10 of the luminous transmittance
+    /// Y/100 under the given illuminant and observer.
+    pub fn visual_density(&self, source: Illuminant, obs: Observer) -> f32 {
+        let y = self.to_xyz_ext(source, obs).y / 100.0;
+        -y.max(MIN_TRANSMITTANCE).log10()
+    }
+
+    /// Convert reflectance to XYZ using provided weighting factors, one per
+    /// sample.
+    fn to_xyz_reflective_weighted(&self, wx: &[f32], wy: &[f32], wz: &[f32], sum_wy: f32) -> XYZ {
         let mut x = 0.0f32;
         let mut y = 0.0f32;
         let mut z = 0.0f32;
 
-        for i in 0..41 {
-            x += self.values[i] * wx[i];
-            y += self.values[i] * wy[i];
-            z += self.values[i] * wz[i];
+        for (i, v) in self.values.iter().enumerate() {
+            x += v * wx[i];
+            y += v * wy[i];
+            z += v * wz[i];
         }
 
         // Normalize so that Y=100 for a perfect white diffuser
@@ -132,84 +477,113 @@ impl SpectralData {
 
     /// Resample spectral data to a new wavelength range and step.
     /// Uses Sprague interpolation for high accuracy, which is recommended
-    /// by the CIE for spectral data resampling.
+    /// by the CIE for spectral data resampling; data on a non-uniform grid
+    /// is interpolated linearly. Beyond the measured range the end values
+    /// are repeated (CIE 15).
+    ///
+    /// Returns empty data if the spectrum or the requested grid is invalid;
+    /// see [`SpectralData::try_resample`].
     pub fn resample(&self, start: f32, end: f32, step: f32) -> Self {
-        let mut new_values = Vec::new();
-        let mut current_wl = start;
-
-        // Pad values for Sprague (needs 2 before and 3 after)
-        let mut padded_values = Vec::with_capacity(self.values.len() + 5);
-        if !self.values.is_empty() {
-            padded_values.push(self.values[0]);
-            padded_values.push(self.values[0]);
-            padded_values.extend_from_slice(&self.values);
-            padded_values.push(*self.values.last().unwrap());
-            padded_values.push(*self.values.last().unwrap());
-            padded_values.push(*self.values.last().unwrap());
-        } else {
-            return Self {
+        self.try_resample(start, end, step)
+            .unwrap_or_else(|_| Self {
                 wavelengths: Vec::new(),
                 values: Vec::new(),
-                mode: self.mode,
-            };
-        }
+                quantity: self.quantity,
+                metadata: self.metadata.clone(),
+            })
+    }
 
-        let orig_start = self.wavelengths[0];
-        let orig_step = if self.wavelengths.len() > 1 {
-            self.wavelengths[1] - self.wavelengths[0]
-        } else {
-            10.0
-        };
+    /// Resample to `start..=end` in `step` nm increments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data fails [`SpectralData::validate`] or the
+    /// requested grid is empty.
+    pub fn try_resample(&self, start: f32, end: f32, step: f32) -> Result<Self> {
+        if !(step > 0.0 && start.is_finite() && end >= start) {
+            return Err(SpectroError::Data(format!(
+                "Invalid resampling grid {}-{}nm in {}nm steps",
+                start, end, step
+            )));
+        }
 
-        while current_wl <= end + 1e-3 {
-            let t = (current_wl - orig_start) / orig_step;
-            let i = t.floor() as i32;
-            let x = t - i as f32;
-
-            // i is the index of y0 in the original values
-            // In padded_values, y0 is at index i + 2
-            let idx = (i + 2) as usize;
-
-            if idx < 2 || idx + 3 >= padded_values.len() {
-                // Fallback to linear or clamping at edges
-                let val = if current_wl <= orig_start {
-                    self.values[0]
-                } else if current_wl >= orig_start + (self.values.len() - 1) as f32 * orig_step {
-                    *self.values.last().unwrap()
-                } else {
-                    let i_idx = i.max(0) as usize;
-                    let v0 = self.values[i_idx];
-                    let v1 = self.values[(i_idx + 1).min(self.values.len() - 1)];
-                    v0 + x * (v1 - v0)
-                };
-                new_values.push(val);
-            } else {
-                let y = [
-                    padded_values[idx - 2],
-                    padded_values[idx - 1],
-                    padded_values[idx],
-                    padded_values[idx + 1],
-                    padded_values[idx + 2],
-                    padded_values[idx + 3],
-                ];
-                new_values.push(Self::sprague_interpolate(x, &y));
-            }
+        let count = ((end - start) / step + 1e-4).floor() as usize + 1;
+        self.try_resample_to((0..count).map(|i| start + i as f32 * step).collect())
+    }
 
-            current_wl += step;
+    /// Resample onto an explicit grid, such as another spectrum's
+    /// wavelengths, interpolating as [`SpectralData::resample`] does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either grid is invalid.
+    pub fn try_resample_to(&self, wavelengths: Vec<f32>) -> Result<Self> {
+        self.validate()?;
+        if wavelengths.is_empty()
+            || wavelengths.iter().any(|w| !w.is_finite())
+            || wavelengths.windows(2).any(|w| w[1] <= w[0])
+        {
+            return Err(SpectroError::Data(
+                "Resampling grid must be non-empty and increasing".into(),
+            ));
         }
 
-        let mut wavelengths = Vec::new();
-        let mut wl = start;
-        while wl <= end + 1e-3 {
-            wavelengths.push(wl);
-            wl += step;
-        }
+        let values = match self.uniform_step() {
+            Some(orig_step) => {
+                // Pad values for Sprague (needs 2 before and 3 after)
+                let first = self.values[0];
+                let last = self.values[self.values.len() - 1];
+                let mut padded_values = Vec::with_capacity(self.values.len() + 5);
+                padded_values.extend([first, first]);
+                padded_values.extend_from_slice(&self.values);
+                padded_values.extend([last, last, last]);
 
-        Self {
+                let orig_start = self.wavelengths[0];
+                let orig_end = self.wavelengths[self.wavelengths.len() - 1];
+                wavelengths
+                    .iter()
+                    .map(|&wl| {
+                        let t = (wl - orig_start) / orig_step;
+                        let i = t.floor() as i32;
+                        let x = t - i as f32;
+
+                        // i is the index of y0 in the original values
+                        // In padded_values, y0 is at index i + 2
+                        let idx = (i + 2) as usize;
+
+                        if wl <= orig_start {
+                            first
+                        } else if wl >= orig_end || idx + 3 >= padded_values.len() {
+                            last
+                        } else {
+                            let y = [
+                                padded_values[idx - 2],
+                                padded_values[idx - 1],
+                                padded_values[idx],
+                                padded_values[idx + 1],
+                                padded_values[idx + 2],
+                                padded_values[idx + 3],
+                            ];
+                            Self::sprague_interpolate(x, &y)
+                        }
+                    })
+                    .collect()
+            }
+            None => {
+                crate::sprague::linear_interpolate(&self.wavelengths, &self.values, &wavelengths)
+            }
+        };
+
+        Ok(Self {
             wavelengths,
-            values: new_values,
-            mode: self.mode,
-        }
+            values,
+            quantity: self.quantity,
+            // The deviation is per original band and does not carry over
+            metadata: MeasurementMetadata {
+                std_dev: None,
+                ..self.metadata.clone()
+            },
+        })
     }
 
     /// Sprague interpolation for a point x in [0, 1] between y[2] and y[3].
@@ -236,27 +610,118 @@ impl SpectralData {
     }
 
     /// Convert spectral power distribution to XYZ with specified observer.
+    ///
+    /// For absolute units Y is in lux (irradiance) or cd/m² (radiance); see
+    /// [`Quantity::luminous_efficacy`]. Returns NaN components if the data
+    /// fails [`SpectralData::validate`].
     pub fn to_xyz_emissive_ext(&self, obs: Observer) -> XYZ {
-        const STEP: f32 = 10.0;
-        let (xb, yb, zb) = obs.get_cmfs();
+        self.try_to_xyz_emissive_ext(obs).unwrap_or(INVALID_XYZ)
+    }
+
+    /// Integrate spectral power against the CMFs at the sample wavelengths,
+    /// Σ P(λ)·CMF(λ)·Δλ with each sample's own band width.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data fails [`SpectralData::validate`].
+    pub fn try_to_xyz_emissive_ext(&self, obs: Observer) -> Result<XYZ> {
+        self.validate()?;
+        let k = self.quantity.luminous_efficacy();
 
         let mut x = 0.0f32;
         let mut y = 0.0f32;
         let mut z = 0.0f32;
 
-        for i in 0..41 {
-            x += self.values[i] * xb[i];
-            y += self.values[i] * yb[i];
-            z += self.values[i] * zb[i];
+        for ((&wl, &v), dw) in self
+            .wavelengths
+            .iter()
+            .zip(&self.values)
+            .zip(self.band_widths())
+        {
+            let (xb, yb, zb) = obs.cmf_at(wl);
+            x += v * xb * dw;
+            y += v * yb * dw;
+            z += v * zb * dw;
         }
 
-        XYZ {
-            x: x * STEP,
-            y: y * STEP,
-            z: z * STEP,
+        Ok(XYZ {
+            x: x * k,
+            y: y * k,
+            z: z * k,
+        })
+    }
+
+    /// Illuminance or luminance of an absolute irradiance or radiance
+    /// reading, or `None` for relative data.
+    pub fn photometric(&self) -> Option<Photometric> {
+        let y = self.to_xyz_emissive_2().y;
+        match self.quantity {
+            Quantity::Irradiance => Some(Photometric::illuminance(y)),
+            Quantity::Radiance => Some(Photometric::luminance(y)),
+            _ => None,
         }
     }
 
+    /// Scotopic illuminance or luminance of an absolute reading, weighted by
+    /// V'(λ) and [`KM_SCOTOPIC`], or `None` for relative data.
+    pub fn scotopic(&self) -> Option<Photometric> {
+        let y = KM_SCOTOPIC * self.scotopic_integral();
+        match self.quantity {
+            Quantity::Irradiance => Some(Photometric::illuminance(y)),
+            Quantity::Radiance => Some(Photometric::luminance(y)),
+            _ => None,
+        }
+    }
+
+    /// Scotopic to photopic ratio of a light source, or `None` for
+    /// reflectance, transmittance or a spectrum with no photopic content.
+    pub fn sp_ratio(&self) -> Option<f32> {
+        if !self.quantity.is_emissive() {
+            return None;
+        }
+        let photopic = self.try_to_xyz_emissive_ext(Observer::CIE1931_2).ok()?.y
+            / self.quantity.luminous_efficacy();
+        (photopic > 0.0).then(|| KM_SCOTOPIC * self.scotopic_integral() / (KM * photopic))
+    }
+
+    /// CIE 191 mesopic luminance of this light at a photopic adaptation
+    /// luminance in cd/m², such as the road surface of a street lighting
+    /// installation. Only the spectrum's S/P ratio is used, so relative data
+    /// will do.
+    pub fn mesopic(&self, photopic: f32) -> Option<Mesopic> {
+        Some(photometry::mesopic_luminance(photopic, self.sp_ratio()?))
+    }
+
+    /// Luminous efficacy of radiation in lm/W: luminous flux over the
+    /// radiant flux within the measured range. `None` for reflectance,
+    /// transmittance or a spectrum with no power.
+    pub fn luminous_efficacy_of_radiation(&self) -> Option<f32> {
+        if !self.quantity.is_emissive() {
+            return None;
+        }
+        let photopic = self.try_to_xyz_emissive_ext(Observer::CIE1931_2).ok()?.y
+            / self.quantity.luminous_efficacy();
+        let radiant: f32 = self
+            .values
+            .iter()
+            .zip(self.band_widths())
+            .map(|(v, dw)| v * dw)
+            .sum();
+        (radiant > 0.0).then(|| KM * photopic / radiant)
+    }
+
+    /// Σ P(λ)·V'(λ)·Δλ at the sample wavelengths.
+    fn scotopic_integral(&self) -> f32 {
+        self.wavelengths
+            .iter()
+            .zip(&self.values)
+            .zip(self.band_widths())
+            .map(|((&wl, &v), dw)| {
+                v * colorimetry::interpolate_1nm(wl, |i| cie_data::SCOTOPIC[i]) * dw
+            })
+            .sum()
+    }
+
     /// Get the raw wavelengths and values as references.
     /// Used for spectral reconstruction and external processing.
     pub fn get_wavelength_data(&self) -> (Vec<f32>, Vec<f32>) {
@@ -270,26 +735,12 @@ impl SpectralData {
     /// - D65 spectral power distribution
     /// - CIE 1931 2° standard observer CMFs
     /// - Proper normalization
+    ///
+    /// Data off the 10nm/20nm grids uses weights computed at its own
+    /// wavelengths.
     pub fn to_xyz_reflective_2(&self) -> XYZ {
-        let mut x = 0.0f32;
-        let mut y = 0.0f32;
-        let mut z = 0.0f32;
-
-        for i in 0..41 {
-            x += self.values[i] * weighting::WX_D65_2_10[i];
-            y += self.values[i] * weighting::WY_D65_2_10[i];
-            z += self.values[i] * weighting::WZ_D65_2_10[i];
-        }
-
-        // ASTM E308 weights when summed for R=1.0 give ~10.683
-        // Normalize so that Y=100 for a perfect white diffuser
-        let scale = 100.0 / weighting::SUM_WY_D65_2_10;
-
-        XYZ {
-            x: x * scale,
-            y: y * scale,
-            z: z * scale,
-        }
+        self.try_to_xyz_reflective(Illuminant::D65, Observer::CIE1931_2)
+            .unwrap_or(INVALID_XYZ)
     }
 
     /// Convert spectral power distribution to XYZ for emissive sources (2° observer).
@@ -297,41 +748,17 @@ impl SpectralData {
     ///
     /// # Output Units
     ///
-    /// The output units depend on how the spectral data was processed:
-    /// - If spectral values are in device-calibrated units (via EEPROM `emis_coef`),
-    ///   the Y value approximates luminance in cd/m² (after proper device calibration).
-    /// - For raw spectral power in W/sr/m²/nm, multiply Y by Km=683 lm/W for cd/m².
-    ///
-    /// Note: The ColorMunki's EEPROM `emis_coef` provides device-specific calibration
-    /// that should produce results comparable to ArgyllCMS when properly applied.
+    /// The output units follow [`SpectralData::quantity`]:
+    /// - Radiance in W/sr/m²/nm gives Y in cd/m² (scaled by Km = 683 lm/W).
+    /// - Irradiance in W/m²/nm gives Y in lux.
+    /// - Relative data gives the unscaled integral Σ S(λ)·ȳ(λ)·Δλ.
     pub fn to_xyz_emissive_2(&self) -> XYZ {
-        const STEP: f32 = 10.0; // 10nm wavelength step
-
-        let mut x = 0.0f32;
-        let mut y = 0.0f32;
-        let mut z = 0.0f32;
-
-        for i in 0..41 {
-            x += self.values[i] * X_BAR_2[i];
-            y += self.values[i] * Y_BAR_2[i];
-            z += self.values[i] * Z_BAR_2[i];
-        }
-
-        // Integrate P(λ) * CMF(λ) * Δλ
-        // No additional Km scaling - emis_coef from EEPROM provides calibration
-        XYZ {
-            x: x * STEP,
-            y: y * STEP,
-            z: z * STEP,
-        }
+        self.to_xyz_emissive_ext(Observer::CIE1931_2)
     }
 
     /// Convert to XYZ using the 2-degree observer (CIE 1931).
     /// Legacy method - uses CMF integration (suitable for emissive sources)
-    #[deprecated(
-        since = "0.2.0",
-        note = "Use to_xyz() with appropriate MeasurementMode"
-    )]
+    #[deprecated(since = "0.2.0", note = "Use to_xyz() with the appropriate Quantity")]
     pub fn to_xyz_2(&self) -> XYZ {
         self.to_xyz_emissive_2()
     }
@@ -339,23 +766,7 @@ impl SpectralData {
     /// Convert to XYZ using the 10-degree observer (CIE 1964).
     /// Uses CMF integration (suitable for emissive sources)
     pub fn to_xyz_10(&self) -> XYZ {
-        const STEP: f32 = 10.0;
-
-        let mut x = 0.0f32;
-        let mut y = 0.0f32;
-        let mut z = 0.0f32;
-
-        for i in 0..41 {
-            x += self.values[i] * X_BAR_10[i];
-            y += self.values[i] * Y_BAR_10[i];
-            z += self.values[i] * Z_BAR_10[i];
-        }
-
-        XYZ {
-            x: x * STEP,
-            y: y * STEP,
-            z: z * STEP,
-        }
+        self.to_xyz_emissive_ext(Observer::CIE1964_10)
     }
 
     /// Calculate the normalization constant k for reflectance mode.
@@ -387,21 +798,261 @@ impl XYZ {
         (self.x / sum, self.y / sum)
     }
 
-    /// Calculate Correlated Color Temperature (CCT) using McCamy's formula.
+    /// Correlated Color Temperature (CCT) in kelvin, NaN when it cannot be
+    /// determined. See [`to_cct_duv`](Self::to_cct_duv).
     pub fn to_cct(&self) -> f32 {
-        let (x, y) = self.to_chromaticity();
-        let n = (x - 0.3320) / (0.1858 - y);
-        // McCamy's formula
-        449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5524.33
+        self.to_cct_duv().map_or(f32::NAN, |r| r.cct)
+    }
+
+    /// CCT and signed Duv with the Ohno (2013) method (see [`cct`]). The
+    /// values must be CIE 1931 2° tristimulus values.
+    pub fn to_cct_duv(&self) -> Option<CctDuv> {
+        cct::from_xyz(self)
     }
 }
 
 impl std::fmt::Display for SpectralData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Spectral Data (380nm - 730nm, {:?} mode):", self.mode)?;
+        match (self.wavelengths.first(), self.wavelengths.last()) {
+            (Some(first), Some(last)) => writeln!(
+                f,
+                "Spectral Data ({:.0}nm - {:.0}nm, {:?}):",
+                first, last, self.quantity
+            )?,
+            _ => writeln!(f, "Spectral Data (empty, {:?}):", self.quantity)?,
+        }
         for (w, v) in self.wavelengths.iter().zip(self.values.iter()) {
             writeln!(f, "  {:.0}nm: {:.6}", w, v)?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neutral_density_filter() {
+        let source: Vec<f32> = (0..36).map(|i| 0.5 + i as f32 * 0.02).collect();
+        let reference = SpectralData::with_quantity(source.clone(), Quantity::Irradiance);
+        let sample = SpectralData::with_quantity(
+            source.iter().map(|v| v * 0.1).collect(),
+            Quantity::Irradiance,
+        );
+
+        let t = SpectralData::transmittance(&sample, &reference).unwrap();
+        assert_eq!(t.quantity, Quantity::Transmittance);
+        assert!((t.values[20] - 0.1).abs() < 1e-6);
+        // Bands beyond the device range are not invented
+        assert_eq!(t.values.len(), 36);
+        assert!((t.optical_density()[20] - 1.0).abs() < 1e-5);
+
+        // A flat 10% filter inside 380-730nm
+        let mut flat = t.clone();
+        flat.values.iter_mut().for_each(|v| *v = 0.1);
+        let density = flat.visual_density(Illuminant::D65, Observer::CIE1931_2);
+        assert!((density - 1.0).abs() < 1e-3, "density {}", density);
+        let lab = flat.to_lab_ext(Illuminant::D65, Observer::CIE1931_2);
+        assert!((lab.l - 37.84).abs() < 0.05, "L* {}", lab.l);
+        assert!(lab.a.abs() < 0.1 && lab.b.abs() < 0.1);
+    }
+
+    #[test]
+    fn test_absolute_irradiance_to_lux() {
+        let mut data = SpectralData::with_quantity(vec![1e-3; 41], Quantity::RelativePower);
+        assert!(data.photometric().is_none());
+
+        // 1 mW/m²/nm across 380-780nm: Σȳ·Δλ ≈ 106.86 nm, times Km
+        data.quantity = Quantity::Irradiance;
+        let e = data.photometric().unwrap();
+        assert!(e.unit.is_illuminance());
+        assert!((e.value - 72.98).abs() < 0.1, "illuminance {}", e.value);
+    }
+
+    #[test]
+    fn test_scotopic_and_efficacy() {
+        let source = |illuminant: Illuminant| {
+            let values = crate::WAVELENGTHS.iter().map(|&wl| illuminant.spd_at(wl));
+            SpectralData::with_quantity(values.collect(), Quantity::RelativePower)
+        };
+        // Published S/P ratios of CIE A and D65
+        let a = source(Illuminant::A).sp_ratio().unwrap();
+        let d65 = source(Illuminant::D65).sp_ratio().unwrap();
+        assert!((a - 1.41).abs() < 0.02, "A {}", a);
+        assert!((d65 - 2.47).abs() < 0.03, "D65 {}", d65);
+        assert!(source(Illuminant::D65).mesopic(0.1).unwrap().luminance > 0.1);
+
+        // Equal energy over 380-780nm: 683 × 106.86 / 410 lm/W
+        let flat = SpectralData::with_quantity(vec![1.0; 41], Quantity::Irradiance);
+        let ler = flat.luminous_efficacy_of_radiation().unwrap();
+        assert!((ler - 178.0).abs() < 0.5, "LER {}", ler);
+        assert!(flat.scotopic().unwrap().unit.is_illuminance());
+    }
+
+    #[test]
+    fn test_average_frames() {
+        let frames: Vec<SpectralData> = [0.9f32, 1.0, 1.1]
+            .iter()
+            .map(|&v| SpectralData::with_quantity(vec![v; 36], Quantity::Reflectance))
+            .collect();
+
+        let avg = SpectralData::average(&frames).unwrap();
+        assert!((avg.values[0] - 1.0).abs() < 1e-6);
+        assert_eq!(avg.metadata.averaged_frames, Some(3));
+        assert!((avg.metadata.std_dev.as_ref().unwrap()[0] - 0.1).abs() < 1e-5);
+        assert!(SpectralData::average(&[]).is_err());
+    }
+
+    #[test]
+    fn test_quantity_selects_integration_path() {
+        let values = vec![0.5f32; 41];
+        let reflectance = SpectralData::with_quantity(values.clone(), Quantity::Reflectance);
+        assert!((reflectance.to_xyz().y - 50.0).abs() < 0.05);
+
+        let radiance = SpectralData::with_quantity(values, Quantity::Radiance);
+        let y = radiance.to_xyz().y;
+        assert!((y - radiance.to_xyz_emissive_2().y).abs() < 1e-3);
+        assert!((radiance.photometric().unwrap().value - y).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_arbitrary_grids() {
+        let values: Vec<f32> = (0..41)
+            .map(|i| 0.5 + 0.3 * (i as f32 * 0.2).sin())
+            .collect();
+        for quantity in [Quantity::Reflectance, Quantity::RelativePower] {
+            let coarse = SpectralData::with_quantity(values.clone(), quantity);
+            let xyz = coarse.to_xyz_ext(Illuminant::A, Observer::CIE1931_2);
+            for step in [5.0, 1.0, 10.0 / 3.0] {
+                let fine = coarse.try_resample(380.0, 780.0, step).unwrap();
+                let f = fine.to_xyz_ext(Illuminant::A, Observer::CIE1931_2);
+                assert!(
+                    (f.y - xyz.y).abs() < 5e-3 * xyz.y && (f.z - xyz.z).abs() < 1e-2 * xyz.z,
+                    "{:?} at {}nm: {:?} vs {:?}",
+                    quantity,
+                    step,
+                    f,
+                    xyz
+                );
+            }
+        }
+
+        let unordered =
+            SpectralData::from_grid(vec![400.0, 390.0], vec![1.0, 1.0], Quantity::Reflectance);
+        assert!(unordered.is_err());
+        let mut short = SpectralData::new(values);
+        short.values.pop();
+        assert!(short
+            .try_to_xyz_ext(Illuminant::D65, Observer::CIE1931_2)
+            .is_err());
+        assert!(short.to_xyz().y.is_nan());
+    }
+
+    #[test]
+    fn test_e308_intervals() {
+        use crate::colorimetry::weighting as w;
+
+        // D65 and D50 readings on the 10nm grid are weighted with the
+        // published E308 tables
+        let ink = SpectralData::new((0..41).map(|i| if i < 20 { 0.05 } else { 0.8 }).collect());
+        let weigh = |r: &[f32], table: &[f32], sum_wy: f32| {
+            r.iter().zip(table).map(|(r, w)| r * w).sum::<f32>() * 100.0 / sum_wy
+        };
+        for (ill, wx, wy, wz) in [
+            (
+                Illuminant::D65,
+                w::WX_D65_2_10,
+                w::WY_D65_2_10,
+                w::WZ_D65_2_10,
+            ),
+            (
+                Illuminant::D50,
+                w::WX_D50_2_10,
+                w::WY_D50_2_10,
+                w::WZ_D50_2_10,
+            ),
+        ] {
+            let sum_wy: f32 = wy.iter().sum();
+            let xyz = ink.to_xyz_ext(ill, Observer::CIE1931_2);
+            assert!((xyz.x - weigh(&ink.values, &wx, sum_wy)).abs() < 1e-3);
+            assert!((xyz.y - weigh(&ink.values, &wy, sum_wy)).abs() < 1e-3);
+            assert!((xyz.z - weigh(&ink.values, &wz, sum_wy)).abs() < 1e-3);
+        }
+
+        // Over 400-700nm the published weights outside the range are added
+        // to the end bands
+        let truncated = SpectralData::from_grid(
+            crate::WAVELENGTHS[2..=32].to_vec(),
+            ink.values[2..=32].to_vec(),
+            Quantity::Reflectance,
+        )
+        .unwrap();
+        let mut wx = w::WX_D65_2_10[2..=32].to_vec();
+        wx[0] += w::WX_D65_2_10[..2].iter().sum::<f32>();
+        wx[30] += w::WX_D65_2_10[33..].iter().sum::<f32>();
+        let sum_wy: f32 = w::WY_D65_2_10.iter().sum();
+        assert!((truncated.to_xyz().x - weigh(&truncated.values, &wx, sum_wy)).abs() < 1e-3);
+
+        // A perfect white on the 20nm grid gives the white point
+        let wavelengths: Vec<f32> = (0..21).map(|i| 380.0 + i as f32 * 20.0).collect();
+        let white =
+            SpectralData::from_grid(wavelengths, vec![1.0; 21], Quantity::Reflectance).unwrap();
+        let xyz = white.to_xyz_ext(Illuminant::C, Observer::CIE1931_2);
+        assert!((xyz.x - 98.07).abs() < 0.1 && (xyz.z - 118.22).abs() < 0.1);
+
+        // Bandpass-corrected data is weighted with Table 6
+        let corrected = ink.correct_bandpass(BandpassCorrection::Stearns).unwrap();
+        assert_eq!(
+            corrected.metadata.bandpass,
+            Some(BandpassCorrection::Stearns)
+        );
+        assert!(corrected.values[19] < ink.values[19]);
+        let t6 = e308::weights(
+            Illuminant::D65,
+            Observer::CIE1931_2,
+            Interval::Nm10,
+            E308Table::Table6,
+        );
+        let sum_wy: f32 = t6.y.iter().sum();
+        assert!((corrected.to_xyz().x - weigh(&corrected.values, &t6.x, sum_wy)).abs() < 1e-3);
+        // Both routes describe the same sample
+        assert!((corrected.to_xyz().y - ink.to_xyz().y).abs() < 0.2);
+        assert!(corrected
+            .correct_bandpass(BandpassCorrection::Stearns)
+            .is_err());
+    }
+
+    #[test]
+    fn test_bandpass_round_trip() {
+        // A 15nm Gaussian band seen through a 10nm triangular bandpass,
+        // integrated at 1nm
+        let truth = |wl: f32| 0.1 + 0.8 * (-0.5 * ((wl - 550.0) / 15.0).powi(2)).exp();
+        let measured: Vec<f32> = crate::WAVELENGTHS
+            .iter()
+            .map(|&wl| {
+                (-10..=10)
+                    .map(|d| (1.0 - (d as f32).abs() / 10.0) * truth(wl + d as f32))
+                    .sum::<f32>()
+                    / 10.0
+            })
+            .collect();
+        let data = SpectralData::new(measured);
+        let corrected = data
+            .correct_bandpass(BandpassCorrection::E2729 { bandwidth: 10.0 })
+            .unwrap();
+
+        let max_error = |d: &SpectralData| {
+            d.wavelengths
+                .iter()
+                .zip(&d.values)
+                .map(|(&wl, v)| (v - truth(wl)).abs())
+                .fold(0.0f32, f32::max)
+        };
+        assert!(max_error(&data) > 0.025);
+        assert!(max_error(&corrected) < 0.003, "{}", max_error(&corrected));
+        // Flat ends are untouched
+        assert!((corrected.values[0] - 0.1).abs() < 1e-4);
+        assert!((corrected.values[40] - 0.1).abs() < 1e-4);
+    }
+}
@@ -99,7 +99,7 @@ fn sprague_value(t: f32, y0: f32, y1: f32, y2: f32, y3: f32) -> f32 {
 }
 
 /// Linear fallback interpolation (for insufficient data points).
-fn linear_interpolate(
+pub(crate) fn linear_interpolate(
     wavelengths: &[f32],
     intensities: &[f32],
     output_wavelengths: &[f32],
@@ -0,0 +1,177 @@
+//! Spectral Similarity Index (SSI)
+//!
+//! The Academy of Motion Picture Arts and Sciences index for how closely a
+//! light source matches a reference spectrum, as seen by cameras and film
+//! rather than by the eye (Holm et al., 2016). Both spectra are:
+//!
+//! 1. Resampled to 1nm over 375-675nm and integrated into 30 bins of 10nm
+//!    centered on 380-670nm (trapezoidal, half weight at the bin edges).
+//! 2. Normalized to unit sum.
+//! 3. Differenced relative to the reference plus its mean bin value, and
+//!    weighted to de-emphasize the ends of the range.
+//! 4. Smoothed with a [0.22, 0.56, 0.22] kernel.
+//!
+//! SSI = 100 − 32·√Σ(smoothed difference)². It is conventionally reported
+//! as an integer. Spectra that do not reach 375nm have their end values
+//! repeated, as in [`SpectralData::resample`].
+
+use crate::colorimetry::illuminant;
+use crate::spectrum::{Quantity, SpectralData};
+use crate::{cie_data, Illuminant, Result, SpectroError};
+
+/// Color temperature of the tungsten reference, in kelvin.
+pub const TUNGSTEN_CCT: f32 = 3200.0;
+
+/// Number of 10nm bins.
+const N_BINS: usize = 30;
+
+/// Weight of each bin; the short and long ends count for less.
+const BIN_WEIGHTS: [f32; N_BINS] = [
+    12.0 / 45.0,
+    22.0 / 45.0,
+    32.0 / 45.0,
+    40.0 / 45.0,
+    44.0 / 45.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    11.0 / 15.0,
+    3.0 / 15.0,
+];
+
+const SMOOTHING: [f32; 3] = [0.22, 0.56, 0.22];
+
+/// Reference spectrum for the SSI.
+#[derive(Debug, Clone)]
+pub enum SsiReference {
+    /// Planckian radiator at [`TUNGSTEN_CCT`].
+    Tungsten,
+    /// CIE D55.
+    D55,
+    /// Any other spectrum, such as a luminaire to match.
+    Spectrum(Box<SpectralData>),
+}
+
+impl SsiReference {
+    /// The reference as spectral data.
+    pub fn spd(&self) -> SpectralData {
+        let cie = |values: Vec<f32>| {
+            let wavelengths = (0..cie_data::LEN)
+                .map(|i| cie_data::START + i as f32)
+                .collect();
+            SpectralData {
+                wavelengths,
+                values,
+                quantity: Quantity::RelativePower,
+                metadata: Default::default(),
+            }
+        };
+        match self {
+            SsiReference::Tungsten => cie(illuminant::planckian_1nm(TUNGSTEN_CCT).to_vec()),
+            SsiReference::D55 => cie(Illuminant::D55.spd_1nm().to_vec()),
+            SsiReference::Spectrum(spd) => (**spd).clone(),
+        }
+    }
+}
+
+/// Calculate the SSI of a test spectrum against a reference.
+pub fn calculate_ssi(test: &SpectralData, reference: &SsiReference) -> Result<f32> {
+    let test = bins(test)?;
+    let reference = bins(&reference.spd())?;
+    let mean = 1.0 / N_BINS as f32;
+
+    let weighted: Vec<f32> = (0..N_BINS)
+        .map(|i| BIN_WEIGHTS[i] * (test[i] - reference[i]) / (reference[i] + mean))
+        .collect();
+    let sum_sq: f32 = smooth(&weighted).iter().map(|c| c * c).sum();
+
+    Ok(100.0 - 32.0 * sum_sq.sqrt())
+}
+
+/// Full convolution with [`SMOOTHING`], with zeros beyond both ends.
+fn smooth(weighted: &[f32]) -> Vec<f32> {
+    (0..weighted.len() + 2)
+        .map(|n| {
+            (0..3)
+                .filter_map(|k| {
+                    let w = weighted.get((n + k).checked_sub(2)?)?;
+                    Some(SMOOTHING[2 - k] * w)
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// 10nm bins of a spectrum, normalized to unit sum.
+fn bins(spd: &SpectralData) -> Result<[f32; N_BINS]> {
+    let values = spd.try_resample(375.0, 675.0, 1.0)?.values;
+    let mut bins = [0.0f32; N_BINS];
+    for (i, bin) in bins.iter_mut().enumerate() {
+        let slice = &values[10 * i..=10 * i + 10];
+        *bin = slice.iter().sum::<f32>() - 0.5 * (slice[0] + slice[10]);
+    }
+    let total: f32 = bins.iter().sum();
+    if !total.is_finite() || total <= 0.0 {
+        return Err(SpectroError::Data(
+            "SSI needs a spectrum with power in 375-675nm".into(),
+        ));
+    }
+    Ok(bins.map(|b| b / total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(illuminant: Illuminant) -> SpectralData {
+        let wavelengths = (0..cie_data::LEN).map(|i| 380.0 + i as f32).collect();
+        let values = illuminant.spd_1nm().to_vec();
+        SpectralData::from_grid(wavelengths, values, Quantity::RelativePower).unwrap()
+    }
+
+    #[test]
+    fn test_smoothing_edges() {
+        // The first tap is 0.56·w0 + 0.22·w1, not shifted by the missing w-1
+        let smoothed = smooth(&[1.0, 2.0, 3.0]);
+        let expected = [0.22, 1.0, 2.0, 2.12, 0.66];
+        for (s, e) in smoothed.iter().zip(expected) {
+            assert!((s - e).abs() < 1e-6, "{:?}", smoothed);
+        }
+    }
+
+    #[test]
+    fn test_ssi() {
+        let tungsten = SsiReference::Tungsten.spd();
+        assert!((calculate_ssi(&tungsten, &SsiReference::Tungsten).unwrap() - 100.0).abs() < 1e-3);
+
+        // CIE C scores 94 against D65
+        let c = source(Illuminant::C);
+        let d65 = SsiReference::Spectrum(Box::new(source(Illuminant::D65)));
+        assert_eq!(calculate_ssi(&c, &d65).unwrap().round(), 94.0);
+
+        // Daylight is far from tungsten, fluorescent far from both
+        let d55 = calculate_ssi(&source(Illuminant::D55), &SsiReference::Tungsten).unwrap();
+        let f2 = calculate_ssi(&source(Illuminant::F2), &SsiReference::D55).unwrap();
+        assert!(d55 < 70.0 && f2 < 70.0, "{} {}", d55, f2);
+    }
+}
@@ -0,0 +1,379 @@
+//! Television Lighting Consistency Index (TLCI-2012) and Television
+//! Luminaire Matching Factor (TLMF-2013)
+//!
+//! EBU Tech 3355. Instead of the eye, a standard broadcast camera and
+//! display are simulated, and the 24 ColorChecker patches are compared as
+//! they would appear on screen under the test source and under a reference:
+//!
+//! 1. The camera's RGB sensitivities integrate each patch; the channel
+//!    gains white-balance the camera on the source, so a perfect white
+//!    reads (1, 1, 1), and the camera matrix is applied.
+//! 2. The signals are encoded with the BT.709 OETF and clipped to the
+//!    video range.
+//! 3. The display decodes them with a 2.4 gamma (BT.1886, zero black
+//!    level) and BT.709 primaries; the colors are expressed in CIELAB
+//!    relative to the display's D65 white.
+//! 4. Each patch's CIEDE2000 difference is taken between the two sources.
+//!    ΔEa is the fourth-power mean over the 18 chromatic patches, which
+//!    weights the worst errors, and Qa = 100 / (1 + (ΔEa / 3.16)^3.4).
+//!
+//! TLCI uses a reference of the same CCT: Planckian below 3400K, CIE
+//! daylight above 5000K, and a mix of the two (at equal luminance) in
+//! between. TLMF runs the same calculation with another luminaire, such as
+//! the key light, as the reference.
+//!
+//! The EBU camera sensitivities and the ColorChecker reflectances are not
+//! shipped with this crate; they are supplied as a [`TlciModel`], e.g. with
+//! [`persistence::load_tlci_model`](crate::persistence::load_tlci_model).
+
+use crate::colorimetry::{illuminant, Lab, XYZ};
+use crate::spectrum::{Quantity, SpectralData};
+use crate::{cie_data, Observer, Result, SpectroError};
+
+/// Number of ColorChecker patches.
+pub const N_PATCHES: usize = 24;
+
+/// Number of chromatic patches (the first 18); the rest are the gray scale.
+pub const N_CHROMATIC: usize = 18;
+
+/// Below this CCT the reference is Planckian.
+const PLANCKIAN_MAX_CCT: f32 = 3400.0;
+
+/// Above this CCT the reference is CIE daylight.
+const DAYLIGHT_MIN_CCT: f32 = 5000.0;
+
+/// Constants of the ΔEa to Qa mapping.
+const QA_SCALE: f32 = 3.16;
+const QA_EXPONENT: f32 = 3.4;
+
+/// BT.709 primaries to XYZ (D65 white).
+const BT709_TO_XYZ: [[f32; 3]; 3] = [
+    [0.4124, 0.3576, 0.1805],
+    [0.2126, 0.7152, 0.0722],
+    [0.0193, 0.1192, 0.9505],
+];
+
+/// A camera and test chart for the TLCI calculation, tabulated on one
+/// uniform wavelength grid.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TlciModel {
+    /// Wavelengths in nm.
+    pub wavelengths: Vec<f32>,
+    /// Camera red, green and blue sensitivities at each wavelength.
+    pub camera: Vec<[f32; 3]>,
+    /// Camera matrix applied to the white-balanced signals. Its rows sum to
+    /// 1, so white is preserved.
+    pub matrix: [[f32; 3]; 3],
+    /// Reflectances of the ColorChecker patches, in chart order (dark skin
+    /// to black), one value per wavelength.
+    pub patches: Vec<Vec<f32>>,
+}
+
+impl TlciModel {
+    /// Check that the model can be used: a uniform grid shared by the
+    /// camera and all 24 patches, finite values and a matrix that
+    /// preserves white.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpectroError::Data`] describing the first problem found.
+    pub fn validate(&self) -> Result<()> {
+        let n = self.wavelengths.len();
+        if n < 2 {
+            return Err(SpectroError::Data(
+                "TLCI model needs at least two wavelengths".into(),
+            ));
+        }
+        let step = self.wavelengths[1] - self.wavelengths[0];
+        if step.is_nan()
+            || step <= 0.0
+            || self
+                .wavelengths
+                .windows(2)
+                .any(|w| ((w[1] - w[0]) - step).abs() > step * 1e-3)
+        {
+            return Err(SpectroError::Data(
+                "TLCI model wavelengths must be uniformly spaced".into(),
+            ));
+        }
+        if self.camera.len() != n {
+            return Err(SpectroError::Data(format!(
+                "{} camera sensitivities for {} wavelengths",
+                self.camera.len(),
+                n
+            )));
+        }
+        if self.patches.len() != N_PATCHES {
+            return Err(SpectroError::Data(format!(
+                "TLCI model has {} patches, expected {}",
+                self.patches.len(),
+                N_PATCHES
+            )));
+        }
+        if let Some(i) = self.patches.iter().position(|p| p.len() != n) {
+            return Err(SpectroError::Data(format!(
+                "patch {} has {} values for {} wavelengths",
+                i + 1,
+                self.patches[i].len(),
+                n
+            )));
+        }
+        let finite = self.wavelengths.iter().all(|v| v.is_finite())
+            && self.camera.iter().flatten().all(|v| v.is_finite())
+            && self.matrix.iter().flatten().all(|v| v.is_finite())
+            && self.patches.iter().flatten().all(|v| v.is_finite());
+        if !finite {
+            return Err(SpectroError::Data(
+                "TLCI model contains non-finite values".into(),
+            ));
+        }
+        if self
+            .matrix
+            .iter()
+            .any(|row| (row.iter().sum::<f32>() - 1.0).abs() > 1e-3)
+        {
+            return Err(SpectroError::Data(
+                "TLCI camera matrix rows must sum to 1".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// The patches as they appear on the display when the camera is
+    /// balanced on `source`.
+    fn render(&self, source: &SpectralData) -> Result<[Lab; N_PATCHES]> {
+        let source = source.try_resample_to(self.wavelengths.clone())?.values;
+
+        let signal = |reflectance: Option<&[f32]>| {
+            let mut rgb = [0.0f32; 3];
+            for (i, (s, cam)) in source.iter().zip(&self.camera).enumerate() {
+                let r = reflectance.map_or(1.0, |r| r[i]);
+                for c in 0..3 {
+                    rgb[c] += s * r * cam[c];
+                }
+            }
+            rgb
+        };
+
+        let white = signal(None);
+        if white.iter().any(|w| !(w.is_finite() && *w > 0.0)) {
+            return Err(SpectroError::Data(
+                "TLCI needs a source the camera can white-balance on".into(),
+            ));
+        }
+        let display_white = display_xyz([1.0; 3]);
+
+        let mut labs = [Lab {
+            l: 0.0,
+            a: 0.0,
+            b: 0.0,
+        }; N_PATCHES];
+        for (lab, patch) in labs.iter_mut().zip(&self.patches) {
+            let raw = signal(Some(patch));
+            let balanced = [0, 1, 2].map(|c| raw[c] / white[c]);
+            let rgb = self
+                .matrix
+                .map(|row| (0..3).map(|c| row[c] * balanced[c]).sum::<f32>());
+            let video = rgb.map(|v| oetf(v).clamp(0.0, 1.0));
+            *lab = display_xyz(video).to_lab(display_white);
+        }
+        Ok(labs)
+    }
+}
+
+/// TLCI or TLMF of a source.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TlciMetrics {
+    /// Overall score Qa, 0-100.
+    pub qa: f32,
+    /// Fourth-power mean CIEDE2000 over the chromatic patches.
+    pub delta_e_a: f32,
+    /// CIEDE2000 of each patch, in chart order.
+    pub delta_e: [f32; N_PATCHES],
+}
+
+impl TlciMetrics {
+    /// The patch with the largest ΔE (0-based) and its ΔE.
+    pub fn worst_patch(&self) -> (usize, f32) {
+        self.delta_e
+            .iter()
+            .copied()
+            .enumerate()
+            .fold((0, f32::NEG_INFINITY), |a, b| if b.1 > a.1 { b } else { a })
+    }
+}
+
+/// Calculate the TLCI-2012 of a light source.
+///
+/// # Errors
+///
+/// Returns an error if the model fails [`TlciModel::validate`], or the CCT
+/// of the source cannot be determined.
+pub fn calculate_tlci(spd: &SpectralData, model: &TlciModel) -> Result<TlciMetrics> {
+    let reference = reference_spd(spd)?;
+    compare(spd, &reference, model)
+}
+
+/// Calculate the TLMF-2013 of a luminaire against a reference luminaire.
+///
+/// # Errors
+///
+/// Returns an error if the model fails [`TlciModel::validate`], or either
+/// spectrum cannot be resampled onto its grid.
+pub fn calculate_tlmf(
+    spd: &SpectralData,
+    reference: &SpectralData,
+    model: &TlciModel,
+) -> Result<TlciMetrics> {
+    compare(spd, reference, model)
+}
+
+fn compare(
+    test: &SpectralData,
+    reference: &SpectralData,
+    model: &TlciModel,
+) -> Result<TlciMetrics> {
+    model.validate()?;
+    let test = model.render(test)?;
+    let reference = model.render(reference)?;
+
+    let delta_e: [f32; N_PATCHES] = std::array::from_fn(|i| test[i].delta_e_2000(&reference[i]));
+    let delta_e_a = (delta_e[..N_CHROMATIC]
+        .iter()
+        .map(|e| e.powi(4))
+        .sum::<f32>()
+        / N_CHROMATIC as f32)
+        .powf(0.25);
+    Ok(TlciMetrics {
+        qa: 100.0 / (1.0 + (delta_e_a / QA_SCALE).powf(QA_EXPONENT)),
+        delta_e_a,
+        delta_e,
+    })
+}
+
+/// Reference illuminant of the same CCT as the source.
+fn reference_spd(spd: &SpectralData) -> Result<SpectralData> {
+    let cct = spd
+        .try_to_xyz_emissive_ext(Observer::CIE1931_2)?
+        .to_cct_duv()
+        .ok_or_else(|| SpectroError::Data("CCT of the source cannot be determined".into()))?
+        .cct;
+
+    let planckian = || normalized(illuminant::planckian_1nm(cct));
+    let daylight = || normalized(illuminant::daylight_1nm(cct));
+    let values: Vec<f32> = if cct <= PLANCKIAN_MAX_CCT {
+        planckian().to_vec()
+    } else if cct >= DAYLIGHT_MIN_CCT {
+        daylight().to_vec()
+    } else {
+        let t = (cct - PLANCKIAN_MAX_CCT) / (DAYLIGHT_MIN_CCT - PLANCKIAN_MAX_CCT);
+        planckian()
+            .iter()
+            .zip(daylight())
+            .map(|(p, d)| (1.0 - t) * p + t * d)
+            .collect()
+    };
+    let wavelengths = (0..cie_data::LEN)
+        .map(|i| cie_data::START + i as f32)
+        .collect();
+    SpectralData::from_grid(wavelengths, values, Quantity::RelativePower)
+}
+
+/// A 1nm spectrum scaled to unit luminance.
+fn normalized(mut spd: [f32; cie_data::LEN]) -> [f32; cie_data::LEN] {
+    let cmfs = Observer::CIE1931_2.cmfs_1nm();
+    let y: f32 = spd.iter().zip(cmfs).map(|(s, c)| s * c[1]).sum();
+    spd.iter_mut().for_each(|s| *s /= y);
+    spd
+}
+
+/// BT.709 opto-electronic transfer function, odd-symmetric below zero.
+fn oetf(l: f32) -> f32 {
+    let v = l.abs();
+    let v = if v < 0.018 {
+        4.5 * v
+    } else {
+        1.099 * v.powf(0.45) - 0.099
+    };
+    v.copysign(l)
+}
+
+/// XYZ (white Y = 100) shown by a BT.1886 display for a video signal.
+fn display_xyz(video: [f32; 3]) -> XYZ {
+    let rgb = video.map(|v| 100.0 * v.powf(2.4));
+    let [x, y, z] = BT709_TO_XYZ.map(|row| (0..3).map(|c| row[c] * rgb[c]).sum::<f32>());
+    XYZ { x, y, z }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colorimetry::upsample_5nm;
+    use crate::Illuminant;
+
+    /// A colorimetric camera (the CIE 1931 CMFs mapped to BT.709) and a
+    /// stand-in chart of TCS01-14, TCS01-04 and six flat grays.
+    fn model() -> TlciModel {
+        const XYZ_TO_BT709: [[f32; 3]; 3] = [
+            [3.2406, -1.5372, -0.4986],
+            [-0.9689, 1.8758, 0.0415],
+            [0.0557, -0.2040, 1.0570],
+        ];
+        let camera = Observer::CIE1931_2
+            .cmfs_1nm()
+            .iter()
+            .map(|c| XYZ_TO_BT709.map(|row| (0..3).map(|i| row[i] * c[i]).sum::<f32>()))
+            .collect();
+        let tcs = cie_data::TCS.map(|t| upsample_5nm(&t).to_vec());
+        let grays = [0.9, 0.59, 0.36, 0.2, 0.09, 0.03].map(|g| vec![g; cie_data::LEN]);
+        let patches = tcs.iter().chain(&tcs[..4]).cloned().chain(grays).collect();
+        TlciModel {
+            wavelengths: (0..cie_data::LEN)
+                .map(|i| cie_data::START + i as f32)
+                .collect(),
+            camera,
+            matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            patches,
+        }
+    }
+
+    fn source(illuminant: Illuminant) -> SpectralData {
+        let wavelengths = (0..cie_data::LEN).map(|i| 380.0 + i as f32).collect();
+        let values = illuminant.spd_1nm().to_vec();
+        SpectralData::from_grid(wavelengths, values, Quantity::RelativePower).unwrap()
+    }
+
+    #[test]
+    fn test_tlci() {
+        let model = model();
+        // The CIE references score (nearly) 100 against themselves
+        for illuminant in [Illuminant::A, Illuminant::D65] {
+            let m = calculate_tlci(&source(illuminant), &model).unwrap();
+            assert!(m.qa > 99.0, "{:?}: {:?}", illuminant, m);
+        }
+
+        // Halophosphate fluorescent is poor, the broadband F7 much better
+        let f2 = calculate_tlci(&source(Illuminant::F2), &model).unwrap();
+        let f7 = calculate_tlci(&source(Illuminant::F7), &model).unwrap();
+        assert!(f2.qa < 80.0 && f7.qa > f2.qa, "{} {}", f2.qa, f7.qa);
+        assert!(f2.worst_patch().1 >= f2.delta_e_a);
+        // The grays stay neutral after white balance
+        assert!(f2.delta_e[N_CHROMATIC..].iter().all(|e| *e < 0.5));
+    }
+
+    #[test]
+    fn test_tlmf() {
+        let model = model();
+        let f2 = source(Illuminant::F2);
+        let m = calculate_tlmf(&f2, &f2, &model).unwrap();
+        assert!((m.qa - 100.0).abs() < 1e-3 && m.delta_e_a < 1e-3);
+
+        // Luminaires of different spectra do not match
+        let m = calculate_tlmf(&source(Illuminant::F11), &f2, &model).unwrap();
+        assert!(m.qa < 100.0 && m.delta_e_a > 0.5, "{:?}", m);
+
+        let mut bad = model;
+        bad.patches.pop();
+        assert!(calculate_tlmf(&f2, &f2, &bad).is_err());
+    }
+}
@@ -1,15 +1,37 @@
-//! TM-30-18 Color Quality Evaluation Metrics
+//! TM-30-20 Color Quality Evaluation Metrics
 //!
-//! Implements the IES TM-30-18 standard for evaluating light source color quality
-//! using CES99 test samples and color appearance modeling.
-
-#![allow(clippy::needless_range_loop)]
+//! Implements ANSI/IES TM-30-20. The fidelity calculation is CIE 224:2017
+//! (see [`crate::cie224`]), with which TM-30-20 is harmonized. The 99 CES are
+//! then grouped into 16 hue-angle bins by their hue under the reference
+//! illuminant, giving:
+//!
+//! - **Rg**, the gamut index: the area of the test polygon of bin-averaged
+//!   a'b' relative to the reference polygon.
+//! - **Rf,hj**, the local fidelity of each bin.
+//! - **Rcs,hj** and **Rhs,hj**, the local chroma and hue shifts: the average
+//!   test-reference difference projected on the radial and tangential
+//!   direction of the bin's central hue, relative to the reference chroma.
+//! - **Rf,skin**, the fidelity for the skin samples CES 15 and CES 18.
+//!
+//! [`TM30Metrics::priority_level`] classifies a source against the design
+//! intents of Annex E.
 
-use crate::cam02::{Cam02State, Surround, ViewingConditions};
-use crate::colorimetry::{calculate_cct, XYZ};
+use crate::cam02::Cam02Ucs;
+use crate::cie224::{self, rf_from_delta_e, N_CES};
+use crate::colorimetry::XYZ;
 use crate::spectrum::SpectralData;
 use crate::tm30_data::CES99_SPDS;
 use crate::tm30_data_cmf::{X_BAR_10_5NM, Y_BAR_10_5NM, Z_BAR_10_5NM};
+use crate::Result;
+
+/// Number of hue-angle bins.
+pub const N_BINS: usize = 16;
+
+/// Width of a hue-angle bin in degrees.
+const BIN_WIDTH: f32 = 360.0 / N_BINS as f32;
+
+/// Skin-tone samples CES 15 and CES 18 (zero-based).
+pub const SKIN_CES: [usize; 2] = [14, 17];
 
 #[derive(Debug, Clone)]
 pub struct TM30Metrics {
@@ -17,160 +39,187 @@ pub struct TM30Metrics {
     pub rg: f32,
     pub cct: f32,
     pub duv: f32,
-    pub bin_rf: [f32; 16],
-    pub bin_chroma_shift: [f32; 16],
-    pub bin_hue_shift: [f32; 16],
-    pub bin_test_a: [f32; 16],
-    pub bin_test_b: [f32; 16],
-    pub bin_ref_a: [f32; 16],
-    pub bin_ref_b: [f32; 16],
+    /// Special color fidelity indices Rf,i for CES 1-99.
+    pub rf_i: Vec<f32>,
+    /// Skin fidelity Rf,skin.
+    pub rf_skin: f32,
+    /// Local color fidelity Rf,hj.
+    pub bin_rf: [f32; N_BINS],
+    /// Local chroma shift Rcs,hj, as a fraction (0.1 = 10% more saturated).
+    pub bin_chroma_shift: [f32; N_BINS],
+    /// Local hue shift Rhs,hj, relative to the reference chroma (positive
+    /// is counterclockwise).
+    pub bin_hue_shift: [f32; N_BINS],
+    pub bin_test_a: [f32; N_BINS],
+    pub bin_test_b: [f32; N_BINS],
+    pub bin_ref_a: [f32; N_BINS],
+    pub bin_ref_b: [f32; N_BINS],
     pub ces_rgb: Vec<[u8; 3]>,
 }
 
-/// Calculate IES TM-30-18 metrics (Rf and Rg).
-pub fn calculate_tm30(test_spd: &SpectralData) -> TM30Metrics {
-    // 1. Resample test SPD to 5nm (360-830nm)
-    let test_5nm = test_spd.resample(360.0, 830.0, 5.0);
-    let test_vals = &test_5nm.values;
-
-    // 2. Calculate CCT and Duv
-    let (cct, duv) = calculate_cct(test_spd);
-
-    // 3. Generate reference SPD (5nm, 360-830nm)
-    let ref_vals = generate_reference_spd_5nm(cct);
-
-    // 4. Calculate XYZ for test and reference white points (10 degree)
-    let test_white_raw = calculate_xyz_10_5nm(test_vals, test_vals);
-    let ref_white_raw = calculate_xyz_10_5nm(&ref_vals, &ref_vals);
-
-    // Normalize white points to Y=100
-    let test_white = XYZ {
-        x: test_white_raw.x * 100.0 / test_white_raw.y,
-        y: 100.0,
-        z: test_white_raw.z * 100.0 / test_white_raw.y,
-    };
-    let ref_white = XYZ {
-        x: ref_white_raw.x * 100.0 / ref_white_raw.y,
-        y: 100.0,
-        z: ref_white_raw.z * 100.0 / ref_white_raw.y,
-    };
-
-    // 5. Calculate XYZ for 99 CES samples under both sources
-    let mut test_ucs = Vec::with_capacity(99);
-    let mut ref_ucs = Vec::with_capacity(99);
-    let mut ces_rgb = Vec::with_capacity(99);
-
-    let vc_test = ViewingConditions::new(test_white, 100.0, 20.0, Surround::AVERAGE);
-    let cam_test = Cam02State::new(&vc_test);
-
-    let vc_ref = ViewingConditions::new(ref_white, 100.0, 20.0, Surround::AVERAGE);
-    let cam_ref = Cam02State::new(&vc_ref);
-
-    for ces_spd in &CES99_SPDS {
-        // Test source XYZ
-        let mut test_sample_vals = [0.0f32; 95];
-        for j in 0..95 {
-            test_sample_vals[j] = test_vals[j] * ces_spd[j];
+/// Design intents of TM-30-20 Annex E.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DesignIntent {
+    Preference,
+    Fidelity,
+    Vividness,
+}
+
+impl DesignIntent {
+    pub const ALL: [DesignIntent; 3] = [
+        DesignIntent::Preference,
+        DesignIntent::Fidelity,
+        DesignIntent::Vividness,
+    ];
+
+    /// Criteria for priority levels 1, 2 and 3, from TM-30-20 Table E.1.
+    fn criteria(&self) -> [Criteria; 3] {
+        let any = f32::NEG_INFINITY..=f32::INFINITY;
+        match self {
+            DesignIntent::Preference => [
+                Criteria::new(78.0, f32::NEG_INFINITY, 95.0, -0.01..=0.15),
+                Criteria::new(75.0, f32::NEG_INFINITY, 92.0, -0.07..=0.19),
+                Criteria::new(70.0, f32::NEG_INFINITY, 89.0, -0.12..=0.23),
+            ],
+            DesignIntent::Fidelity => [
+                Criteria::new(95.0, 91.0, f32::NEG_INFINITY, any.clone()),
+                Criteria::new(90.0, 85.0, f32::NEG_INFINITY, any.clone()),
+                Criteria::new(85.0, 79.0, f32::NEG_INFINITY, any),
+            ],
+            DesignIntent::Vividness => [
+                Criteria::new(64.0, f32::NEG_INFINITY, 117.0, 0.19..=f32::INFINITY),
+                Criteria::new(64.0, f32::NEG_INFINITY, 110.0, 0.15..=f32::INFINITY),
+                Criteria::new(60.0, f32::NEG_INFINITY, 100.0, 0.05..=f32::INFINITY),
+            ],
         }
-        let test_xyz_raw = calculate_xyz_10_5nm(&test_sample_vals, test_vals);
-        let test_xyz = XYZ {
-            x: test_xyz_raw.x * 100.0 / test_white_raw.y,
-            y: test_xyz_raw.y * 100.0 / test_white_raw.y,
-            z: test_xyz_raw.z * 100.0 / test_white_raw.y,
-        };
-
-        // Convert to sRGB for preview (normalize to white point)
-        let (r, g, b) = test_xyz.to_srgb_safe(test_white);
-        ces_rgb.push([r, g, b]);
-
-        // Reference source XYZ
-        let mut ref_sample_vals = [0.0f32; 95];
-        for j in 0..95 {
-            ref_sample_vals[j] = ref_vals[j] * ces_spd[j];
+    }
+}
+
+/// Minimum Rf, Rf,h1 and Rg, and the allowed Rcs,h1, for one priority level.
+struct Criteria {
+    rf: f32,
+    rf_h1: f32,
+    rg: f32,
+    rcs_h1: std::ops::RangeInclusive<f32>,
+}
+
+impl Criteria {
+    fn new(rf: f32, rf_h1: f32, rg: f32, rcs_h1: std::ops::RangeInclusive<f32>) -> Self {
+        Self {
+            rf,
+            rf_h1,
+            rg,
+            rcs_h1,
         }
-        let ref_xyz_raw = calculate_xyz_10_5nm(&ref_sample_vals, &ref_vals);
-        let ref_xyz = XYZ {
-            x: ref_xyz_raw.x * 100.0 / ref_white_raw.y,
-            y: ref_xyz_raw.y * 100.0 / ref_white_raw.y,
-            z: ref_xyz_raw.z * 100.0 / ref_white_raw.y,
-        };
-
-        test_ucs.push(cam_test.xyz_to_ucs(test_xyz));
-        ref_ucs.push(cam_ref.xyz_to_ucs(ref_xyz));
     }
+}
 
-    // 6. Calculate Rf
-    let mut sum_de = 0.0;
-    for i in 0..99 {
-        sum_de += test_ucs[i].distance(&ref_ucs[i]);
+impl TM30Metrics {
+    /// Metrics of a spectrum the calculation cannot use: NaN indices and no
+    /// per-sample values.
+    fn invalid() -> Self {
+        Self {
+            rf: f32::NAN,
+            rg: f32::NAN,
+            cct: f32::NAN,
+            duv: f32::NAN,
+            rf_i: Vec::new(),
+            rf_skin: f32::NAN,
+            bin_rf: [f32::NAN; N_BINS],
+            bin_chroma_shift: [f32::NAN; N_BINS],
+            bin_hue_shift: [f32::NAN; N_BINS],
+            bin_test_a: [f32::NAN; N_BINS],
+            bin_test_b: [f32::NAN; N_BINS],
+            bin_ref_a: [f32::NAN; N_BINS],
+            bin_ref_b: [f32::NAN; N_BINS],
+            ces_rgb: Vec::new(),
+        }
     }
-    let avg_de = sum_de / 99.0;
-    let rf = 10.0f32 * (((100.0f32 - 7.54f32 * avg_de) / 10.0f32).exp() + 1.0f32).ln();
-
-    // 7. Calculate Rg
-    let mut bin_test_a = [0.0f32; 16];
-    let mut bin_test_b = [0.0f32; 16];
-    let mut bin_ref_a = [0.0f32; 16];
-    let mut bin_ref_b = [0.0f32; 16];
-    let mut bin_count = [0usize; 16];
-    let mut bin_rf = [0.0f32; 16];
-    let mut bin_de_sum = [0.0f32; 16];
-
-    for i in 0..99 {
-        let h = ref_ucs[i].h();
-        let bin = ((h / 22.5).floor() as usize) % 16;
-
-        bin_test_a[bin] += test_ucs[i].a_prime;
-        bin_test_b[bin] += test_ucs[i].b_prime;
-        bin_ref_a[bin] += ref_ucs[i].a_prime;
-        bin_ref_b[bin] += ref_ucs[i].b_prime;
-        bin_count[bin] += 1;
 
-        let de = test_ucs[i].distance(&ref_ucs[i]);
-        bin_de_sum[bin] += de;
+    /// The most stringent Annex E priority level (1-3) the source meets for
+    /// `intent`, or `None` if it meets none. Rf, Rg and Rcs,h1 are compared
+    /// at the precision they are reported with.
+    pub fn priority_level(&self, intent: DesignIntent) -> Option<u8> {
+        let rf = self.rf.round();
+        let rf_h1 = self.bin_rf[0].round();
+        let rg = self.rg.round();
+        let rcs_h1 = (self.bin_chroma_shift[0] * 100.0).round() / 100.0;
+        intent
+            .criteria()
+            .iter()
+            .position(|c| {
+                rf >= c.rf && rf_h1 >= c.rf_h1 && rg >= c.rg && c.rcs_h1.contains(&rcs_h1)
+            })
+            .map(|i| i as u8 + 1)
     }
+}
 
-    for i in 0..16 {
-        if bin_count[i] > 0 {
-            bin_test_a[i] /= bin_count[i] as f32;
-            bin_test_b[i] /= bin_count[i] as f32;
-            bin_ref_a[i] /= bin_count[i] as f32;
-            bin_ref_b[i] /= bin_count[i] as f32;
+/// Calculate ANSI/IES TM-30-20 metrics.
+///
+/// Returns NaN metrics if the spectrum is not a usable light source; use
+/// [`try_calculate_tm30`] to get the error instead.
+pub fn calculate_tm30(test_spd: &SpectralData) -> TM30Metrics {
+    try_calculate_tm30(test_spd).unwrap_or_else(|_| TM30Metrics::invalid())
+}
 
-            let avg_de_bin = bin_de_sum[i] / bin_count[i] as f32;
-            bin_rf[i] =
-                10.0f32 * (((100.0f32 - 7.54f32 * avg_de_bin) / 10.0f32).exp() + 1.0f32).ln();
-        }
+/// Calculate ANSI/IES TM-30-20 metrics, reporting unusable spectra as errors.
+///
+/// # Errors
+///
+/// Returns an error if the spectrum does not cover 380-780nm or its CCT
+/// cannot be determined.
+pub fn try_calculate_tm30(test_spd: &SpectralData) -> Result<TM30Metrics> {
+    let fidelity = cie224::calculate_rf(test_spd)?;
+    let (test_ucs, ref_ucs) = (&fidelity.test_ucs, &fidelity.ref_ucs);
+
+    // Bin by hue under the reference illuminant
+    let mut bin_test_a = [0.0f32; N_BINS];
+    let mut bin_test_b = [0.0f32; N_BINS];
+    let mut bin_ref_a = [0.0f32; N_BINS];
+    let mut bin_ref_b = [0.0f32; N_BINS];
+    let mut bin_count = [0usize; N_BINS];
+    let mut bin_de_sum = [0.0f32; N_BINS];
+    for (t, r) in test_ucs.iter().zip(ref_ucs) {
+        let bin = hue_bin(r);
+        bin_test_a[bin] += t.a_prime;
+        bin_test_b[bin] += t.b_prime;
+        bin_ref_a[bin] += r.a_prime;
+        bin_ref_b[bin] += r.b_prime;
+        bin_de_sum[bin] += t.distance(r);
+        bin_count[bin] += 1;
     }
 
-    let area_test = calculate_polygon_area(&bin_test_a, &bin_test_b);
-    let area_ref = calculate_polygon_area(&bin_ref_a, &bin_ref_b);
-    let rg = 100.0 * (area_test / area_ref);
-
-    let mut bin_chroma_shift = [0.0f32; 16];
-    let mut bin_hue_shift = [0.0f32; 16];
-    for i in 0..16 {
-        let c_test = (bin_test_a[i].powi(2) + bin_test_b[i].powi(2)).sqrt();
-        let c_ref = (bin_ref_a[i].powi(2) + bin_ref_b[i].powi(2)).sqrt();
-        bin_chroma_shift[i] = (c_test - c_ref) / c_ref;
-
-        let h_test = bin_test_b[i].atan2(bin_test_a[i]);
-        let h_ref = bin_ref_b[i].atan2(bin_ref_a[i]);
-        let mut dh = h_test - h_ref;
-        while dh > std::f32::consts::PI {
-            dh -= 2.0 * std::f32::consts::PI;
-        }
-        while dh < -std::f32::consts::PI {
-            dh += 2.0 * std::f32::consts::PI;
-        }
-        bin_hue_shift[i] = dh;
+    let mut bin_rf = [0.0f32; N_BINS];
+    let mut bin_chroma_shift = [0.0f32; N_BINS];
+    let mut bin_hue_shift = [0.0f32; N_BINS];
+    for j in 0..N_BINS {
+        let n = bin_count[j] as f32;
+        bin_test_a[j] /= n;
+        bin_test_b[j] /= n;
+        bin_ref_a[j] /= n;
+        bin_ref_b[j] /= n;
+        bin_rf[j] = rf_from_delta_e(bin_de_sum[j] / n);
+
+        // Shifts are projected on the hue at the center of the bin
+        let c_ref = bin_ref_a[j].hypot(bin_ref_b[j]);
+        let (sin, cos) = ((j as f32 + 0.5) * BIN_WIDTH).to_radians().sin_cos();
+        let (da, db) = (bin_test_a[j] - bin_ref_a[j], bin_test_b[j] - bin_ref_b[j]);
+        bin_chroma_shift[j] = (da * cos + db * sin) / c_ref;
+        bin_hue_shift[j] = (db * cos - da * sin) / c_ref;
     }
 
-    TM30Metrics {
-        rf,
-        rg,
-        cct,
-        duv,
+    let area_test = polygon_area(&bin_test_a, &bin_test_b);
+    let area_ref = polygon_area(&bin_ref_a, &bin_ref_b);
+
+    let rf_skin = SKIN_CES.iter().map(|&i| fidelity.rf_i[i]).sum::<f32>() / SKIN_CES.len() as f32;
+
+    Ok(TM30Metrics {
+        rf: fidelity.rf,
+        rg: 100.0 * area_test / area_ref,
+        cct: fidelity.cct,
+        duv: fidelity.duv,
+        rf_skin,
+        rf_i: fidelity.rf_i,
         bin_rf,
         bin_chroma_shift,
         bin_hue_shift,
@@ -178,21 +227,45 @@ pub fn calculate_tm30(test_spd: &SpectralData) -> TM30Metrics {
         bin_test_b,
         bin_ref_a,
         bin_ref_b,
-        ces_rgb,
+        ces_rgb: ces_preview(test_spd),
+    })
+}
+
+/// Hue bin of a sample, 0 for 0-22.5°.
+fn hue_bin(ucs: &Cam02Ucs) -> usize {
+    ((ucs.h() / BIN_WIDTH).floor() as usize).min(N_BINS - 1)
+}
+
+/// sRGB preview of each CES under the test source.
+fn ces_preview(test_spd: &SpectralData) -> Vec<[u8; 3]> {
+    let test_vals = test_spd.resample(360.0, 830.0, 5.0).values;
+    if test_vals.len() != X_BAR_10_5NM.len() {
+        return vec![[0; 3]; N_CES];
     }
+    let white = calculate_xyz_10_5nm(&test_vals, &test_vals);
+    CES99_SPDS
+        .iter()
+        .map(|ces| {
+            let sample: Vec<f32> = test_vals.iter().zip(ces).map(|(s, r)| s * r).collect();
+            let (r, g, b) = calculate_xyz_10_5nm(&sample, &test_vals).to_srgb_safe(white);
+            [r, g, b]
+        })
+        .collect()
 }
 
+/// XYZ with the 10° observer at 5nm (360-830nm), scaled so the source has
+/// Y = 100.
 fn calculate_xyz_10_5nm(sample_vals: &[f32], source_vals: &[f32]) -> XYZ {
     let mut x = 0.0;
     let mut y = 0.0;
     let mut z = 0.0;
     let mut sum_y_source = 0.0;
 
-    for i in 0..95 {
-        x += sample_vals[i] * X_BAR_10_5NM[i];
-        y += sample_vals[i] * Y_BAR_10_5NM[i];
-        z += sample_vals[i] * Z_BAR_10_5NM[i];
-        sum_y_source += source_vals[i] * Y_BAR_10_5NM[i];
+    for (i, (s, src)) in sample_vals.iter().zip(source_vals).enumerate() {
+        x += s * X_BAR_10_5NM[i];
+        y += s * Y_BAR_10_5NM[i];
+        z += s * Z_BAR_10_5NM[i];
+        sum_y_source += src * Y_BAR_10_5NM[i];
     }
 
     let scale = 100.0 / sum_y_source;
@@ -203,100 +276,10 @@ fn calculate_xyz_10_5nm(sample_vals: &[f32], source_vals: &[f32]) -> XYZ {
     }
 }
 
-fn generate_reference_spd_5nm(cct: f32) -> [f32; 95] {
-    if cct < 4000.0 {
-        generate_planckian_5nm(cct)
-    } else if cct > 5000.0 {
-        generate_daylight_5nm(cct)
-    } else {
-        let p = (5000.0 - cct) / (5000.0 - 4000.0);
-        let planck = generate_planckian_5nm(cct);
-        let daylight = generate_daylight_5nm(cct);
-        let mut blended = [0.0f32; 95];
-        for i in 0..95 {
-            blended[i] = p * planck[i] + (1.0 - p) * daylight[i];
-        }
-        blended
-    }
-}
-
-fn generate_planckian_5nm(temp: f32) -> [f32; 95] {
-    let mut spd = [0.0f32; 95];
-    let c1 = 3.741771e-16;
-    let c2 = 1.4388e-2;
-
-    for i in 0..95 {
-        let wl = (360 + i * 5) as f32 * 1e-9;
-        spd[i] = c1 * wl.powi(-5) / ((c2 / (wl * temp)).exp() - 1.0);
-    }
-    spd
-}
-
-fn generate_daylight_5nm(temp: f32) -> [f32; 95] {
-    let x_d = if temp <= 7000.0 {
-        -4.6070e9 / temp.powi(3) + 2.9678e6 / temp.powi(2) + 0.09911e3 / temp + 0.244063
-    } else {
-        -2.0064e9 / temp.powi(3) + 1.9018e6 / temp.powi(2) + 0.24748e3 / temp + 0.237040
-    };
-
-    let y_d = -3.000 * x_d * x_d + 2.870 * x_d - 0.275;
-
-    let m1 = (-1.3515 - 1.7703 * x_d + 5.9114 * y_d) / (0.0241 + 0.2562 * x_d - 0.7341 * y_d);
-    let m2 = (0.0300 - 31.4424 * x_d + 30.0717 * y_d) / (0.0241 + 0.2562 * x_d - 0.7341 * y_d);
-
-    // CIE S0, S1, S2 at 10nm (380-780)
-    const S0: [f32; 41] = [
-        0.0, 0.0, 33.4, 37.4, 117.4, 117.8, 114.9, 115.9, 108.8, 109.3, 107.8, 104.8, 107.7, 104.4,
-        104.0, 100.0, 96.0, 95.1, 89.1, 90.5, 90.3, 88.4, 84.0, 85.1, 81.9, 82.6, 84.9, 81.3, 71.9,
-        74.3, 76.4, 63.3, 71.7, 77.0, 65.2, 47.7, 68.6, 65.0, 66.0, 61.0, 53.3,
-    ];
-    const S1: [f32; 41] = [
-        0.0, 0.0, -1.1, -0.5, -0.7, -1.2, -2.6, -2.9, -2.8, -4.5, -6.1, -7.6, -9.7, -11.7, -12.2,
-        -13.6, -12.0, -13.3, -12.9, -10.6, -11.6, -10.8, -8.1, -10.3, -11.0, -11.5, -10.8, -10.9,
-        -8.8, -7.3, -12.9, -15.8, -15.1, -12.2, -10.2, -8.6, -12.0, -14.6, -15.1, -14.9, -13.7,
-    ];
-    const S2: [f32; 41] = [
-        0.0, 0.0, -2.1, -1.9, -1.1, -2.2, -3.5, -3.5, -3.3, -2.0, -1.2, -1.1, -0.5, 0.2, 0.5, 2.1,
-        3.2, 4.1, 4.7, 5.1, 6.7, 7.3, 8.6, 9.8, 10.2, 14.9, 18.1, 15.9, 16.8, 24.2, 31.7, 15.3,
-        18.9, 21.2, 15.6, 8.3, 18.9, 14.6, 15.5, 15.4, 14.6,
-    ];
-
-    let mut spd = [0.0f32; 95];
-    for i in 0..95 {
-        let wl = (360 + i * 5) as f32;
-        // Interpolate S0, S1, S2 from 10nm table
-        let t = (wl - 380.0) / 10.0;
-        let idx = t.floor() as i32;
-        let x = t - idx as f32;
-
-        let get_val = |table: &[f32; 41], idx: i32, x: f32| {
-            if idx < 0 {
-                table[0]
-            } else if idx >= 40 {
-                table[40]
-            } else {
-                let v0 = table[idx as usize];
-                let v1 = table[(idx + 1) as usize];
-                v0 + x * (v1 - v0)
-            }
-        };
-
-        let s0 = get_val(&S0, idx, x);
-        let s1 = get_val(&S1, idx, x);
-        let s2 = get_val(&S2, idx, x);
-
-        spd[i] = s0 + m1 * s1 + m2 * s2;
-        if spd[i] < 0.0 {
-            spd[i] = 0.0;
-        }
-    }
-    spd
-}
-
-fn calculate_polygon_area(a: &[f32; 16], b: &[f32; 16]) -> f32 {
+fn polygon_area(a: &[f32; N_BINS], b: &[f32; N_BINS]) -> f32 {
     let mut area = 0.0;
-    for i in 0..16 {
-        let j = (i + 1) % 16;
+    for i in 0..N_BINS {
+        let j = (i + 1) % N_BINS;
         area += a[i] * b[j] - a[j] * b[i];
     }
     0.5 * area.abs()
@@ -305,35 +288,78 @@ fn calculate_polygon_area(a: &[f32; 16], b: &[f32; 16]) -> f32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::spectrum::MeasurementMode;
+    use crate::spectrum::Quantity;
+    use crate::{cie_data, Illuminant};
+
+    fn source(illuminant: Illuminant) -> SpectralData {
+        let wavelengths = (0..cie_data::LEN).map(|i| 380.0 + i as f32).collect();
+        let values = illuminant.spd_1nm().to_vec();
+        SpectralData::from_grid(wavelengths, values, Quantity::RelativePower).unwrap()
+    }
 
     #[test]
     fn test_tm30_d65() {
-        // Create a D65-like spectrum
-        let mut values = Vec::new();
-        let mut wavelengths = Vec::new();
-        for i in 0..41 {
-            let wl = 380.0 + i as f32 * 10.0;
-            wavelengths.push(wl);
-            // Simplified D65-like values or just use a known CCT
-            values.push(1.0); // Flat spectrum for now
-        }
-
-        let spd = SpectralData {
-            wavelengths,
-            values,
-            mode: MeasurementMode::Emissive,
-        };
+        let metrics = try_calculate_tm30(&source(Illuminant::D65)).unwrap();
+        assert!(metrics.rf > 99.5 && (metrics.rg - 100.0).abs() < 0.5);
+        assert!((metrics.duv - 0.0032).abs() < 2e-4);
+        assert!(metrics.bin_chroma_shift.iter().all(|c| c.abs() < 0.01));
+        assert_eq!(metrics.priority_level(DesignIntent::Fidelity), Some(1));
+        assert_eq!(metrics.priority_level(DesignIntent::Vividness), None);
+
+        // The infallible form reports unusable data as NaN
+        let dark = SpectralData::with_quantity(vec![0.0; 41], Quantity::RelativePower);
+        assert!(try_calculate_tm30(&dark).is_err());
+        let invalid = calculate_tm30(&dark);
+        assert!(invalid.rf.is_nan() && invalid.rg.is_nan());
+        assert_eq!(invalid.priority_level(DesignIntent::Fidelity), None);
+    }
 
-        let metrics = calculate_tm30(&spd);
-        println!(
-            "Rf: {}, Rg: {}, CCT: {}",
-            metrics.rf, metrics.rg, metrics.cct
+    #[test]
+    fn test_tm30_f12_against_ies_calculator() {
+        // Reference output of the IES TM-30 Spectral Calculator for CIE F12
+        const RF_HJ: [f32; N_BINS] = [
+            78.222, 86.843, 80.612, 68.521, 72.478, 79.408, 72.551, 79.812, 82.356, 77.682, 74.540,
+            80.448, 82.391, 76.766, 79.243, 76.808,
+        ];
+        const RCS_HJ: [f32; N_BINS] = [
+            -0.08510, -0.03160, -0.01216, 0.09247, 0.18373, 0.13567, 0.10277, -0.03677, -0.04746,
+            -0.12181, -0.12371, 0.01845, 0.08819, 0.04632, 0.04186, -0.03594,
+        ];
+        const RHS_HJ: [f32; N_BINS] = [
+            -0.04646, 0.03206, 0.09473, 0.17904, 0.12972, 0.00023, -0.14516, -0.12447, -0.09830,
+            0.03149, 0.14218, 0.08155, -0.02598, -0.06301, -0.09517, -0.10676,
+        ];
+
+        let m = try_calculate_tm30(&source(Illuminant::F12)).unwrap();
+        assert!(
+            (m.rf - 77.7).abs() < 0.5 && (m.rg - 102.4).abs() < 0.5,
+            "{:?}",
+            m
         );
-
-        // For a flat spectrum, Rf should be high but not necessarily 100
-        // because the reference will be Planckian/Daylight which is not flat.
-        assert!(metrics.rf > 0.0);
-        assert!(metrics.rg > 0.0);
+        // CES 92 lies within 0.05° of the h15/h16 boundary, where the bin it
+        // falls in depends on rounding
+        for j in 0..N_BINS - 2 {
+            assert!(
+                (m.bin_rf[j] - RF_HJ[j]).abs() < 1.0,
+                "Rf,h{}: {}",
+                j + 1,
+                m.bin_rf[j]
+            );
+            assert!(
+                (m.bin_chroma_shift[j] - RCS_HJ[j]).abs() < 0.01,
+                "Rcs,h{}: {}",
+                j + 1,
+                m.bin_chroma_shift[j]
+            );
+            assert!(
+                (m.bin_hue_shift[j] - RHS_HJ[j]).abs() < 0.01,
+                "Rhs,h{}: {}",
+                j + 1,
+                m.bin_hue_shift[j]
+            );
+        }
+        let skin = (m.rf_i[SKIN_CES[0]] + m.rf_i[SKIN_CES[1]]) / 2.0;
+        assert!((m.rf_skin - skin).abs() < 1e-4 && m.rf_skin < 100.0);
+        assert_eq!(m.priority_level(DesignIntent::Fidelity), None);
     }
 }
@@ -0,0 +1,574 @@
+//! TM-30 Graphics and Report
+//!
+//! Headless SVG renderings of the ANSI/IES TM-30-20 graphics:
+//!
+//! - The **Color Vector Graphic** (CVG): the reference polygon normalized to
+//!   the unit circle, with the test polygon and the shift of each hue bin.
+//! - Bar charts of the local chroma shift Rcs,hj, the local hue shift Rhs,hj
+//!   and the local fidelity Rf,hj.
+//! - The one-page report with the source SPD against its reference, CCT,
+//!   Duv, Rf, Rg and the CVG, as SVG or as a standalone HTML page.
+//!
+//! Every function returns a complete document as a `String`; nothing here
+//! depends on a GUI toolkit.
+
+use std::fmt::Write;
+
+use crate::cie224;
+use crate::cie_data;
+use crate::spectrum::SpectralData;
+use crate::tm30::{DesignIntent, TM30Metrics, N_BINS};
+use crate::Observer;
+
+/// Colors of the 16 hue bins used throughout the TM-30 graphics.
+pub const BIN_COLORS: [&str; N_BINS] = [
+    "#e62828", "#e74b4b", "#fb812e", "#ffb529", "#cbca46", "#7eb94c", "#41c06d", "#009c7c",
+    "#16bcb0", "#00a4bf", "#0085c3", "#3b62aa", "#4568ae", "#6a4e85", "#9d69a1", "#a74f81",
+];
+
+const FONT: &str = "font-family=\"Helvetica, Arial, sans-serif\"";
+
+/// Page size of the report in CSS pixels (US Letter at 96 dpi).
+const PAGE_WIDTH: f32 = 816.0;
+const PAGE_HEIGHT: f32 = 1056.0;
+
+/// Color Vector Graphic as an SVG document.
+pub fn color_vector_graphic(metrics: &TM30Metrics) -> String {
+    document(400.0, 400.0, |out| cvg(out, 0.0, 0.0, 400.0, metrics))
+}
+
+/// Local chroma shift Rcs,hj as an SVG bar chart.
+pub fn chroma_shift_chart(metrics: &TM30Metrics) -> String {
+    document(480.0, 220.0, |out| {
+        chroma_shift(out, 0.0, 0.0, 480.0, 220.0, metrics)
+    })
+}
+
+/// Local hue shift Rhs,hj as an SVG bar chart.
+pub fn hue_shift_chart(metrics: &TM30Metrics) -> String {
+    document(480.0, 220.0, |out| {
+        hue_shift(out, 0.0, 0.0, 480.0, 220.0, metrics)
+    })
+}
+
+/// Local color fidelity Rf,hj as an SVG bar chart.
+pub fn local_fidelity_chart(metrics: &TM30Metrics) -> String {
+    document(480.0, 220.0, |out| {
+        local_fidelity(out, 0.0, 0.0, 480.0, 220.0, metrics)
+    })
+}
+
+/// The one-page TM-30 report as an SVG document.
+///
+/// `title` names the source; `spd` is the spectrum `metrics` was
+/// calculated from.
+pub fn report_svg(title: &str, spd: &SpectralData, metrics: &TM30Metrics) -> String {
+    document(PAGE_WIDTH, PAGE_HEIGHT, |out| {
+        let _ = write!(
+            out,
+            r#"<rect width="{PAGE_WIDTH}" height="{PAGE_HEIGHT}" fill="white"/>"#
+        );
+        text(
+            out,
+            40.0,
+            50.0,
+            22.0,
+            "start",
+            "bold",
+            "ANSI/IES TM-30-20 Color Rendition Report",
+        );
+        text(out, 40.0, 78.0, 15.0, "start", "normal", title);
+
+        spectrum(out, 40.0, 100.0, 360.0, 240.0, spd, metrics);
+        summary(out, 40.0, 370.0, metrics);
+        cvg(out, 436.0, 100.0, 340.0, metrics);
+
+        chroma_shift(out, 40.0, 500.0, 736.0, 170.0, metrics);
+        hue_shift(out, 40.0, 680.0, 736.0, 170.0, metrics);
+        local_fidelity(out, 40.0, 860.0, 736.0, 170.0, metrics);
+    })
+}
+
+/// The one-page TM-30 report as a standalone HTML page, followed by a table
+/// of the hue-bin values.
+pub fn report_html(title: &str, spd: &SpectralData, metrics: &TM30Metrics) -> String {
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>TM-30 Report: {}</title>\n\
+         <style>body{{font-family:Helvetica,Arial,sans-serif;margin:2em}}\
+         table{{border-collapse:collapse}}td,th{{border:1px solid #999;padding:2px 8px;text-align:right}}</style>\n\
+         </head>\n<body>\n",
+        escape(title)
+    );
+    out.push_str(&report_svg(title, spd, metrics));
+    out.push_str(
+        "\n<h2>Hue-angle bins</h2>\n<table>\n\
+         <tr><th>Bin</th><th>R<sub>f,hj</sub></th><th>R<sub>cs,hj</sub></th><th>R<sub>hs,hj</sub></th></tr>\n",
+    );
+    for j in 0..N_BINS {
+        let _ = writeln!(
+            out,
+            "<tr><td>{}</td><td>{:.0}</td><td>{:.0}%</td><td>{:.2}</td></tr>",
+            j + 1,
+            metrics.bin_rf[j],
+            metrics.bin_chroma_shift[j] * 100.0,
+            metrics.bin_hue_shift[j]
+        );
+    }
+    out.push_str("</table>\n</body>\n</html>\n");
+    out
+}
+
+/// Wrap the output of `draw` in an `<svg>` element of the given size.
+fn document(width: f32, height: f32, draw: impl FnOnce(&mut String)) -> String {
+    let mut out = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+    draw(&mut out);
+    out.push_str("</svg>");
+    out
+}
+
+fn text(out: &mut String, x: f32, y: f32, size: f32, anchor: &str, weight: &str, s: &str) {
+    let _ = write!(
+        out,
+        r#"<text x="{x:.1}" y="{y:.1}" {FONT} font-size="{size}" text-anchor="{anchor}" font-weight="{weight}">{}</text>"#,
+        escape(s)
+    );
+}
+
+fn line(out: &mut String, (x1, y1): (f32, f32), (x2, y2): (f32, f32), stroke: &str, width: f32) {
+    let _ = write!(
+        out,
+        r#"<line x1="{x1:.1}" y1="{y1:.1}" x2="{x2:.1}" y2="{y2:.1}" stroke="{stroke}" stroke-width="{width}"/>"#
+    );
+}
+
+fn polygon(out: &mut String, points: &[(f32, f32)], fill: &str, stroke: &str, width: f32) {
+    let points: Vec<String> = points
+        .iter()
+        .map(|(x, y)| format!("{x:.1},{y:.1}"))
+        .collect();
+    let _ = write!(
+        out,
+        r#"<polygon points="{}" fill="{fill}" stroke="{stroke}" stroke-width="{width}"/>"#,
+        points.join(" ")
+    );
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Color Vector Graphic in a square of side `size` at (`x`, `y`).
+fn cvg(out: &mut String, x: f32, y: f32, size: f32, metrics: &TM30Metrics) {
+    let center = (x + size / 2.0, y + size / 2.0);
+    // The unit circle takes 2/3 of the half-width, leaving room for shifts
+    let r = size / 3.0;
+    let to_px = |a: f32, b: f32| (center.0 + a * r, center.1 - b * r);
+
+    let _ = write!(
+        out,
+        r##"<rect x="{x}" y="{y}" width="{size}" height="{size}" fill="#f4f4f4"/>"##
+    );
+    for (j, color) in BIN_COLORS.iter().enumerate() {
+        let (a0, a1) = (j as f32 * 22.5, (j + 1) as f32 * 22.5);
+        let wedge = [
+            center,
+            to_px(1.45 * a0.to_radians().cos(), 1.45 * a0.to_radians().sin()),
+            to_px(1.45 * a1.to_radians().cos(), 1.45 * a1.to_radians().sin()),
+        ];
+        let _ = write!(out, r#"<g fill-opacity="0.15">"#);
+        polygon(out, &wedge, color, "none", 0.0);
+        out.push_str("</g>");
+    }
+    for ring in [0.8, 1.2] {
+        let _ = write!(
+            out,
+            r##"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="none" stroke="#bbb" stroke-dasharray="4 3"/>"##,
+            center.0,
+            center.1,
+            ring * r
+        );
+    }
+    let _ = write!(
+        out,
+        r#"<circle cx="{:.1}" cy="{:.1}" r="{r:.1}" fill="none" stroke="black" stroke-width="1.5"/>"#,
+        center.0, center.1
+    );
+
+    // Test polygon normalized so each reference bin lies on the unit circle
+    let mut reference = Vec::with_capacity(N_BINS);
+    let mut test = Vec::with_capacity(N_BINS);
+    for j in 0..N_BINS {
+        let c_ref = metrics.bin_ref_a[j].hypot(metrics.bin_ref_b[j]);
+        reference.push(to_px(
+            metrics.bin_ref_a[j] / c_ref,
+            metrics.bin_ref_b[j] / c_ref,
+        ));
+        test.push(to_px(
+            metrics.bin_test_a[j] / c_ref,
+            metrics.bin_test_b[j] / c_ref,
+        ));
+    }
+    polygon(out, &test, "none", "#d00", 2.0);
+    for j in 0..N_BINS {
+        line(out, reference[j], test[j], BIN_COLORS[j], 2.0);
+        let _ = write!(
+            out,
+            r#"<circle cx="{:.1}" cy="{:.1}" r="3" fill="{}"/>"#,
+            test[j].0, test[j].1, BIN_COLORS[j]
+        );
+    }
+
+    text(
+        out,
+        x + 8.0,
+        y + 20.0,
+        14.0,
+        "start",
+        "bold",
+        &format!("Rf {:.0}", metrics.rf),
+    );
+    text(
+        out,
+        x + size - 8.0,
+        y + 20.0,
+        14.0,
+        "end",
+        "bold",
+        &format!("Rg {:.0}", metrics.rg),
+    );
+    text(
+        out,
+        x + 8.0,
+        y + size - 10.0,
+        12.0,
+        "start",
+        "normal",
+        &format!("CCT {:.0} K", metrics.cct),
+    );
+    text(
+        out,
+        x + size - 8.0,
+        y + size - 10.0,
+        12.0,
+        "end",
+        "normal",
+        &format!("Duv {:.4}", metrics.duv),
+    );
+}
+
+fn chroma_shift(out: &mut String, x: f32, y: f32, w: f32, h: f32, m: &TM30Metrics) {
+    let values = m.bin_chroma_shift.map(|v| v * 100.0);
+    let limit = values.iter().fold(40.0f32, |l, v| l.max(v.abs().ceil()));
+    let labels = values.map(|v| format!("{v:.0}%"));
+    bar_chart(
+        out,
+        (x, y, w, h),
+        "Local Chroma Shift (Rcs,hj)",
+        &values,
+        (-limit, limit),
+        &labels,
+    );
+}
+
+fn hue_shift(out: &mut String, x: f32, y: f32, w: f32, h: f32, m: &TM30Metrics) {
+    let limit = m
+        .bin_hue_shift
+        .iter()
+        .fold(0.5f32, |l, v| l.max((v.abs() * 10.0).ceil() / 10.0));
+    let labels = m.bin_hue_shift.map(|v| format!("{v:.2}"));
+    bar_chart(
+        out,
+        (x, y, w, h),
+        "Local Hue Shift (Rhs,hj)",
+        &m.bin_hue_shift,
+        (-limit, limit),
+        &labels,
+    );
+}
+
+fn local_fidelity(out: &mut String, x: f32, y: f32, w: f32, h: f32, m: &TM30Metrics) {
+    let labels = m.bin_rf.map(|v| format!("{v:.0}"));
+    bar_chart(
+        out,
+        (x, y, w, h),
+        "Local Color Fidelity (Rf,hj)",
+        &m.bin_rf,
+        (0.0, 100.0),
+        &labels,
+    );
+}
+
+/// One bar per hue bin, from zero, with the value printed at the bar's end.
+fn bar_chart(
+    out: &mut String,
+    (x, y, w, h): (f32, f32, f32, f32),
+    title: &str,
+    values: &[f32; N_BINS],
+    (min, max): (f32, f32),
+    labels: &[String; N_BINS],
+) {
+    text(out, x + w / 2.0, y + 14.0, 13.0, "middle", "bold", title);
+    let (left, top, bottom) = (x + 40.0, y + 24.0, y + h - 18.0);
+    let slot = (x + w - left) / N_BINS as f32;
+    let to_py = |v: f32| bottom - (v.clamp(min, max) - min) / (max - min) * (bottom - top);
+
+    for tick in [min, (min + max) / 2.0, max] {
+        let py = to_py(tick);
+        line(out, (left, py), (x + w, py), "#ddd", 1.0);
+        let label = if max > 1.0 {
+            format!("{tick:.0}")
+        } else {
+            format!("{tick:.1}")
+        };
+        text(out, left - 4.0, py + 4.0, 10.0, "end", "normal", &label);
+    }
+
+    let zero = to_py(0.0);
+    for j in 0..N_BINS {
+        let px = left + slot * j as f32 + slot * 0.15;
+        let py = to_py(values[j]);
+        let _ = write!(
+            out,
+            r#"<rect x="{px:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}"/>"#,
+            py.min(zero),
+            slot * 0.7,
+            (py - zero).abs(),
+            BIN_COLORS[j]
+        );
+        let ly = if values[j] >= 0.0 {
+            py - 3.0
+        } else {
+            py + 11.0
+        };
+        text(
+            out,
+            px + slot * 0.35,
+            ly,
+            9.0,
+            "middle",
+            "normal",
+            &labels[j],
+        );
+        text(
+            out,
+            px + slot * 0.35,
+            y + h - 4.0,
+            10.0,
+            "middle",
+            "normal",
+            &(j + 1).to_string(),
+        );
+    }
+    line(out, (left, zero), (x + w, zero), "black", 1.0);
+}
+
+/// Test SPD and its reference illuminant, scaled to the same luminous flux
+/// and normalized to the peak of either.
+fn spectrum(out: &mut String, x: f32, y: f32, w: f32, h: f32, spd: &SpectralData, m: &TM30Metrics) {
+    let test = spd.resample(cie_data::START, 780.0, 1.0).values;
+    let reference = cie224::reference_spd(m.cct);
+    let ybar = Observer::CIE1931_2.cmfs_1nm();
+    let luminance = |s: &[f32]| s.iter().zip(ybar).map(|(v, c)| v * c[1]).sum::<f32>();
+    let k = luminance(&test) / luminance(&reference);
+    let reference: Vec<f32> = reference.iter().map(|v| v * k).collect();
+    let peak = test
+        .iter()
+        .chain(&reference)
+        .fold(f32::MIN_POSITIVE, |p, v| p.max(*v));
+
+    text(
+        out,
+        x + w / 2.0,
+        y + 14.0,
+        13.0,
+        "middle",
+        "bold",
+        "Spectral Power Distribution",
+    );
+    let (left, top, right, bottom) = (x + 10.0, y + 24.0, x + w - 10.0, y + h - 20.0);
+    let _ = write!(
+        out,
+        r##"<rect x="{left}" y="{top}" width="{}" height="{}" fill="none" stroke="#999"/>"##,
+        right - left,
+        bottom - top
+    );
+    let path = |values: &[f32]| {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let px = left + (right - left) * i as f32 / (values.len() - 1) as f32;
+                let py = bottom - (bottom - top) * (v / peak).clamp(0.0, 1.0);
+                format!("{px:.1},{py:.1}")
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let _ = write!(
+        out,
+        r#"<polyline points="{}" fill="none" stroke="black" stroke-width="1.5" stroke-dasharray="5 3"/>"#,
+        path(&reference)
+    );
+    let _ = write!(
+        out,
+        r##"<polyline points="{}" fill="none" stroke="#d00" stroke-width="1.5"/>"##,
+        path(&test)
+    );
+    for nm in [400, 500, 600, 700] {
+        let px = left + (right - left) * (nm as f32 - cie_data::START) / 400.0;
+        text(
+            out,
+            px,
+            bottom + 14.0,
+            10.0,
+            "middle",
+            "normal",
+            &nm.to_string(),
+        );
+    }
+    text(
+        out,
+        right - 4.0,
+        top + 14.0,
+        10.0,
+        "end",
+        "normal",
+        "— test   - - reference",
+    );
+}
+
+/// Table of the headline values and Annex E design-intent levels.
+fn summary(out: &mut String, x: f32, y: f32, m: &TM30Metrics) {
+    let level = |intent: DesignIntent, prefix: &str| {
+        m.priority_level(intent)
+            .map_or_else(|| "—".to_string(), |l| format!("{prefix}{l}"))
+    };
+    let rows = [
+        ("CCT", format!("{:.0} K", m.cct)),
+        ("Duv", format!("{:.4}", m.duv)),
+        ("Rf", format!("{:.0}", m.rf)),
+        ("Rg", format!("{:.0}", m.rg)),
+        ("Rf,skin", format!("{:.0}", m.rf_skin)),
+        ("Rcs,h1", format!("{:.0}%", m.bin_chroma_shift[0] * 100.0)),
+        (
+            "Design intent",
+            format!(
+                "{}  {}  {}",
+                level(DesignIntent::Preference, "P"),
+                level(DesignIntent::Vividness, "V"),
+                level(DesignIntent::Fidelity, "F")
+            ),
+        ),
+    ];
+    for (i, (name, value)) in rows.iter().enumerate() {
+        let row_y = y + 16.0 * i as f32;
+        text(out, x, row_y, 12.0, "start", "bold", name);
+        text(out, x + 110.0, row_y, 12.0, "start", "normal", value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spectrum::Quantity;
+    use crate::tm30::try_calculate_tm30;
+    use crate::Illuminant;
+
+    #[test]
+    fn test_tm30_report() {
+        let wavelengths = (0..cie_data::LEN).map(|i| 380.0 + i as f32).collect();
+        let values = Illuminant::F12.spd_1nm().to_vec();
+        let spd = SpectralData::from_grid(wavelengths, values, Quantity::RelativePower).unwrap();
+        let metrics = try_calculate_tm30(&spd).unwrap();
+
+        let cvg = color_vector_graphic(&metrics);
+        assert!(cvg.starts_with("<svg") && cvg.ends_with("</svg>"));
+        assert_eq!(cvg.matches("<line").count(), N_BINS);
+        for chart in [
+            chroma_shift_chart(&metrics),
+            hue_shift_chart(&metrics),
+            local_fidelity_chart(&metrics),
+        ] {
+            assert_eq!(chart.matches("<rect").count(), N_BINS);
+            assert!(!chart.contains("NaN"));
+        }
+
+        let html = report_html("F12 <fluorescent> & co", &spd, &metrics);
+        assert!(html.contains("F12 &lt;fluorescent&gt; &amp; co"));
+        assert_eq!(html.matches("<tr>").count(), N_BINS + 1);
+
+        // Rendered values agree with the IES TM-30 Spectral Calculator output
+        // for CIE F12 within the rounding of the report
+        let rendered = |prefix: &str, suffix: &str| -> f32 {
+            let start = html.find(prefix).unwrap_or_else(|| panic!("{}", prefix)) + prefix.len();
+            let end = start + html[start..].find(suffix).unwrap();
+            html[start..end].parse().unwrap()
+        };
+        assert!((rendered(r#""bold">Rf "#, "<") - 77.7).abs() <= 1.0);
+        assert!((rendered(r#""bold">Rg "#, "<") - 102.4).abs() <= 1.0);
+        let summary = |label: &str| -> f32 {
+            let at = html.find(&format!(">{}</text>", label)).unwrap();
+            let start = at + html[at..].find(r#""normal">"#).unwrap() + r#""normal">"#.len();
+            let end = start + html[start..].find('<').unwrap();
+            html[start..end].trim_end_matches('%').parse().unwrap()
+        };
+        assert!((summary("Rf") - 77.7).abs() <= 1.0);
+        assert!((summary("Rg") - 102.4).abs() <= 1.0);
+        assert!((summary("Rcs,h1") + 8.51).abs() <= 1.5);
+
+        const RF_HJ: [f32; 14] = [
+            78.222, 86.843, 80.612, 68.521, 72.478, 79.408, 72.551, 79.812, 82.356, 77.682, 74.540,
+            80.448, 82.391, 76.766,
+        ];
+        const RCS_HJ: [f32; 14] = [
+            -8.510, -3.160, -1.216, 9.247, 18.373, 13.567, 10.277, -3.677, -4.746, -12.181,
+            -12.371, 1.845, 8.819, 4.632,
+        ];
+        const RHS_HJ: [f32; 14] = [
+            -0.04646, 0.03206, 0.09473, 0.17904, 0.12972, 0.00023, -0.14516, -0.12447, -0.09830,
+            0.03149, 0.14218, 0.08155, -0.02598, -0.06301,
+        ];
+        // CES 92 sits on the h15/h16 boundary, so the last two bins depend
+        // on rounding
+        for j in 0..14 {
+            let row = format!("<tr><td>{}</td><td>", j + 1);
+            let start = html.find(&row).unwrap() + row.len();
+            let end = start + html[start..].find("</td></tr>").unwrap();
+            let cells: Vec<f32> = html[start..end]
+                .split("</td><td>")
+                .map(|c| c.trim_end_matches('%').parse().unwrap())
+                .collect();
+            assert!(
+                (cells[0] - RF_HJ[j]).abs() <= 1.5,
+                "Rf,h{}: {:?}",
+                j + 1,
+                cells
+            );
+            assert!(
+                (cells[1] - RCS_HJ[j]).abs() <= 1.5,
+                "Rcs,h{}: {:?}",
+                j + 1,
+                cells
+            );
+            assert!(
+                (cells[2] - RHS_HJ[j]).abs() <= 0.015,
+                "Rhs,h{}: {:?}",
+                j + 1,
+                cells
+            );
+        }
+
+        // Rcs,h1 of -9% is within Preference level 3 (-12% to 23%) and below
+        // Vividness; Rf 78 is short of Fidelity
+        assert!(html.contains(r#">Design intent</text>"#));
+        assert!(html.contains(r#"font-weight="normal">P3  —  —</text>"#));
+    }
+}
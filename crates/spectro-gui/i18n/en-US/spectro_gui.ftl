@@ -25,12 +25,28 @@ gui-clear = Clear
 gui-reflective = Reflective
 gui-emissive = Emissive
 gui-ambient = Ambient
+gui-projector = Projector
+gui-flash = Flash
+gui-transmissive = Transmissive
+gui-capture-reference = Capture Reference
 
 # Settings
 gui-interval = interval (s)
 gui-colorimetry-standards = Colorimetry Standards
 gui-illuminant = Illuminant:
 gui-observer = Observer:
+gui-bandpass = Bandpass Correction:
+gui-bandpass-off = Off
+gui-bandpass-width = Bandwidth:
+gui-circadian-title = Circadian Stimulus
+gui-circadian-duration = Exposure duration:
+gui-circadian-field = Field factor:
+gui-melanopic-edi = Melanopic EDI:
+gui-sample-title = Sample
+gui-sample-name = Sample Name:
+gui-sample-notes = Notes:
+gui-backing = Backing:
+gui-backing-unspecified = Unspecified
 gui-language-title = Language Settings
 gui-language = Language:
 
@@ -71,7 +87,7 @@ gui-observer-func = 📐 Observer Functions
 gui-conv-pipeline = 🔄 Conversion Pipeline
 gui-current-calc = 🧪 Current Calculation
 gui-chromaticity = 🎯 CIE 1931 xy Chromaticity
-gui-color-quality-tm30 = 🌈 IES TM-30-18 Color Quality
+gui-color-quality-tm30 = 🌈 IES TM-30-20 Color Quality
 gui-no-tm30 = No TM-30 data available.
 
 # Calibration
@@ -80,10 +96,22 @@ gui-quick-calibrate = ⚡ Quick Calibrate
 gui-cal-failed = ⚠️ Calibration Failed
 
 # TM-30
-gui-tm30-vector = TM-30-18 Color Vector Graphic
+gui-tm30-vector = TM-30-20 Color Vector Graphic
 gui-tm30-ces = 99 Color Evaluation Samples (CES)
 gui-tm30-hue-bin = Hue Bin Details
 gui-tm30-bin = Bin
 gui-tm30-rf = Rf
 gui-tm30-chroma-shift = Chroma Shift
 gui-tm30-hue-shift = Hue Shift
+gui-tm30-skin = Skin Fidelity Rf,skin
+gui-tm30-intent = Design Intent (Annex E)
+gui-tm30-intent-preference = Preference
+gui-tm30-intent-fidelity = Fidelity
+gui-tm30-intent-vividness = Vividness
+gui-tm30-intent-none = not met
+gui-tm30-export-report = 💾 Export TM-30 Report
+
+# SSI
+gui-ssi = 🎬 Spectral Similarity Index (SSI)
+gui-ssi-tungsten = Tungsten 3200 K
+gui-ssi-d55 = D55
@@ -25,12 +25,28 @@ gui-clear = 清除
 gui-reflective = 反射
 gui-emissive = 发射
 gui-ambient = 环境光
+gui-projector = 投影
+gui-flash = 闪光灯
+gui-transmissive = 透射
+gui-capture-reference = 采集参考
 
 # 设置
 gui-interval = 间隔 (秒)
 gui-colorimetry-standards = 色度学标准
 gui-illuminant = 光源:
 gui-observer = 观察者:
+gui-bandpass = 带宽校正：
+gui-bandpass-off = 关闭
+gui-bandpass-width = 带宽：
+gui-circadian-title = 昼夜节律刺激
+gui-circadian-duration = 照射时长:
+gui-circadian-field = 视场系数:
+gui-melanopic-edi = 黑视素 EDI:
+gui-sample-title = 样品
+gui-sample-name = 样品名称：
+gui-sample-notes = 备注：
+gui-backing = 衬底：
+gui-backing-unspecified = 未指定
 gui-language-title = 语言设置
 gui-language = 语言:
 
@@ -71,7 +87,7 @@ gui-observer-func = 📐 观察者函数
 gui-conv-pipeline = 🔄 转换流程
 gui-current-calc = 🧪 当前计算
 gui-chromaticity = 🎯 CIE 1931 xy 色度图
-gui-color-quality-tm30 = 🌈 IES TM-30-18 色彩质量
+gui-color-quality-tm30 = 🌈 IES TM-30-20 色彩质量
 gui-no-tm30 = 暂无 TM-30 数据。
 
 # 校准
@@ -80,10 +96,22 @@ gui-quick-calibrate = ⚡ 快速校准
 gui-cal-failed = ⚠️ 校准失败
 
 # TM-30
-gui-tm30-vector = TM-30-18 色彩向量图
+gui-tm30-vector = TM-30-20 色彩向量图
 gui-tm30-ces = 99 色彩评估样本 (CES)
 gui-tm30-hue-bin = 色相分区详情
 gui-tm30-bin = 分区
 gui-tm30-rf = Rf
 gui-tm30-chroma-shift = 色度偏移
 gui-tm30-hue-shift = 色相偏移
+gui-tm30-skin = 肤色保真度 Rf,skin
+gui-tm30-intent = 设计意图 (附录 E)
+gui-tm30-intent-preference = 偏好
+gui-tm30-intent-fidelity = 保真
+gui-tm30-intent-vividness = 鲜艳
+gui-tm30-intent-none = 未达到
+gui-tm30-export-report = 💾 导出 TM-30 报告
+
+# SSI
+gui-ssi = 🎬 光谱相似度指数 (SSI)
+gui-ssi-tungsten = 钨丝灯 3200 K
+gui-ssi-d55 = D55
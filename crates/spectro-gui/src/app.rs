@@ -10,10 +10,14 @@ use crossbeam_channel::{unbounded, Receiver, Sender};
 use eframe::egui;
 use egui_plot::{HLine, Legend, Line, Plot, PlotPoints, Points, VLine};
 use spectro_rs::{
-    colorimetry::{illuminant, Lab, XYZ, X_BAR_2, Y_BAR_2, Z_BAR_2},
+    bandpass::BandpassCorrection,
+    circadian::{self, CircadianExposure},
+    colorimetry::{illuminant, Lab, XYZ},
     discover,
-    tm30::calculate_tm30,
-    BoxedSpectrometer, Illuminant, MeasurementMode, Observer, SpectralData,
+    metadata::{Backing, MeasurementMetadata},
+    ssi::{calculate_ssi, SsiReference},
+    tm30::try_calculate_tm30,
+    tm30_report, BoxedSpectrometer, Illuminant, MeasurementMode, Observer, Quantity, SpectralData,
 };
 use std::thread;
 use std::time::{Duration, Instant};
@@ -45,6 +49,8 @@ pub struct SpectroApp {
 
     // Measurement State
     selected_mode: MeasurementMode,
+    transmission_source: MeasurementMode,
+    has_transmission_ref: bool,
     last_result: Option<SpectralData>,
     last_tm30: Option<spectro_rs::tm30::TM30Metrics>,
     measurement_history: Vec<MeasurementEntry>,
@@ -53,6 +59,14 @@ pub struct SpectroApp {
     reference_lab: Option<Lab>,
     delta_e_tolerance: f32,
 
+    // Exposure assumed for the circadian stimulus of ambient readings
+    circadian_exposure: CircadianExposure,
+
+    // Sample details recorded with each measurement
+    sample_name: String,
+    sample_notes: String,
+    sample_backing: Option<Backing>,
+
     // Reference input dialog state
     ref_input_l: f32,
     ref_input_a: f32,
@@ -75,6 +89,7 @@ pub struct SpectroApp {
     // Algorithm calculation settings
     selected_illuminant: Illuminant,
     selected_observer: Observer,
+    bandpass_correction: Option<BandpassCorrection>,
 
     // Calibration wizard (extracted component)
     calibration_wizard: CalibrationWizard,
@@ -180,14 +195,22 @@ impl SpectroApp {
 
                     DeviceCommand::Measure(mode) => {
                         if let Some(ref mut d) = device {
-                            update_tx
-                                .send(UIUpdate::Status("📊 Measuring...".into()))
-                                .ok();
+                            let status = if mode == MeasurementMode::Flash {
+                                "⚡ Armed: fire the flash now..."
+                            } else {
+                                "📊 Measuring..."
+                            };
+                            update_tx.send(UIUpdate::Status(status.into())).ok();
 
                             match d.measure(mode) {
                                 Ok(data) => {
-                                    let tm30 = if mode == MeasurementMode::Emissive {
-                                        Some(Box::new(calculate_tm30(&data)))
+                                    let tm30 = if matches!(
+                                        mode,
+                                        MeasurementMode::Emissive
+                                            | MeasurementMode::Projector
+                                            | MeasurementMode::Flash
+                                    ) {
+                                        try_calculate_tm30(&data).ok().map(Box::new)
                                     } else {
                                         None
                                     };
@@ -217,6 +240,36 @@ impl SpectroApp {
                                 .ok();
                         }
                     }
+
+                    DeviceCommand::CaptureReference(source) => {
+                        if let Some(ref mut d) = device {
+                            update_tx
+                                .send(UIUpdate::Status("📊 Measuring bare light source...".into()))
+                                .ok();
+
+                            match d.capture_reference(source) {
+                                Ok(_) => {
+                                    update_tx
+                                        .send(UIUpdate::Status(
+                                            "✅ Transmission reference captured".into(),
+                                        ))
+                                        .ok();
+                                }
+                                Err(e) => {
+                                    update_tx
+                                        .send(UIUpdate::Error(format!(
+                                            "❌ Reference capture failed: {}",
+                                            e
+                                        )))
+                                        .ok();
+                                }
+                            }
+                        } else {
+                            update_tx
+                                .send(UIUpdate::Error("⚠️ No device connected".into()))
+                                .ok();
+                        }
+                    }
                 }
             }
         });
@@ -233,11 +286,17 @@ impl SpectroApp {
             is_busy: false,
             is_calibrated: false,
             selected_mode: MeasurementMode::Reflective,
+            transmission_source: MeasurementMode::Emissive,
+            has_transmission_ref: false,
             last_result: None,
             last_tm30: None,
             measurement_history: Vec::new(),
             reference_lab: None,
             delta_e_tolerance: 2.0,
+            circadian_exposure: CircadianExposure::default(),
+            sample_name: String::new(),
+            sample_notes: String::new(),
+            sample_backing: None,
             ref_input_l: 50.0,
             ref_input_a: 0.0,
             ref_input_b: 0.0,
@@ -251,6 +310,7 @@ impl SpectroApp {
             last_measurement_time: None,
             selected_illuminant: Illuminant::D65,
             selected_observer: Observer::CIE1931_2,
+            bandpass_correction: None,
             calibration_wizard: CalibrationWizard::new(),
         }
     }
@@ -294,6 +354,21 @@ impl SpectroApp {
         }
     }
 
+    /// Attach the user's sample details to a new measurement.
+    fn annotate(&self, data: &mut SpectralData) {
+        let name = self.sample_name.trim();
+        if !name.is_empty() {
+            data.metadata.sample_name = Some(name.to_string());
+        }
+        let notes = self.sample_notes.trim();
+        if !notes.is_empty() {
+            data.metadata.notes = Some(notes.to_string());
+        }
+        if self.sample_backing.is_some() {
+            data.metadata.backing = self.sample_backing;
+        }
+    }
+
     fn add_to_history(&mut self, data: SpectralData) {
         let lab = {
             let xyz = data.to_xyz_ext(self.selected_illuminant, self.selected_observer);
@@ -317,6 +392,8 @@ impl SpectroApp {
             mode: self.selected_mode,
             data,
             lab,
+            illuminant: self.selected_illuminant,
+            observer: self.selected_observer,
             delta_e,
         };
 
@@ -339,20 +416,7 @@ impl SpectroApp {
             .save_file();
 
         if let Some(path) = file_path {
-            let mut csv = String::from("Timestamp,Mode,L*,a*,b*,DeltaE\n");
-            for entry in &self.measurement_history {
-                csv.push_str(&format!(
-                    "{},{:?},{:.4},{:.4},{:.4},{}\n",
-                    entry.timestamp,
-                    entry.mode,
-                    entry.lab.l,
-                    entry.lab.a,
-                    entry.lab.b,
-                    entry.delta_e.map(|e| e.to_string()).unwrap_or_default()
-                ));
-            }
-
-            if let Err(e) = std::fs::write(path, csv) {
+            if let Err(e) = std::fs::write(path, history_csv(&self.measurement_history)) {
                 eprintln!("Failed to write CSV: {}", e);
             }
         }
@@ -378,6 +442,38 @@ impl SpectroApp {
         }
     }
 
+    /// Export the one-page TM-30 report of the last measurement as HTML or SVG.
+    fn export_tm30_report(&self, metrics: &spectro_rs::tm30::TM30Metrics) {
+        let Some(data) = &self.last_result else {
+            return;
+        };
+
+        let file_path = rfd::FileDialog::new()
+            .add_filter("HTML File", &["html"])
+            .add_filter("SVG File", &["svg"])
+            .set_file_name("tm30-report.html")
+            .save_file();
+
+        if let Some(path) = file_path {
+            let title = data
+                .metadata
+                .sample_name
+                .as_deref()
+                .unwrap_or("Measured source");
+            let is_svg = path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"));
+            let report = if is_svg {
+                tm30_report::report_svg(title, data, metrics)
+            } else {
+                tm30_report::report_html(title, data, metrics)
+            };
+            if let Err(e) = std::fs::write(path, report) {
+                eprintln!("Failed to write TM-30 report: {}", e);
+            }
+        }
+    }
+
     // NOTE: render_device_dial and render_calibration_wizard have been
     // extracted to crate::calibration::CalibrationWizard
 
@@ -393,53 +489,7 @@ impl SpectroApp {
             .save_file();
 
         if let Some(path) = file_path {
-            let mut cgats = String::new();
-            cgats.push_str("CTI3\n\n");
-            cgats.push_str("DESCRIPTOR \"Argyll Device Measurement data\"\n");
-            cgats.push_str("ORIGINATOR \"spectro-rs\"\n");
-            cgats.push_str(&format!(
-                "CREATED \"{}\"\n\n",
-                chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
-            ));
-
-            // Define fields: ID, Lab, XYZ, and Spectral data
-            cgats.push_str("NUMBER_OF_FIELDS 47\n");
-            cgats.push_str("BEGIN_DATA_FORMAT\n");
-            cgats.push_str("SAMPLE_ID SAMPLE_NAME LAB_L LAB_A LAB_B XYZ_X XYZ_Y XYZ_Z ");
-            for wl in (380..=780).step_by(10) {
-                cgats.push_str(&format!("SPEC_{} ", wl));
-            }
-            cgats.push_str("\nEND_DATA_FORMAT\n\n");
-
-            cgats.push_str(&format!(
-                "NUMBER_OF_SETS {}\n",
-                self.measurement_history.len()
-            ));
-            cgats.push_str("BEGIN_DATA\n");
-
-            for (i, entry) in self.measurement_history.iter().enumerate() {
-                let xyz = entry.data.to_xyz();
-                cgats.push_str(&format!(
-                    "{} \"{}\" {:.4} {:.4} {:.4} {:.4} {:.4} {:.4} ",
-                    i + 1,
-                    entry.timestamp,
-                    entry.lab.l,
-                    entry.lab.a,
-                    entry.lab.b,
-                    xyz.x,
-                    xyz.y,
-                    xyz.z
-                ));
-
-                for val in &entry.data.values {
-                    cgats.push_str(&format!("{:.6} ", val));
-                }
-                cgats.push('\n');
-            }
-
-            cgats.push_str("END_DATA\n");
-
-            if let Err(e) = std::fs::write(path, cgats) {
+            if let Err(e) = std::fs::write(path, history_cgats(&self.measurement_history)) {
                 eprintln!("Failed to write CGATS: {}", e);
             }
         }
@@ -652,15 +702,14 @@ impl SpectroApp {
                 plot_ui.line(line);
 
                 // Mark peak wavelength
-                let peak_idx = data
-                    .values
+                let peak_wl = data
+                    .wavelengths
                     .iter()
-                    .enumerate()
-                    .skip(4) // Skip noise below 420nm
+                    .zip(data.values.iter())
+                    .filter(|(w, _)| **w >= 420.0) // Skip noise below 420nm
                     .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
-                    .map(|(i, _)| i)
-                    .unwrap_or(0);
-                let peak_wl = 380.0 + peak_idx as f64 * 10.0;
+                    .map(|(w, _)| *w as f64)
+                    .unwrap_or(0.0);
                 plot_ui.vline(
                     VLine::new(peak_wl)
                         .color(egui::Color32::from_rgba_unmultiplied(255, 255, 0, 100))
@@ -717,25 +766,22 @@ impl SpectroApp {
             let cct = xyz.to_cct();
 
             // Peak and centroid
-            let peak_idx = data
-                .values
+            let visible: Vec<(f32, f32)> = data
+                .wavelengths
                 .iter()
-                .enumerate()
-                .skip(4)
-                .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
-                .map(|(i, _)| i)
+                .cloned()
+                .zip(data.values.iter().cloned())
+                .filter(|(w, _)| *w >= 420.0)
+                .collect();
+            let peak_wl = visible
+                .iter()
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(w, _)| w.round() as i32)
                 .unwrap_or(0);
-            let peak_wl = 380 + peak_idx * 10;
 
-            let total_power: f32 = data.values.iter().skip(4).sum();
-            let centroid: f32 = data
-                .values
-                .iter()
-                .enumerate()
-                .skip(4)
-                .map(|(i, v)| (380 + i * 10) as f32 * v)
-                .sum::<f32>()
-                / total_power.max(1e-6);
+            let total_power: f32 = visible.iter().map(|(_, v)| v).sum();
+            let centroid: f32 =
+                visible.iter().map(|(w, v)| w * v).sum::<f32>() / total_power.max(1e-6);
 
             ui.columns(3, |cols| {
                 // Column 1: XYZ & Lab
@@ -790,6 +836,34 @@ impl SpectroApp {
                             ui.label("CCT:");
                             ui.label(format!("{:.0} K", cct));
                             ui.end_row();
+                            if let Some(p) = data.photometric() {
+                                if p.unit.is_illuminance() {
+                                    ui.label("Illuminance:");
+                                } else {
+                                    ui.label("Luminance:");
+                                }
+                                ui.label(format!("{} ({})", p, p.imperial()));
+                                ui.end_row();
+                            }
+                            if data.quantity == Quantity::Irradiance {
+                                if let Ok(a) = circadian::calculate_alpha_opic(data) {
+                                    ui.label(t!("gui-melanopic-edi"));
+                                    ui.label(format!(
+                                        "{:.1} lx (DER {:.3})",
+                                        a.melanopic_edi(),
+                                        a.melanopic_der()
+                                    ));
+                                    ui.end_row();
+                                }
+                                if let Ok(c) = circadian::calculate_circadian_stimulus(
+                                    data,
+                                    &self.circadian_exposure,
+                                ) {
+                                    ui.label("CLA 2.0 / CS:");
+                                    ui.label(format!("{:.0} / {:.3}", c.cla, c.cs));
+                                    ui.end_row();
+                                }
+                            }
                             ui.label("Peak λ:");
                             ui.label(format!("{} nm", peak_wl));
                             ui.end_row();
@@ -1078,7 +1152,11 @@ impl SpectroApp {
         ui.add_space(5.0);
 
         if let Some(data) = &self.last_result {
-            ui.label(egui::RichText::new("Spectral Values (380-780nm, 10nm steps)").strong());
+            let range = match (data.wavelengths.first(), data.wavelengths.last()) {
+                (Some(first), Some(last)) => format!("{:.0}-{:.0}nm", first, last),
+                _ => "empty".to_string(),
+            };
+            ui.label(egui::RichText::new(format!("Spectral Values ({})", range)).strong());
             ui.add_space(5.0);
 
             // Scrollable table of values
@@ -1098,15 +1176,12 @@ impl SpectroApp {
                             ui.end_row();
 
                             // Values in two columns
-                            for i in (0..data.values.len()).step_by(2) {
-                                let wl1 = 380 + i * 10;
-                                ui.label(format!("{}", wl1));
-                                ui.label(format!("{:.6}", data.values[i]));
-
-                                if i + 1 < data.values.len() {
-                                    let wl2 = 380 + (i + 1) * 10;
-                                    ui.label(format!("{}", wl2));
-                                    ui.label(format!("{:.6}", data.values[i + 1]));
+                            let samples: Vec<(&f32, &f32)> =
+                                data.wavelengths.iter().zip(data.values.iter()).collect();
+                            for pair in samples.chunks(2) {
+                                for (wl, v) in pair {
+                                    ui.label(format!("{:.1}", wl));
+                                    ui.label(format!("{:.6}", v));
                                 }
                                 ui.end_row();
                             }
@@ -1152,13 +1227,19 @@ impl SpectroApp {
         ui.add_space(5.0);
 
         ui.collapsing("🎯 White Point Reference", |ui| {
-            let wp = illuminant::D65_2;
+            let wp = self
+                .selected_illuminant
+                .get_white_point(self.selected_observer);
             egui::Grid::new("wp_grid")
                 .num_columns(2)
                 .spacing([20.0, 4.0])
                 .show(ui, |ui| {
                     ui.label("Illuminant:");
-                    ui.label("D65 (2° Observer)");
+                    ui.label(format!(
+                        "{:?} ({})",
+                        self.selected_illuminant,
+                        self.selected_observer.description()
+                    ));
                     ui.end_row();
                     ui.label("Xn:");
                     ui.label(format!("{:.5}", wp.x));
@@ -1173,7 +1254,10 @@ impl SpectroApp {
         });
 
         ui.collapsing("📐 Observer Functions", |ui| {
-            ui.label("Currently using: CIE 1931 2° Standard Observer");
+            ui.label(format!(
+                "Currently using: {}",
+                self.selected_observer.description()
+            ));
             ui.add_space(5.0);
 
             // Option to show CMF plot
@@ -1214,7 +1298,7 @@ impl SpectroApp {
                 };
                 let lab = xyz_norm.to_lab(illuminant::D65_2);
 
-                ui.label(format!("Mode: {:?}", data.mode));
+                ui.label(format!("Quantity: {:?}", data.quantity));
                 ui.add_space(5.0);
 
                 egui::Grid::new("calc_grid")
@@ -1239,29 +1323,48 @@ impl SpectroApp {
     }
 
     fn render_chromaticity_tab(&self, ui: &mut egui::Ui) {
+        let observer = self.selected_observer;
+        // Observers built from the cone fundamentals get an (l,m) diagram
+        let cone_based = observer.lms_1nm().is_some();
+        let coords = |xyz: XYZ| -> [f64; 2] {
+            let (u, v) = match xyz.to_lms(observer) {
+                Some(lms) => lms.to_chromaticity(),
+                None => xyz.to_chromaticity(),
+            };
+            [u as f64, v as f64]
+        };
+
         ui.add_space(5.0);
-        ui.heading("🎯 CIE 1931 xy Chromaticity");
+        if cone_based {
+            ui.heading(format!("🎯 (l,m) Chromaticity, {}", observer.description()));
+        } else {
+            ui.heading(format!("🎯 xy Chromaticity, {}", observer.description()));
+        }
         ui.add_space(10.0);
 
         let plot = Plot::new("chromaticity_plot")
             .data_aspect(1.0)
             .view_aspect(1.0)
             .include_x(0.0)
-            .include_x(0.8)
+            .include_x(if cone_based { 1.0 } else { 0.8 })
             .include_y(0.0)
-            .include_y(0.9)
+            .include_y(if cone_based { 0.6 } else { 0.9 })
             .legend(Legend::default())
             .allow_zoom(true)
             .allow_drag(true);
 
         let visuals = ui.ctx().style().visuals.clone();
         plot.show(ui, |plot_ui| {
-            // 1. Draw Spectral Locus (Horseshoe)
+            // 1. Draw Spectral Locus (Horseshoe), 5nm steps
             let mut locus_points = Vec::new();
-            for i in 0..41 {
-                let sum = X_BAR_2[i] + Y_BAR_2[i] + Z_BAR_2[i];
-                if sum > 0.0 {
-                    locus_points.push([(X_BAR_2[i] / sum) as f64, (Y_BAR_2[i] / sum) as f64]);
+            for cmf in observer.cmfs_1nm().iter().step_by(5) {
+                let xyz = XYZ {
+                    x: cmf[0],
+                    y: cmf[1],
+                    z: cmf[2],
+                };
+                if xyz.x + xyz.y + xyz.z > 1e-4 {
+                    locus_points.push(coords(xyz));
                 }
             }
             // Close the horseshoe with the purple line (connect 380nm to 780nm)
@@ -1275,14 +1378,13 @@ impl SpectroApp {
                     .name("Spectral Locus"),
             );
 
-            // 2. Draw D65 White Point
-            let d65_x = 0.31272;
-            let d65_y = 0.32903;
+            // 2. Draw White Point of the selected illuminant
+            let wp = coords(self.selected_illuminant.get_white_point(observer));
             plot_ui.points(
-                Points::new(vec![[d65_x, d65_y]])
+                Points::new(vec![wp])
                     .color(plot_line_color(&visuals))
                     .shape(egui_plot::MarkerShape::Plus)
-                    .name("D65"),
+                    .name(format!("{:?}", self.selected_illuminant)),
             );
 
             // 3. Draw History Trail (Faded)
@@ -1290,11 +1392,7 @@ impl SpectroApp {
                 .measurement_history
                 .iter()
                 .rev() // Draw from oldest to newest
-                .map(|e| {
-                    let xyz = e.data.to_xyz();
-                    let (x, y) = xyz.to_chromaticity();
-                    [x as f64, y as f64]
-                })
+                .map(|e| coords(e.data.to_xyz_ext(self.selected_illuminant, observer)))
                 .collect();
 
             if history_points.len() > 1 {
@@ -1307,10 +1405,9 @@ impl SpectroApp {
 
             // 4. Draw Current Point
             if let Some(data) = &self.last_result {
-                let xyz = data.to_xyz();
-                let (x, y) = xyz.to_chromaticity();
+                let xyz = data.to_xyz_ext(self.selected_illuminant, observer);
                 plot_ui.points(
-                    Points::new(vec![[x as f64, y as f64]])
+                    Points::new(vec![coords(xyz)])
                         .color(egui::Color32::RED)
                         .radius(4.0)
                         .name("Current Entry"),
@@ -1328,8 +1425,28 @@ impl SpectroApp {
         ui.add_space(10.0);
 
         if let Some(metrics) = &self.last_tm30 {
+            if ui.button(t!("gui-tm30-export-report")).clicked() {
+                self.export_tm30_report(metrics);
+            }
+            ui.add_space(5.0);
             let visualizer = crate::tm30_gui::Tm30Visualizer::new(metrics.clone());
             visualizer.ui(ui);
+
+            if let Some(data) = &self.last_result {
+                ui.add_space(20.0);
+                ui.heading(t!("gui-ssi"));
+                ui.horizontal(|ui| {
+                    for (label, reference) in [
+                        (t!("gui-ssi-tungsten"), SsiReference::Tungsten),
+                        (t!("gui-ssi-d55"), SsiReference::D55),
+                    ] {
+                        let value = calculate_ssi(data, &reference)
+                            .map_or_else(|_| "—".to_string(), |ssi| format!("{:.0}", ssi));
+                        ui.label(format!("{}: {}", label, value));
+                        ui.add_space(20.0);
+                    }
+                });
+            }
         } else {
             ui.vertical_centered(|ui| {
                 ui.add_space(50.0);
@@ -1340,6 +1457,183 @@ impl SpectroApp {
     }
 }
 
+/// Render the measurement history as CSV, one row per entry.
+fn history_csv(history: &[MeasurementEntry]) -> String {
+    let mut csv = String::from(
+        "Timestamp,Mode,Sample,L*,a*,b*,Illuminant,Observer,DeltaE,Device,Serial,Calibrated,\
+         IntegrationTime,Gain,Frames,StdDev,Condition,Geometry,Backing,Bandpass,Notes\n",
+    );
+    for entry in history {
+        let meta = &entry.data.metadata;
+        let opt = |v: Option<String>| v.unwrap_or_default();
+        // Per-band standard deviations in one field, in wavelength order
+        let std_dev = meta.std_dev.as_ref().map(|sd| {
+            sd.iter()
+                .map(|v| format!("{:.6}", v))
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        csv.push_str(&format!(
+            "{},{:?},{},{:.4},{:.4},{:.4},{:?},{:?},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            meta.timestamp
+                .map(format_unix_time)
+                .unwrap_or_else(|| entry.timestamp.clone()),
+            entry.mode,
+            csv_field(meta.sample_name.as_deref().unwrap_or_default()),
+            entry.lab.l,
+            entry.lab.a,
+            entry.lab.b,
+            entry.illuminant,
+            entry.observer,
+            entry.delta_e.map(|e| e.to_string()).unwrap_or_default(),
+            csv_field(meta.device_model.as_deref().unwrap_or_default()),
+            csv_field(meta.device_serial.as_deref().unwrap_or_default()),
+            opt(meta.calibration_timestamp.map(format_unix_time)),
+            opt(meta.integration_time.map(|t| format!("{:.4}", t))),
+            opt(meta.gain.map(|g| format!("{:?}", g))),
+            opt(meta.averaged_frames.map(|n| n.to_string())),
+            opt(std_dev),
+            meta.condition.map(|c| c.name()).unwrap_or_default(),
+            meta.geometry.map(|g| g.name()).unwrap_or_default(),
+            meta.backing.map(|b| b.name()).unwrap_or_default(),
+            opt(meta.bandpass.map(|b| b.to_string())),
+            csv_field(meta.notes.as_deref().unwrap_or_default()),
+        ));
+    }
+    csv
+}
+
+/// Render the measurement history as a CGATS (.ti3) file.
+///
+/// Instrument and condition keywords shared by every entry are written once
+/// in the header; those that differ between entries become per-sample fields.
+fn history_cgats(history: &[MeasurementEntry]) -> String {
+    let mut cgats = String::new();
+    cgats.push_str("CTI3\n\n");
+    cgats.push_str("DESCRIPTOR \"Argyll Device Measurement data\"\n");
+    cgats.push_str("ORIGINATOR \"spectro-rs\"\n");
+    cgats.push_str(&format!(
+        "CREATED \"{}\"\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+    ));
+
+    type Keyword = fn(&MeasurementMetadata) -> Option<String>;
+    let keywords: [(&str, Keyword); 6] = [
+        ("INSTRUMENTATION", |m| m.device_model.clone()),
+        ("INSTRUMENT_SERIAL", |m| m.device_serial.clone()),
+        ("MEASUREMENT_CONDITION", |m| {
+            m.condition.map(|c| c.name().to_string())
+        }),
+        ("MEASUREMENT_GEOMETRY", |m| {
+            m.geometry.map(|g| g.name().to_string())
+        }),
+        ("SAMPLE_BACKING", |m| {
+            m.backing.map(|b| b.name().to_string())
+        }),
+        ("CALIBRATION_DATE", |m| {
+            m.calibration_timestamp.map(format_unix_time)
+        }),
+    ];
+    let mut per_sample = Vec::new();
+    for (keyword, value) in keywords {
+        let values: Vec<_> = history.iter().map(|e| value(&e.data.metadata)).collect();
+        if values.iter().all(|v| *v == values[0]) {
+            if let Some(v) = &values[0] {
+                cgats.push_str(&format!("{} \"{}\"\n", keyword, v.replace('"', "'")));
+            }
+        } else {
+            cgats.push_str(&format!("KEYWORD \"{}\"\n", keyword));
+            per_sample.push((keyword, values));
+        }
+    }
+    cgats.push('\n');
+
+    // Define fields: ID, Lab, XYZ, per-sample keywords and spectral data on
+    // the first entry's wavelength grid
+    let grid = history[0].data.wavelengths.clone();
+    cgats.push_str(&format!(
+        "NUMBER_OF_FIELDS {}\n",
+        8 + per_sample.len() + grid.len()
+    ));
+    cgats.push_str("BEGIN_DATA_FORMAT\n");
+    cgats.push_str("SAMPLE_ID SAMPLE_NAME LAB_L LAB_A LAB_B XYZ_X XYZ_Y XYZ_Z ");
+    for (keyword, _) in &per_sample {
+        cgats.push_str(&format!("{} ", keyword));
+    }
+    for wl in &grid {
+        cgats.push_str(&format!("SPEC_{:.0} ", wl));
+    }
+    cgats.push_str("\nEND_DATA_FORMAT\n\n");
+
+    cgats.push_str(&format!("NUMBER_OF_SETS {}\n", history.len()));
+    cgats.push_str("BEGIN_DATA\n");
+
+    for (i, entry) in history.iter().enumerate() {
+        let xyz = entry.data.to_xyz();
+        let name = entry
+            .data
+            .metadata
+            .sample_name
+            .as_deref()
+            .unwrap_or(&entry.timestamp)
+            .replace('"', "'");
+        cgats.push_str(&format!(
+            "{} \"{}\" {:.4} {:.4} {:.4} {:.4} {:.4} {:.4} ",
+            i + 1,
+            name,
+            entry.lab.l,
+            entry.lab.a,
+            entry.lab.b,
+            xyz.x,
+            xyz.y,
+            xyz.z
+        ));
+        for (_, values) in &per_sample {
+            let value = values[i].as_deref().unwrap_or_default().replace('"', "'");
+            cgats.push_str(&format!("\"{}\" ", value));
+        }
+
+        // Entries on another grid are resampled; unusable data is
+        // written as zeros to keep the row complete
+        let values = if entry.data.same_grid(&history[0].data) {
+            entry.data.values.clone()
+        } else {
+            entry
+                .data
+                .try_resample_to(grid.clone())
+                .map(|d| d.values)
+                .unwrap_or_else(|_| vec![0.0; grid.len()])
+        };
+        for val in &values {
+            cgats.push_str(&format!("{:.6} ", val));
+        }
+        cgats.push('\n');
+    }
+
+    cgats.push_str("END_DATA\n");
+    cgats
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Format a UNIX timestamp (seconds) as local date and time.
+fn format_unix_time(secs: u64) -> String {
+    chrono::DateTime::from_timestamp(secs as i64, 0)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
 // ============================================================================
 // eframe::App Implementation
 // ============================================================================
@@ -1359,10 +1653,20 @@ impl eframe::App for SpectroApp {
                         self.is_calibrated = true;
                         self.calibration_wizard.on_calibration_success();
                     }
+                    if msg.contains("Transmission reference captured") {
+                        self.has_transmission_ref = true;
+                    }
                     self.status_msg = msg;
                     self.is_busy = false;
                 }
-                UIUpdate::Result(data, tm30) => {
+                UIUpdate::Result(mut data, tm30) => {
+                    self.annotate(&mut data);
+                    if let Some(method) = self.bandpass_correction {
+                        match data.correct_bandpass(method) {
+                            Ok(corrected) => data = corrected,
+                            Err(e) => self.status_msg = e.to_string(),
+                        }
+                    }
                     self.add_to_history(data.clone());
                     self.last_result = Some(data);
                     self.last_tm30 = tm30.map(|b| *b);
@@ -1482,6 +1786,11 @@ impl eframe::App for SpectroApp {
                             MeasurementMode::Reflective => format!("📄 {}", t!("gui-reflective")),
                             MeasurementMode::Emissive => format!("🖥️ {}", t!("gui-emissive")),
                             MeasurementMode::Ambient => format!("💡 {}", t!("gui-ambient")),
+                            MeasurementMode::Projector => format!("📽️ {}", t!("gui-projector")),
+                            MeasurementMode::Flash => format!("⚡ {}", t!("gui-flash")),
+                            MeasurementMode::Transmissive => {
+                                format!("🎞️ {}", t!("gui-transmissive"))
+                            }
                         })
                         .show_ui(ui, |ui| {
                             ui.selectable_value(
@@ -1499,13 +1808,63 @@ impl eframe::App for SpectroApp {
                                 MeasurementMode::Ambient,
                                 format!("💡 {}", t!("gui-ambient")),
                             );
+                            ui.selectable_value(
+                                &mut self.selected_mode,
+                                MeasurementMode::Projector,
+                                format!("📽️ {}", t!("gui-projector")),
+                            );
+                            ui.selectable_value(
+                                &mut self.selected_mode,
+                                MeasurementMode::Flash,
+                                format!("⚡ {}", t!("gui-flash")),
+                            );
+                            ui.selectable_value(
+                                &mut self.selected_mode,
+                                MeasurementMode::Transmissive,
+                                format!("🎞️ {}", t!("gui-transmissive")),
+                            );
                         });
 
+                    // Transmissive: light source reference capture
+                    if self.selected_mode == MeasurementMode::Transmissive {
+                        egui::ComboBox::from_id_salt("transmission_source")
+                            .selected_text(match self.transmission_source {
+                                MeasurementMode::Ambient => t!("gui-ambient"),
+                                _ => t!("gui-emissive"),
+                            })
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    &mut self.transmission_source,
+                                    MeasurementMode::Emissive,
+                                    t!("gui-emissive"),
+                                );
+                                ui.selectable_value(
+                                    &mut self.transmission_source,
+                                    MeasurementMode::Ambient,
+                                    t!("gui-ambient"),
+                                );
+                            });
+
+                        let ref_btn = ui.add_enabled(
+                            !self.is_busy && self.is_connected,
+                            egui::Button::new(format!("💡 {}", t!("gui-capture-reference"))),
+                        );
+                        if ref_btn.clicked() {
+                            self.is_busy = true;
+                            self.cmd_tx
+                                .send(DeviceCommand::CaptureReference(self.transmission_source))
+                                .ok();
+                        }
+                    }
+
                     ui.separator();
 
                     // Main action buttons
                     let measure_btn = ui.add_enabled(
-                        !self.is_busy && self.is_connected,
+                        !self.is_busy
+                            && self.is_connected
+                            && (self.selected_mode != MeasurementMode::Transmissive
+                                || self.has_transmission_ref),
                         egui::Button::new(format!("🚀 {}", t!("gui-measure")))
                             .min_size(egui::vec2(100.0, 30.0)),
                     );
@@ -1607,57 +1966,144 @@ impl eframe::App for SpectroApp {
                         .show(ui, |ui| {
                             ui.label(t!("gui-illuminant"));
                             egui::ComboBox::from_id_salt("illuminant_selector_settings")
-                                .selected_text(format!("{:?}", self.selected_illuminant))
+                                .selected_text(self.selected_illuminant.description())
                                 .show_ui(ui, |ui| {
-                                    ui.selectable_value(
-                                        &mut self.selected_illuminant,
-                                        Illuminant::D65,
-                                        "D65 (Daylight, sRGB)",
-                                    );
-                                    ui.selectable_value(
-                                        &mut self.selected_illuminant,
-                                        Illuminant::D50,
-                                        "D50 (Print Industry)",
-                                    );
-                                    ui.selectable_value(
-                                        &mut self.selected_illuminant,
-                                        Illuminant::A,
-                                        "A (Tungsten 2856K)",
-                                    );
-                                    ui.selectable_value(
-                                        &mut self.selected_illuminant,
-                                        Illuminant::F2,
-                                        "F2 (Cool White Fluorescent)",
-                                    );
-                                    ui.selectable_value(
-                                        &mut self.selected_illuminant,
-                                        Illuminant::F7,
-                                        "F7 (Daylight Fluorescent)",
-                                    );
-                                    ui.selectable_value(
-                                        &mut self.selected_illuminant,
-                                        Illuminant::F11,
-                                        "F11 (TL84)",
-                                    );
+                                    for illuminant in Illuminant::ALL {
+                                        ui.selectable_value(
+                                            &mut self.selected_illuminant,
+                                            illuminant,
+                                            illuminant.description(),
+                                        );
+                                    }
                                 });
                             ui.end_row();
 
                             ui.label(t!("gui-observer"));
                             egui::ComboBox::from_id_salt("observer_selector_settings")
-                                .selected_text(match self.selected_observer {
-                                    Observer::CIE1931_2 => "2° (Standard)",
-                                    Observer::CIE1964_10 => "10° (Supplementary)",
+                                .selected_text(self.selected_observer.description())
+                                .show_ui(ui, |ui| {
+                                    for observer in Observer::ALL {
+                                        ui.selectable_value(
+                                            &mut self.selected_observer,
+                                            observer,
+                                            observer.description(),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+
+                            ui.label(t!("gui-bandpass"));
+                            ui.horizontal(|ui| {
+                                egui::ComboBox::from_id_salt("bandpass_selector")
+                                    .selected_text(match self.bandpass_correction {
+                                        Some(method) => method.name().to_string(),
+                                        None => t!("gui-bandpass-off"),
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(
+                                            &mut self.bandpass_correction,
+                                            None,
+                                            t!("gui-bandpass-off"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut self.bandpass_correction,
+                                            Some(BandpassCorrection::Stearns),
+                                            "Stearns & Stearns",
+                                        );
+                                        if ui
+                                            .selectable_label(
+                                                matches!(
+                                                    self.bandpass_correction,
+                                                    Some(BandpassCorrection::E2729 { .. })
+                                                ),
+                                                "ASTM E2729",
+                                            )
+                                            .clicked()
+                                        {
+                                            self.bandpass_correction =
+                                                Some(BandpassCorrection::E2729 { bandwidth: 10.0 });
+                                        }
+                                    });
+                                if let Some(BandpassCorrection::E2729 { bandwidth }) =
+                                    &mut self.bandpass_correction
+                                {
+                                    ui.label(t!("gui-bandpass-width"));
+                                    ui.add(
+                                        egui::DragValue::new(bandwidth)
+                                            .range(1.0..=20.0)
+                                            .speed(0.1)
+                                            .suffix(" nm"),
+                                    );
+                                }
+                            });
+                            ui.end_row();
+                        });
+
+                    ui.add_space(20.0);
+                    ui.separator();
+                    ui.heading(t!("gui-circadian-title"));
+                    ui.add_space(10.0);
+
+                    egui::Grid::new("circadian_settings_grid")
+                        .num_columns(2)
+                        .spacing([20.0, 10.0])
+                        .show(ui, |ui| {
+                            ui.label(t!("gui-circadian-duration"));
+                            ui.add(
+                                egui::DragValue::new(&mut self.circadian_exposure.duration)
+                                    .range(0.1..=12.0)
+                                    .speed(0.1)
+                                    .suffix(" h"),
+                            );
+                            ui.end_row();
+
+                            ui.label(t!("gui-circadian-field"));
+                            ui.add(
+                                egui::DragValue::new(&mut self.circadian_exposure.field_factor)
+                                    .range(0.1..=1.0)
+                                    .speed(0.01),
+                            );
+                            ui.end_row();
+                        });
+
+                    ui.add_space(20.0);
+                    ui.separator();
+                    ui.heading(t!("gui-sample-title"));
+                    ui.add_space(10.0);
+
+                    egui::Grid::new("sample_settings_grid")
+                        .num_columns(2)
+                        .spacing([20.0, 10.0])
+                        .show(ui, |ui| {
+                            ui.label(t!("gui-sample-name"));
+                            ui.text_edit_singleline(&mut self.sample_name);
+                            ui.end_row();
+
+                            ui.label(t!("gui-sample-notes"));
+                            ui.text_edit_multiline(&mut self.sample_notes);
+                            ui.end_row();
+
+                            ui.label(t!("gui-backing"));
+                            egui::ComboBox::from_id_salt("backing_selector")
+                                .selected_text(match self.sample_backing {
+                                    Some(backing) => backing.name().to_string(),
+                                    None => t!("gui-backing-unspecified"),
                                 })
                                 .show_ui(ui, |ui| {
                                     ui.selectable_value(
-                                        &mut self.selected_observer,
-                                        Observer::CIE1931_2,
-                                        "2° (CIE 1931 Standard)",
+                                        &mut self.sample_backing,
+                                        None,
+                                        t!("gui-backing-unspecified"),
                                     );
                                     ui.selectable_value(
-                                        &mut self.selected_observer,
-                                        Observer::CIE1964_10,
-                                        "10° (CIE 1964 Large Field)",
+                                        &mut self.sample_backing,
+                                        Some(Backing::White),
+                                        Backing::White.name(),
+                                    );
+                                    ui.selectable_value(
+                                        &mut self.sample_backing,
+                                        Some(Backing::Black),
+                                        Backing::Black.name(),
                                     );
                                 });
                             ui.end_row();
@@ -1828,6 +2274,9 @@ impl eframe::App for SpectroApp {
                                         MeasurementMode::Reflective => "📄",
                                         MeasurementMode::Emissive => "🖥️",
                                         MeasurementMode::Ambient => "💡",
+                                        MeasurementMode::Projector => "📽️",
+                                        MeasurementMode::Flash => "⚡",
+                                        MeasurementMode::Transmissive => "🎞️",
                                     };
                                     ui.label(
                                         egui::RichText::new(format!(
@@ -1836,6 +2285,9 @@ impl eframe::App for SpectroApp {
                                         ))
                                         .small(),
                                     );
+                                    if let Some(name) = &entry.data.metadata.sample_name {
+                                        ui.label(egui::RichText::new(name).small().strong());
+                                    }
                                     ui.label(
                                         egui::RichText::new(format!(
                                             "L:{:.0} a:{:.0} b:{:.0}",
@@ -1922,7 +2374,12 @@ impl eframe::App for SpectroApp {
                     let highlight = match self.selected_mode {
                         MeasurementMode::Reflective => "REFLECTIVE",
                         MeasurementMode::Emissive => "EMISSIVE",
-                        MeasurementMode::Ambient => "AMBIENT",
+                        MeasurementMode::Ambient | MeasurementMode::Flash => "AMBIENT",
+                        MeasurementMode::Projector => "PROJECTOR",
+                        MeasurementMode::Transmissive => match self.transmission_source {
+                            MeasurementMode::Ambient => "AMBIENT",
+                            _ => "EMISSIVE",
+                        },
                     };
                     CalibrationWizard::render_dial_check(ctx, highlight);
                 }
@@ -1932,3 +2389,66 @@ impl eframe::App for SpectroApp {
         ctx.request_repaint();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(serial: &str) -> MeasurementEntry {
+        let mut data = SpectralData::with_quantity(vec![0.5; 36], Quantity::Reflectance);
+        data.metadata = MeasurementMetadata {
+            timestamp: Some(1_700_000_000),
+            device_model: Some("ColorMunki".into()),
+            device_serial: Some(serial.into()),
+            averaged_frames: Some(3),
+            std_dev: Some(vec![0.01; 36]),
+            ..MeasurementMetadata::default()
+        };
+        MeasurementEntry {
+            timestamp: "12:00:00".into(),
+            mode: MeasurementMode::Reflective,
+            data,
+            lab: Lab {
+                l: 76.0,
+                a: 0.0,
+                b: 0.0,
+            },
+            illuminant: Illuminant::D50,
+            observer: Observer::CIE1931_2,
+            delta_e: None,
+        }
+    }
+
+    #[test]
+    fn test_history_csv_rows() {
+        let csv = history_csv(&[entry("A1")]);
+        let lines: Vec<_> = csv.lines().collect();
+        let header: Vec<_> = lines[0].split(',').collect();
+        let row: Vec<_> = lines[1].split(',').collect();
+        assert_eq!(header.len(), row.len());
+
+        let field = |name: &str| row[header.iter().position(|h| *h == name).unwrap()];
+        assert_eq!(field("Timestamp"), format_unix_time(1_700_000_000));
+        assert_eq!(field("Frames"), "3");
+        assert_eq!(field("StdDev").split(' ').count(), 36);
+        assert!(field("StdDev").starts_with("0.010000 "));
+    }
+
+    #[test]
+    fn test_history_cgats_keywords() {
+        let cgats = history_cgats(&[entry("A1"), entry("B2")]);
+        // Shared by both entries: once in the header
+        assert!(cgats.contains("INSTRUMENTATION \"ColorMunki\"\n"));
+        // Differing: a per-sample field
+        assert!(!cgats.contains("INSTRUMENT_SERIAL \""));
+        assert!(cgats.contains("KEYWORD \"INSTRUMENT_SERIAL\"\n"));
+        assert!(cgats.contains("NUMBER_OF_FIELDS 45\n"));
+        assert!(cgats.contains("XYZ_Z INSTRUMENT_SERIAL SPEC_380 "));
+        assert!(cgats.contains(" \"A1\" 0.500000 "));
+        assert!(cgats.contains(" \"B2\" 0.500000 "));
+
+        let cgats = history_cgats(&[entry("A1"), entry("A1")]);
+        assert!(cgats.contains("INSTRUMENT_SERIAL \"A1\"\n"));
+        assert!(cgats.contains("NUMBER_OF_FIELDS 44\n"));
+    }
+}
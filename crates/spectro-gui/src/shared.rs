@@ -1,6 +1,9 @@
 //! Shared types for communication between UI and device worker threads.
 
-use spectro_rs::{colorimetry::Lab, tm30::TM30Metrics, DeviceInfo, MeasurementMode, SpectralData};
+use spectro_rs::{
+    colorimetry::Lab, tm30::TM30Metrics, DeviceInfo, Illuminant, MeasurementMode, Observer,
+    SpectralData,
+};
 
 // ============================================================================
 // Device Information Structures
@@ -32,6 +35,9 @@ pub struct MeasurementEntry {
     pub mode: MeasurementMode,
     pub data: SpectralData,
     pub lab: Lab,
+    /// Illuminant and observer `lab` was computed with
+    pub illuminant: Illuminant,
+    pub observer: Observer,
     pub delta_e: Option<f32>,
 }
 
@@ -44,6 +50,9 @@ pub enum DeviceCommand {
     Connect,
     Calibrate,
     Measure(MeasurementMode),
+    /// Capture the bare light source, read in the given mode, as the
+    /// reference for transmissive measurements.
+    CaptureReference(MeasurementMode),
 }
 
 /// Messages sent from the Device worker thread to the UI thread.
@@ -1,5 +1,5 @@
 use eframe::egui;
-use spectro_rs::tm30::TM30Metrics;
+use spectro_rs::tm30::{DesignIntent, TM30Metrics};
 
 use crate::t;
 use crate::theme::{
@@ -136,6 +136,26 @@ impl Tm30Visualizer {
                 "CCT: {:.0} K (Duv: {:.4})",
                 self.metrics.cct, self.metrics.duv
             ));
+            ui.label(format!(
+                "{}: {:.1}",
+                t!("gui-tm30-skin"),
+                self.metrics.rf_skin
+            ));
+            ui.horizontal(|ui| {
+                ui.label(format!("{}:", t!("gui-tm30-intent")));
+                for intent in DesignIntent::ALL {
+                    let (name, prefix) = match intent {
+                        DesignIntent::Preference => (t!("gui-tm30-intent-preference"), "P"),
+                        DesignIntent::Fidelity => (t!("gui-tm30-intent-fidelity"), "F"),
+                        DesignIntent::Vividness => (t!("gui-tm30-intent-vividness"), "V"),
+                    };
+                    let level = match self.metrics.priority_level(intent) {
+                        Some(level) => format!("{}{}", prefix, level),
+                        None => t!("gui-tm30-intent-none").to_string(),
+                    };
+                    ui.label(format!("{} {}", name, level));
+                }
+            });
 
             ui.add_space(20.0);
             ui.heading(t!("gui-tm30-ces"));
@@ -185,10 +205,7 @@ impl Tm30Visualizer {
                         };
                         ui.colored_label(chroma_color, format!("{:.1}%", chroma_shift));
 
-                        ui.label(format!(
-                            "{:.1}°",
-                            self.metrics.bin_hue_shift[i].to_degrees()
-                        ));
+                        ui.label(format!("{:.2}", self.metrics.bin_hue_shift[i]));
                         ui.end_row();
                     }
                 });